@@ -0,0 +1,121 @@
+use crate::board::Board;
+use crate::card::Card;
+use crate::hand::{Hand, HandValue, HoleCards};
+
+use super::combos::{hole_card_combos, score_with_board};
+
+/// The weakest hole cards beating a hand, see `minimal_beating_hand`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BeatingHand {
+    /// The hole cards, ordered by descending rank.
+    pub hole_cards: HoleCards,
+    /// The score of the hole cards together with the board.
+    pub score: u32,
+    /// The description of the hand, e.g. "Two Pair, Kings and Twos".
+    pub description: String,
+}
+
+/// Finds the weakest hole cards that still beat a hand on the board, e.g.
+/// to explain that a call needed at least a certain hand.
+///
+/// Every combination of hole cards not holding a card of the target or the
+/// board is evaluated with the board, and the one with the lowest score
+/// above the target's wins. Of several combinations with that score, the
+/// first in the order of a fresh `Deck` is returned.
+///
+/// # Arguments
+///
+/// * `target` - The hand to beat. Its cards are combined with the board, so
+///   either the hole cards alone or together with the board can be given.
+/// * `board` - The community cards.
+///
+/// # Returns
+///
+/// * `Option<BeatingHand>` - The weakest beating hole cards with their
+///   score and description, or `None` if the target is the nuts.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::minimal_beating_hand;
+/// use pkr::board::Board;
+/// use pkr::hand::Hand;
+///
+/// let board = Board::new_from_str("Kh 9d 5c 2s 2h").unwrap();
+/// let target = Hand::new_from_str("Kd Qd").unwrap();
+///
+/// let beating = minimal_beating_hand(&target, &board).unwrap();
+/// assert_eq!(beating.hole_cards.to_string(), "AhKc");
+/// assert_eq!(beating.description, "Two Pair, Kings and Twos");
+/// ```
+///
+/// # Panics
+///
+/// Panics if the target and the board together hold more than
+/// `Hand::MAX_CARDS` distinct cards.
+pub fn minimal_beating_hand(target: &Hand, board: &Board) -> Option<BeatingHand> {
+    let mut dead: Vec<Card> = board.cards().to_vec();
+    dead.extend(target.iter().filter(|card| !board.cards().contains(card)));
+    let target_score = Hand::new(dead.clone())
+        .expect("The target and the board must form a valid hand")
+        .get_score();
+
+    let (score, [first, second]) = hole_card_combos(&dead)
+        .into_iter()
+        .map(|combo| (score_with_board(&combo, board.cards()), combo))
+        .filter(|&(score, _)| score > target_score)
+        .min_by_key(|&(score, _)| score)?;
+    Some(BeatingHand {
+        hole_cards: HoleCards::new(first, second).expect("Combinations hold distinct cards"),
+        score,
+        description: HandValue::new(score).describe(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(s: &str) -> Hand {
+        Hand::new_from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_kicker_decides() {
+        let board = Board::new_from_str("Kh 9d 5c 2s 2h").unwrap();
+        // Kings and twos with a queen kicker lose to an ace kicker only.
+        let beating = minimal_beating_hand(&hand("Kd Qd"), &board).unwrap();
+        assert_eq!(beating.score, hand("Kh Kc 2s 2h Ah").get_score());
+        assert_eq!(
+            minimal_beating_hand(&hand("Kd Qd Kh 9d 5c 2s 2h"), &board),
+            Some(beating)
+        );
+    }
+
+    #[test]
+    fn test_single_beating_combo() {
+        // The nine of spades blocks the king high straight flush, so only
+        // the royal flush beats the queen high straight flush.
+        let board = Board::new_from_str("Qs Js Ts 2d 3c").unwrap();
+        let beating = minimal_beating_hand(&hand("9s 8s"), &board).unwrap();
+        assert_eq!(beating.hole_cards, HoleCards::new_from_str("AsKs").unwrap());
+        assert_eq!(beating.description, "Royal Flush");
+    }
+
+    #[test]
+    fn test_nuts() {
+        let board = Board::new_from_str("Qs Js Ts 2d 3c").unwrap();
+        assert_eq!(minimal_beating_hand(&hand("As Ks"), &board), None);
+        // Holding the king of spades blocks the only better hand.
+        assert_eq!(minimal_beating_hand(&hand("Ks 9s"), &board), None);
+    }
+
+    #[test]
+    fn test_flop() {
+        // On the flop the weakest hand above a set of twos is a set of
+        // fives.
+        let board = Board::new_from_str("Kh 5d 2c").unwrap();
+        let beating = minimal_beating_hand(&hand("2s 2h"), &board).unwrap();
+        assert_eq!(beating.hole_cards.to_string(), "5h5c");
+    }
+}
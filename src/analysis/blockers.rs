@@ -0,0 +1,266 @@
+use strum::IntoEnumIterator;
+
+use crate::board::Board;
+use crate::card::{Card, Suit};
+use crate::hand::HandRank;
+use crate::range::Range;
+
+use super::combos::score_with_board;
+
+/// The hand classes in a villain range tracked by the blocker analysis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum BlockerClass {
+    /// A flush or better made with the highest card of the flush suit that is
+    /// not on the board.
+    NutFlush,
+    /// Any flush or straight flush.
+    Flush,
+    /// A straight that is not a straight flush.
+    Straight,
+    /// A pocket pair matching exactly one board card.
+    Set,
+    /// A pocket pair above every board card.
+    Overpair,
+}
+
+/// The effect of the hero's cards on one hand class of the villain range.
+///
+/// All counts are weighted combination counts.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BlockerEntry {
+    pub class: BlockerClass,
+    /// The combinations of the class left when holding a neutral hand that
+    /// blocks none of them.
+    pub neutral: f64,
+    /// The combinations of the class left when holding the hero's cards.
+    pub remaining: f64,
+    /// The combinations removed by the hero's cards.
+    pub removed: f64,
+    /// The removed combinations as a percentage of the neutral count, or 0.0
+    /// if the class is empty.
+    pub removed_percent: f64,
+}
+
+/// The result of a blocker analysis, holding one entry per `BlockerClass`.
+///
+/// With the `serde` feature it serializes as `{"entries": [{"class":
+/// "NutFlush", "neutral": 9.0, "remaining": 0.0, "removed": 9.0,
+/// "removed_percent": 100.0}]}`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BlockerReport {
+    pub entries: Vec<BlockerEntry>,
+}
+
+impl BlockerReport {
+    /// Returns the entry of the given class.
+    pub fn get(&self, class: BlockerClass) -> &BlockerEntry {
+        self.entries
+            .iter()
+            .find(|entry| entry.class == class)
+            .expect("Every class has an entry")
+    }
+}
+
+/// Quantifies how many combinations of each relevant hand class in a villain
+/// range are removed by the hero's hole cards.
+///
+/// Combinations containing a board card are never counted. The remaining
+/// combinations are classified on the current board and compared against
+/// holding a neutral hand, i.e. one that blocks none of them.
+///
+/// # Arguments
+///
+/// * `hero` - The hole cards of the hero.
+/// * `range` - The range of the villain.
+/// * `board` - The community cards.
+///
+/// # Returns
+///
+/// * `BlockerReport` - The neutral, remaining and removed combination counts
+///   of every `BlockerClass`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::{blockers, BlockerClass};
+/// use pkr::board::Board;
+/// use pkr::card::Card;
+/// use pkr::range::Range;
+///
+/// let hero = [
+///     Card::new_from_str("Ah").unwrap(),
+///     Card::new_from_str("Qd").unwrap(),
+/// ];
+/// let board = Board::new_from_str("Kh 9h 5h 2c 3d").unwrap();
+///
+/// let report = blockers(hero, &Range::full(), &board);
+/// assert_eq!(report.get(BlockerClass::NutFlush).remaining, 0.0);
+/// ```
+pub fn blockers(hero: [Card; 2], range: &Range, board: &Board) -> BlockerReport {
+    let board = board.cards();
+    let classes = [
+        BlockerClass::NutFlush,
+        BlockerClass::Flush,
+        BlockerClass::Straight,
+        BlockerClass::Set,
+        BlockerClass::Overpair,
+    ];
+    let mut neutral = [0.0; 5];
+    let mut remaining = [0.0; 5];
+
+    let nut_flush_card = nut_flush_card(board);
+
+    for (combo, weight) in range.get_combos() {
+        if board.contains(&combo[0]) || board.contains(&combo[1]) {
+            continue;
+        }
+        let blocked = hero.contains(&combo[0]) || hero.contains(&combo[1]);
+
+        for (i, class) in classes.iter().enumerate() {
+            if is_class(*class, combo, board, nut_flush_card) {
+                neutral[i] += weight;
+                if !blocked {
+                    remaining[i] += weight;
+                }
+            }
+        }
+    }
+
+    let entries = classes
+        .iter()
+        .enumerate()
+        .map(|(i, &class)| {
+            let removed = neutral[i] - remaining[i];
+            let removed_percent = if neutral[i] > 0.0 {
+                removed / neutral[i] * 100.0
+            } else {
+                0.0
+            };
+            BlockerEntry {
+                class,
+                neutral: neutral[i],
+                remaining: remaining[i],
+                removed,
+                removed_percent,
+            }
+        })
+        .collect();
+
+    BlockerReport { entries }
+}
+
+/// Returns the highest card of the suit with at least three board cards that
+/// is not on the board itself.
+fn nut_flush_card(board: &[Card]) -> Option<Card> {
+    let suit = Suit::iter().find(|&suit| board.iter().filter(|c| c.suit == suit).count() >= 3)?;
+
+    let mut candidates: Vec<Card> = crate::deck::Deck::new()
+        .get_cards()
+        .iter()
+        .filter(|card| card.suit == suit && !board.contains(card))
+        .copied()
+        .collect();
+    candidates.sort();
+    candidates.pop()
+}
+
+fn is_class(
+    class: BlockerClass,
+    combo: &[Card; 2],
+    board: &[Card],
+    nut_flush_card: Option<Card>,
+) -> bool {
+    let is_pocket_pair = combo[0].rank == combo[1].rank;
+
+    match class {
+        BlockerClass::NutFlush => match nut_flush_card {
+            Some(card) => combo.contains(&card) && is_flush(combo, board),
+            None => false,
+        },
+        BlockerClass::Flush => is_flush(combo, board),
+        BlockerClass::Straight => {
+            HandRank::from_score(score_with_board(combo, board)) == HandRank::Straight
+        }
+        BlockerClass::Set => {
+            is_pocket_pair && board.iter().filter(|c| c.rank == combo[0].rank).count() == 1
+        }
+        BlockerClass::Overpair => {
+            is_pocket_pair && !board.is_empty() && board.iter().all(|c| c.rank < combo[0].rank)
+        }
+    }
+}
+
+fn is_flush(combo: &[Card; 2], board: &[Card]) -> bool {
+    let hand_rank = HandRank::from_score(score_with_board(combo, board));
+    hand_rank == HandRank::Flush || hand_rank == HandRank::StraightFlush
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(s: &str) -> Vec<Card> {
+        s.split_whitespace()
+            .map(|c| Card::new_from_str(c).unwrap())
+            .collect()
+    }
+
+    fn hole(s: &str) -> [Card; 2] {
+        let cards = cards(s);
+        [cards[0], cards[1]]
+    }
+
+    #[test]
+    fn test_nut_flush_blocker() {
+        let board = Board::new_from_str("Kh 9h 5h 2c 3d").unwrap();
+
+        // The ace of hearts pairs with any of the nine live hearts.
+        let report = blockers(hole("Ah Qd"), &Range::full(), &board);
+        let nut_flush = report.get(BlockerClass::NutFlush);
+        assert_eq!(nut_flush.neutral, 9.0);
+        assert_eq!(nut_flush.remaining, 0.0);
+        assert_eq!(nut_flush.removed, 9.0);
+        assert_eq!(nut_flush.removed_percent, 100.0);
+
+        // Two live hearts out of ten remaining make C(10, 2) flushes.
+        let flush = report.get(BlockerClass::Flush);
+        assert_eq!(flush.neutral, 45.0);
+        assert_eq!(flush.remaining, 36.0);
+
+        // An offsuit ace blocks no flush at all.
+        let report = blockers(hole("Ac Kd"), &Range::full(), &board);
+        assert_eq!(report.get(BlockerClass::NutFlush).removed, 0.0);
+        assert_eq!(report.get(BlockerClass::Flush).removed, 0.0);
+    }
+
+    #[test]
+    fn test_set_and_overpair_blockers() {
+        let board = Board::new_from_str("Kh 9h 5h 2c 3d").unwrap();
+        let range = Range::new_from_str("AA, 99").unwrap();
+
+        let report = blockers(hole("As 9c"), &range, &board);
+
+        let sets = report.get(BlockerClass::Set);
+        assert_eq!(sets.neutral, 3.0);
+        assert_eq!(sets.remaining, 1.0);
+
+        let overpairs = report.get(BlockerClass::Overpair);
+        assert_eq!(overpairs.neutral, 6.0);
+        assert_eq!(overpairs.remaining, 3.0);
+        assert_eq!(overpairs.removed_percent, 50.0);
+    }
+
+    #[test]
+    fn test_straight_blockers() {
+        // A4 and 64 make a straight, suited hearts make a flush instead.
+        let board = Board::new_from_str("Kh 9h 5h 2c 3d").unwrap();
+        let report = blockers(hole("4c 4d"), &Range::full(), &board);
+
+        let straights = report.get(BlockerClass::Straight);
+        assert_eq!(straights.neutral, 30.0);
+        assert_eq!(straights.remaining, 14.0);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::hand::HandRank;
+
+/// Returns the lowest score a hand of the category can have, see
+/// `HandRank::score_range`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::worst_hand_of_category;
+/// use pkr::hand::{Hand, HandRank};
+///
+/// let wheel = Hand::new_from_str("5d 4c 3h 2s Ad").unwrap();
+/// assert_eq!(worst_hand_of_category(HandRank::Straight), wheel.get_score());
+/// ```
+pub fn worst_hand_of_category(rank: HandRank) -> u32 {
+    *rank.score_range().start()
+}
+
+/// Returns the highest score a hand of the category can have, see
+/// `HandRank::score_range`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::best_hand_of_category;
+/// use pkr::hand::{Hand, HandRank};
+///
+/// let aces_full = Hand::new_from_str("As Ah Ad Ks Kh").unwrap();
+/// assert_eq!(best_hand_of_category(HandRank::FullHouse), aces_full.get_score());
+/// ```
+pub fn best_hand_of_category(rank: HandRank) -> u32 {
+    *rank.score_range().end()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::Rank;
+    use crate::hand::{evaluate_with_wild_set, Hand, WildSpec, FIVE_OF_A_KIND};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use strum::IntoEnumIterator;
+
+    fn score(s: &str) -> u32 {
+        Hand::new_from_str(s).unwrap().get_score()
+    }
+
+    #[test]
+    fn test_bounds_are_scores_of_hands() {
+        let bounds = [
+            ("3s 2d", "As Kd Qh Js 9c"),
+            ("2s 2d", "As Ad Kh Qs Jc"),
+            ("3s 3d 2s 2d", "As Ad Ks Kd Qc"),
+            ("2s 2d 2c", "As Ad Ac Ks Qc"),
+            ("5d 4c 3h 2s Ad", "As Kd Qh Js Tc"),
+            ("7s 5s 4s 3s 2s", "As Ks Qs Js 9s"),
+            ("2s 2d 2c 3s 3d", "As Ad Ac Ks Kd"),
+            ("2s 2d 2c 2h", "As Ad Ac Ah Ks"),
+            ("5s 4s 3s 2s As", "As Ks Qs Js Ts"),
+        ];
+        for (rank, (worst, best)) in HandRank::iter().zip(bounds) {
+            assert_eq!(worst_hand_of_category(rank), score(worst), "{:?}", rank);
+            assert_eq!(best_hand_of_category(rank), score(best), "{:?}", rank);
+        }
+    }
+
+    #[test]
+    fn test_category_ranges_are_disjoint_and_ordered() {
+        // The nine categories of `HandRank` and five of a kind, which only
+        // wild cards make.
+        let mut ranges: Vec<(u32, u32)> = HandRank::iter()
+            .map(|rank| (worst_hand_of_category(rank), best_hand_of_category(rank)))
+            .collect();
+        let five_aces = Hand::new_from_str("As Ah Ad Ac 2s").unwrap();
+        let five_deuces = Hand::new_from_str("2s 2h 2d 2c 3s").unwrap();
+        ranges.push((
+            evaluate_with_wild_set(&five_deuces, &WildSpec::Rank(Rank::Three)),
+            evaluate_with_wild_set(&five_aces, &WildSpec::Rank(Rank::Two)),
+        ));
+        assert_eq!(ranges.len(), 10);
+        assert_eq!(ranges[9], (FIVE_OF_A_KIND + 2, FIVE_OF_A_KIND + 14));
+
+        for (i, &(worst, best)) in ranges.iter().enumerate() {
+            assert!(worst <= best);
+            if let Some(&(next_worst, _)) = ranges.get(i + 1) {
+                assert!(best < next_worst, "Category {} overlaps the next", i);
+            }
+        }
+        for rank in HandRank::iter() {
+            assert_eq!(HandRank::from_score(worst_hand_of_category(rank)), rank);
+            assert_eq!(HandRank::from_score(best_hand_of_category(rank)), rank);
+        }
+    }
+
+    #[test]
+    fn test_random_hands_within_bounds() {
+        let mut rng = StdRng::seed_from_u64(202);
+        for n in Hand::MIN_CARDS..=Hand::MAX_CARDS {
+            for _ in 0..2000 {
+                let hand = Hand::random(&mut rng, n);
+                assert!(
+                    hand.get_hand_rank()
+                        .score_range()
+                        .contains(&hand.get_score()),
+                    "{}",
+                    hand
+                );
+            }
+        }
+    }
+}
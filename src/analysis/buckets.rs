@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::board::Board;
+use crate::card::Rank;
+
+use super::{straights_possible, CanonicalFlop};
+
+/// The class of the highest card of a flop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HighCard {
+    Ace,
+    Broadway,
+    Middle,
+    Low,
+}
+
+/// Whether a flop has a pair or three of a kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pairing {
+    Unpaired,
+    Paired,
+    Trips,
+}
+
+/// The number of suits on a flop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Suitedness {
+    Rainbow,
+    TwoTone,
+    Monotone,
+}
+
+/// Whether a straight can be made on an unpaired flop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    Dry,
+    Connected,
+}
+
+/// The thresholds of a flop bucketing.
+///
+/// The default scheme puts flops with a king to a ten on top into
+/// `HighCard::Broadway`, flops of eights and lower into `HighCard::Low` and
+/// splits the buckets by suits and connectivity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BucketScheme {
+    /// The lowest top card of a broadway flop.
+    pub broadway_min: Rank,
+    /// The highest top card of a low flop.
+    pub low_max: Rank,
+    /// Whether the buckets are split by the number of suits.
+    pub split_suits: bool,
+    /// Whether the buckets of unpaired flops are split by whether a straight
+    /// can be made.
+    pub split_connectivity: bool,
+}
+
+impl Default for BucketScheme {
+    fn default() -> Self {
+        Self {
+            broadway_min: Rank::Ten,
+            low_max: Rank::Eight,
+            split_suits: true,
+            split_connectivity: true,
+        }
+    }
+}
+
+impl BucketScheme {
+    /// Returns the bucket of the flop of the board, or `None` preflop.
+    pub fn classify(&self, board: &Board) -> Option<FlopBucket> {
+        let flop = CanonicalFlop::of(board)?.to_board();
+        let mut ranks: Vec<Rank> = flop.cards().iter().map(|card| card.rank).collect();
+        ranks.sort_unstable_by(|a, b| b.cmp(a));
+
+        let top = ranks[0];
+        let high = if top == Rank::Ace {
+            HighCard::Ace
+        } else if top >= self.broadway_min {
+            HighCard::Broadway
+        } else if top <= self.low_max {
+            HighCard::Low
+        } else {
+            HighCard::Middle
+        };
+
+        ranks.dedup();
+        let pairing = match ranks.len() {
+            3 => Pairing::Unpaired,
+            2 => Pairing::Paired,
+            _ => Pairing::Trips,
+        };
+
+        let suits = self.split_suits.then(|| {
+            if flop.is_monotone() {
+                Suitedness::Monotone
+            } else if flop.is_two_tone() {
+                Suitedness::TwoTone
+            } else {
+                Suitedness::Rainbow
+            }
+        });
+
+        let connectivity = (self.split_connectivity && pairing == Pairing::Unpaired).then(|| {
+            if straights_possible(&flop).is_empty() {
+                Connectivity::Dry
+            } else {
+                Connectivity::Connected
+            }
+        });
+
+        Some(FlopBucket {
+            high,
+            pairing,
+            suits,
+            connectivity,
+        })
+    }
+}
+
+/// A group of flops that are played with a similar strategy, e.g. "ace-high
+/// dry rainbow" or "paired broadway two-tone".
+///
+/// The suits and connectivity are `None` if the scheme does not split the
+/// buckets by them. Paired flops are never split by connectivity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FlopBucket {
+    pub high: HighCard,
+    pub pairing: Pairing,
+    pub suits: Option<Suitedness>,
+    pub connectivity: Option<Connectivity>,
+}
+
+impl FlopBucket {
+    /// Returns the bucket of the flop of the board with the default scheme,
+    /// or `None` preflop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::analysis::FlopBucket;
+    /// use pkr::board::Board;
+    ///
+    /// let bucket = |s| FlopBucket::of(&Board::new_from_str(s).unwrap()).unwrap().to_string();
+    /// assert_eq!(bucket("As 7d 2c"), "ace-high dry rainbow");
+    /// assert_eq!(bucket("7s 6s 4d"), "low connected two-tone");
+    /// assert_eq!(bucket("Kh Kd Js"), "paired broadway rainbow");
+    /// ```
+    pub fn of(board: &Board) -> Option<Self> {
+        BucketScheme::default().classify(board)
+    }
+}
+
+impl fmt::Display for FlopBucket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.pairing {
+            Pairing::Unpaired => {}
+            Pairing::Paired => write!(f, "paired ")?,
+            Pairing::Trips => write!(f, "trips ")?,
+        }
+        let high = match self.high {
+            HighCard::Ace => "ace-high",
+            HighCard::Broadway => "broadway",
+            HighCard::Middle => "middle",
+            HighCard::Low => "low",
+        };
+        write!(f, "{}", high)?;
+        match self.connectivity {
+            Some(Connectivity::Dry) => write!(f, " dry")?,
+            Some(Connectivity::Connected) => write!(f, " connected")?,
+            None => {}
+        }
+        match self.suits {
+            Some(Suitedness::Rainbow) => write!(f, " rainbow"),
+            Some(Suitedness::TwoTone) => write!(f, " two-tone"),
+            Some(Suitedness::Monotone) => write!(f, " monotone"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Groups all 1755 canonical flops into the buckets of the scheme.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::{flop_buckets, BucketScheme};
+///
+/// let buckets = flop_buckets(&BucketScheme::default());
+/// let flops: usize = buckets.values().map(Vec::len).sum();
+/// assert_eq!(flops, 1755);
+/// ```
+pub fn flop_buckets(scheme: &BucketScheme) -> HashMap<FlopBucket, Vec<CanonicalFlop>> {
+    let mut buckets: HashMap<FlopBucket, Vec<CanonicalFlop>> = HashMap::new();
+    for flop in CanonicalFlop::all() {
+        let bucket = scheme
+            .classify(&flop.to_board())
+            .expect("A flop has a bucket");
+        buckets.entry(bucket).or_default().push(flop);
+    }
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bucket(s: &str) -> String {
+        FlopBucket::of(&Board::new_from_str(s).unwrap())
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_named_buckets() {
+        assert_eq!(bucket("As 7d 2c"), "ace-high dry rainbow");
+        assert_eq!(bucket("Ah 5h 4c"), "ace-high connected two-tone");
+        assert_eq!(bucket("7s 6s 4d"), "low connected two-tone");
+        assert_eq!(bucket("8s 5d 4s 9c"), "low connected two-tone");
+        assert_eq!(bucket("Kh Kd Js"), "paired broadway rainbow");
+        assert_eq!(bucket("Jc Tc 8c"), "broadway connected monotone");
+        assert_eq!(bucket("Jc 9c 2c"), "broadway dry monotone");
+        assert_eq!(bucket("9c 5d 2h"), "middle dry rainbow");
+        assert_eq!(bucket("6c 6d 6h"), "trips low rainbow");
+        assert_eq!(FlopBucket::of(&Board::Preflop), None);
+    }
+
+    #[test]
+    fn test_every_flop_in_one_bucket() {
+        let buckets = flop_buckets(&BucketScheme::default());
+        let total: usize = buckets.values().map(Vec::len).sum();
+        assert_eq!(total, 1755);
+
+        let mut seen = std::collections::HashSet::new();
+        for (bucket, flops) in &buckets {
+            for flop in flops {
+                assert!(seen.insert(*flop));
+                assert_eq!(FlopBucket::of(&flop.to_board()), Some(*bucket));
+            }
+        }
+        assert_eq!(seen.len(), 1755);
+    }
+
+    #[test]
+    fn test_coarse_scheme() {
+        let scheme = BucketScheme {
+            split_suits: false,
+            split_connectivity: false,
+            ..BucketScheme::default()
+        };
+        let buckets = flop_buckets(&scheme);
+        // Four high card classes of unpaired and paired flops and the trips.
+        assert_eq!(buckets.len(), 4 + 4 + 4);
+        assert_eq!(buckets.values().map(Vec::len).sum::<usize>(), 1755);
+
+        let trips: usize = buckets
+            .iter()
+            .filter(|(bucket, _)| bucket.pairing == Pairing::Trips)
+            .map(|(_, flops)| flops.len())
+            .sum();
+        assert_eq!(trips, 13);
+    }
+}
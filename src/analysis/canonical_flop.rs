@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::board::Board;
+use crate::card::Card;
+use crate::combinations::combinations_array;
+use crate::deck::Deck;
+use crate::hand::CanonicalHand;
+
+/// A flop up to the order of its cards and a relabeling of the suits.
+///
+/// The 22100 possible flops fall into 1755 strategically different classes,
+/// e.g. "Ah Kh 2c" and "As Ks 2d" are the same canonical flop. The canonical
+/// cards are those of `CanonicalHand`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::CanonicalFlop;
+/// use pkr::board::Board;
+///
+/// let flop = CanonicalFlop::of(&Board::new_from_str("Ah Kh 2c").unwrap()).unwrap();
+/// let other = CanonicalFlop::of(&Board::new_from_str("2d As Ks").unwrap()).unwrap();
+/// assert_eq!(flop, other);
+/// assert_eq!(flop.to_string(), "2c Kd Ad");
+/// assert_eq!(CanonicalFlop::all().len(), 1755);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CanonicalFlop {
+    cards: [Card; 3],
+}
+
+impl CanonicalFlop {
+    /// Returns the canonical flop of the first three cards of the board, or
+    /// `None` preflop.
+    pub fn of(board: &Board) -> Option<Self> {
+        let cards = board.cards().get(..3)?;
+        Some(Self::from_cards([cards[0], cards[1], cards[2]]))
+    }
+
+    /// Returns all 1755 canonical flops in ascending order of their cards.
+    pub fn all() -> Vec<Self> {
+        let mut flops: Vec<Self> = combinations_array(Deck::new().get_cards())
+            .map(Self::from_cards)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        flops.sort_unstable();
+        flops
+    }
+
+    /// Returns the canonical cards in ascending order.
+    pub fn get_cards(&self) -> [Card; 3] {
+        self.cards
+    }
+
+    /// Returns the canonical flop as a board.
+    pub fn to_board(&self) -> Board {
+        Board::Flop(self.cards)
+    }
+
+    fn from_cards(cards: [Card; 3]) -> Self {
+        let canonical = CanonicalHand::new(&cards);
+        let cards = canonical.as_slice();
+        Self {
+            cards: [cards[0], cards[1], cards[2]],
+        }
+    }
+}
+
+impl fmt::Display for CanonicalFlop {
+    /// Formats the canonical flop like a hand, e.g. "2c Kd Ad".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cards: Vec<String> = self.cards.iter().map(Card::as_str).collect();
+        write!(f, "{}", cards.join(" "))
+    }
+}
@@ -0,0 +1,176 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use strum::EnumCount;
+
+use crate::board::Board;
+use crate::card::Card;
+use crate::combinations::combinations;
+use crate::hand::{Evaluator, FastEvaluator, HandRank, HoleCards};
+
+use super::combos::live_cards;
+
+/// Calculates the probability of the hero's final hand landing in every
+/// category, enumerating every runout of the board.
+///
+/// This answers questions like "how often will I make a flush by the
+/// river". Preflop all 2,118,760 runouts are enumerated, which is still
+/// feasible, use `category_distribution_monte_carlo` to trade precision for
+/// speed.
+///
+/// # Arguments
+///
+/// * `hero` - The hole cards of the hero.
+/// * `board` - The community cards dealt so far.
+///
+/// # Returns
+///
+/// * `[f64; HandRank::COUNT]` - The probability of every category, indexed
+///   in the order of `HandRank` from `HighCard` to `StraightFlush`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::category_distribution;
+/// use pkr::board::Board;
+/// use pkr::hand::{HandRank, HoleCards};
+///
+/// let hero = HoleCards::new_from_str("AhKh").unwrap();
+/// let board = Board::new_from_str("7h 2h 9c").unwrap();
+///
+/// let distribution = category_distribution(&hero, &board);
+/// let flush = distribution[HandRank::Flush.to_index()];
+/// assert!((flush - 0.35).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if a hole card of the hero is on the board.
+pub fn category_distribution(hero: &HoleCards, board: &Board) -> [f64; HandRank::COUNT] {
+    let (mut cards, live) = known_and_live_cards(hero, board);
+    let known = cards.len();
+
+    let mut counts = [0u64; HandRank::COUNT];
+    for runout in combinations(&live, 7 - known) {
+        cards.truncate(known);
+        cards.extend_from_slice(&runout);
+        counts[category(&cards)] += 1;
+    }
+    normalize(counts)
+}
+
+/// Estimates the probability of the hero's final hand landing in every
+/// category like `category_distribution` by dealing random runouts.
+///
+/// The same arguments always produce the same result.
+///
+/// # Arguments
+///
+/// * `hero` - The hole cards of the hero.
+/// * `board` - The community cards dealt so far.
+/// * `trials` - The number of runouts to deal.
+/// * `seed` - The seed of the random number generator.
+///
+/// # Panics
+///
+/// Panics if a hole card of the hero is on the board.
+pub fn category_distribution_monte_carlo(
+    hero: &HoleCards,
+    board: &Board,
+    trials: usize,
+    seed: u64,
+) -> [f64; HandRank::COUNT] {
+    let (mut cards, live) = known_and_live_cards(hero, board);
+    let known = cards.len();
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut counts = [0u64; HandRank::COUNT];
+    for _ in 0..trials {
+        cards.truncate(known);
+        cards.extend(live.choose_multiple(&mut rng, 7 - known));
+        counts[category(&cards)] += 1;
+    }
+    normalize(counts)
+}
+
+/// Returns the hero's and the board's cards followed by the cards left in
+/// the deck.
+fn known_and_live_cards(hero: &HoleCards, board: &Board) -> (Vec<Card>, Vec<Card>) {
+    let mut cards = hero.get_cards().to_vec();
+    for card in board.cards() {
+        assert!(
+            !cards.contains(card),
+            "The hole cards must not be on the board, found {}",
+            card.as_str()
+        );
+        cards.push(*card);
+    }
+    let live = live_cards(&cards);
+    (cards, live)
+}
+
+/// Returns the index of the category of the best hand of the cards.
+fn category(cards: &[Card]) -> usize {
+    HandRank::from_score(FastEvaluator.score(cards)).to_index()
+}
+
+/// Converts the counts into probabilities, leaving them at zero if nothing
+/// was counted.
+fn normalize(counts: [u64; HandRank::COUNT]) -> [f64; HandRank::COUNT] {
+    let total: u64 = counts.iter().sum();
+    counts.map(|count| {
+        if total == 0 {
+            0.0
+        } else {
+            count as f64 / total as f64
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hole(s: &str) -> HoleCards {
+        HoleCards::new_from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_made_royal_flush() {
+        let board = Board::new_from_str("Qs Js Ts").unwrap();
+        let distribution = category_distribution(&hole("AsKs"), &board);
+        let mut expected = [0.0; HandRank::COUNT];
+        expected[HandRank::StraightFlush.to_index()] = 1.0;
+        assert_eq!(distribution, expected);
+        assert_eq!(
+            category_distribution_monte_carlo(&hole("AsKs"), &board, 100, 1),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_flush_draw() {
+        // Only a flush beats the unpaired ace king, and the board cannot
+        // pair twice.
+        let board = Board::new_from_str("7h 2h 9c").unwrap();
+        let distribution = category_distribution(&hole("AhKh"), &board);
+        let flush_or_better: f64 = distribution[HandRank::Flush.to_index()..].iter().sum();
+        assert!((flush_or_better - 378.0 / 1081.0).abs() < 1e-12);
+        assert!((distribution.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+        let sampled = category_distribution_monte_carlo(&hole("AhKh"), &board, 20000, 7);
+        let sampled_flush: f64 = sampled[HandRank::Flush.to_index()..].iter().sum();
+        assert!((sampled_flush - 0.349).abs() < 0.015, "{}", sampled_flush);
+        assert_eq!(
+            sampled,
+            category_distribution_monte_carlo(&hole("AhKh"), &board, 20000, 7)
+        );
+    }
+
+    #[test]
+    fn test_river() {
+        let board = Board::new_from_str("7h 2h 9c 9d Ks").unwrap();
+        let distribution = category_distribution(&hole("AhKh"), &board);
+        assert_eq!(distribution[HandRank::TwoPair.to_index()], 1.0);
+    }
+}
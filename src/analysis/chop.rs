@@ -0,0 +1,313 @@
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use strum::EnumCount;
+
+use crate::board::Board;
+use crate::card::Card;
+use crate::combinations::combinations;
+use crate::combinatorics::n_choose_k;
+use crate::equity::EquityError;
+use crate::hand::{Evaluator, FastEvaluator, HandRank};
+use crate::range::Range;
+
+use super::combos::live_cards;
+
+/// How `chop_frequency` evaluates the matchup.
+///
+/// By default matchups needing at most a million showdowns are enumerated
+/// exactly and all others are simulated with 100,000 trials.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChopOptions {
+    /// The largest number of showdowns, i.e. pairs of combinations times
+    /// runouts, that is enumerated exactly.
+    pub exact_limit: u64,
+    /// The number of showdowns to deal when simulating.
+    pub trials: usize,
+    /// The seed of the random number generator when simulating.
+    pub seed: u64,
+}
+
+impl Default for ChopOptions {
+    fn default() -> Self {
+        Self {
+            exact_limit: 1_000_000,
+            trials: 100_000,
+            seed: 0,
+        }
+    }
+}
+
+/// How often two ranges chop the pot, see `chop_frequency`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ChopFrequency {
+    /// The probability of a tie at showdown.
+    pub frequency: f64,
+    /// The probability of every category given a tie, indexed by
+    /// `HandRank::to_index`. All zero if the ranges never tie.
+    pub categories: [f64; HandRank::COUNT],
+    /// Whether every showdown was enumerated.
+    pub exact: bool,
+}
+
+/// Calculates how often two ranges tie at showdown and with which hands.
+///
+/// Every pair of combinations that do not share a card counts with the
+/// product of their weights, combinations blocked by the board are left
+/// out. The showdowns are enumerated exactly if there are at most
+/// `options.exact_limit` of them and simulated otherwise.
+///
+/// # Arguments
+///
+/// * `range_a` - The range of the first player.
+/// * `range_b` - The range of the second player.
+/// * `board` - The community cards dealt so far, `None` preflop.
+/// * `options` - When and how to simulate.
+///
+/// # Returns
+///
+/// * `ChopFrequency` - The probability of a tie and the categories the
+///   ties happen in.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::{chop_frequency, ChopOptions};
+/// use pkr::board::Board;
+/// use pkr::hand::HandRank;
+/// use pkr::range::Range;
+///
+/// let board = Board::new_from_str("Ah Kd Qc Jh Ts").unwrap();
+/// let range = Range::new_from_str("22, 33").unwrap();
+///
+/// let chop = chop_frequency(&range, &range, Some(&board), ChopOptions::default()).unwrap();
+/// assert_eq!(chop.frequency, 1.0);
+/// assert_eq!(chop.categories[HandRank::Straight.to_index()], 1.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns `EquityError::EmptyRange` if no pair of combinations can be
+/// dealt together with the board.
+pub fn chop_frequency(
+    range_a: &Range,
+    range_b: &Range,
+    board: Option<&Board>,
+    options: ChopOptions,
+) -> Result<ChopFrequency, EquityError> {
+    let board = board.map_or(&[][..], Board::cards);
+    let live_combos = |range: &Range| -> Vec<([Card; 2], f64)> {
+        range
+            .get_combos()
+            .iter()
+            .filter(|(combo, weight)| {
+                *weight > 0.0 && !combo.iter().any(|card| board.contains(card))
+            })
+            .copied()
+            .collect()
+    };
+    let combos_a = live_combos(range_a);
+    let combos_b = live_combos(range_b);
+
+    let pairs = combos_a
+        .iter()
+        .flat_map(|(a, _)| combos_b.iter().filter(move |(b, _)| disjoint(a, b)))
+        .count() as u64;
+    if pairs == 0 {
+        return Err(EquityError::EmptyRange);
+    }
+
+    let missing = 5 - board.len();
+    let runouts = n_choose_k(48 - board.len() as u64, missing as u64);
+    let mut counter = ChopCounter::new(board);
+    let exact = pairs.saturating_mul(runouts) <= options.exact_limit;
+    if exact {
+        for (a, weight_a) in &combos_a {
+            for (b, weight_b) in combos_b.iter().filter(|(b, _)| disjoint(a, b)) {
+                let live = live_cards(&[board, &a[..], &b[..]].concat());
+                for runout in combinations(&live, missing) {
+                    counter.record(a, b, &runout, weight_a * weight_b);
+                }
+            }
+        }
+    } else {
+        let mut rng = StdRng::seed_from_u64(options.seed);
+        let index_a = WeightedIndex::new(combos_a.iter().map(|(_, weight)| *weight))
+            .expect("Live combinations have positive weights");
+        let index_b = WeightedIndex::new(combos_b.iter().map(|(_, weight)| *weight))
+            .expect("Live combinations have positive weights");
+        let live = live_cards(board);
+        let mut runout_cards = Vec::with_capacity(live.len());
+        for _ in 0..options.trials {
+            // Redraw both combinations until they can be dealt together,
+            // which keeps every compatible pair at its weight.
+            let (a, b) = loop {
+                let a = combos_a[index_a.sample(&mut rng)].0;
+                let b = combos_b[index_b.sample(&mut rng)].0;
+                if disjoint(&a, &b) {
+                    break (a, b);
+                }
+            };
+            runout_cards.clear();
+            runout_cards.extend(
+                live.iter()
+                    .filter(|card| !a.contains(card) && !b.contains(card)),
+            );
+            let runout: Vec<Card> = runout_cards
+                .choose_multiple(&mut rng, missing)
+                .copied()
+                .collect();
+            counter.record(&a, &b, &runout, 1.0);
+        }
+    }
+
+    Ok(counter.finish(exact))
+}
+
+/// Returns whether two combinations do not share a card.
+fn disjoint(a: &[Card; 2], b: &[Card; 2]) -> bool {
+    !a.iter().any(|card| b.contains(card))
+}
+
+/// Sums up the weights of all showdowns and of the tied ones per category.
+struct ChopCounter<'a> {
+    board: &'a [Card],
+    cards: Vec<Card>,
+    total: f64,
+    ties: [f64; HandRank::COUNT],
+}
+
+impl<'a> ChopCounter<'a> {
+    fn new(board: &'a [Card]) -> Self {
+        Self {
+            board,
+            cards: Vec::with_capacity(7),
+            total: 0.0,
+            ties: [0.0; HandRank::COUNT],
+        }
+    }
+
+    fn record(&mut self, a: &[Card; 2], b: &[Card; 2], runout: &[Card], weight: f64) {
+        self.total += weight;
+        let score_a = self.score(a, runout);
+        if score_a == self.score(b, runout) {
+            self.ties[HandRank::from_score(score_a).to_index()] += weight;
+        }
+    }
+
+    fn score(&mut self, hole: &[Card; 2], runout: &[Card]) -> u32 {
+        self.cards.clear();
+        self.cards.extend_from_slice(hole);
+        self.cards.extend_from_slice(self.board);
+        self.cards.extend_from_slice(runout);
+        FastEvaluator.score(&self.cards)
+    }
+
+    fn finish(self, exact: bool) -> ChopFrequency {
+        let tied: f64 = self.ties.iter().sum();
+        let categories = self
+            .ties
+            .map(|ties| if tied > 0.0 { ties / tied } else { 0.0 });
+        ChopFrequency {
+            frequency: tied / self.total,
+            categories,
+            exact,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(s: &str) -> Range {
+        Range::new_from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_identical_hands_on_the_river() {
+        let board = Board::new_from_str("2c 7d 9h Jc 3s").unwrap();
+        let chop = chop_frequency(
+            &range("AhKd"),
+            &range("AcKs"),
+            Some(&board),
+            ChopOptions::default(),
+        )
+        .unwrap();
+        assert!(chop.exact);
+        assert_eq!(chop.frequency, 1.0);
+        assert_eq!(chop.categories[HandRank::HighCard.to_index()], 1.0);
+    }
+
+    #[test]
+    fn test_exact_and_simulated_agree() {
+        let board = Board::new_from_str("Qs 7h 2d").unwrap();
+        let exact = chop_frequency(
+            &range("AK"),
+            &range("AK, QJs"),
+            Some(&board),
+            ChopOptions::default(),
+        )
+        .unwrap();
+        assert!(exact.exact);
+        assert!(exact.frequency > 0.5 && exact.frequency < 1.0);
+
+        let options = ChopOptions {
+            exact_limit: 0,
+            trials: 20_000,
+            seed: 3,
+        };
+        let simulated =
+            chop_frequency(&range("AK"), &range("AK, QJs"), Some(&board), options).unwrap();
+        assert!(!simulated.exact);
+        assert!(
+            (simulated.frequency - exact.frequency).abs() < 0.015,
+            "{} {}",
+            simulated.frequency,
+            exact.frequency
+        );
+        assert_eq!(
+            simulated,
+            chop_frequency(&range("AK"), &range("AK, QJs"), Some(&board), options).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ace_king_preflop() {
+        let options = ChopOptions {
+            trials: 20_000,
+            seed: 1,
+            ..ChopOptions::default()
+        };
+        let chop = chop_frequency(&range("AKo"), &range("AKo"), None, options).unwrap();
+        assert!(!chop.exact);
+        // Enumerating all 1,712,304 runouts of every pair of combinations
+        // gives a chop frequency of 0.96882.
+        assert!(
+            (chop.frequency - 0.96882).abs() < 0.005,
+            "{}",
+            chop.frequency
+        );
+        assert!(chop.categories[HandRank::Straight.to_index()] > 0.0);
+    }
+
+    #[test]
+    fn test_blocked_ranges() {
+        let board = Board::new_from_str("Ah Kd Qc").unwrap();
+        assert_eq!(
+            chop_frequency(
+                &range("AhKs"),
+                &range("KsQs"),
+                Some(&board),
+                ChopOptions::default()
+            ),
+            Err(EquityError::EmptyRange)
+        );
+        assert_eq!(
+            chop_frequency(&range("JsTs"), &range("JsTs"), None, ChopOptions::default()),
+            Err(EquityError::EmptyRange)
+        );
+    }
+}
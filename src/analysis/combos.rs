@@ -0,0 +1,81 @@
+use crate::card::Card;
+use crate::combinations::combinations_array;
+use crate::deck::Deck;
+use crate::hand::Hand;
+
+/// Returns all cards of a standard 52-card deck that are not in `dead`.
+///
+/// The cards are returned in the order of a fresh `Deck`.
+pub fn live_cards(dead: &[Card]) -> Vec<Card> {
+    Deck::new()
+        .get_cards()
+        .iter()
+        .filter(|card| !dead.contains(card))
+        .copied()
+        .collect()
+}
+
+/// Returns all two card combinations that can be built from the cards not in
+/// `dead`.
+///
+/// The cards of each combination are ordered by descending rank, and by
+/// descending suit for equal ranks.
+pub fn hole_card_combos(dead: &[Card]) -> Vec<[Card; 2]> {
+    let live = live_cards(dead);
+    let mut combos = Vec::with_capacity(live.len() * live.len().saturating_sub(1) / 2);
+
+    for [a, b] in combinations_array(&live) {
+        combos.push([a.max(b), a.min(b)]);
+    }
+
+    combos
+}
+
+/// Returns the score of the hand built from the hole cards and the board.
+///
+/// # Panics
+///
+/// Panics if the hole cards and the board together exceed the maximum
+/// number of cards of a `Hand` or if a card appears more than once among
+/// them. Callers taking cards from the user check them up front.
+pub fn score_with_board(hole: &[Card; 2], board: &[Card]) -> u32 {
+    Hand::from_hole_and_board(hole, board)
+        .expect("Hole cards and board must form a valid hand")
+        .get_score()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_live_cards() {
+        assert_eq!(live_cards(&[]).len(), 52);
+
+        let dead = vec![
+            Card::new_from_str("As").unwrap(),
+            Card::new_from_str("Kd").unwrap(),
+        ];
+        let live = live_cards(&dead);
+        assert_eq!(live.len(), 50);
+        assert!(!live.contains(&dead[0]));
+        assert!(!live.contains(&dead[1]));
+    }
+
+    #[test]
+    fn test_hole_card_combos() {
+        assert_eq!(hole_card_combos(&[]).len(), 1326);
+
+        let board = vec![
+            Card::new_from_str("As").unwrap(),
+            Card::new_from_str("Ks").unwrap(),
+            Card::new_from_str("Qs").unwrap(),
+        ];
+        let combos = hole_card_combos(&board);
+        assert_eq!(combos.len(), 1176);
+        for combo in combos {
+            assert!(combo[0].rank >= combo[1].rank);
+            assert!(!board.contains(&combo[0]) && !board.contains(&combo[1]));
+        }
+    }
+}
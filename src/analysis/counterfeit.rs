@@ -0,0 +1,111 @@
+use crate::board::Board;
+use crate::card::Card;
+use crate::hand::{HandRank, HoleCards};
+
+use super::made_hand_usage;
+
+/// Returns whether dealing a card counterfeits the hero's one pair or two
+/// pair hand.
+///
+/// A hand is counterfeited if its best five cards used both hole cards
+/// before the card and no longer do afterwards while still being one pair
+/// or two pair, e.g. when the board pairs a card above the hero's lower
+/// pair or a board card outkicks the hero's kicker. Hands that improve to
+/// three of a kind or better are never counterfeited. Pocket pairs are
+/// never counterfeited either, as their hole cards only play together.
+///
+/// # Arguments
+///
+/// * `hole` - The hole cards of the hero.
+/// * `board_before` - The flop or turn before the card is dealt.
+/// * `card` - The next board card.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::is_counterfeited;
+/// use pkr::board::Board;
+/// use pkr::card::Card;
+/// use pkr::hand::HoleCards;
+///
+/// let hole = HoleCards::new_from_str("5s4s").unwrap();
+/// let flop = Board::new_from_str("Ad 5h 4c").unwrap();
+///
+/// assert!(is_counterfeited(&hole, &flop, Card::new_from_str("Ac").unwrap()));
+/// assert!(!is_counterfeited(&hole, &flop, Card::new_from_str("Kc").unwrap()));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the board is not a flop or a turn or if the card is already
+/// among the hole cards or the board.
+pub fn is_counterfeited(hole: &HoleCards, board_before: &Board, card: Card) -> bool {
+    let mut board_after = *board_before;
+    let dealt = match board_before {
+        Board::Flop(_) => board_after.deal_turn(card),
+        Board::Turn(_) => board_after.deal_river(card),
+        _ => panic!(
+            "Counterfeits are detected on the flop or the turn, got the {:?}",
+            board_before.get_street()
+        ),
+    };
+    dealt.expect("The card must not be on the board");
+
+    let [first, second] = hole.get_cards();
+    if first.rank == second.rank {
+        return false;
+    }
+    let pairs = |score: u32| {
+        matches!(
+            HandRank::from_score(score),
+            HandRank::OnePair | HandRank::TwoPair
+        )
+    };
+
+    let before = made_hand_usage(hole, board_before);
+    let after = made_hand_usage(hole, &board_after);
+    pairs(before.score) && before.get_count() == 2 && pairs(after.score) && after.get_count() < 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counterfeited(hole: &str, board: &str, card: &str) -> bool {
+        is_counterfeited(
+            &HoleCards::new_from_str(hole).unwrap(),
+            &Board::new_from_str(board).unwrap(),
+            Card::new_from_str(card).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_two_pair_counterfeited() {
+        assert!(counterfeited("5s4s", "Ad 5h 4c", "Ac"));
+        assert!(counterfeited("5s4s", "Ad 5h 4c Kd", "Kc"));
+        // Improving to a full house is not a counterfeit.
+        assert!(!counterfeited("5s4s", "Ad 5h 4c", "5c"));
+        assert!(!counterfeited("5s4s", "Ad 5h 4c", "9c"));
+    }
+
+    #[test]
+    fn test_kicker_counterfeited() {
+        assert!(counterfeited("Kh7d", "Ks 9c 2d Qh", "Ac"));
+        assert!(!counterfeited("Kh7d", "Ks 9c 2d Qh", "3c"));
+        // The kicker did not play before the river.
+        assert!(!counterfeited("Kh2c", "Ks 9c 7d Qh", "Ac"));
+    }
+
+    #[test]
+    fn test_pocket_pairs_never_counterfeited() {
+        assert!(!counterfeited("7s7d", "Kd Kc Qh", "Qs"));
+        assert!(!counterfeited("7s7d", "Ad 9h 4c 4d", "9c"));
+        assert!(!counterfeited("2s2d", "Ad 5h 4c", "Ac"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_requires_flop_or_turn() {
+        counterfeited("5s4s", "Ad 5h 4c Kd 9c", "Ac");
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use crate::board::Board;
+use crate::card::Card;
+use crate::csv::{parse_field, read_single_row, write_row, CsvError};
+use crate::equity::{equity_exact, EquityError};
+use crate::hand::HoleCards;
+
+/// The columns of `EquityCurve::to_csv`.
+const CURVE_HEADER: [&str; 4] = ["preflop", "flop", "turn", "river"];
+
+/// The exact equity of the hero on every street of a known runout, see
+/// `equity_curve`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EquityCurve {
+    pub preflop: f64,
+    pub flop: f64,
+    pub turn: f64,
+    pub river: f64,
+}
+
+impl EquityCurve {
+    /// Returns the equities from preflop to the river.
+    pub fn values(&self) -> [f64; 4] {
+        [self.preflop, self.flop, self.turn, self.river]
+    }
+
+    /// Writes the curve as a CSV table of the header
+    /// `preflop,flop,turn,river` and one row of the equities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::analysis::EquityCurve;
+    ///
+    /// let curve = EquityCurve { preflop: 0.8, flop: 0.9, turn: 0.95, river: 1.0 };
+    ///
+    /// let mut csv = Vec::new();
+    /// curve.to_csv(&mut csv).unwrap();
+    /// assert_eq!(String::from_utf8(csv.clone()).unwrap(), "preflop,flop,turn,river\n0.8,0.9,0.95,1\n");
+    /// assert_eq!(EquityCurve::from_csv(csv.as_slice()).unwrap(), curve);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the writer.
+    pub fn to_csv<W: Write>(&self, mut w: W) -> io::Result<()> {
+        write_row(&mut w, &CURVE_HEADER)?;
+        let values = self.values().map(|equity| equity.to_string());
+        write_row(&mut w, &values.each_ref().map(String::as_str))
+    }
+
+    /// Reads a curve written by `to_csv`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CsvError` if the table does not have the header of
+    /// `to_csv` and exactly one row of numbers.
+    pub fn from_csv<R: Read>(r: R) -> Result<Self, CsvError> {
+        let (line, fields) = read_single_row(r, &CURVE_HEADER)?;
+        Ok(Self {
+            preflop: parse_field(line, CURVE_HEADER[0], &fields[0])?,
+            flop: parse_field(line, CURVE_HEADER[1], &fields[1])?,
+            turn: parse_field(line, CURVE_HEADER[2], &fields[2])?,
+            river: parse_field(line, CURVE_HEADER[3], &fields[3])?,
+        })
+    }
+}
+
+impl fmt::Display for EquityCurve {
+    /// Formats a sparkline followed by the equities in percent, e.g.
+    /// "~^^_ 81.3% 91.1% 95.5% 0.0%".
+    ///
+    /// The sparkline uses one of `_.-~^` per street, from an equity of 0 to
+    /// an equity of 1.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const LEVELS: [char; 5] = ['_', '.', '-', '~', '^'];
+        for equity in self.values() {
+            write!(f, "{}", LEVELS[(equity * 4.0).round() as usize])?;
+        }
+        for equity in self.values() {
+            write!(f, " {:.1}%", equity * 100.0)?;
+        }
+        Ok(())
+    }
+}
+
+/// Calculates the exact equity of the hero against the villain on every
+/// street of a known runout, e.g. to review how a hand was won or lost.
+///
+/// Every street is enumerated exhaustively, so the preflop equity takes
+/// all runouts of five cards into account, the flop equity all turn and
+/// river cards and so on. The river equity is 0.0, 0.5 or 1.0.
+///
+/// # Arguments
+///
+/// * `hero` - The hole cards of the hero.
+/// * `villain` - The hole cards of the villain.
+/// * `full_board` - The five community cards in the order they were dealt.
+///
+/// # Returns
+///
+/// * `EquityCurve` - The equity of the hero preflop, on the flop, the turn
+///   and the river.
+///
+/// # Examples
+///
+/// Enumerating all preflop runouts takes a while without optimizations.
+///
+/// ```no_run
+/// use pkr::analysis::equity_curve;
+/// use pkr::card::Card;
+/// use pkr::hand::HoleCards;
+///
+/// let hero = HoleCards::new_from_str("AhAd").unwrap();
+/// let villain = HoleCards::new_from_str("KsKc").unwrap();
+/// let runout = ["7c", "8d", "2h", "4s", "Kh"].map(|c| Card::new_from_str(c).unwrap());
+///
+/// let curve = equity_curve(&hero, &villain, runout).unwrap();
+/// assert!(curve.turn > 0.95);
+/// assert_eq!(curve.river, 0.0);
+/// println!("{}", curve);
+/// ```
+///
+/// # Errors
+///
+/// Returns `EquityError::DuplicateCard` if a card appears more than once
+/// among the hands and the board.
+pub fn equity_curve(
+    hero: &HoleCards,
+    villain: &HoleCards,
+    full_board: [Card; 5],
+) -> Result<EquityCurve, EquityError> {
+    let hands = [*hero, *villain];
+    let equity = |board: Board| -> Result<f64, EquityError> {
+        Ok(equity_exact(&hands, &board)?[0].equity())
+    };
+
+    let [a, b, c, d, e] = full_board;
+    // The river is checked first, so duplicate cards fail without
+    // enumerating the earlier streets.
+    let river = equity(Board::River([a, b, c, d, e]))?;
+    Ok(EquityCurve {
+        preflop: equity(Board::Preflop)?,
+        flop: equity(Board::Flop([a, b, c]))?,
+        turn: equity(Board::Turn([a, b, c, d]))?,
+        river,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runout(s: &str) -> [Card; 5] {
+        let cards: Vec<Card> = s
+            .split_whitespace()
+            .map(|c| Card::new_from_str(c).unwrap())
+            .collect();
+        cards.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_bad_beat() {
+        let hands = [
+            HoleCards::new_from_str("AhAd").unwrap(),
+            HoleCards::new_from_str("KsKc").unwrap(),
+        ];
+        let full_board = runout("7c 8d 2h 4s Kh");
+        let curve = equity_curve(&hands[0], &hands[1], full_board).unwrap();
+
+        // The exact preflop equity of these aces against these kings.
+        assert!((curve.preflop - 0.8126).abs() < 1e-4, "{}", curve.preflop);
+        for (cards, value) in [
+            (&full_board[..3], curve.flop),
+            (&full_board[..4], curve.turn),
+            (&full_board[..], curve.river),
+        ] {
+            let board = Board::try_from(cards).unwrap();
+            assert_eq!(value, equity_exact(&hands, &board).unwrap()[0].equity());
+        }
+        assert!(curve.preflop < curve.flop && curve.flop < curve.turn);
+        assert_eq!(curve.turn, 42.0 / 44.0);
+        assert_eq!(curve.river, 0.0);
+        assert!(curve.to_string().starts_with("~^^_ 81.3% "));
+    }
+
+    #[test]
+    fn test_display_and_errors() {
+        let curve = EquityCurve {
+            preflop: 0.5,
+            flop: 0.25,
+            turn: 1.0,
+            river: 0.5,
+        };
+        assert_eq!(curve.to_string(), "-.^- 50.0% 25.0% 100.0% 50.0%");
+
+        let hero = HoleCards::new_from_str("AhKd").unwrap();
+        let villain = HoleCards::new_from_str("AcKs").unwrap();
+        let duplicate = equity_curve(&hero, &villain, runout("Ah 7d 9h Jc 3s"));
+        assert_eq!(
+            duplicate,
+            Err(EquityError::DuplicateCard(
+                Card::new_from_str("Ah").unwrap()
+            ))
+        );
+    }
+}
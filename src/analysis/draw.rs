@@ -0,0 +1,143 @@
+use crate::card::Card;
+use crate::combinations::combinations;
+use crate::deck::Deck;
+use crate::game::{payout_of_score, PayTable};
+use crate::hand::{Evaluator, FastEvaluator};
+
+use super::percentile;
+
+/// What a five card draw hand is played for, see `draw_ev`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawObjective {
+    /// The payout per coin of a video poker machine.
+    PayTable(PayTable),
+    /// The percentile of the final hand among all seven card hands, see
+    /// `analysis::percentile`.
+    Percentile,
+}
+
+/// Calculates the expected value of every way to draw to a five card hand.
+///
+/// For each of the 32 subsets of cards to hold, every combination of
+/// replacement cards from the deck is dealt and the final hands are valued
+/// by the objective. Cards of the hand that are still in the deck are never
+/// dealt as replacements.
+///
+/// # Arguments
+///
+/// * `hand` - The five cards dealt.
+/// * `deck` - The cards the replacements are drawn from.
+/// * `objective` - How a final hand is valued.
+///
+/// # Returns
+///
+/// * `Vec<(Vec<usize>, f64)>` - The indices of the held cards and the
+///   expected value of every subset, from the best to the worst. Subsets
+///   with the same value keep the order of holding fewer cards first.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::{draw_ev, DrawObjective};
+/// use pkr::card::Card;
+/// use pkr::deck::Deck;
+/// use pkr::game::PayTable;
+///
+/// let hand = ["As", "Ks", "Qs", "Js", "Ts"].map(|c| Card::new_from_str(c).unwrap());
+/// let objective = DrawObjective::PayTable(PayTable::JACKS_OR_BETTER_9_6);
+///
+/// let evs = draw_ev(&hand, &Deck::new(), objective);
+/// assert_eq!(evs.len(), 32);
+/// assert_eq!(evs[0], (vec![0, 1, 2, 3, 4], 250.0));
+/// ```
+///
+/// # Panics
+///
+/// Panics if the deck holds fewer than five cards besides the hand.
+pub fn draw_ev(hand: &[Card; 5], deck: &Deck, objective: DrawObjective) -> Vec<(Vec<usize>, f64)> {
+    let live: Vec<Card> = deck
+        .get_cards()
+        .iter()
+        .filter(|card| !hand.contains(card))
+        .copied()
+        .collect();
+    assert!(
+        live.len() >= 5,
+        "The deck must hold at least five cards besides the hand, got {}",
+        live.len()
+    );
+    let value = |cards: &[Card]| {
+        let score = FastEvaluator.score(cards);
+        match objective {
+            DrawObjective::PayTable(table) => payout_of_score(score, &table) as f64,
+            DrawObjective::Percentile => percentile(score),
+        }
+    };
+
+    let mut evs: Vec<(Vec<usize>, f64)> = (0..=5)
+        .flat_map(|count| combinations(&[0, 1, 2, 3, 4], count))
+        .map(|held| {
+            let mut cards: Vec<Card> = held.iter().map(|&i| hand[i]).collect();
+            let mut total = 0.0;
+            let mut draws = 0;
+            for replacements in combinations(&live, 5 - held.len()) {
+                cards.truncate(held.len());
+                cards.extend_from_slice(&replacements);
+                total += value(&cards);
+                draws += 1;
+            }
+            (held, total / draws as f64)
+        })
+        .collect();
+    evs.sort_by(|a, b| b.1.total_cmp(&a.1));
+    evs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(s: &str) -> [Card; 5] {
+        let cards: Vec<Card> = s
+            .split_whitespace()
+            .map(|c| Card::new_from_str(c).unwrap())
+            .collect();
+        cards.try_into().unwrap()
+    }
+
+    const JACKS_OR_BETTER: DrawObjective = DrawObjective::PayTable(PayTable::JACKS_OR_BETTER_9_6);
+
+    #[test]
+    fn test_pat_flush() {
+        let evs = draw_ev(&cards("As 9s 7s 4s 2s"), &Deck::new(), JACKS_OR_BETTER);
+        assert_eq!(evs[0], (vec![0, 1, 2, 3, 4], 6.0));
+        // Holding four to the flush, 8 of 47 cards make a flush and 3 a pair
+        // of aces.
+        let four_flush = evs.iter().find(|(held, _)| held == &[0, 1, 2, 3]).unwrap();
+        assert!((four_flush.1 - (8.0 * 6.0 + 3.0) / 47.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_four_flush_against_pairs() {
+        // Four to a flush beats a low pair...
+        let evs = draw_ev(&cards("5h 5c 9h Kh 2h"), &Deck::new(), JACKS_OR_BETTER);
+        assert_eq!(evs[0].0, [0, 2, 3, 4]);
+        assert_eq!(evs[1].0, [0, 1]);
+
+        // ...but not a high pair.
+        let evs = draw_ev(&cards("Kh Kc 9h 5h 2h"), &Deck::new(), JACKS_OR_BETTER);
+        assert_eq!(evs[0].0, [0, 1]);
+        assert!((evs[0].1 - 1.5365).abs() < 1e-4, "{}", evs[0].1);
+    }
+
+    #[test]
+    fn test_percentile() {
+        let evs = draw_ev(
+            &cards("Ah Ad Ac 7s 2d"),
+            &Deck::new(),
+            DrawObjective::Percentile,
+        );
+        assert_eq!(evs[0].0, [0, 1, 2]);
+        assert!(evs[0].1 > percentile(FastEvaluator.score(&cards("Ah Ad Ac 7s 2d"))));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::card::Card;
+use crate::combinatorics::{index_to_combination, n_choose_k};
+use crate::deck::Deck;
+
+/// The number of cards in a standard deck.
+const DECK_SIZE: usize = 52;
+
+/// A lazy iterator over all hands of `K` cards of a standard deck.
+///
+/// The hands are yielded in the colexicographic order of the positions of
+/// their cards in `Deck::new`, so the hand at position `i` is the one
+/// `combinatorics::index_to_combination(i, 52, K)` picks. Every step only
+/// moves a few indices and never allocates.
+struct AllHands<const K: usize> {
+    deck: [Card; DECK_SIZE],
+    indices: [usize; K],
+    remaining: u64,
+}
+
+impl<const K: usize> AllHands<K> {
+    fn new() -> Self {
+        let deck = Deck::new();
+        Self {
+            deck: core::array::from_fn(|i| deck.get_cards()[i]),
+            indices: core::array::from_fn(|i| i),
+            remaining: n_choose_k(DECK_SIZE as u64, K as u64),
+        }
+    }
+}
+
+impl<const K: usize> Iterator for AllHands<K> {
+    type Item = [Card; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let hand = self.indices.map(|i| self.deck[i]);
+        self.remaining -= 1;
+
+        // Move the lowest index that has room up by one and reset the
+        // indices below it to their smallest values.
+        if let Some(i) = (0..K).find(|&i| {
+            let limit = self.indices.get(i + 1).copied().unwrap_or(DECK_SIZE);
+            self.indices[i] + 1 < limit
+        }) {
+            self.indices[i] += 1;
+            for j in 0..i {
+                self.indices[j] = j;
+            }
+        }
+        Some(hand)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+/// Returns a lazy iterator over all 2,598,960 hands of five cards.
+///
+/// The hands are yielded in the order of `nth_five_card_hand`, with the
+/// cards of every hand in the order of `Deck::new`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::{all_five_card_hands, nth_five_card_hand};
+///
+/// let mut hands = all_five_card_hands();
+/// assert_eq!(hands.size_hint(), (2_598_960, Some(2_598_960)));
+/// assert_eq!(hands.next(), Some(nth_five_card_hand(0)));
+/// assert_eq!(hands.nth(99), Some(nth_five_card_hand(100)));
+/// ```
+pub fn all_five_card_hands() -> impl Iterator<Item = [Card; 5]> {
+    AllHands::<5>::new()
+}
+
+/// Returns a lazy iterator over all 133,784,560 hands of seven cards, in the
+/// same order as `all_five_card_hands`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::all_seven_card_hands;
+///
+/// let first = all_seven_card_hands().next().unwrap();
+/// assert_eq!(first.map(|card| card.as_str()), ["2h", "3h", "4h", "5h", "6h", "7h", "8h"]);
+/// ```
+pub fn all_seven_card_hands() -> impl Iterator<Item = [Card; 7]> {
+    AllHands::<7>::new()
+}
+
+/// Returns the five card hand at the given position of
+/// `all_five_card_hands` without iterating.
+///
+/// # Arguments
+///
+/// * `index` - The position of the hand, below 2,598,960.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::nth_five_card_hand;
+///
+/// let hand = nth_five_card_hand(2_598_959);
+/// assert_eq!(hand.map(|card| card.as_str()), ["Ts", "Js", "Qs", "Ks", "As"]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `index` is not below 2,598,960.
+pub fn nth_five_card_hand(index: u64) -> [Card; 5] {
+    let deck = Deck::new();
+    let positions = index_to_combination(index, DECK_SIZE, 5);
+    core::array::from_fn(|i| deck.get_cards()[positions[i]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combinatorics::combination_to_index;
+
+    #[test]
+    fn test_random_access() {
+        let deck = Deck::new();
+        let position = |card: &Card| {
+            deck.get_cards()
+                .iter()
+                .position(|other| other == card)
+                .unwrap()
+        };
+        for (index, hand) in all_five_card_hands().enumerate().step_by(997) {
+            assert_eq!(nth_five_card_hand(index as u64), hand);
+            let positions: Vec<usize> = hand.iter().map(position).collect();
+            assert_eq!(combination_to_index(&positions, DECK_SIZE), index as u64);
+        }
+        assert_eq!(
+            all_five_card_hands().last(),
+            Some(nth_five_card_hand(2_598_959))
+        );
+    }
+
+    #[test]
+    fn test_seven_card_hands_are_distinct() {
+        let mut hands = all_seven_card_hands();
+        let mut previous = hands.next().unwrap();
+        for hand in hands.take(100_000) {
+            assert!(hand
+                .iter()
+                .all(|card| hand.iter().filter(|c| *c == card).count() == 1));
+            assert_ne!(hand, previous);
+            previous = hand;
+        }
+    }
+
+    #[test]
+    #[ignore = "Iterates all 2,598,960 five card hands"]
+    fn test_five_card_count() {
+        assert_eq!(all_five_card_hands().count(), 2_598_960);
+    }
+
+    #[test]
+    #[ignore = "Iterates all 133,784,560 seven card hands"]
+    fn test_seven_card_count() {
+        assert_eq!(all_seven_card_hands().count(), 133_784_560);
+    }
+}
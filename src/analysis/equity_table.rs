@@ -0,0 +1,460 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::card::{Card, Suit};
+use crate::equity::Equity;
+use crate::hand::{Evaluator, FastEvaluator};
+use crate::range::StartingHand;
+
+use super::buckets::{Connectivity, HighCard, Pairing, Suitedness};
+use super::combos::live_cards;
+use super::{flop_buckets, BucketScheme, CanonicalFlop, FlopBucket};
+
+/// The first bytes of a file written by `EquityTable::write_to`, followed
+/// by the version of the format.
+const MAGIC: &[u8; 4] = b"PKEQ";
+const FORMAT_VERSION: u8 = 1;
+
+/// Precomputed heads-up equities of starting hands against each other on
+/// the flops of a bucket, for approximate equity in real time.
+///
+/// The table is built offline by `build_tables`, saved with `write_to` and
+/// loaded with `read_from`. The buckets are those of `FlopBucket::of`.
+///
+/// # Approximation error
+///
+/// An entry is the average equity over the flops of the bucket, the
+/// combinations of both starting hands and the turns and rivers, estimated
+/// from random samples. On a specific flop the exact equity differs from
+/// the average of its bucket, mostly where the flop hits one hand but not
+/// the other flops of the bucket. Where neither hand pairs or draws to the
+/// flop in particular, e.g. kings against queen jack on 8 5 3, the error
+/// is typically below 0.05. Where one hand does, it is larger, e.g. aces
+/// against seven deuce on K 9 4 are 0.98 but 0.89 on the average flop of
+/// "broadway dry rainbow", some of which pair the seven or the deuce, and
+/// errors of 0.2 occur. The sampling adds an error with a standard
+/// deviation of about `0.5 / sqrt(samples)`. Every canonical flop of a
+/// bucket is sampled equally often, regardless of how many flops it stands
+/// for.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::{build_tables, EquityTable, FlopBucket};
+/// use pkr::board::Board;
+/// use pkr::range::StartingHand;
+///
+/// let aces = StartingHand::new_from_str("AA").unwrap();
+/// let seven_deuce = StartingHand::new_from_str("72o").unwrap();
+/// let bucket = FlopBucket::of(&Board::new_from_str("Kd 9s 4c").unwrap()).unwrap();
+///
+/// let table = build_tables(&[aces], &[seven_deuce], &[bucket], 2000, 1);
+/// let equity = table.equity_fast(aces, seven_deuce, bucket).unwrap();
+/// assert!(equity.equity() > 0.85);
+///
+/// // The table is symmetric.
+/// let reverse = table.equity_fast(seven_deuce, aces, bucket).unwrap();
+/// assert!((equity.equity() + reverse.equity() - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EquityTable {
+    entries: HashMap<(StartingHand, StartingHand, FlopBucket), Equity>,
+}
+
+impl EquityTable {
+    /// Returns the number of entries of the table.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the table has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the approximate equity of the hero against the villain on a
+    /// flop of the bucket, see the approximation error of `EquityTable`.
+    ///
+    /// An entry of the villain against the hero is used reversed.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Equity>` - The sampled showdowns of the hero, or `None` if
+    ///   the table has no entry for the starting hands and the bucket.
+    pub fn equity_fast(
+        &self,
+        hero: StartingHand,
+        villain: StartingHand,
+        texture: FlopBucket,
+    ) -> Option<Equity> {
+        if let Some(equity) = self.entries.get(&(hero, villain, texture)) {
+            return Some(*equity);
+        }
+        let reverse = self.entries.get(&(villain, hero, texture))?;
+        let (wins, ties) = reverse.get_wins_and_ties();
+        let trials = reverse.get_trials();
+        Some(Equity::heads_up(trials, trials - wins - ties, ties))
+    }
+
+    /// Writes the table in a compact binary format.
+    ///
+    /// The format is the magic bytes "PKEQ", a version byte, the number of
+    /// entries as a little endian `u32` and one record per entry: the
+    /// indices of the hero and the villain in `StartingHand::all`, the
+    /// bucket as a byte and the showdowns, wins and ties of the hero as
+    /// little endian `u32`s. The entries are sorted, so equal tables are
+    /// written to equal bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the writer.
+    pub fn write_to<W: Write>(&self, mut w: W) -> io::Result<()> {
+        let indices = starting_hand_indices();
+        let mut records: Vec<[u8; 15]> = self
+            .entries
+            .iter()
+            .map(|((hero, villain, bucket), equity)| {
+                let (wins, ties) = equity.get_wins_and_ties();
+                let mut record = [0; 15];
+                record[0] = indices[hero];
+                record[1] = indices[villain];
+                record[2] = bucket_to_byte(bucket);
+                for (i, count) in [equity.get_trials(), wins, ties].into_iter().enumerate() {
+                    let count = u32::try_from(count).expect("Tables have fewer than 2^32 samples");
+                    record[3 + 4 * i..7 + 4 * i].copy_from_slice(&count.to_le_bytes());
+                }
+                record
+            })
+            .collect();
+        records.sort_unstable();
+
+        w.write_all(MAGIC)?;
+        w.write_all(&[FORMAT_VERSION])?;
+        w.write_all(&(records.len() as u32).to_le_bytes())?;
+        for record in &records {
+            w.write_all(record)?;
+        }
+        Ok(())
+    }
+
+    /// Reads a table written by `write_to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the reader, and an error of kind
+    /// `io::ErrorKind::InvalidData` if the bytes are not a table.
+    pub fn read_from<R: Read>(mut r: R) -> io::Result<Self> {
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message);
+        let mut header = [0; 9];
+        r.read_exact(&mut header)?;
+        if &header[..4] != MAGIC || header[4] != FORMAT_VERSION {
+            return Err(invalid("Not an equity table of a known version"));
+        }
+        let count = u32::from_le_bytes(header[5..].try_into().expect("Four bytes"));
+
+        let hands: Vec<StartingHand> = StartingHand::all().collect();
+        let mut table = EquityTable::default();
+        for _ in 0..count {
+            let mut record = [0; 15];
+            r.read_exact(&mut record)?;
+            let hand = |byte: u8| {
+                hands
+                    .get(byte as usize)
+                    .copied()
+                    .ok_or_else(|| invalid("Invalid starting hand"))
+            };
+            let bucket = bucket_from_byte(record[2]).ok_or_else(|| invalid("Invalid bucket"))?;
+            let count = |i: usize| {
+                u32::from_le_bytes(record[3 + 4 * i..7 + 4 * i].try_into().expect("Four bytes"))
+                    as u64
+            };
+            let (trials, wins, ties) = (count(0), count(1), count(2));
+            if wins + ties > trials {
+                return Err(invalid("More wins and ties than showdowns"));
+            }
+            let key = (hand(record[0])?, hand(record[1])?, bucket);
+            table
+                .entries
+                .insert(key, Equity::heads_up(trials, wins, ties));
+        }
+        Ok(table)
+    }
+}
+
+/// Builds an `EquityTable` of every hero against every villain on the
+/// flops of every bucket by sampling showdowns.
+///
+/// Every sample deals a random canonical flop of the bucket with its suits
+/// randomly relabeled, random combinations of both starting hands that do
+/// not share a card with the flop or each other, and a random turn and
+/// river. Starting hands that can never be dealt together on a bucket, e.g.
+/// aces against aces on a flop with two aces, get no entry. Building the
+/// tables of all 169 starting hands against each other takes hours and is
+/// meant to be done once, offline. The same arguments always produce the
+/// same table.
+///
+/// # Arguments
+///
+/// * `heroes` - The starting hands of the hero.
+/// * `villains` - The starting hands of the villain.
+/// * `buckets` - The flop buckets, see `FlopBucket::of`.
+/// * `samples` - The number of showdowns to sample per entry.
+/// * `seed` - The seed of the random number generator.
+pub fn build_tables(
+    heroes: &[StartingHand],
+    villains: &[StartingHand],
+    buckets: &[FlopBucket],
+    samples: usize,
+    seed: u64,
+) -> EquityTable {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut flops = flop_buckets(&BucketScheme::default());
+    let mut table = EquityTable::default();
+    for bucket in buckets {
+        let Some(flops) = flops.remove(bucket) else {
+            continue;
+        };
+        for &hero in heroes {
+            for &villain in villains {
+                let equity = sample_entry(hero, villain, &flops, samples, &mut rng);
+                if equity.get_trials() > 0 {
+                    table.entries.insert((hero, villain, *bucket), equity);
+                }
+            }
+        }
+    }
+    table
+}
+
+/// Samples the showdowns of an entry, giving up after `10 * samples`
+/// attempts to deal both starting hands on the flops.
+fn sample_entry(
+    hero: StartingHand,
+    villain: StartingHand,
+    flops: &[CanonicalFlop],
+    samples: usize,
+    rng: &mut StdRng,
+) -> Equity {
+    let (hero_combos, villain_combos) = (hero.combos(), villain.combos());
+    let mut equity = Equity::new();
+    let mut dealt = Vec::with_capacity(9);
+    for _ in 0..samples.saturating_mul(10) {
+        if equity.get_trials() == samples as u64 {
+            break;
+        }
+        let mut suits = [Suit::Club, Suit::Diamond, Suit::Heart, Suit::Spade];
+        suits.shuffle(rng);
+        let flop = flops.choose(rng).expect("Buckets have flops").get_cards();
+        dealt.clear();
+        dealt.extend(flop.map(|card| Card::new(card.rank, suits[card.suit.to_index()])));
+
+        let Some(hero_combo) = choose_live(&hero_combos, &dealt, rng) else {
+            continue;
+        };
+        dealt.extend(hero_combo);
+        let Some(villain_combo) = choose_live(&villain_combos, &dealt, rng) else {
+            continue;
+        };
+        dealt.extend(villain_combo);
+        let runout = live_cards(&dealt);
+        let runout: Vec<Card> = runout.choose_multiple(rng, 2).copied().collect();
+
+        let score = |hole: [Card; 2]| {
+            let cards = [&dealt[..3], &hole[..], &runout[..]].concat();
+            FastEvaluator.score(&cards)
+        };
+        match score(hero_combo).cmp(&score(villain_combo)) {
+            Ordering::Greater => equity.record_win(),
+            Ordering::Equal => equity.record_tie(2),
+            Ordering::Less => equity.record_loss(),
+        }
+    }
+    equity
+}
+
+/// Returns a random combination that does not share a card with the dealt
+/// cards, or `None` if every combination does.
+fn choose_live(combos: &[[Card; 2]], dealt: &[Card], rng: &mut StdRng) -> Option<[Card; 2]> {
+    let live: Vec<&[Card; 2]> = combos
+        .iter()
+        .filter(|combo| !combo.iter().any(|card| dealt.contains(card)))
+        .collect();
+    live.choose(rng).map(|combo| **combo)
+}
+
+/// Returns the index of every starting hand in `StartingHand::all`.
+fn starting_hand_indices() -> HashMap<StartingHand, u8> {
+    StartingHand::all()
+        .enumerate()
+        .map(|(i, hand)| (hand, i as u8))
+        .collect()
+}
+
+/// Packs the bucket into a byte of two bits per field, where 0 stands for
+/// `None` of the optional fields.
+fn bucket_to_byte(bucket: &FlopBucket) -> u8 {
+    let high = match bucket.high {
+        HighCard::Ace => 0,
+        HighCard::Broadway => 1,
+        HighCard::Middle => 2,
+        HighCard::Low => 3,
+    };
+    let pairing = match bucket.pairing {
+        Pairing::Unpaired => 0,
+        Pairing::Paired => 1,
+        Pairing::Trips => 2,
+    };
+    let suits = match bucket.suits {
+        None => 0,
+        Some(Suitedness::Rainbow) => 1,
+        Some(Suitedness::TwoTone) => 2,
+        Some(Suitedness::Monotone) => 3,
+    };
+    let connectivity = match bucket.connectivity {
+        None => 0,
+        Some(Connectivity::Dry) => 1,
+        Some(Connectivity::Connected) => 2,
+    };
+    high << 6 | pairing << 4 | suits << 2 | connectivity
+}
+
+/// Unpacks a bucket packed by `bucket_to_byte`.
+fn bucket_from_byte(byte: u8) -> Option<FlopBucket> {
+    let high = [
+        HighCard::Ace,
+        HighCard::Broadway,
+        HighCard::Middle,
+        HighCard::Low,
+    ][(byte >> 6) as usize];
+    let pairing =
+        *[Pairing::Unpaired, Pairing::Paired, Pairing::Trips].get((byte >> 4 & 3) as usize)?;
+    let suits = [
+        None,
+        Some(Suitedness::Rainbow),
+        Some(Suitedness::TwoTone),
+        Some(Suitedness::Monotone),
+    ][(byte >> 2 & 3) as usize];
+    let connectivity =
+        *[None, Some(Connectivity::Dry), Some(Connectivity::Connected)].get((byte & 3) as usize)?;
+    Some(FlopBucket {
+        high,
+        pairing,
+        suits,
+        connectivity,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Board;
+    use crate::equity::equity_exact;
+    use crate::hand::HoleCards;
+
+    fn hand(s: &str) -> StartingHand {
+        StartingHand::new_from_str(s).unwrap()
+    }
+
+    fn bucket(s: &str) -> FlopBucket {
+        FlopBucket::of(&Board::new_from_str(s).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_build_subset() {
+        let (heroes, villains) = ([hand("AA"), hand("KQs")], [hand("72o"), hand("AA")]);
+        let buckets = [bucket("Kd 9s 4c"), bucket("Ah Ad 5c")];
+        let table = build_tables(&heroes, &villains, &buckets, 200, 7);
+
+        // Aces against aces on a flop with two aces are never dealt.
+        assert_eq!(table.len(), 7);
+        assert!(table
+            .equity_fast(hand("AA"), hand("AA"), buckets[1])
+            .is_none());
+        assert!(table
+            .equity_fast(hand("AA"), hand("AA"), buckets[0])
+            .is_some());
+        assert!(table
+            .equity_fast(hand("QQ"), hand("72o"), buckets[0])
+            .is_none());
+        for equity in table.entries.values() {
+            assert_eq!(equity.get_trials(), 200);
+        }
+        assert_eq!(table, build_tables(&heroes, &villains, &buckets, 200, 7));
+    }
+
+    #[test]
+    fn test_reversed_entry() {
+        let buckets = [bucket("Kd 9s 4c")];
+        let table = build_tables(&[hand("KQs")], &[hand("72o")], &buckets, 500, 1);
+        let equity = table
+            .equity_fast(hand("KQs"), hand("72o"), buckets[0])
+            .unwrap();
+        let reverse = table
+            .equity_fast(hand("72o"), hand("KQs"), buckets[0])
+            .unwrap();
+        assert_eq!(reverse.get_trials(), equity.get_trials());
+        assert_eq!(reverse.win(), equity.lose());
+        assert_eq!(reverse.tie(), equity.tie());
+    }
+
+    #[test]
+    fn test_file_round_trip() {
+        let buckets = [bucket("Kd 9s 4c"), bucket("7s 6s 4d"), bucket("Ah Ad 5c")];
+        let table = build_tables(&[hand("AA"), hand("T9s")], &[hand("AKo")], &buckets, 100, 3);
+
+        let mut bytes = Vec::new();
+        table.write_to(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 9 + 15 * table.len());
+        assert_eq!(EquityTable::read_from(&bytes[..]).unwrap(), table);
+
+        let mut again = Vec::new();
+        table.write_to(&mut again).unwrap();
+        assert_eq!(again, bytes);
+
+        let error = EquityTable::read_from(&b"PKEQ\x02\0\0\0\0"[..]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let error = EquityTable::read_from(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_bucket_bytes() {
+        let scheme = BucketScheme::default();
+        for bucket in flop_buckets(&scheme).keys() {
+            assert_eq!(bucket_from_byte(bucket_to_byte(bucket)), Some(*bucket));
+        }
+    }
+
+    #[test]
+    fn test_tolerance_against_exact() {
+        // Hands that connect with no flop of the bucket in particular are
+        // approximated within the documented error.
+        for (hero, villain, hole, flop) in [
+            ("KK", "QJs", ["KsKh", "QcJc"], "8d 5s 3h"),
+            ("QQ", "A5o", ["QsQh", "Ac5d"], "Jd 8s 2c"),
+            ("KK", "AQo", ["KsKh", "AcQd"], "Td 6s 3h"),
+        ] {
+            let board = Board::new_from_str(flop).unwrap();
+            let hands = hole.map(|s| HoleCards::new_from_str(s).unwrap());
+            let exact = equity_exact(&hands, &board).unwrap()[0].equity();
+
+            let texture = bucket(flop);
+            let table = build_tables(&[hand(hero)], &[hand(villain)], &[texture], 4000, 2);
+            let fast = table
+                .equity_fast(hand(hero), hand(villain), texture)
+                .unwrap();
+            assert!(
+                (fast.equity() - exact).abs() < 0.05,
+                "{} {} {}",
+                hero,
+                fast.equity(),
+                exact
+            );
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::board::Board;
+use crate::csv::{parse_field, read_table, write_row, CsvError, CsvErrorKind};
+use crate::equity::{equity_exact, Equity};
+use crate::hand::HoleCards;
+
+/// The columns of the tables of `write_matchups_csv`.
+const MATCHUP_HEADER: [&str; 12] = [
+    "label",
+    "player",
+    "hole_cards",
+    "board",
+    "trials",
+    "wins",
+    "ties",
+    "tie_shares",
+    "win",
+    "tie",
+    "lose",
+    "equity",
+];
+
+/// Hands all-in against each other on a board, see `export_matchups_csv`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matchup {
+    /// A name of the matchup written to every row of it.
+    pub label: String,
+    /// The hole cards of every player.
+    pub hands: Vec<HoleCards>,
+    /// The community cards dealt so far.
+    pub board: Board,
+}
+
+/// A row of the table of `write_matchups_csv`, the equity of one player of
+/// a matchup.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchupRow {
+    /// The label of the matchup.
+    pub label: String,
+    /// The position of the player in the hands of the matchup.
+    pub player: usize,
+    /// The hole cards of the player.
+    pub hole_cards: HoleCards,
+    /// The board of the matchup.
+    pub board: Board,
+    /// The exact equity of the player.
+    pub equity: Equity,
+}
+
+/// Calculates the exact equity of every matchup and writes them to a CSV
+/// file, see `write_matchups_csv` for the layout.
+///
+/// The file is created or truncated.
+///
+/// # Errors
+///
+/// Returns the error of creating or writing the file, and an error of kind
+/// `io::ErrorKind::InvalidInput` holding the `EquityError` if the equity of
+/// a matchup cannot be calculated.
+pub fn export_matchups_csv(matchups: &[Matchup], path: impl AsRef<Path>) -> io::Result<()> {
+    let mut w = BufWriter::new(File::create(path)?);
+    write_matchups_csv(matchups, &mut w)?;
+    w.flush()
+}
+
+/// Calculates the exact equity of every matchup and writes them as a CSV
+/// table, one row per player, streaming the rows of a matchup as soon as it
+/// is calculated.
+///
+/// The columns are `label`, `player`, the zero-based position of the
+/// player, `hole_cards`, e.g. "AhKh", `board`, the board as it is
+/// displayed, e.g. "Qs Js 2d | 7c", followed by the columns of
+/// `Equity::to_csv`. Read the table back with `read_matchups_csv`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::{read_matchups_csv, write_matchups_csv, Matchup};
+/// use pkr::board::Board;
+/// use pkr::hand::HoleCards;
+///
+/// let matchup = Matchup {
+///     label: "overcards vs pair".to_string(),
+///     hands: vec![
+///         HoleCards::new_from_str("AhKh").unwrap(),
+///         HoleCards::new_from_str("9c9d").unwrap(),
+///     ],
+///     board: Board::new_from_str("Qs Js 2d 7c").unwrap(),
+/// };
+///
+/// let mut csv = Vec::new();
+/// write_matchups_csv(&[matchup], &mut csv).unwrap();
+/// let rows = read_matchups_csv(csv.as_slice()).unwrap();
+/// assert_eq!(rows[1].hole_cards.to_string(), "9c9d");
+/// assert_eq!(rows[1].equity.win(), 34.0 / 44.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns the error of the writer, and an error of kind
+/// `io::ErrorKind::InvalidInput` holding the `EquityError` if the equity of
+/// a matchup cannot be calculated.
+pub fn write_matchups_csv<W: Write>(matchups: &[Matchup], mut w: W) -> io::Result<()> {
+    write_row(&mut w, &MATCHUP_HEADER)?;
+    for matchup in matchups {
+        let equities = equity_exact(&matchup.hands, &matchup.board)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let board = matchup.board.to_string();
+        for (player, (hole_cards, equity)) in matchup.hands.iter().zip(&equities).enumerate() {
+            let (player, hole_cards) = (player.to_string(), hole_cards.to_string());
+            let mut fields = vec![matchup.label.as_str(), &player, &hole_cards, &board];
+            let equity = equity.csv_fields();
+            fields.extend(equity.iter().map(String::as_str));
+            write_row(&mut w, &fields)?;
+        }
+        w.flush()?;
+    }
+    Ok(())
+}
+
+/// Reads the rows of a table written by `write_matchups_csv`.
+///
+/// # Errors
+///
+/// Returns a `CsvError` if the table does not have the header of
+/// `write_matchups_csv` or a row holds an invalid value.
+pub fn read_matchups_csv<R: Read>(r: R) -> Result<Vec<MatchupRow>, CsvError> {
+    read_table(r, &MATCHUP_HEADER)?
+        .into_iter()
+        .map(|(line, fields)| {
+            let invalid = |column: &'static str, value: &str| CsvError {
+                line,
+                kind: CsvErrorKind::InvalidField {
+                    column,
+                    value: value.to_string(),
+                },
+            };
+            Ok(MatchupRow {
+                label: fields[0].clone(),
+                player: parse_field(line, "player", &fields[1])?,
+                hole_cards: HoleCards::new_from_str(&fields[2])
+                    .map_err(|_| invalid("hole_cards", &fields[2]))?,
+                board: Board::new_from_str(&fields[3]).map_err(|_| invalid("board", &fields[3]))?,
+                equity: Equity::from_csv_fields(line, &fields[4..])?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matchups() -> Vec<Matchup> {
+        let hands = |hands: &[&str]| {
+            hands
+                .iter()
+                .map(|s| HoleCards::new_from_str(s).unwrap())
+                .collect()
+        };
+        vec![
+            Matchup {
+                label: "flush draw, \"combo\"".to_string(),
+                hands: hands(&["AhKh", "9c9d"]),
+                board: Board::new_from_str("Qh Jh 2d 7c").unwrap(),
+            },
+            Matchup {
+                label: "three way".to_string(),
+                hands: hands(&["AsAd", "KsKd", "7c6c"]),
+                board: Board::new_from_str("8c 5c 2h").unwrap(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_golden_csv() {
+        let mut csv = Vec::new();
+        write_matchups_csv(&matchups(), &mut csv).unwrap();
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden/matchups.csv");
+        let expected = std::fs::read_to_string(path).expect("Golden file must exist");
+        assert_eq!(String::from_utf8(csv).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let path = std::env::temp_dir().join(format!("pkr-matchups-{}.csv", std::process::id()));
+        export_matchups_csv(&matchups(), &path).unwrap();
+        let rows = read_matchups_csv(File::open(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(rows.len(), 5);
+        for matchup in matchups() {
+            let equities = equity_exact(&matchup.hands, &matchup.board).unwrap();
+            let matchup_rows: Vec<&MatchupRow> = rows
+                .iter()
+                .filter(|row| row.label == matchup.label)
+                .collect();
+            assert_eq!(matchup_rows.len(), matchup.hands.len());
+            for (player, row) in matchup_rows.into_iter().enumerate() {
+                assert_eq!(row.player, player);
+                assert_eq!(row.hole_cards, matchup.hands[player]);
+                assert_eq!(row.board, matchup.board);
+                assert_eq!(row.equity, equities[player]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_preflop_and_errors() {
+        let csv = "label,player,hole_cards,board,trials,wins,ties,tie_shares,win,tie,lose,equity\n\
+                   x,0,AhKh,,0,0,0,0,0,0,0,0\n";
+        assert_eq!(
+            read_matchups_csv(csv.as_bytes()).unwrap()[0].board,
+            Board::Preflop
+        );
+
+        let csv = csv.replace("AhKh", "AhAh");
+        let error = read_matchups_csv(csv.as_bytes()).unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(matches!(
+            error.kind,
+            CsvErrorKind::InvalidField {
+                column: "hole_cards",
+                ..
+            }
+        ));
+
+        let duplicate = Matchup {
+            label: String::new(),
+            hands: vec![HoleCards::new_from_str("AhKh").unwrap()],
+            board: Board::new_from_str("Ah 2c 3d").unwrap(),
+        };
+        let error = write_matchups_csv(&[duplicate], io::sink()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
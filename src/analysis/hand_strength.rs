@@ -0,0 +1,183 @@
+use crate::board::Board;
+use crate::card::Card;
+use crate::equity::EquityError;
+
+use super::combos::{hole_card_combos, score_with_board};
+
+/// The number of villain hole card combinations a hero hand beats, ties and
+/// loses to on a given board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CombosAhead {
+    pub ahead: usize,
+    pub tied: usize,
+    pub behind: usize,
+}
+
+impl CombosAhead {
+    /// Returns the total number of villain combinations that were compared.
+    pub fn total(&self) -> usize {
+        self.ahead + self.tied + self.behind
+    }
+
+    /// Returns the hand strength as the fraction of villain combinations
+    /// beaten, counting ties as half a win.
+    ///
+    /// Returns 0.0 if no combination was compared.
+    pub fn hand_strength(&self) -> f64 {
+        let total = self.total();
+        if total == 0 {
+            return 0.0;
+        }
+        (self.ahead as f64 + self.tied as f64 / 2.0) / total as f64
+    }
+}
+
+/// Compares the hero's hole cards against every possible villain combination
+/// on the current board.
+///
+/// All two card combinations not containing a hero or board card are
+/// evaluated together with the board. No further board cards are dealt, so
+/// this is the classic hand strength metric for the current street.
+///
+/// # Arguments
+///
+/// * `hero` - The hole cards of the hero.
+/// * `board` - The community cards.
+///
+/// # Returns
+///
+/// * `CombosAhead` - The number of villain combinations the hero beats, ties
+///   and loses to.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::hand_vs_all;
+/// use pkr::board::Board;
+/// use pkr::card::Card;
+///
+/// let hero = [
+///     Card::new_from_str("Js").unwrap(),
+///     Card::new_from_str("Ts").unwrap(),
+/// ];
+/// let board = Board::new_from_str("As Ks Qs 2d 7c").unwrap();
+///
+/// let combos = hand_vs_all(hero, &board).unwrap();
+/// assert_eq!(combos.behind, 0);
+/// assert_eq!(combos.total(), 990);
+/// ```
+///
+/// # Errors
+///
+/// Returns `EquityError::DuplicateCard` if both hole cards of the hero are
+/// the same card or if a card of the hero is on the board.
+pub fn hand_vs_all(hero: [Card; 2], board: &Board) -> Result<CombosAhead, EquityError> {
+    let board = board.cards();
+    let mut dead = board.to_vec();
+    for card in hero {
+        if dead.contains(&card) {
+            return Err(EquityError::DuplicateCard(card));
+        }
+        dead.push(card);
+    }
+    let hero_score = score_with_board(&hero, board);
+
+    let mut result = CombosAhead {
+        ahead: 0,
+        tied: 0,
+        behind: 0,
+    };
+    for villain in hole_card_combos(&dead) {
+        let villain_score = score_with_board(&villain, board);
+        if hero_score > villain_score {
+            result.ahead += 1;
+        } else if hero_score == villain_score {
+            result.tied += 1;
+        } else {
+            result.behind += 1;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Returns the hand strength of the hero's hole cards on the current board as
+/// a percentile between 0.0 and 1.0.
+///
+/// This is the fraction of villain combinations beaten by the hero, counting
+/// ties as half a win. See `hand_vs_all` for the underlying counts.
+///
+/// # Arguments
+///
+/// * `hero` - The hole cards of the hero.
+/// * `board` - The community cards.
+///
+/// # Errors
+///
+/// Returns the errors of `hand_vs_all`.
+pub fn hand_strength_percentile(hero: [Card; 2], board: &Board) -> Result<f64, EquityError> {
+    Ok(hand_vs_all(hero, board)?.hand_strength())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(s: &str) -> Vec<Card> {
+        s.split_whitespace()
+            .map(|c| Card::new_from_str(c).unwrap())
+            .collect()
+    }
+
+    fn hole(s: &str) -> [Card; 2] {
+        let cards = cards(s);
+        [cards[0], cards[1]]
+    }
+
+    #[test]
+    fn test_nuts_is_never_behind() {
+        let board = Board::new_from_str("Kh Kd 7c 7s 2h").unwrap();
+        let combos = hand_vs_all(hole("Ks Kc"), &board).unwrap();
+        assert_eq!(combos.behind, 0);
+        assert_eq!(combos.tied, 0);
+        assert_eq!(combos.ahead, 990);
+        assert_eq!(hand_strength_percentile(hole("Ks Kc"), &board), Ok(1.0));
+    }
+
+    #[test]
+    fn test_board_playing_hand() {
+        // Only the 45 combinations holding two hearts beat the straight on
+        // the board, everything else plays the board as well.
+        let board = Board::new_from_str("Ah Kd Qc Jh Th").unwrap();
+        let combos = hand_vs_all(hole("2c 3d"), &board).unwrap();
+        assert_eq!(combos.ahead, 0);
+        assert_eq!(combos.behind, 45);
+        assert_eq!(combos.tied, 945);
+    }
+
+    #[test]
+    fn test_counts_sum_to_live_combos() {
+        let board = Board::new_from_str("9c 8d 2s").unwrap();
+        let combos = hand_vs_all(hole("Ah Kh"), &board).unwrap();
+        assert_eq!(combos.total(), 1081);
+
+        let combos = hand_vs_all(hole("Ah Kh"), &Board::Preflop).unwrap();
+        assert_eq!(combos.total(), 1225);
+
+        let strength = combos.hand_strength();
+        assert!(strength > 0.0 && strength < 1.0);
+    }
+
+    #[test]
+    fn test_duplicate_cards() {
+        let board = Board::new_from_str("Kh Kd 7c 7s 2h").unwrap();
+        assert_eq!(
+            hand_vs_all(hole("Ah 7c"), &board),
+            Err(EquityError::DuplicateCard(cards("7c")[0]))
+        );
+        assert_eq!(
+            hand_strength_percentile(hole("As As"), &Board::Preflop),
+            Err(EquityError::DuplicateCard(cards("As")[0]))
+        );
+    }
+}
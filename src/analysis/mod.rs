@@ -0,0 +1,53 @@
+mod beating;
+mod blockers;
+mod bounds;
+mod buckets;
+mod canonical_flop;
+mod categories;
+mod chop;
+mod combos;
+mod counterfeit;
+mod curve;
+mod draw;
+mod enumerate;
+mod equity_table;
+mod export;
+mod hand_strength;
+mod nuts;
+mod outs;
+mod percentile;
+mod preflop;
+mod river;
+mod sampling;
+mod straights;
+mod stud;
+mod usage;
+
+pub use beating::{minimal_beating_hand, BeatingHand};
+pub use blockers::{blockers, BlockerClass, BlockerEntry, BlockerReport};
+pub use bounds::{best_hand_of_category, worst_hand_of_category};
+pub use buckets::{
+    flop_buckets, BucketScheme, Connectivity, FlopBucket, HighCard, Pairing, Suitedness,
+};
+pub use canonical_flop::CanonicalFlop;
+pub use categories::{category_distribution, category_distribution_monte_carlo};
+pub use chop::{chop_frequency, ChopFrequency, ChopOptions};
+pub use counterfeit::is_counterfeited;
+pub use curve::{equity_curve, EquityCurve};
+pub use draw::{draw_ev, DrawObjective};
+pub use enumerate::{all_five_card_hands, all_seven_card_hands, nth_five_card_hand};
+pub use equity_table::{build_tables, EquityTable};
+pub use export::{export_matchups_csv, read_matchups_csv, write_matchups_csv, Matchup, MatchupRow};
+pub use hand_strength::{hand_strength_percentile, hand_vs_all, CombosAhead};
+pub use nuts::{nut_score, nuts, top_n_hands};
+pub use outs::{clean_outs, OutClass, OutInfo, OutsReport};
+pub use percentile::percentile;
+pub use preflop::preflop_win_rates;
+pub use river::{
+    river_classification, river_classification_with, RiverClass, RiverClassification,
+    RiverThresholds,
+};
+pub use sampling::{sample_from_ranges, sample_opponents, MAX_SAMPLE_ATTEMPTS};
+pub use straights::{straights_possible, StraightInfo};
+pub use stud::stud_board_order;
+pub use usage::{made_hand_usage, HandUsage};
@@ -0,0 +1,150 @@
+use crate::board::Board;
+use crate::card::Card;
+
+use super::combos::{hole_card_combos, score_with_board};
+
+/// Returns the hole card combinations that make the best possible hand on the
+/// given board.
+///
+/// All two card combinations not containing a board card are evaluated
+/// together with the board. Every combination reaching the highest score is
+/// returned, so boards where several combinations share the nuts yield more
+/// than one entry.
+///
+/// # Arguments
+///
+/// * `board` - The community cards.
+///
+/// # Returns
+///
+/// * `Vec<[Card; 2]>` - The hole card combinations making the nuts, each
+///   ordered by descending rank.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::nuts;
+/// use pkr::board::Board;
+///
+/// let board = Board::new_from_str("As Ks Qs 2d 7c").unwrap();
+///
+/// let combos = nuts(&board);
+/// assert_eq!(combos.len(), 1);
+/// assert_eq!(combos[0][0].as_str(), "Js");
+/// assert_eq!(combos[0][1].as_str(), "Ts");
+/// ```
+pub fn nuts(board: &Board) -> Vec<[Card; 2]> {
+    top_n_hands(board, 1)
+        .into_iter()
+        .next()
+        .map(|(_, combos)| combos)
+        .unwrap_or_default()
+}
+
+/// Returns the score of the best possible hand on the given board.
+///
+/// # Arguments
+///
+/// * `board` - The community cards.
+///
+/// # Returns
+///
+/// * `u32` - The score of the nuts.
+pub fn nut_score(board: &Board) -> u32 {
+    top_n_hands(board, 1)
+        .first()
+        .map(|(score, _)| *score)
+        .unwrap_or(0)
+}
+
+/// Lists the `n` best distinct hand strengths on the given board together with
+/// the hole card combinations reaching them.
+///
+/// The entries are ordered by descending score. Within an entry the
+/// combinations keep the order of a fresh `Deck`.
+///
+/// # Arguments
+///
+/// * `board` - The community cards.
+/// * `n` - The number of distinct scores to return.
+///
+/// # Returns
+///
+/// * `Vec<(u32, Vec<[Card; 2]>)>` - Up to `n` pairs of a score and all hole
+///   card combinations reaching exactly that score.
+pub fn top_n_hands(board: &Board, n: usize) -> Vec<(u32, Vec<[Card; 2]>)> {
+    let board = board.cards();
+    let mut scored: Vec<(u32, [Card; 2])> = hole_card_combos(board)
+        .into_iter()
+        .map(|combo| (score_with_board(&combo, board), combo))
+        .collect();
+    scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+
+    let mut result: Vec<(u32, Vec<[Card; 2]>)> = Vec::new();
+    for (score, combo) in scored {
+        match result.last_mut() {
+            Some((last_score, combos)) if *last_score == score => combos.push(combo),
+            _ => {
+                if result.len() == n {
+                    break;
+                }
+                result.push((score, vec![combo]));
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(s: &str) -> Vec<Card> {
+        s.split_whitespace()
+            .map(|c| Card::new_from_str(c).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_nuts_royal_flush() {
+        let board = Board::new_from_str("As Ks Qs 2d 7c").unwrap();
+        assert_eq!(nuts(&board), vec![[cards("Js")[0], cards("Ts")[0]]]);
+        assert_eq!(nut_score(&board), 8_000_000 + 14);
+    }
+
+    #[test]
+    fn test_nuts_paired_board() {
+        // Quads beat every full house on a double paired board.
+        let board = Board::new_from_str("Kh Kd 7c 7s 2h").unwrap();
+        assert_eq!(nuts(&board), vec![[cards("Ks")[0], cards("Kc")[0]]]);
+        assert_eq!(nut_score(&board), 7_000_000 + (13 << 4) + 7);
+
+        // Trips on board leave a single quads card and the best kicker.
+        let board = Board::new_from_str("Kh Kd Kc 7s 2h").unwrap();
+        let combos = nuts(&board);
+        assert_eq!(combos.len(), 4);
+        assert!(combos.iter().all(|combo| combo.contains(&cards("Ks")[0])));
+        assert_eq!(nut_score(&board), 7_000_000 + (13 << 4) + 14);
+
+        // A straight flush still beats quads on a paired board.
+        let board = Board::new_from_str("9h 8h 7h 7c 2d").unwrap();
+        assert_eq!(nuts(&board), vec![[cards("Jh")[0], cards("Th")[0]]]);
+        assert_eq!(nut_score(&board), 8_000_000 + 11);
+    }
+
+    #[test]
+    fn test_top_n_hands() {
+        let board = Board::new_from_str("As Ks Qs 2d 7c").unwrap();
+        let top = top_n_hands(&board, 2);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].0, 8_000_000 + 14);
+        assert_eq!(
+            top[1].0,
+            5_000_000 + (14 << 16) + (13 << 12) + (12 << 8) + (11 << 4) + 9
+        );
+        assert_eq!(top[1].1, vec![[cards("Js")[0], cards("9s")[0]]]);
+
+        assert!(top_n_hands(&board, 0).is_empty());
+    }
+}
@@ -0,0 +1,258 @@
+use crate::board::Board;
+use crate::card::Card;
+use crate::equity::equity_exact;
+use crate::hand::{Hand, HandRank, HoleCards};
+use crate::range::Range;
+
+use super::combos::{live_cards, score_with_board};
+
+/// How a card that may help the hero plays out against the villain range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum OutClass {
+    /// The card puts the hero ahead and the hero stays ahead after the
+    /// villain's hand improved with it.
+    Clean,
+    /// The card puts the hero ahead of some villain hands, but improves at
+    /// least one of them even more.
+    Dirty,
+    /// The card improves the hero's hand without putting it ahead, e.g.
+    /// pairing an overcard against a set.
+    Neutral,
+}
+
+/// A card that improves the hero's hand or puts it ahead, see `clean_outs`.
+///
+/// All fractions are weighted by the villain range, counting only the
+/// combinations that do not share a card with the hero, the board or the
+/// out.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OutInfo {
+    pub card: Card,
+    pub class: OutClass,
+    /// The fraction of the range the card puts the hero ahead of, compared
+    /// to the villain's hand before the card.
+    pub raw: f64,
+    /// The fraction of the range the hero is still ahead of once the
+    /// villain's hand includes the card.
+    pub clean: f64,
+    /// The clean fraction weighted by the hero's equity against each clean
+    /// combination over the cards still to come, equal to `clean` once the
+    /// card completes the board.
+    pub discounted: f64,
+}
+
+/// The outs of the hero against a villain range, see `clean_outs`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OutsReport {
+    /// Every card that improves the hero's hand category or puts the hero
+    /// ahead, in the order of a fresh `Deck`.
+    pub outs: Vec<OutInfo>,
+    /// The sum of the raw fractions, the out count of naive outs counting.
+    pub raw: f64,
+    /// The sum of the clean fractions.
+    pub clean: f64,
+    /// The sum of the discounted fractions.
+    pub discounted: f64,
+}
+
+impl OutsReport {
+    /// Returns the entry of a card, or `None` if the card is no out.
+    pub fn get(&self, card: &Card) -> Option<&OutInfo> {
+        self.outs.iter().find(|out| out.card == *card)
+    }
+}
+
+/// Counts the outs of the hero on the next card against a villain range and
+/// discounts the dirty ones.
+///
+/// Naive outs counting counts every card that puts the hero ahead of the
+/// villain's current hand, even if the card improves the villain even more,
+/// like a flush card pairing the board for a set. Every card is therefore
+/// also checked against the villain's hand including the card. If a street
+/// remains after the card, the hero's exact equity over the last card
+/// discounts the clean outs further.
+///
+/// # Arguments
+///
+/// * `hero` - The hole cards of the hero.
+/// * `range` - The range of the villain, a single hand can be passed with
+///   `Range::from_combos`.
+/// * `board` - The community cards, a flop or a turn.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::{clean_outs, OutClass};
+/// use pkr::board::Board;
+/// use pkr::card::Card;
+/// use pkr::hand::HoleCards;
+/// use pkr::range::Range;
+///
+/// let hero = HoleCards::new_from_str("Ah9h").unwrap().get_cards();
+/// let set = Range::from_combos(&[HoleCards::new_from_str("7c7d").unwrap().get_cards()]).unwrap();
+/// let board = Board::new_from_str("Kh 7h 2c Qs").unwrap();
+///
+/// let report = clean_outs(hero, &set, &board);
+/// assert_eq!(report.raw, 9.0);
+/// assert_eq!(report.clean, 7.0);
+/// let queen = Card::new_from_str("Qh").unwrap();
+/// assert_eq!(report.get(&queen).unwrap().class, OutClass::Dirty);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the board is not a flop or a turn or if a hole card of the hero
+/// is on the board.
+pub fn clean_outs(hero: [Card; 2], range: &Range, board: &Board) -> OutsReport {
+    let board = board.cards();
+    assert!(
+        board.len() == 3 || board.len() == 4,
+        "Outs are counted on the flop or the turn, got {} board cards",
+        board.len()
+    );
+    let hero_hole = HoleCards::new(hero[0], hero[1]).expect("Hero's hole cards are distinct");
+    let hero_rank = hand_rank(&hero, board);
+
+    let mut dead = board.to_vec();
+    dead.extend_from_slice(&hero);
+
+    let mut outs = Vec::new();
+    for card in live_cards(&dead) {
+        let mut next_board = board.to_vec();
+        next_board.push(card);
+        let next = Board::try_from(&next_board).expect("The out is not on the board");
+        let hero_score = score_with_board(&hero, &next_board);
+
+        let (mut total, mut raw, mut clean, mut discounted) = (0.0, 0.0, 0.0, 0.0);
+        for (combo, weight) in range.get_combos() {
+            if combo.iter().any(|card| dead.contains(card)) || combo.contains(&card) {
+                continue;
+            }
+            total += weight;
+            if hero_score <= score_with_board(combo, board) {
+                continue;
+            }
+            raw += weight;
+            if hero_score > score_with_board(combo, &next_board) {
+                clean += weight;
+                let villain = HoleCards::new(combo[0], combo[1]).expect("Combos are distinct");
+                let equity = equity_exact(&[hero_hole, villain], &next)
+                    .expect("Hero, villain and board are distinct")[0]
+                    .equity();
+                discounted += weight * equity;
+            }
+        }
+        if total == 0.0 {
+            continue;
+        }
+
+        let class = if raw == 0.0 {
+            if hand_rank(&hero, &next_board) <= hero_rank {
+                continue;
+            }
+            OutClass::Neutral
+        } else if clean < raw {
+            OutClass::Dirty
+        } else {
+            OutClass::Clean
+        };
+        outs.push(OutInfo {
+            card,
+            class,
+            raw: raw / total,
+            clean: clean / total,
+            discounted: discounted / total,
+        });
+    }
+
+    OutsReport {
+        raw: outs.iter().map(|out| out.raw).sum(),
+        clean: outs.iter().map(|out| out.clean).sum(),
+        discounted: outs.iter().map(|out| out.discounted).sum(),
+        outs,
+    }
+}
+
+fn hand_rank(hole: &[Card; 2], board: &[Card]) -> HandRank {
+    Hand::from_hole_and_board(hole, board)
+        .expect("Hole cards and board must form a valid hand")
+        .get_hand_rank()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hole(s: &str) -> [Card; 2] {
+        HoleCards::new_from_str(s).unwrap().get_cards()
+    }
+
+    fn single(s: &str) -> Range {
+        Range::from_combos(&[hole(s)]).unwrap()
+    }
+
+    fn count(report: &OutsReport, class: OutClass) -> usize {
+        report.outs.iter().filter(|out| out.class == class).count()
+    }
+
+    #[test]
+    fn test_flush_draw_against_a_set() {
+        let board = Board::new_from_str("Kh 7h 2c Qs").unwrap();
+        let report = clean_outs(hole("Ah9h"), &single("7c7d"), &board);
+        assert_eq!(report.raw, 9.0);
+        assert_eq!(report.clean, 7.0);
+        assert_eq!(report.discounted, 7.0);
+        assert!(report.clean < report.raw);
+        assert_eq!(count(&report, OutClass::Clean), 7);
+        assert_eq!(count(&report, OutClass::Dirty), 2);
+
+        // Pairing the hero's cards or the board does not help against the set.
+        let ace = report.get(&Card::new_from_str("Ad").unwrap()).unwrap();
+        assert_eq!(ace.class, OutClass::Neutral);
+        assert_eq!(ace.raw, 0.0);
+        assert_eq!(count(&report, OutClass::Neutral), 14);
+    }
+
+    #[test]
+    fn test_flush_draw_against_one_pair() {
+        let board = Board::new_from_str("Ah 9h 2c Jd").unwrap();
+        let report = clean_outs(hole("6h5h"), &single("AsKd"), &board);
+        assert_eq!(report.raw, 9.0);
+        assert_eq!(report.clean, 9.0);
+        assert_eq!(count(&report, OutClass::Clean), 9);
+        assert_eq!(count(&report, OutClass::Dirty), 0);
+    }
+
+    #[test]
+    fn test_flop_discounts_by_equity() {
+        let board = Board::new_from_str("Kh 7h 2c").unwrap();
+        let report = clean_outs(hole("Ah9h"), &single("7c7d"), &board);
+        // A flush on the turn can still lose to a full house on the river.
+        let eight = report.get(&Card::new_from_str("8h").unwrap()).unwrap();
+        assert_eq!(eight.class, OutClass::Clean);
+        assert_eq!(eight.clean, 1.0);
+        assert!(eight.discounted < 1.0 && eight.discounted > 0.7);
+        assert!(report.discounted < report.clean);
+    }
+
+    #[test]
+    fn test_range() {
+        let board = Board::new_from_str("Kh 7h 2c Qs").unwrap();
+        let range = Range::new_from_str("77, AK").unwrap();
+        let report = clean_outs(hole("Ah9h"), &range, &board);
+        let heart = report.get(&Card::new_from_str("3h").unwrap()).unwrap();
+        assert_eq!(heart.class, OutClass::Clean);
+        assert_eq!(heart.raw, 1.0);
+        assert!(report.clean < report.raw);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_river() {
+        let board = Board::new_from_str("Kh 7h 2c Qs 3d").unwrap();
+        clean_outs(hole("Ah9h"), &single("7c7d"), &board);
+    }
+}
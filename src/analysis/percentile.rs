@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use crate::card::{Card, Rank, Suit};
+use crate::combinatorics::n_choose_k;
+use crate::hand::Hand;
+
+/// The number of seven card hands, 52 choose 7.
+const SEVEN_CARD_HANDS: u64 = 133_784_560;
+
+/// Returns the fraction of all seven card hands whose score is lower than
+/// the given score, e.g. 0.93 for a hand better than 93% of random hands.
+///
+/// Any score can be passed, including scores of hands with fewer or more
+/// than seven cards. The distribution of all seven card hands is computed
+/// on the first call, which takes a moment, and cached afterwards.
+///
+/// # Arguments
+///
+/// * `score` - A score as returned by `Hand::get_score`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::percentile;
+/// use pkr::hand::Hand;
+///
+/// let royal_flush = Hand::new_from_str("As Ks Qs Js Ts").unwrap();
+/// assert!(percentile(royal_flush.get_score()) > 0.9999);
+///
+/// let seven_high = Hand::new_from_str("7s 5h 4c 3d 2s").unwrap();
+/// assert_eq!(percentile(seven_high.get_score()), 0.0);
+/// ```
+pub fn percentile(score: u32) -> f64 {
+    let distribution = distribution();
+    let index = distribution.partition_point(|&(other, _)| other < score);
+    let below = distribution
+        .get(index)
+        .map_or(SEVEN_CARD_HANDS, |&(_, below)| below);
+    below as f64 / SEVEN_CARD_HANDS as f64
+}
+
+/// Returns every score of a seven card hand in ascending order, together
+/// with the number of seven card hands scoring lower.
+fn distribution() -> &'static [(u32, u64)] {
+    static DISTRIBUTION: OnceLock<Vec<(u32, u64)>> = OnceLock::new();
+    DISTRIBUTION.get_or_init(|| {
+        let mut below = 0;
+        score_counts()
+            .into_iter()
+            .map(|(score, count)| {
+                below += count;
+                (score, below - count)
+            })
+            .collect()
+    })
+}
+
+/// Counts the seven card hands of every score.
+///
+/// Instead of evaluating all hands, every multiset of seven ranks is
+/// evaluated once without a flush and once per set of ranks that can form a
+/// flush, and the number of suit assignments leading to each case is
+/// counted.
+fn score_counts() -> BTreeMap<u32, u64> {
+    let mut counts = BTreeMap::new();
+    let mut rank_counts = [0usize; 13];
+    for_each_rank_multiset(&mut rank_counts, 0, 7, &mut |rank_counts| {
+        count_rank_multiset(rank_counts, &mut counts);
+    });
+    counts
+}
+
+/// Calls `f` with every way to pick `remaining` more cards of the ranks from
+/// index `rank` on, with at most four cards per rank.
+fn for_each_rank_multiset(
+    rank_counts: &mut [usize; 13],
+    rank: usize,
+    remaining: usize,
+    f: &mut impl FnMut(&[usize; 13]),
+) {
+    if rank == 13 {
+        if remaining == 0 {
+            f(rank_counts);
+        }
+        return;
+    }
+    for count in 0..=remaining.min(4) {
+        rank_counts[rank] = count;
+        for_each_rank_multiset(rank_counts, rank + 1, remaining - count, f);
+    }
+    rank_counts[rank] = 0;
+}
+
+/// Adds the hands with the given number of cards per rank to `counts`.
+fn count_rank_multiset(rank_counts: &[usize; 13], counts: &mut BTreeMap<u32, u64>) {
+    let ranks: Vec<usize> = (0..13).filter(|&rank| rank_counts[rank] > 0).collect();
+    let assignments: u64 = ranks
+        .iter()
+        .map(|&rank| n_choose_k(4, rank_counts[rank] as u64))
+        .product();
+
+    // Only one suit can hold five of seven cards. The cards of the flush suit
+    // are exactly one card of each rank in `flush_ranks`, the other cards are
+    // spread over the other three suits.
+    let mut flush_assignments = 0;
+    for mask in 0u32..1 << ranks.len() {
+        if mask.count_ones() < 5 {
+            continue;
+        }
+        let in_flush = |i: usize| mask & (1 << i) != 0;
+        let count = 4 * ranks
+            .iter()
+            .enumerate()
+            .map(|(i, &rank)| n_choose_k(3, (rank_counts[rank] - in_flush(i) as usize) as u64))
+            .product::<u64>();
+        if count == 0 {
+            continue;
+        }
+        flush_assignments += count;
+
+        let mut cards = Vec::with_capacity(7);
+        let mut other_suit = 0;
+        for (i, &rank) in ranks.iter().enumerate() {
+            let mut copies = rank_counts[rank];
+            if in_flush(i) {
+                cards.push(card(rank, Suit::Spade));
+                copies -= 1;
+            }
+            for _ in 0..copies {
+                cards.push(card(
+                    rank,
+                    [Suit::Club, Suit::Diamond, Suit::Heart][other_suit % 3],
+                ));
+                other_suit += 1;
+            }
+        }
+        *counts.entry(score(cards)).or_default() += count;
+    }
+
+    let no_flush = assignments - flush_assignments;
+    if no_flush > 0 {
+        // Cycling through the suits puts at most two cards in each suit and
+        // never two cards of the same rank in one suit.
+        let cards = ranks
+            .iter()
+            .flat_map(|&rank| std::iter::repeat_n(rank, rank_counts[rank]))
+            .enumerate()
+            .map(|(i, rank)| {
+                card(
+                    rank,
+                    [Suit::Club, Suit::Diamond, Suit::Heart, Suit::Spade][i % 4],
+                )
+            })
+            .collect();
+        *counts.entry(score(cards)).or_default() += no_flush;
+    }
+}
+
+fn card(rank_index: usize, suit: Suit) -> Card {
+    Card::new(
+        Rank::new_from_num(rank_index + 2).expect("Rank index is valid"),
+        suit,
+    )
+}
+
+fn score(cards: Vec<Card>) -> u32 {
+    Hand::new(cards)
+        .expect("Constructed cards are distinct")
+        .get_score()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand::HandRank;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_category_counts() {
+        let mut categories: BTreeMap<HandRank, u64> = BTreeMap::new();
+        for (score, count) in score_counts() {
+            *categories.entry(HandRank::from_score(score)).or_default() += count;
+        }
+        let expected = [
+            (HandRank::HighCard, 23_294_460),
+            (HandRank::OnePair, 58_627_800),
+            (HandRank::TwoPair, 31_433_400),
+            (HandRank::ThreeOfAKind, 6_461_620),
+            (HandRank::Straight, 6_180_020),
+            (HandRank::Flush, 4_047_644),
+            (HandRank::FullHouse, 3_473_184),
+            (HandRank::FourOfAKind, 224_848),
+            (HandRank::StraightFlush, 41_584),
+        ];
+        assert_eq!(categories.into_iter().collect::<Vec<_>>(), expected);
+        assert_eq!(distribution().len(), 4824);
+    }
+
+    #[test]
+    fn test_percentile() {
+        let percentile_of = |s| percentile(Hand::new_from_str(s).unwrap().get_score());
+        assert!(percentile_of("As Ks Qs Js Ts") >= 0.9999);
+        assert_eq!(
+            percentile_of("As Ks Qs Js Ts"),
+            1.0 - 4324.0 / 133_784_560.0
+        );
+        assert_eq!(percentile_of("7s 5h 4c 3d 2s"), 0.0);
+        assert!(percentile_of("8s 5h 4c 3d 2s") < 0.0001);
+        assert_eq!(percentile(0), 0.0);
+        assert_eq!(percentile(u32::MAX), 1.0);
+
+        let pair = percentile_of("As Ah Kc 7d 2s 3c 9h");
+        assert!(pair > 0.55 && pair < 0.65, "{}", pair);
+    }
+
+    #[test]
+    fn test_monotone() {
+        let mut rng = StdRng::seed_from_u64(170);
+        let mut scores: Vec<u32> = (0..2000)
+            .map(|_| Hand::random(&mut rng, 7).get_score())
+            .collect();
+        scores.sort_unstable();
+        let percentiles: Vec<f64> = scores.iter().map(|&score| percentile(score)).collect();
+        assert!(percentiles.windows(2).all(|pair| pair[0] <= pair[1]));
+        for pair in scores.windows(2) {
+            if pair[0] < pair[1] {
+                assert!(percentile(pair[0]) < percentile(pair[1]));
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::equity::Equity;
+use crate::hand::Hand;
+use crate::range::StartingHand;
+
+/// The most opponents that can be dealt in along with the hero before the
+/// deck runs out of cards for a full board.
+const MAX_OPPONENTS: usize = 22;
+
+/// Estimates how often every starting hand wins a showdown against random
+/// hands, the data of the classic preflop chart.
+///
+/// For every starting hand `trials_per_hand` showdowns are dealt, each with
+/// a random combination of the starting hand, random hole cards for every
+/// opponent and a random board. A tie counts as the share of the pot won, so
+/// the win rate is the equity of the hand.
+///
+/// Every starting hand is simulated with its own generator seeded from
+/// `seed` and the hand's position, so the same arguments always produce the
+/// same chart. With the `parallel` feature the starting hands are simulated
+/// in parallel, which gives exactly the same results.
+///
+/// # Arguments
+///
+/// * `num_opponents` - The number of opponents, between 1 and 22.
+/// * `trials_per_hand` - The number of showdowns to deal per starting hand.
+/// * `seed` - The seed of the random number generators.
+///
+/// # Returns
+///
+/// * `[(StartingHand, f64); 169]` - The win rate of every starting hand, in
+///   the order of `StartingHand::all`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::preflop_win_rates;
+/// use pkr::range::StartingHand;
+///
+/// let chart = preflop_win_rates(1, 100, 42);
+/// assert_eq!(chart[0].0, StartingHand::new_from_str("AA").unwrap());
+/// assert!(chart[0].1 > 0.7);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `num_opponents` is not between 1 and 22.
+pub fn preflop_win_rates(
+    num_opponents: usize,
+    trials_per_hand: usize,
+    seed: u64,
+) -> [(StartingHand, f64); 169] {
+    assert!(
+        (1..=MAX_OPPONENTS).contains(&num_opponents),
+        "The number of opponents must be between 1 and {}, got {}.",
+        MAX_OPPONENTS,
+        num_opponents
+    );
+
+    let hands: Vec<(usize, StartingHand)> = StartingHand::all().enumerate().collect();
+    let simulate = |&(i, hand): &(usize, StartingHand)| {
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_add(i as u64));
+        (
+            hand,
+            win_rate(hand, num_opponents, trials_per_hand, &mut rng),
+        )
+    };
+
+    #[cfg(feature = "parallel")]
+    let rates: Vec<(StartingHand, f64)> = {
+        use rayon::prelude::*;
+        hands.par_iter().map(simulate).collect()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let rates: Vec<(StartingHand, f64)> = hands.iter().map(simulate).collect();
+
+    rates.try_into().expect("There are 169 starting hands")
+}
+
+/// Simulates `trials` showdowns of the starting hand against random hands
+/// and returns its equity.
+fn win_rate(hand: StartingHand, num_opponents: usize, trials: usize, rng: &mut StdRng) -> f64 {
+    let combos = hand.combos();
+    let deck = Deck::new();
+    let mut equity = Equity::new();
+    let mut live: Vec<Card> = Vec::with_capacity(52);
+
+    for _ in 0..trials {
+        let hole = *combos.choose(rng).expect("Every starting hand has combos");
+        live.clear();
+        live.extend(deck.get_cards().iter().filter(|card| !hole.contains(card)));
+        let dealt: Vec<Card> = live
+            .choose_multiple(rng, 2 * num_opponents + 5)
+            .copied()
+            .collect();
+        let (board, opponents) = dealt.split_at(5);
+
+        let score = |hole: &[Card; 2]| {
+            Hand::from_hole_and_board(hole, board)
+                .expect("Dealt cards are distinct")
+                .get_score()
+        };
+        let hero_score = score(&hole);
+        let best_opponent = opponents
+            .chunks_exact(2)
+            .map(|cards| score(&[cards[0], cards[1]]))
+            .max()
+            .unwrap_or(0);
+
+        if hero_score > best_opponent {
+            equity.record_win();
+        } else if hero_score < best_opponent {
+            equity.record_loss();
+        } else {
+            let tied = opponents
+                .chunks_exact(2)
+                .filter(|cards| score(&[cards[0], cards[1]]) == hero_score)
+                .count();
+            equity.record_tie(tied + 1);
+        }
+    }
+
+    equity.equity()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate(hand: &str, num_opponents: usize, trials: usize) -> f64 {
+        let hand = StartingHand::new_from_str(hand).unwrap();
+        win_rate(hand, num_opponents, trials, &mut StdRng::seed_from_u64(169))
+    }
+
+    #[test]
+    fn test_heads_up() {
+        let aces = rate("AA", 1, 20_000);
+        assert!((aces - 0.852).abs() < 0.015, "{}", aces);
+        assert!(aces >= 0.84);
+        let seven_deuce = rate("72o", 1, 20_000);
+        assert!((seven_deuce - 0.346).abs() < 0.015, "{}", seven_deuce);
+        assert!(seven_deuce <= 0.36);
+    }
+
+    #[test]
+    fn test_more_opponents_lower_win_rate() {
+        let rates: Vec<f64> = (1..=4).map(|n| rate("AA", n, 5_000)).collect();
+        assert!(
+            rates.windows(2).all(|pair| pair[0] > pair[1]),
+            "{:?}",
+            rates
+        );
+    }
+
+    #[test]
+    fn test_chart() {
+        let chart = preflop_win_rates(1, 200, 7);
+        assert_eq!(
+            chart.map(|(hand, _)| hand).to_vec(),
+            StartingHand::all().collect::<Vec<_>>()
+        );
+        assert!(chart.iter().all(|(_, rate)| (0.0..=1.0).contains(rate)));
+        assert_eq!(chart, preflop_win_rates(1, 200, 7));
+
+        let best = chart.iter().max_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+        assert_eq!(best.0.as_str(), "AA");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_too_many_opponents() {
+        preflop_win_rates(23, 1, 0);
+    }
+}
@@ -0,0 +1,284 @@
+use std::fmt;
+
+use crate::board::Board;
+use crate::card::Card;
+use crate::range::Range;
+
+use super::combos::score_with_board;
+
+/// The role of a made hand on the river against a villain range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum RiverClass {
+    /// Never behind any combination of the range.
+    Nuts,
+    /// Beats most of the range, bet for value.
+    StrongValue,
+    /// Beats more than half of the range, a thin value bet.
+    ThinValue,
+    /// Beats the bluffs of the range but not its value hands.
+    BluffCatcher,
+    /// Beats almost nothing.
+    Air,
+}
+
+impl fmt::Display for RiverClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            RiverClass::Nuts => "nuts",
+            RiverClass::StrongValue => "strong value",
+            RiverClass::ThinValue => "thin value",
+            RiverClass::BluffCatcher => "bluff-catcher",
+            RiverClass::Air => "air",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The thresholds of a river classification.
+///
+/// Each threshold is the lowest fraction of the villain range a hand must
+/// beat to get the class, counting ties as half a win. By default a hand
+/// beating 80% of the range is strong value, 50% thin value and 10% a
+/// bluff-catcher.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RiverThresholds {
+    pub strong_value: f64,
+    pub thin_value: f64,
+    pub bluff_catcher: f64,
+}
+
+impl Default for RiverThresholds {
+    fn default() -> Self {
+        Self {
+            strong_value: 0.8,
+            thin_value: 0.5,
+            bluff_catcher: 0.1,
+        }
+    }
+}
+
+impl RiverThresholds {
+    /// Returns the class of a hand that beats the given fraction of a range
+    /// without being the nuts.
+    pub fn classify(&self, strength: f64) -> RiverClass {
+        if strength >= self.strong_value {
+            RiverClass::StrongValue
+        } else if strength >= self.thin_value {
+            RiverClass::ThinValue
+        } else if strength >= self.bluff_catcher {
+            RiverClass::BluffCatcher
+        } else {
+            RiverClass::Air
+        }
+    }
+}
+
+/// The classification of a hand on the river together with the weighted
+/// number of villain combinations it beats, ties and loses to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RiverClassification {
+    pub class: RiverClass,
+    pub ahead: f64,
+    pub tied: f64,
+    pub behind: f64,
+}
+
+impl RiverClassification {
+    /// Returns the total weight of the villain combinations that were
+    /// compared.
+    pub fn total(&self) -> f64 {
+        self.ahead + self.tied + self.behind
+    }
+
+    /// Returns the fraction of the villain range beaten, counting ties as
+    /// half a win.
+    ///
+    /// Returns 0.0 if no combination was compared.
+    pub fn hand_strength(&self) -> f64 {
+        let total = self.total();
+        if total == 0.0 {
+            return 0.0;
+        }
+        (self.ahead + self.tied / 2.0) / total
+    }
+}
+
+/// Classifies the hero's hand on the river against a villain range with the
+/// default `RiverThresholds`.
+///
+/// # Arguments
+///
+/// * `hero` - The hole cards of the hero.
+/// * `board` - The five community cards.
+/// * `villain_range` - The range of the villain.
+///
+/// # Returns
+///
+/// * `RiverClassification` - The class of the hand and the weighted counts
+///   it is based on.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::{river_classification, RiverClass};
+/// use pkr::board::Board;
+/// use pkr::card::Card;
+/// use pkr::range::Range;
+///
+/// let hero = [
+///     Card::new_from_str("7s").unwrap(),
+///     Card::new_from_str("7d").unwrap(),
+/// ];
+/// let board = Board::new_from_str("Kd 9h 5h 2c 3s").unwrap();
+/// let range = Range::new_from_str("AK, JhTh, Th8h").unwrap();
+///
+/// let classification = river_classification(hero, &board, &range);
+/// assert_eq!(classification.class, RiverClass::BluffCatcher);
+/// assert_eq!(classification.ahead, 2.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics under the same conditions as `river_classification_with`.
+pub fn river_classification(
+    hero: [Card; 2],
+    board: &Board,
+    villain_range: &Range,
+) -> RiverClassification {
+    river_classification_with(hero, board, villain_range, &RiverThresholds::default())
+}
+
+/// Classifies the hero's hand on the river against a villain range.
+///
+/// Combinations of the range blocked by the hero or the board are left out.
+/// A hand that is never behind is `RiverClass::Nuts`, every other hand is
+/// classified by the fraction of the range it beats, see
+/// `RiverThresholds::classify`. A range without a live combination
+/// classifies as `RiverClass::Air`.
+///
+/// # Panics
+///
+/// Panics if the board is not a river or if a hole card of the hero is on
+/// the board.
+pub fn river_classification_with(
+    hero: [Card; 2],
+    board: &Board,
+    villain_range: &Range,
+    thresholds: &RiverThresholds,
+) -> RiverClassification {
+    let board = board.cards();
+    assert!(
+        board.len() == 5,
+        "Hands are classified on the river, got {} board cards",
+        board.len()
+    );
+    let hero_score = score_with_board(&hero, board);
+
+    let mut classification = RiverClassification {
+        class: RiverClass::Air,
+        ahead: 0.0,
+        tied: 0.0,
+        behind: 0.0,
+    };
+    for (combo, weight) in villain_range.get_combos() {
+        if combo
+            .iter()
+            .any(|card| hero.contains(card) || board.contains(card))
+        {
+            continue;
+        }
+        let villain_score = score_with_board(combo, board);
+        if hero_score > villain_score {
+            classification.ahead += weight;
+        } else if hero_score == villain_score {
+            classification.tied += weight;
+        } else {
+            classification.behind += weight;
+        }
+    }
+
+    classification.class = if classification.total() > 0.0 && classification.behind == 0.0 {
+        RiverClass::Nuts
+    } else {
+        thresholds.classify(classification.hand_strength())
+    };
+    classification
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hole(s: &str) -> [Card; 2] {
+        let cards: Vec<Card> = s
+            .split_whitespace()
+            .map(|c| Card::new_from_str(c).unwrap())
+            .collect();
+        [cards[0], cards[1]]
+    }
+
+    #[test]
+    fn test_nuts() {
+        let board = Board::new_from_str("Kh 9h 5h 2c 3d").unwrap();
+        let range = Range::new_from_str("AA, KK, 99, 55, AK, QhJh, 7h6h").unwrap();
+        let classification = river_classification(hole("Ah Th"), &board, &range);
+        assert_eq!(classification.class, RiverClass::Nuts);
+        assert_eq!(classification.behind, 0.0);
+        assert_eq!(classification.tied, 0.0);
+        assert!(classification.ahead > 0.0);
+
+        // Chopping with the board is still the nuts.
+        let board = Board::new_from_str("Ah Kd Qc Jh Ts").unwrap();
+        let range = Range::new_from_str("22, 33").unwrap();
+        let classification = river_classification(hole("4c 5d"), &board, &range);
+        assert_eq!(classification.class, RiverClass::Nuts);
+        assert_eq!(classification.tied, 12.0);
+    }
+
+    #[test]
+    fn test_bluff_catcher() {
+        // Sevens beat only the missed flush draws.
+        let board = Board::new_from_str("Kd 9h 5h 2c 3s").unwrap();
+        let range = Range::new_from_str("AK, 99, 55, JhTh, Th8h, 8h7h").unwrap();
+        let classification = river_classification(hole("7s 7d"), &board, &range);
+        assert_eq!(classification.class, RiverClass::BluffCatcher);
+        assert_eq!(classification.ahead, 3.0);
+        assert_eq!(classification.behind, 12.0 + 3.0 + 3.0);
+
+        // Eight high does not even beat the draws.
+        let classification = river_classification(hole("8s 4d"), &board, &range);
+        assert_eq!(classification.class, RiverClass::Air);
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let board = Board::new_from_str("Kd 9h 5h 2c 3s").unwrap();
+        let range = Range::new_from_str("AK, QQ, JJ, TT, JhTh, Th8h").unwrap();
+        let kings = river_classification(hole("Ks Qd"), &board, &range);
+        assert_eq!(kings.behind, 8.0);
+        assert_eq!(kings.ahead, 17.0);
+        assert_eq!(kings.class, RiverClass::ThinValue);
+
+        let thresholds = RiverThresholds {
+            strong_value: 0.6,
+            ..RiverThresholds::default()
+        };
+        let kings = river_classification_with(hole("Ks Qd"), &board, &range, &thresholds);
+        assert_eq!(kings.class, RiverClass::StrongValue);
+
+        let empty = Range::new_from_str("KdQd").unwrap();
+        assert_eq!(
+            river_classification(hole("Ks Qs"), &board, &empty).class,
+            RiverClass::Air
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_requires_river() {
+        let board = Board::new_from_str("Kd 9h 5h").unwrap();
+        river_classification(hole("As Ad"), &board, &Range::full());
+    }
+}
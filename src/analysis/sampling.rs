@@ -0,0 +1,225 @@
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::card::Card;
+use crate::equity::EquityError;
+use crate::range::Range;
+
+use super::combos::live_cards;
+
+/// The number of deals `sample_from_ranges` tries before giving up.
+pub const MAX_SAMPLE_ATTEMPTS: usize = 1000;
+
+/// Deals random hole cards to a number of opponents from the cards that are
+/// not dead.
+///
+/// The live cards are shuffled and cut into pairs, so every deal of
+/// disjoint hole cards is equally likely and no card is ever drawn twice.
+/// A seeded generator always deals the same hole cards.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator.
+/// * `num_opponents` - The number of opponents to deal to.
+/// * `dead` - The cards that cannot be dealt, e.g. the hero's hole cards and
+///   the board.
+///
+/// # Returns
+///
+/// * `Vec<[Card; 2]>` - The hole cards of every opponent.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::sample_opponents;
+/// use pkr::card::Card;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let dead = [Card::new_from_str("As").unwrap(), Card::new_from_str("Ah").unwrap()];
+/// let opponents = sample_opponents(&mut StdRng::seed_from_u64(1), 3, &dead);
+///
+/// assert_eq!(opponents.len(), 3);
+/// assert!(opponents.iter().flatten().all(|card| !dead.contains(card)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if fewer than two live cards per opponent are left.
+pub fn sample_opponents<R: Rng + ?Sized>(
+    rng: &mut R,
+    num_opponents: usize,
+    dead: &[Card],
+) -> Vec<[Card; 2]> {
+    let mut live = live_cards(dead);
+    let needed = 2 * num_opponents;
+    assert!(
+        live.len() >= needed,
+        "Cannot deal {} opponents from {} live cards.",
+        num_opponents,
+        live.len()
+    );
+    let (dealt, _) = live.partial_shuffle(rng, needed);
+    dealt
+        .chunks_exact(2)
+        .map(|pair| [pair[0], pair[1]])
+        .collect()
+}
+
+/// Deals random hole cards to every opponent from their own range.
+///
+/// Each attempt draws one combination of every range by its weight, leaving
+/// out combinations with a dead card, and is kept only if no two opponents
+/// share a card. Redrawing the whole deal keeps every compatible deal at
+/// the product of its weights. After `MAX_SAMPLE_ATTEMPTS` failed attempts
+/// the ranges are considered to collide and an error is returned instead of
+/// looping on.
+///
+/// # Arguments
+///
+/// * `rng` - The random number generator.
+/// * `ranges` - The range of every opponent.
+/// * `dead` - The cards that cannot be dealt.
+///
+/// # Returns
+///
+/// * `Vec<[Card; 2]>` - The hole cards of every opponent, in the order of
+///   the ranges.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::sample_from_ranges;
+/// use pkr::range::Range;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let ranges = [Range::new_from_str("AA").unwrap(), Range::new_from_str("KK").unwrap()];
+/// let opponents = sample_from_ranges(&mut StdRng::seed_from_u64(1), &ranges, &[]).unwrap();
+///
+/// assert!(ranges[0].contains(&opponents[0]));
+/// assert!(ranges[1].contains(&opponents[1]));
+/// ```
+///
+/// # Errors
+///
+/// Returns `EquityError::EmptyRange` if every combination of a range is
+/// dead or has weight zero, and `EquityError::RangesCollide` if no deal was
+/// found within `MAX_SAMPLE_ATTEMPTS` attempts.
+pub fn sample_from_ranges<R: Rng + ?Sized>(
+    rng: &mut R,
+    ranges: &[Range],
+    dead: &[Card],
+) -> Result<Vec<[Card; 2]>, EquityError> {
+    let live: Vec<(Vec<[Card; 2]>, WeightedIndex<f64>)> = ranges
+        .iter()
+        .map(|range| {
+            let (combos, weights): (Vec<[Card; 2]>, Vec<f64>) = range
+                .get_combos()
+                .iter()
+                .filter(|(combo, weight)| {
+                    *weight > 0.0 && !combo.iter().any(|card| dead.contains(card))
+                })
+                .copied()
+                .unzip();
+            let index = WeightedIndex::new(weights).map_err(|_| EquityError::EmptyRange)?;
+            Ok((combos, index))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut dealt: Vec<[Card; 2]> = Vec::with_capacity(ranges.len());
+    for _ in 0..MAX_SAMPLE_ATTEMPTS {
+        dealt.clear();
+        for (combos, index) in &live {
+            let combo = combos[index.sample(rng)];
+            if dealt.iter().flatten().any(|card| combo.contains(card)) {
+                break;
+            }
+            dealt.push(combo);
+        }
+        if dealt.len() == ranges.len() {
+            return Ok(dealt);
+        }
+    }
+    Err(EquityError::RangesCollide)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    fn cards(s: &str) -> Vec<Card> {
+        s.split_whitespace()
+            .map(|c| Card::new_from_str(c).unwrap())
+            .collect()
+    }
+
+    fn assert_disjoint(hands: &[[Card; 2]], dead: &[Card]) {
+        let mut seen = dead.to_vec();
+        for card in hands.iter().flatten() {
+            assert!(!seen.contains(card), "{} dealt twice", card.as_str());
+            seen.push(*card);
+        }
+    }
+
+    #[test]
+    fn test_sample_opponents() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let dead = cards("As Ks Qh Jh Tc");
+        for num_opponents in 0..=23 {
+            let opponents = sample_opponents(&mut rng, num_opponents, &dead);
+            assert_eq!(opponents.len(), num_opponents);
+            assert_disjoint(&opponents, &dead);
+        }
+        assert_eq!(
+            sample_opponents(&mut StdRng::seed_from_u64(1), 4, &dead),
+            sample_opponents(&mut StdRng::seed_from_u64(1), 4, &dead)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot deal 24 opponents from 47 live cards.")]
+    fn test_sample_too_many_opponents() {
+        sample_opponents(&mut StdRng::seed_from_u64(1), 24, &cards("As Ks Qh Jh Tc"));
+    }
+
+    #[test]
+    fn test_sample_from_ranges() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let ranges: Vec<Range> = ["AA, KK", "AK, AQ", "22+", "T9s+"]
+            .iter()
+            .map(|s| Range::new_from_str(s).unwrap())
+            .collect();
+        let dead = cards("Ac 9s");
+        for _ in 0..500 {
+            let opponents = sample_from_ranges(&mut rng, &ranges, &dead).unwrap();
+            assert_disjoint(&opponents, &dead);
+            for (range, hole) in ranges.iter().zip(&opponents) {
+                assert!(range.contains(hole));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sample_from_colliding_ranges() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let range = |s: &str| Range::new_from_str(s).unwrap();
+
+        // Six combinations of aces, but only two players can hold them.
+        let ranges = vec![range("AA"); 3];
+        assert_eq!(
+            sample_from_ranges(&mut rng, &ranges, &[]),
+            Err(EquityError::RangesCollide)
+        );
+
+        assert_eq!(
+            sample_from_ranges(&mut rng, &[range("AA"), range("AsKs")], &cards("Ks")),
+            Err(EquityError::EmptyRange)
+        );
+        assert_eq!(sample_from_ranges(&mut rng, &[], &[]), Ok(vec![]));
+    }
+}
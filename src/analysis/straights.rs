@@ -0,0 +1,111 @@
+use crate::board::Board;
+use crate::card::Rank;
+
+/// A straight that can be completed on a board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StraightInfo {
+    /// The high card of the straight, `Five` for the wheel.
+    pub high: Rank,
+    /// The ranks the hole cards need to complete the straight, in descending
+    /// order. Empty if the board itself is the straight.
+    pub needed: Vec<Rank>,
+}
+
+/// Lists every straight that can be completed on the board with at most two
+/// hole cards, from the highest straight down.
+///
+/// A hand holding the needed ranks makes at least this straight, e.g. on
+/// "9 8 7" the ten-high straight needs "T 6" and the jack-high straight
+/// needs "J T".
+///
+/// # Arguments
+///
+/// * `board` - The community cards.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::straights_possible;
+/// use pkr::board::Board;
+/// use pkr::card::Rank;
+///
+/// let board = Board::new_from_str("9s 8h 7c").unwrap();
+/// let straights = straights_possible(&board);
+/// assert_eq!(straights[0].high, Rank::Jack);
+/// assert_eq!(straights[0].needed, [Rank::Jack, Rank::Ten]);
+/// assert_eq!(straights.len(), 3);
+/// ```
+pub fn straights_possible(board: &Board) -> Vec<StraightInfo> {
+    let mut on_board = [false; 13];
+    for card in board.cards() {
+        on_board[card.rank.to_index()] = true;
+    }
+
+    // The rank indices of every straight, from ace-high down to the wheel.
+    (3..=12)
+        .rev()
+        .filter_map(|high: usize| {
+            let ranks: Vec<usize> = (0..5).map(|i| (high + 13 - i) % 13).collect();
+            let needed: Vec<Rank> = ranks
+                .into_iter()
+                .filter(|&index| !on_board[index])
+                .map(|index| Rank::new_from_num(index + 2).expect("Rank index is valid"))
+                .collect();
+            (needed.len() <= 2).then(|| StraightInfo {
+                high: Rank::new_from_num(high + 2).expect("Rank index is valid"),
+                needed,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+
+    fn straights(board: &str) -> Vec<(Rank, Vec<Rank>)> {
+        straights_possible(&Board::new_from_str(board).unwrap())
+            .into_iter()
+            .map(|info| (info.high, info.needed))
+            .collect()
+    }
+
+    #[test]
+    fn test_connected_flop() {
+        assert_eq!(
+            straights("9s 8h 7c"),
+            [
+                (Jack, vec![Jack, Ten]),
+                (Ten, vec![Ten, Six]),
+                (Nine, vec![Six, Five]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wheel_and_broadway() {
+        assert_eq!(straights("As 4h 3c 9d"), [(Five, vec![Five, Two])]);
+        assert!(straights("Ks Qh 4c").is_empty());
+        assert_eq!(
+            straights("Ks Qh Jc 2d"),
+            [(Ace, vec![Ace, Ten]), (King, vec![Ten, Nine]),]
+        );
+    }
+
+    #[test]
+    fn test_made_straights() {
+        assert_eq!(
+            straights("Ts 9h 8c 7d 6s"),
+            [
+                (Queen, vec![Queen, Jack]),
+                (Jack, vec![Jack]),
+                (Ten, vec![]),
+                (Nine, vec![Five]),
+                (Eight, vec![Five, Four]),
+            ]
+        );
+        assert!(straights("As Kh 7c 2d").is_empty());
+        assert!(straights_possible(&Board::Preflop).is_empty());
+    }
+}
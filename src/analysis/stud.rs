@@ -0,0 +1,92 @@
+use core::cmp::Reverse;
+
+use crate::card::{Card, Rank, Suit};
+use crate::hand::{Evaluator, FastEvaluator};
+
+/// Orders seven card stud players by their exposed cards, from the best
+/// board to the worst.
+///
+/// The exposed cards are scored like a partial hand by `FastEvaluator`, so
+/// a pair outranks any high card and straights and flushes only count once
+/// five cards are showing. Boards of the same score are ordered by their
+/// cards from the highest down, where equal ranks are broken by the suit
+/// order club < diamond < heart < spade. On third street this makes the
+/// last player the one to bring in, the lowest up card with the lowest
+/// suit, and on later streets the first player acts first.
+///
+/// # Arguments
+///
+/// * `exposed` - The up cards of every player.
+///
+/// # Returns
+///
+/// * `Vec<usize>` - The indices of the players, from the best exposed
+///   cards to the worst.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::stud_board_order;
+/// use pkr::card::Card;
+///
+/// let up = ["7c", "7s", "Kd"].map(|c| [Card::new_from_str(c).unwrap()]);
+/// let exposed: Vec<&[Card]> = up.iter().map(|cards| &cards[..]).collect();
+///
+/// let order = stud_board_order(&exposed);
+/// assert_eq!(order, [2, 1, 0]);
+/// // The seven of clubs brings in.
+/// assert_eq!(order.last(), Some(&0));
+/// ```
+pub fn stud_board_order(exposed: &[&[Card]]) -> Vec<usize> {
+    let keys: Vec<(u32, Vec<(Rank, Suit)>)> = exposed
+        .iter()
+        .map(|cards| {
+            let mut ranked: Vec<(Rank, Suit)> =
+                cards.iter().map(|card| (card.rank, card.suit)).collect();
+            ranked.sort_unstable_by(|a, b| b.cmp(a));
+            (FastEvaluator.score(cards), ranked)
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..exposed.len()).collect();
+    order.sort_by_key(|&player| Reverse(&keys[player]));
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(boards: &[&str]) -> Vec<usize> {
+        let cards: Vec<Vec<Card>> = boards
+            .iter()
+            .map(|board| {
+                board
+                    .split_whitespace()
+                    .map(|c| Card::new_from_str(c).unwrap())
+                    .collect()
+            })
+            .collect();
+        let exposed: Vec<&[Card]> = cards.iter().map(Vec::as_slice).collect();
+        stud_board_order(&exposed)
+    }
+
+    #[test]
+    fn test_bring_in() {
+        assert_eq!(order(&["9h", "2d", "As", "5c"]).last(), Some(&1));
+        // Equal ranks bring in by suit, clubs first.
+        assert_eq!(order(&["2h", "2s", "2c", "2d"]), [1, 0, 3, 2]);
+        assert_eq!(order(&["Kd", "3s", "3d"]).last(), Some(&2));
+    }
+
+    #[test]
+    fn test_high_board_acts_first() {
+        // A pair outranks any high card.
+        assert_eq!(order(&["Ah Kh", "4c 4d", "Qs Js"])[0], 1);
+        assert_eq!(order(&["Ah Kh 9c", "4c 4d 2s", "Qs Js Qd"]), [2, 1, 0]);
+        // Equal pairs are broken by the kicker, then by the highest suit.
+        assert_eq!(order(&["8c 8d 3s", "8h 8s 5c"]), [1, 0]);
+        assert_eq!(order(&["8c 8s 5d", "8h 8d 5c"]), [0, 1]);
+        // Four cards make no straight or flush.
+        assert_eq!(order(&["9s 8s 7s 6s", "Ah 2c 3d 4h"]), [1, 0]);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::board::Board;
+use crate::card::Card;
+use crate::combinations::combinations;
+use crate::hand::{Hand, HoleCards};
+
+/// How the hole cards take part in the best five card hand, see
+/// `made_hand_usage`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HandUsage {
+    /// The best five cards, or all cards if there are fewer than five, in
+    /// the order of `Hand::best_five`.
+    pub best_five: Vec<Card>,
+    /// The hole cards among the best five cards.
+    pub hole_cards_used: Vec<Card>,
+    /// The score of the best five cards.
+    pub score: u32,
+}
+
+impl HandUsage {
+    /// Returns the number of hole cards used, between 0 and 2.
+    pub fn get_count(&self) -> usize {
+        self.hole_cards_used.len()
+    }
+
+    /// Returns whether the board alone makes the best hand.
+    pub fn board_plays(&self) -> bool {
+        self.hole_cards_used.is_empty()
+    }
+}
+
+/// Reports which hole cards are part of the best five card hand made with
+/// the board.
+///
+/// If several five card hands reach the best score, the one using the
+/// fewest hole cards is reported, so a hole card only counts as used if the
+/// hand would be worse without it. This tells "the board plays" apart from
+/// hands that are really made with the hole cards. Remaining ties prefer
+/// cards of the lower suit index like `Hand::best_five`, so the result does
+/// not depend on the order of the cards.
+///
+/// # Arguments
+///
+/// * `hole` - The hole cards.
+/// * `board` - The community cards.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::analysis::made_hand_usage;
+/// use pkr::board::Board;
+/// use pkr::hand::HoleCards;
+///
+/// let board = Board::new_from_str("9s 8h 7c 6d 5s").unwrap();
+/// let usage = made_hand_usage(&HoleCards::new_from_str("2c2d").unwrap(), &board);
+/// assert!(usage.board_plays());
+///
+/// let usage = made_hand_usage(&HoleCards::new_from_str("Tc2d").unwrap(), &board);
+/// assert_eq!(usage.get_count(), 1);
+/// assert_eq!(usage.hole_cards_used[0].as_str(), "Tc");
+/// ```
+///
+/// # Panics
+///
+/// Panics if a hole card is also on the board.
+pub fn made_hand_usage(hole: &HoleCards, board: &Board) -> HandUsage {
+    let hole_cards = hole.get_cards();
+    let mut cards = board.cards().to_vec();
+    cards.extend_from_slice(&hole_cards);
+
+    let mut best: Option<(HandUsage, Vec<(u32, usize)>)> = None;
+    for five in combinations(&cards, cards.len().min(5)) {
+        let hole_cards_used: Vec<Card> = hole_cards
+            .iter()
+            .filter(|card| five.contains(card))
+            .copied()
+            .collect();
+        let hand = Hand::new(five).expect("Hole cards and board must be distinct");
+        let score = hand.get_score();
+        let best_five = hand.best_five();
+        let suits = suit_key(&best_five);
+        let better = best.as_ref().is_none_or(|(best, best_suits)| {
+            (
+                score,
+                core::cmp::Reverse(hole_cards_used.len()),
+                core::cmp::Reverse(&suits),
+            ) > (
+                best.score,
+                core::cmp::Reverse(best.hole_cards_used.len()),
+                core::cmp::Reverse(best_suits),
+            )
+        });
+        if better {
+            let usage = HandUsage {
+                best_five,
+                hole_cards_used,
+                score,
+            };
+            best = Some((usage, suits));
+        }
+    }
+    best.expect("Hole cards and board form at least one hand").0
+}
+
+/// Returns the ranks and suit indices of the cards sorted by rank
+/// descending, then by suit index, so that of two sets of cards of the same
+/// ranks the one with lower suits has the lower key.
+fn suit_key(cards: &[Card]) -> Vec<(u32, usize)> {
+    let mut key: Vec<(u32, usize)> = cards
+        .iter()
+        .map(|card| (14 - card.rank.as_num(), card.suit.to_index()))
+        .collect();
+    key.sort_unstable();
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(hole: &str, board: &str) -> HandUsage {
+        made_hand_usage(
+            &HoleCards::new_from_str(hole).unwrap(),
+            &Board::new_from_str(board).unwrap(),
+        )
+    }
+
+    fn used(usage: &HandUsage) -> Vec<String> {
+        usage.hole_cards_used.iter().map(Card::as_str).collect()
+    }
+
+    #[test]
+    fn test_board_quads_with_kicker() {
+        let quads = usage("AhKd", "7s 7h 7c 7d 2s");
+        assert_eq!(used(&quads), ["Ah"]);
+        assert_eq!(
+            quads.score,
+            Hand::new_from_str("7s 7h 7c 7d Ah").unwrap().get_score()
+        );
+
+        assert!(usage("3h2d", "7s 7h 7c 7d Ks").board_plays());
+    }
+
+    #[test]
+    fn test_board_straight() {
+        let straight = usage("2c2d", "9s 8h 7c 6d 5s");
+        assert!(straight.board_plays());
+        assert_eq!(straight.get_count(), 0);
+        assert_eq!(straight.best_five.len(), 5);
+
+        assert_eq!(used(&usage("Tc2d", "9s 8h 7c 6d 5s")), ["Tc"]);
+        assert_eq!(used(&usage("Tc Jd", "9s 8h 7c 6d 5s")), ["Tc", "Jd"]);
+    }
+
+    #[test]
+    fn test_sets() {
+        let set = usage("7h7d", "7s Kh Qc 3d 2s");
+        assert_eq!(set.get_count(), 2);
+
+        // Trips with one hole card and a board pair.
+        let trips = usage("7h2d", "7s 7c Kh Qc Js");
+        assert_eq!(used(&trips), ["7h"]);
+
+        // A pocket pair below the board pair only plays as a full house.
+        let full_house = usage("5h5d", "7s 7c 7h Kc 2s");
+        assert_eq!(full_house.get_count(), 2);
+        let board_trips = usage("4h3d", "7s 7c 7h Kc Qs");
+        assert!(board_trips.board_plays());
+    }
+
+    #[test]
+    fn test_short_boards() {
+        assert_eq!(usage("AhKd", "7s 7h 2c").get_count(), 2);
+        let preflop = made_hand_usage(&HoleCards::new_from_str("AhKd").unwrap(), &Board::Preflop);
+        assert_eq!(preflop.best_five.len(), 2);
+        assert_eq!(preflop.get_count(), 2);
+    }
+}
@@ -0,0 +1,90 @@
+//! `proptest` strategies for cards and hands, enabled with the `proptest`
+//! feature.
+//!
+//! ```
+//! use pkr::arbitrary::arb_hand;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn evaluates_every_hand(hand in arb_hand(7)) {
+//!         prop_assert!(hand.get_score() > 0);
+//!     }
+//! }
+//! # evaluates_every_hand();
+//! ```
+
+use proptest::prelude::*;
+use proptest::sample::subsequence;
+
+use crate::board::Board;
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::hand::{Hand, HoleCards};
+
+/// Returns a strategy generating any of the 52 cards.
+pub fn arb_card() -> impl Strategy<Value = Card> {
+    (0u8..52).prop_map(|byte| Card::from_byte(byte).expect("Every byte below 52 is a card"))
+}
+
+/// Returns a strategy generating valid hands of `n` distinct cards in a
+/// random order.
+///
+/// # Panics
+///
+/// Panics if `n` is not between `Hand::MIN_CARDS` and `Hand::MAX_CARDS`.
+pub fn arb_hand(n: usize) -> impl Strategy<Value = Hand> {
+    assert!(
+        (Hand::MIN_CARDS..=Hand::MAX_CARDS).contains(&n),
+        "A poker hand must have between {} and {} cards, got {}.",
+        Hand::MIN_CARDS,
+        Hand::MAX_CARDS,
+        n
+    );
+    subsequence(Deck::new().get_cards().to_vec(), n)
+        .prop_shuffle()
+        .prop_map(|cards| Hand::new(cards).expect("The cards are distinct"))
+}
+
+/// Returns a strategy generating distinct hole cards.
+pub fn arb_hole_cards() -> impl Strategy<Value = HoleCards> {
+    subsequence(Deck::new().get_cards().to_vec(), 2)
+        .prop_shuffle()
+        .prop_map(|cards| HoleCards::new(cards[0], cards[1]).expect("The cards are distinct"))
+}
+
+/// Returns a strategy generating boards of every street, from an empty
+/// preflop board to a river.
+pub fn arb_board() -> impl Strategy<Value = Board> {
+    prop_oneof![Just(0usize), Just(3), Just(4), Just(5)]
+        .prop_flat_map(|n| subsequence(Deck::new().get_cards().to_vec(), n).prop_shuffle())
+        .prop_map(|cards| Board::try_from(&cards[..]).expect("The cards are distinct"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_arb_hand_is_valid(hand in arb_hand(7)) {
+            prop_assert_eq!(hand.get_count(), 7);
+            prop_assert!(Hand::new(hand.into_cards()).is_ok());
+        }
+
+        #[test]
+        fn test_arb_card_round_trips(card in arb_card()) {
+            prop_assert_eq!(Card::from_byte(card.to_byte()), Ok(card));
+        }
+
+        #[test]
+        fn test_arb_board_is_valid(board in arb_board()) {
+            prop_assert!([0, 3, 4, 5].contains(&board.cards().len()));
+        }
+
+        #[test]
+        fn test_arb_hole_cards_are_distinct(hole_cards in arb_hole_cards()) {
+            let [first, second] = hole_cards.get_cards();
+            prop_assert_ne!(first, second);
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use std::env;
+use std::error::Error;
+use std::process;
+
+use pkr::board::Board;
+use pkr::equity::{equity_exact, equity_monte_carlo, Equity};
+use pkr::hand::{Hand, HandValue, HoleCards};
+
+const USAGE: &str = "Usage:
+    pkr eval <hand> [--json]
+    pkr compare <hand> <hand> [--json]
+    pkr equity <hole cards>... [--board <cards>] [--trials <n>] [--seed <n>] [--json]
+
+Without --trials the equity is calculated exactly by enumerating every runout.";
+
+/// The options shared by all subcommands.
+struct Options {
+    json: bool,
+    board: Option<String>,
+    trials: Option<usize>,
+    seed: u64,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (positional, options) = match parse_args(&args) {
+        Ok(parsed) => parsed,
+        Err(e) => exit_with_usage(&e.to_string()),
+    };
+
+    let result = match positional.split_first() {
+        Some((command, rest)) if command == "eval" && rest.len() == 1 => eval(&rest[0], &options),
+        Some((command, rest)) if command == "compare" && rest.len() == 2 => {
+            compare(&rest[0], &rest[1], &options)
+        }
+        Some((command, rest)) if command == "equity" && !rest.is_empty() => equity(rest, &options),
+        _ => exit_with_usage("Invalid command"),
+    };
+
+    match result {
+        Ok(output) => println!("{}", output),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("error: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn parse_args(args: &[String]) -> Result<(Vec<String>, Options), Box<dyn Error>> {
+    let mut positional = Vec::new();
+    let mut options = Options {
+        json: false,
+        board: None,
+        trials: None,
+        seed: 0,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--json" => options.json = true,
+            "--board" => options.board = Some(value()?.clone()),
+            "--trials" => options.trials = Some(value()?.parse()?),
+            "--seed" => options.seed = value()?.parse()?,
+            _ if arg.starts_with("--") => return Err(format!("Unknown option {}", arg).into()),
+            _ => positional.push(arg.clone()),
+        }
+    }
+
+    Ok((positional, options))
+}
+
+fn eval(hand: &str, options: &Options) -> Result<String, Box<dyn Error>> {
+    let value = Hand::new_from_str(hand)?.get_value();
+
+    if options.json {
+        Ok(format!(
+            "{{\"hand\":{},{}}}",
+            json_string(hand),
+            value_fields(&value)
+        ))
+    } else {
+        Ok(format!(
+            "Score: {}\nCategory: {:?}\nDescription: {}",
+            value.get_score(),
+            value.get_hand_rank(),
+            value.describe()
+        ))
+    }
+}
+
+fn compare(first: &str, second: &str, options: &Options) -> Result<String, Box<dyn Error>> {
+    let first_value = Hand::new_from_str(first)?.get_value();
+    let second_value = Hand::new_from_str(second)?.get_value();
+    let winner = match first_value.cmp(&second_value) {
+        std::cmp::Ordering::Greater => "first",
+        std::cmp::Ordering::Less => "second",
+        std::cmp::Ordering::Equal => "tie",
+    };
+
+    if options.json {
+        Ok(format!(
+            "{{\"winner\":\"{}\",\"first\":{{\"hand\":{},{}}},\"second\":{{\"hand\":{},{}}}}}",
+            winner,
+            json_string(first),
+            value_fields(&first_value),
+            json_string(second),
+            value_fields(&second_value)
+        ))
+    } else {
+        let result = match winner {
+            "first" => format!("{} beats {}", first_value, second_value),
+            "second" => format!("{} loses to {}", first_value, second_value),
+            _ => format!("{} ties {}", first_value, second_value),
+        };
+        Ok(format!("Winner: {}\n{}", winner, result))
+    }
+}
+
+fn equity(hands: &[String], options: &Options) -> Result<String, Box<dyn Error>> {
+    let hole_cards = hands
+        .iter()
+        .map(|hand| HoleCards::new_from_str(hand))
+        .collect::<Result<Vec<HoleCards>, _>>()?;
+    let board = match &options.board {
+        Some(board) => Board::new_from_str(board)?,
+        None => Board::Preflop,
+    };
+
+    let equities = match options.trials {
+        Some(trials) => equity_monte_carlo(&hole_cards, &board, trials, options.seed)?,
+        None => equity_exact(&hole_cards, &board)?,
+    };
+
+    if options.json {
+        let players: Vec<String> = hole_cards
+            .iter()
+            .zip(equities.iter())
+            .map(|(hole_cards, equity)| {
+                format!("{{\"hand\":\"{}\",{}}}", hole_cards, equity_fields(equity))
+            })
+            .collect();
+        let method = match options.trials {
+            Some(_) => format!("\"monte_carlo\",\"seed\":{}", options.seed),
+            None => "\"exact\"".to_string(),
+        };
+        Ok(format!(
+            "{{\"board\":\"{}\",\"method\":{},\"trials\":{},\"players\":[{}]}}",
+            board,
+            method,
+            equities[0].get_trials(),
+            players.join(",")
+        ))
+    } else {
+        let lines: Vec<String> = hole_cards
+            .iter()
+            .zip(equities.iter())
+            .map(|(hole_cards, equity)| {
+                format!(
+                    "{}: {:.2}% (win {:.2}%, tie {:.2}%)",
+                    hole_cards,
+                    equity.equity() * 100.0,
+                    equity.win() * 100.0,
+                    equity.tie() * 100.0
+                )
+            })
+            .collect();
+        Ok(lines.join("\n"))
+    }
+}
+
+fn value_fields(value: &HandValue) -> String {
+    format!(
+        "\"score\":{},\"category\":\"{:?}\",\"description\":{}",
+        value.get_score(),
+        value.get_hand_rank(),
+        json_string(&value.describe())
+    )
+}
+
+fn equity_fields(equity: &Equity) -> String {
+    format!(
+        "\"equity\":{},\"win\":{},\"tie\":{},\"lose\":{}",
+        equity.equity(),
+        equity.win(),
+        equity.tie(),
+        equity.lose()
+    )
+}
+
+/// Returns the string as a quoted JSON string literal.
+fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
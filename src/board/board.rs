@@ -0,0 +1,582 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::card::{Card, Suit};
+use crate::hand::{encode_cards, fmt_by_suit};
+
+use super::BoardError;
+
+/// The betting rounds of a hold'em hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+}
+
+/// Represents the community cards of a hold'em hand.
+///
+/// Every street holds exactly the number of cards dealt on it, so a flop
+/// always has three cards. A board never contains the same card twice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Board {
+    #[default]
+    Preflop,
+    Flop([Card; 3]),
+    Turn([Card; 4]),
+    River([Card; 5]),
+}
+
+impl Board {
+    /// Creates a new `Board` from a string.
+    ///
+    /// Cards are separated by whitespace. The streets may additionally be
+    /// separated by `|`, in which case the flop must have three cards and the
+    /// turn and the river one card each.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::board::{Board, Street};
+    ///
+    /// let board = Board::new_from_str("Ah Kd 2c | 7s | 9h").unwrap();
+    /// assert_eq!(board.get_street(), Street::River);
+    ///
+    /// let board = Board::new_from_str("Ah Kd 2c 7s").unwrap();
+    /// assert_eq!(board.get_street(), Street::Turn);
+    ///
+    /// assert!(Board::new_from_str("Ah Kd | 2c 7s").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BoardError` if a card cannot be parsed, if a street has the
+    /// wrong number of cards or if a card appears more than once.
+    pub fn new_from_str(s: &str) -> Result<Self, BoardError> {
+        let streets: Vec<&str> = s.split('|').collect();
+        if streets.len() > 3 {
+            return Err(BoardError::TooManyStreets(streets.len()));
+        }
+
+        let mut cards = Vec::with_capacity(5);
+        for (i, street) in streets.iter().enumerate() {
+            let street_cards = street
+                .split_whitespace()
+                .map(Card::new_from_str)
+                .collect::<Result<Vec<Card>, _>>()?;
+
+            let (street, expected) = [(Street::Flop, 3), (Street::Turn, 1), (Street::River, 1)][i];
+            if streets.len() > 1 && street_cards.len() != expected {
+                return Err(BoardError::InvalidStreet {
+                    street,
+                    expected,
+                    found: street_cards.len(),
+                });
+            }
+            cards.extend(street_cards);
+        }
+
+        Board::try_from(cards.as_slice())
+    }
+
+    /// Returns the street the board is on.
+    pub fn get_street(&self) -> Street {
+        match self {
+            Board::Preflop => Street::Preflop,
+            Board::Flop(_) => Street::Flop,
+            Board::Turn(_) => Street::Turn,
+            Board::River(_) => Street::River,
+        }
+    }
+
+    /// Returns the cards on the board in the order they were dealt.
+    pub fn cards(&self) -> &[Card] {
+        match self {
+            Board::Preflop => &[],
+            Board::Flop(cards) => cards,
+            Board::Turn(cards) => cards,
+            Board::River(cards) => cards,
+        }
+    }
+
+    /// Encodes the board like `Hand::to_bytes`, with the cards in the order
+    /// they were dealt. The encoding is stable across versions of the crate.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encode_cards(self.cards())
+    }
+
+    /// Decodes a board encoded by `Board::to_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::board::Board;
+    ///
+    /// let board = Board::new_from_str("Ah Kd 2c").unwrap();
+    /// assert_eq!(Board::from_bytes(&board.to_bytes()).unwrap(), board);
+    /// assert_eq!(Board::from_bytes(&[0]).unwrap(), Board::Preflop);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoardError::InvalidLength` if the length byte does not match
+    /// the number of bytes, `BoardError::InvalidCardCount` if the number of
+    /// cards is not 0, 3, 4 or 5, `BoardError::InvalidByte` if a byte does
+    /// not encode a card and `BoardError::DuplicateCard` if a card appears
+    /// more than once.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BoardError> {
+        let (&count, card_bytes) = bytes.split_first().ok_or(BoardError::InvalidLength {
+            expected: 1,
+            found: 0,
+        })?;
+        if card_bytes.len() != count as usize {
+            return Err(BoardError::InvalidLength {
+                expected: count as usize + 1,
+                found: bytes.len(),
+            });
+        }
+        if !matches!(count, 0 | 3 | 4 | 5) {
+            return Err(BoardError::InvalidCardCount(count as usize));
+        }
+
+        let cards = card_bytes
+            .iter()
+            .enumerate()
+            .map(|(index, &byte)| {
+                Card::from_byte(byte).map_err(|_| BoardError::InvalidByte { index, byte })
+            })
+            .collect::<Result<Vec<Card>, _>>()?;
+        Board::try_from(cards.as_slice())
+    }
+
+    /// Deals the flop.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BoardError` if the board is not preflop or if the flop
+    /// contains the same card twice.
+    pub fn deal_flop(&mut self, flop: [Card; 3]) -> Result<(), BoardError> {
+        match self {
+            Board::Preflop => {
+                check_duplicates(&flop)?;
+                *self = Board::Flop(flop);
+                Ok(())
+            }
+            _ => Err(BoardError::IllegalDeal {
+                dealing: Street::Flop,
+                current: self.get_street(),
+            }),
+        }
+    }
+
+    /// Deals the turn card.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::board::{Board, Street};
+    /// use pkr::card::Card;
+    ///
+    /// let mut board = Board::new_from_str("Ah Kd 2c").unwrap();
+    /// board.deal_turn(Card::new_from_str("7s").unwrap()).unwrap();
+    /// assert_eq!(board.get_street(), Street::Turn);
+    ///
+    /// assert!(board.deal_turn(Card::new_from_str("9h").unwrap()).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BoardError` if the board is not on the flop or if the card
+    /// is already on the board.
+    pub fn deal_turn(&mut self, card: Card) -> Result<(), BoardError> {
+        match *self {
+            Board::Flop([a, b, c]) => {
+                check_duplicates(&[a, b, c, card])?;
+                *self = Board::Turn([a, b, c, card]);
+                Ok(())
+            }
+            _ => Err(BoardError::IllegalDeal {
+                dealing: Street::Turn,
+                current: self.get_street(),
+            }),
+        }
+    }
+
+    /// Deals the river card.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BoardError` if the board is not on the turn or if the card
+    /// is already on the board.
+    pub fn deal_river(&mut self, card: Card) -> Result<(), BoardError> {
+        match *self {
+            Board::Turn([a, b, c, d]) => {
+                check_duplicates(&[a, b, c, d, card])?;
+                *self = Board::River([a, b, c, d, card]);
+                Ok(())
+            }
+            _ => Err(BoardError::IllegalDeal {
+                dealing: Street::River,
+                current: self.get_street(),
+            }),
+        }
+    }
+
+    /// Returns the number of board cards of every suit, indexed by
+    /// `Suit::to_index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::board::Board;
+    /// use pkr::card::Suit;
+    ///
+    /// let board = Board::new_from_str("Ah Kh 2c 7h").unwrap();
+    /// let distribution = board.suit_distribution();
+    /// assert_eq!(distribution[Suit::Heart.to_index()], 3);
+    /// assert_eq!(distribution, [1, 0, 3, 0]);
+    /// ```
+    pub fn suit_distribution(&self) -> [u8; 4] {
+        let mut distribution = [0; 4];
+        for card in self.cards() {
+            distribution[card.suit.to_index()] += 1;
+        }
+        distribution
+    }
+
+    /// Returns whether all board cards have the same suit. A preflop board
+    /// is not monotone.
+    pub fn is_monotone(&self) -> bool {
+        self.suits_present() == 1
+    }
+
+    /// Returns whether exactly two suits appear on the board, e.g. a flop
+    /// with two hearts and a club or a turn with two hearts and two clubs.
+    pub fn is_two_tone(&self) -> bool {
+        self.suits_present() == 2
+    }
+
+    /// Returns the suit with at least three cards on the board, with which a
+    /// player can make a flush, or `None` if no flush is possible. Preflop
+    /// no flush is possible yet.
+    ///
+    /// A board of at most five cards has at most one such suit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::board::Board;
+    /// use pkr::card::Suit;
+    ///
+    /// let board = Board::new_from_str("Ah Kh 2c 7h").unwrap();
+    /// assert_eq!(board.flush_possible(), Some(Suit::Heart));
+    /// assert_eq!(board.flush_complete(), None);
+    /// ```
+    pub fn flush_possible(&self) -> Option<Suit> {
+        self.suit_with_at_least(3)
+    }
+
+    /// Returns the suit if all five cards of a river board share it, so
+    /// every player holds at least that flush.
+    pub fn flush_complete(&self) -> Option<Suit> {
+        self.suit_with_at_least(5)
+    }
+
+    fn suits_present(&self) -> usize {
+        self.suit_distribution()
+            .iter()
+            .filter(|&&count| count > 0)
+            .count()
+    }
+
+    fn suit_with_at_least(&self, count: u8) -> Option<Suit> {
+        self.suit_distribution()
+            .iter()
+            .position(|&n| n >= count)
+            .map(|index| Suit::new_from_num(index).expect("Suit index is valid"))
+    }
+}
+
+impl TryFrom<&[Card]> for Board {
+    type Error = BoardError;
+
+    /// Creates a `Board` from zero, three, four or five distinct cards.
+    fn try_from(cards: &[Card]) -> Result<Self, Self::Error> {
+        check_duplicates(cards)?;
+
+        match *cards {
+            [] => Ok(Board::Preflop),
+            [a, b, c] => Ok(Board::Flop([a, b, c])),
+            [a, b, c, d] => Ok(Board::Turn([a, b, c, d])),
+            [a, b, c, d, e] => Ok(Board::River([a, b, c, d, e])),
+            _ => Err(BoardError::InvalidCardCount(cards.len())),
+        }
+    }
+}
+
+impl TryFrom<&Vec<Card>> for Board {
+    type Error = BoardError;
+
+    fn try_from(cards: &Vec<Card>) -> Result<Self, Self::Error> {
+        Board::try_from(cards.as_slice())
+    }
+}
+
+impl FromStr for Board {
+    type Err = BoardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Board::new_from_str(s)
+    }
+}
+
+impl fmt::Display for Board {
+    /// Formats the board with the streets separated by `|`, e.g.
+    /// "Ah Kd 2c | 7s | 9h".
+    ///
+    /// The alternate form `{:#}` shows one line per suit with the ranks in
+    /// descending order, like the alternate form of a `Hand`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            return fmt_by_suit(self.cards(), f);
+        }
+        let cards: Vec<String> = self.cards().iter().map(|card| card.as_str()).collect();
+        let mut streets = Vec::new();
+        if cards.len() >= 3 {
+            streets.push(cards[..3].join(" "));
+        }
+        streets.extend(cards.iter().skip(3).cloned());
+        write!(f, "{}", streets.join(" | "))
+    }
+}
+
+/// Serializes the board as its string form, e.g. "Ah Kd 2c | 7s | 9h".
+#[cfg(feature = "serde")]
+impl serde::Serialize for Board {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+fn check_duplicates(cards: &[Card]) -> Result<(), BoardError> {
+    for (i, card) in cards.iter().enumerate() {
+        if cards[..i].contains(card) {
+            return Err(BoardError::DuplicateCard(*card));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(s: &str) -> Card {
+        Card::new_from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_from_str_with_separators() {
+        let board = Board::from_str("Ah Kd 2c | 7s | 9h").unwrap();
+        assert_eq!(
+            board,
+            Board::River([card("Ah"), card("Kd"), card("2c"), card("7s"), card("9h")])
+        );
+        assert_eq!(board.to_string(), "Ah Kd 2c | 7s | 9h");
+        assert_eq!(board, Board::from_str("Ah Kd 2c 7s 9h").unwrap());
+
+        assert_eq!(Board::from_str("").unwrap(), Board::Preflop);
+        assert_eq!(
+            Board::from_str("Ah Kd 2c | 7s 9h"),
+            Err(BoardError::InvalidStreet {
+                street: Street::Turn,
+                expected: 1,
+                found: 2,
+            })
+        );
+        assert_eq!(
+            Board::from_str("Ah Kd 2c | 7s | 9h | 3c"),
+            Err(BoardError::TooManyStreets(4))
+        );
+        assert_eq!(
+            Board::from_str("Ah Kd"),
+            Err(BoardError::InvalidCardCount(2))
+        );
+        assert!(matches!(
+            Board::from_str("Ah Kd Xx"),
+            Err(BoardError::InvalidCard(_))
+        ));
+    }
+
+    #[test]
+    fn test_illegal_transitions() {
+        let mut board = Board::Preflop;
+        assert_eq!(
+            board.deal_turn(card("7s")),
+            Err(BoardError::IllegalDeal {
+                dealing: Street::Turn,
+                current: Street::Preflop,
+            })
+        );
+        assert!(board.deal_river(card("7s")).is_err());
+
+        board
+            .deal_flop([card("Ah"), card("Kd"), card("2c")])
+            .unwrap();
+        assert!(board
+            .deal_flop([card("3h"), card("4d"), card("5c")])
+            .is_err());
+        assert!(board.deal_river(card("7s")).is_err());
+
+        board.deal_turn(card("7s")).unwrap();
+        board.deal_river(card("9h")).unwrap();
+        assert_eq!(board.get_street(), Street::River);
+        assert!(board.deal_river(card("3c")).is_err());
+        assert_eq!(board.cards().len(), 5);
+    }
+
+    #[test]
+    fn test_duplicate_cards() {
+        let mut board = Board::from_str("Ah Kd 2c").unwrap();
+        assert_eq!(
+            board.deal_turn(card("Kd")),
+            Err(BoardError::DuplicateCard(card("Kd")))
+        );
+        assert_eq!(board.get_street(), Street::Flop);
+
+        assert!(Board::Preflop
+            .deal_flop([card("Ah"), card("Ah"), card("2c")])
+            .is_err());
+        assert!(Board::from_str("Ah Kd 2c | 7s | 7s").is_err());
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        for s in ["", "Ah Kd 2c", "Ah Kd 2c 7s", "Ah Kd 2c 7s 9h"] {
+            let board = Board::from_str(s).unwrap();
+            assert_eq!(Board::from_bytes(&board.to_bytes()).unwrap(), board);
+        }
+
+        assert_eq!(
+            Board::from_bytes(&[2, 0, 1]),
+            Err(BoardError::InvalidCardCount(2))
+        );
+        assert_eq!(
+            Board::from_bytes(&[3, 0, 1, 1]),
+            Err(BoardError::DuplicateCard(card("2d")))
+        );
+        assert_eq!(
+            Board::from_bytes(&[3, 0, 52, 1]),
+            Err(BoardError::InvalidByte { index: 1, byte: 52 })
+        );
+        assert_eq!(
+            Board::from_bytes(&[3, 0, 1]),
+            Err(BoardError::InvalidLength {
+                expected: 4,
+                found: 3
+            })
+        );
+        assert_eq!(
+            Board::from_bytes(&[]),
+            Err(BoardError::InvalidLength {
+                expected: 1,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_random_bytes() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(151);
+        for _ in 0..10_000 {
+            let len = rng.gen_range(0..8);
+            let mut bytes: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+            // Mostly announce the right length to get past the length check.
+            if len > 0 && rng.gen_bool(0.8) {
+                bytes[0] = len as u8 - 1;
+                for byte in &mut bytes[1..] {
+                    *byte %= 56;
+                }
+            }
+
+            match Board::from_bytes(&bytes) {
+                Ok(board) => assert_eq!(board.to_bytes(), bytes),
+                Err(BoardError::InvalidLength { expected, found }) => {
+                    assert_eq!(found, bytes.len());
+                    assert_ne!(expected, found);
+                }
+                Err(BoardError::InvalidCardCount(count)) => {
+                    assert_eq!(count, bytes[0] as usize);
+                    assert!(![0, 3, 4, 5].contains(&count));
+                }
+                Err(BoardError::InvalidByte { index, byte }) => {
+                    assert_eq!(bytes[index + 1], byte);
+                    assert!(byte >= 52);
+                }
+                Err(BoardError::DuplicateCard(card)) => {
+                    let byte = card.to_byte();
+                    assert!(bytes[1..].iter().filter(|&&b| b == byte).count() > 1);
+                }
+                Err(error) => panic!("Unexpected error for {:?}: {}", bytes, error),
+            }
+        }
+    }
+
+    #[test]
+    fn test_alternate_format() {
+        let board = Board::from_str("Ah Kd 2c | 7h | 9h").unwrap();
+        assert_eq!(board.to_string(), "Ah Kd 2c | 7h | 9h");
+        assert_eq!(format!("{:#}", board), "♥ A 9 7\n♦ K\n♣ 2");
+        assert_eq!(format!("{:#}", Board::Preflop), "");
+    }
+
+    #[test]
+    fn test_suit_classification() {
+        let board = |s| Board::from_str(s).unwrap();
+
+        assert_eq!(Board::Preflop.suit_distribution(), [0; 4]);
+        assert!(!Board::Preflop.is_monotone());
+        assert!(!Board::Preflop.is_two_tone());
+        assert_eq!(Board::Preflop.flush_possible(), None);
+
+        let rainbow = board("Ah Kd 2c");
+        assert!(!rainbow.is_monotone());
+        assert!(!rainbow.is_two_tone());
+        assert_eq!(rainbow.flush_possible(), None);
+
+        let two_tone = board("Ah Kh 2c");
+        assert!(two_tone.is_two_tone());
+        assert_eq!(two_tone.flush_possible(), None);
+
+        let monotone = board("Ah Kh 2h");
+        assert!(monotone.is_monotone());
+        assert_eq!(monotone.flush_possible(), Some(Suit::Heart));
+        assert_eq!(monotone.flush_complete(), None);
+
+        let four_to_a_suit = board("As Ks 2s 7s");
+        assert_eq!(four_to_a_suit.suit_distribution(), [0, 0, 0, 4]);
+        assert!(four_to_a_suit.is_monotone());
+        assert_eq!(four_to_a_suit.flush_possible(), Some(Suit::Spade));
+
+        let three_and_one = board("As Ks 2s 7d");
+        assert!(three_and_one.is_two_tone());
+        assert_eq!(three_and_one.flush_possible(), Some(Suit::Spade));
+
+        let double_flush_draw = board("As Ks 2d 7d");
+        assert!(double_flush_draw.is_two_tone());
+        assert_eq!(double_flush_draw.flush_possible(), None);
+
+        let river = board("As Ks 2d 7d 9c");
+        assert_eq!(river.suit_distribution(), [1, 2, 0, 2]);
+        assert_eq!(river.flush_possible(), None);
+
+        let flush = board("As Ks 2s 7s 9s");
+        assert_eq!(flush.flush_complete(), Some(Suit::Spade));
+        assert_eq!(flush.flush_possible(), Some(Suit::Spade));
+    }
+}
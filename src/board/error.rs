@@ -0,0 +1,99 @@
+use std::fmt;
+
+use crate::card::{Card, CardError};
+
+use super::Street;
+
+/// The error returned when creating a board or dealing to it fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoardError {
+    /// A card of a board string cannot be parsed.
+    InvalidCard(CardError),
+    /// The length of an encoded board does not match its length byte.
+    InvalidLength {
+        /// The number of bytes announced by the length byte.
+        expected: usize,
+        /// The number of bytes found.
+        found: usize,
+    },
+    /// A byte of an encoded board does not encode a card.
+    InvalidByte {
+        /// The zero-based position of the card.
+        index: usize,
+        /// The offending byte.
+        byte: u8,
+    },
+    /// A board string has more than the three streets flop, turn and river.
+    TooManyStreets(usize),
+    /// A street of a board string separated by `|` has the wrong number of
+    /// cards.
+    InvalidStreet {
+        /// The street with the wrong number of cards.
+        street: Street,
+        /// The number of cards dealt on the street.
+        expected: usize,
+        /// The number of cards found.
+        found: usize,
+    },
+    /// The number of cards is not 0, 3, 4 or 5.
+    InvalidCardCount(usize),
+    /// The same card appears on the board more than once.
+    DuplicateCard(Card),
+    /// The street cannot be dealt on a board on the current street.
+    IllegalDeal {
+        /// The street that was to be dealt.
+        dealing: Street,
+        /// The street the board is on.
+        current: Street,
+    },
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BoardError::InvalidCard(e) => write!(f, "{}", e),
+            BoardError::InvalidLength { expected, found } => {
+                write!(f, "Expected {} bytes, got {}.", expected, found)
+            }
+            BoardError::InvalidByte { index, byte } => {
+                write!(f, "Invalid card byte {} at position {}.", byte, index)
+            }
+            BoardError::TooManyStreets(found) => {
+                write!(f, "A board has at most 3 streets, found {}.", found)
+            }
+            BoardError::InvalidStreet {
+                street,
+                expected,
+                found,
+            } => write!(
+                f,
+                "The {:?} must have {} card(s), found {}.",
+                street, expected, found
+            ),
+            BoardError::InvalidCardCount(found) => {
+                write!(f, "A board must have 0, 3, 4 or 5 cards, found {}.", found)
+            }
+            BoardError::DuplicateCard(card) => {
+                write!(f, "Duplicate card on the board: {}", card.as_str())
+            }
+            BoardError::IllegalDeal { dealing, current } => {
+                write!(f, "Cannot deal the {:?} on the {:?}.", dealing, current)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BoardError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BoardError::InvalidCard(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<CardError> for BoardError {
+    fn from(e: CardError) -> Self {
+        BoardError::InvalidCard(e)
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod board;
+mod error;
+
+pub use board::{Board, Street};
+pub use error::BoardError;
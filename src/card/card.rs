@@ -1,10 +1,15 @@
-use std::error::Error;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
+use super::CardError;
 use super::Rank;
 use super::Suit;
 
 /// Represents a playing card with a rank and suit in a standard 52-card deck.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Cards are ordered by rank first and by suit for equal ranks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Card {
     pub rank: Rank,
     pub suit: Suit,
@@ -21,8 +26,8 @@ impl Card {
     /// # Arguments
     ///
     /// * `s` - A string slice that holds the card identifier.
-    ///         The first character represents the rank and the second
-    ///         represents the suit.
+    ///   The first character represents the rank and the second
+    ///   represents the suit.
     ///
     /// # Examples
     ///
@@ -35,19 +40,54 @@ impl Card {
     ///
     /// # Errors
     ///
-    /// Returns a `Box<dyn Error>` if the string does not match any card, the
+    /// Returns a `CardError` if the string is not two characters long or the
     /// rank or the suit are invalid.
-    pub fn new_from_str(s: &str) -> Result<Self, Box<dyn Error>> {
-        if s.len() != 2 {
-            return Err("Card string must be of length 2".into());
-        }
+    pub fn new_from_str(s: &str) -> Result<Self, CardError> {
+        let mut chars = s.char_indices();
+        let suit_start = match (chars.next(), chars.next(), chars.next()) {
+            (Some(_), Some((suit_start, _)), None) => suit_start,
+            _ => return Err(CardError::InvalidLength(s.to_string())),
+        };
 
-        let rank = Rank::new_from_str(&s[0..1])?;
-        let suit = Suit::new_from_str(&s[1..2])?;
+        let rank = Rank::new_from_str(&s[..suit_start])?;
+        let suit = Suit::new_from_str(&s[suit_start..])?;
 
         Ok(Self { rank, suit })
     }
 
+    /// Returns the card encoded as a single byte.
+    ///
+    /// The byte is `4 * rank + suit`, where `rank` is `Rank::to_index` and
+    /// `suit` is `Suit::to_index`, so the 52 cards are encoded as 0 ("2c") to
+    /// 51 ("As"). The encoding is stable across versions of the crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Card;
+    ///
+    /// let card = Card::new_from_str("As").unwrap();
+    /// assert_eq!(card.to_byte(), 51);
+    /// assert_eq!(Card::from_byte(51).unwrap(), card);
+    /// ```
+    pub fn to_byte(&self) -> u8 {
+        (self.rank.to_index() * 4 + self.suit.to_index()) as u8
+    }
+
+    /// Creates a new `Card` from its byte encoding, see `Card::to_byte`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CardError::InvalidByte` if the byte is not smaller than 52.
+    pub fn from_byte(byte: u8) -> Result<Self, CardError> {
+        if byte >= 52 {
+            return Err(CardError::InvalidByte(byte));
+        }
+        let rank = Rank::new_from_num(byte as usize / 4 + 2)?;
+        let suit = Suit::new_from_num(byte as usize % 4)?;
+        Ok(Self { rank, suit })
+    }
+
     /// Returns a string representation of the `Card`.
     ///
     /// # Examples
@@ -66,12 +106,90 @@ impl Card {
     pub fn as_str(&self) -> String {
         format!("{}{}", self.rank.as_str(), self.suit.as_str())
     }
+
+    /// Returns every card starting with the prefix, for autocompletion.
+    ///
+    /// An empty prefix completes to all 52 cards, a rank like "A" to the
+    /// four cards of the rank and a full card to itself. A "10" or "1" is
+    /// read as a ten, as `Hand::new_from_str` accepts "10h". The cards are
+    /// ordered from the highest down, spades first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Card;
+    ///
+    /// assert_eq!(Card::completions("A"), ["As", "Ah", "Ad", "Ac"]);
+    /// assert_eq!(Card::completions("Ah"), ["Ah"]);
+    /// assert!(Card::completions("X").is_empty());
+    /// ```
+    pub fn completions(prefix: &str) -> Vec<String> {
+        Card::completions_excluding(prefix, &[])
+    }
+
+    /// Returns every card starting with the prefix like `Card::completions`,
+    /// leaving out the dead cards, e.g. the cards already dealt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Card;
+    ///
+    /// let dealt = [Card::new_from_str("Ah").unwrap()];
+    /// assert_eq!(Card::completions_excluding("A", &dealt), ["As", "Ad", "Ac"]);
+    /// assert!(Card::completions_excluding("Ah", &dealt).is_empty());
+    /// ```
+    pub fn completions_excluding(prefix: &str, dead: &[Card]) -> Vec<String> {
+        let prefix = match prefix.strip_prefix("10") {
+            Some(suit) => format!("T{}", suit),
+            None if prefix == "1" => "T".to_string(),
+            None => prefix.to_string(),
+        };
+        (0..52u8)
+            .rev()
+            .map(|byte| Card::from_byte(byte).expect("Bytes below 52 are cards"))
+            .filter(|card| !dead.contains(card))
+            .map(|card| card.as_str())
+            .filter(|card| card.starts_with(prefix.as_str()))
+            .collect()
+    }
+}
+
+/// Serializes the card as its string form, e.g. "As".
+#[cfg(feature = "serde")]
+impl serde::Serialize for Card {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.as_str())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_completions() {
+        let all = Card::completions("");
+        assert_eq!(all.len(), 52);
+        assert_eq!(all[0], "As");
+        assert_eq!(all[51], "2c");
+        assert_eq!(Card::completions("T"), ["Ts", "Th", "Td", "Tc"]);
+        assert_eq!(Card::completions("10"), Card::completions("T"));
+        assert_eq!(Card::completions("1"), Card::completions("T"));
+        assert_eq!(Card::completions("10d"), ["Td"]);
+        assert_eq!(Card::completions("7c"), ["7c"]);
+        assert!(Card::completions("7x").is_empty());
+        assert!(Card::completions("7cs").is_empty());
+        assert!(Card::completions("h").is_empty());
+
+        let dead: Vec<Card> = ["7c", "7s", "Kd"]
+            .iter()
+            .map(|c| Card::new_from_str(c).unwrap())
+            .collect();
+        assert_eq!(Card::completions_excluding("7", &dead), ["7h", "7d"]);
+        assert_eq!(Card::completions_excluding("", &dead).len(), 49);
+    }
+
     #[test]
     fn new_card_from_string() {
         let card = Card::new_from_str("Ac").unwrap();
@@ -109,5 +227,16 @@ mod tests {
         assert!(Card::new_from_str("As1").is_err());
         assert!(Card::new_from_str("1c").is_err());
         assert!(Card::new_from_str("").is_err());
+        assert!(Card::new_from_str("é").is_err());
+        assert!(Card::new_from_str("Aé").is_err());
+    }
+
+    #[test]
+    fn byte_round_trip() {
+        for byte in 0..52 {
+            assert_eq!(Card::from_byte(byte).unwrap().to_byte(), byte);
+        }
+        assert_eq!(Card::new_from_str("2c").unwrap().to_byte(), 0);
+        assert_eq!(Card::from_byte(52), Err(CardError::InvalidByte(52)));
     }
 }
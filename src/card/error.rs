@@ -0,0 +1,39 @@
+use alloc::string::String;
+use core::fmt;
+
+/// The error returned when parsing or creating a card, rank or suit fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardError {
+    /// The string is not a rank identifier like "A" or "7".
+    InvalidRank(String),
+    /// The string is not a suit identifier like "h" or "s".
+    InvalidSuit(String),
+    /// The number does not correspond to any rank.
+    InvalidRankNumber(usize),
+    /// The number does not correspond to any suit.
+    InvalidSuitNumber(usize),
+    /// The card string is not two characters long.
+    InvalidLength(String),
+    /// The byte does not encode a card.
+    InvalidByte(u8),
+}
+
+impl fmt::Display for CardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardError::InvalidRank(s) => write!(f, "Invalid rank identifier: {}", s),
+            CardError::InvalidSuit(s) => write!(f, "Invalid suit identifier: {}", s),
+            CardError::InvalidRankNumber(num) => {
+                write!(f, "Invalid numerical value for Rank: {}", num)
+            }
+            CardError::InvalidSuitNumber(num) => {
+                write!(f, "Invalid numerical value for Suit: {}", num)
+            }
+            CardError::InvalidLength(s) => write!(f, "Card string must be of length 2: {}", s),
+            CardError::InvalidByte(byte) => write!(f, "Invalid card byte: {}", byte),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CardError {}
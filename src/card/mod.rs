@@ -1,7 +1,10 @@
+#[allow(clippy::module_inception)]
 mod card;
+mod error;
 mod rank;
 mod suit;
 
 pub use card::Card;
+pub use error::CardError;
 pub use rank::Rank;
 pub use suit::Suit;
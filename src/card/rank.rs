@@ -1,7 +1,9 @@
-use std::error::Error;
+use alloc::string::ToString;
+
+use super::CardError;
 
 /// Represents the rank of a playing card in a standard 52-card deck.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Rank {
     AceLow = 1,
     Two,
@@ -37,8 +39,8 @@ impl Rank {
     ///
     /// # Errors
     ///
-    /// Returns a `Box<dyn Error>` if the string does not match any rank.
-    pub fn new_from_str(s: &str) -> Result<Self, Box<dyn Error>> {
+    /// Returns a `CardError::InvalidRank` if the string does not match any rank.
+    pub fn new_from_str(s: &str) -> Result<Self, CardError> {
         match s {
             "2" => Ok(Rank::Two),
             "3" => Ok(Rank::Three),
@@ -53,7 +55,7 @@ impl Rank {
             "Q" => Ok(Rank::Queen),
             "K" => Ok(Rank::King),
             "A" => Ok(Rank::Ace),
-            _ => Err("Invalid rank identifier".into()),
+            _ => Err(CardError::InvalidRank(s.to_string())),
         }
     }
 
@@ -65,9 +67,9 @@ impl Rank {
     ///
     /// # Returns
     ///
-    /// Returns an instance of `Result<Rank, Box<dyn Error>>`.
+    /// Returns an instance of `Result<Rank, CardError>`.
     /// If `num` does not match any Rank, an error is returned.
-    pub fn new_from_num(num: usize) -> Result<Self, Box<dyn Error>> {
+    pub fn new_from_num(num: usize) -> Result<Self, CardError> {
         match num {
             2 => Ok(Rank::Two),
             3 => Ok(Rank::Three),
@@ -82,7 +84,7 @@ impl Rank {
             12 => Ok(Rank::Queen),
             13 => Ok(Rank::King),
             14 => Ok(Rank::Ace),
-            _ => Err(format!("Invalid numerical value for Rank: {}", num).into()),
+            _ => Err(CardError::InvalidRankNumber(num)),
         }
     }
 
@@ -121,6 +123,24 @@ impl Rank {
         }
     }
 
+    /// Returns the zero-based index of the rank, from 0 for `Two` to 12 for
+    /// `Ace`. `AceLow` has the index of `Ace`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Rank;
+    ///
+    /// assert_eq!(Rank::Two.to_index(), 0);
+    /// assert_eq!(Rank::Ace.to_index(), 12);
+    /// ```
+    pub fn to_index(&self) -> usize {
+        match self {
+            Rank::AceLow => 12,
+            _ => self.as_num() as usize - 2,
+        }
+    }
+
     /// Returns the numerical value of a card's rank.
     pub fn as_num(&self) -> u32 {
         match self {
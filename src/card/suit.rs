@@ -1,9 +1,11 @@
-use std::error::Error;
+use alloc::string::ToString;
+
+use super::CardError;
 
 use strum_macros::EnumIter;
 
 /// Represents the suit of a playing card in a standard 52-card deck.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, EnumIter)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter)]
 pub enum Suit {
     Club,
     Diamond,
@@ -29,15 +31,15 @@ impl Suit {
     ///
     /// # Errors
     ///
-    /// Returns an `Box<dyn Error>` if the string does not match
-    /// any suit.
-    pub fn new_from_str(s: &str) -> Result<Self, Box<dyn Error>> {
+    /// Returns a `CardError::InvalidSuit` if the string does not match any
+    /// suit.
+    pub fn new_from_str(s: &str) -> Result<Self, CardError> {
         match s {
             "h" => Ok(Suit::Heart),
             "d" => Ok(Suit::Diamond),
             "c" => Ok(Suit::Club),
             "s" => Ok(Suit::Spade),
-            _ => Err("Invalid suit identifier".into()),
+            _ => Err(CardError::InvalidSuit(s.to_string())),
         }
     }
 
@@ -49,15 +51,15 @@ impl Suit {
     ///
     /// # Returns
     ///
-    /// Returns an instance of `Result<Suit, Box<dyn Error>>`.
+    /// Returns an instance of `Result<Suit, CardError>`.
     /// If `num` does not match any Suit, an error is returned.
-    pub fn new_from_num(num: usize) -> Result<Self, Box<dyn Error>> {
+    pub fn new_from_num(num: usize) -> Result<Self, CardError> {
         match num {
             0 => Ok(Suit::Club),
             1 => Ok(Suit::Diamond),
             2 => Ok(Suit::Heart),
             3 => Ok(Suit::Spade),
-            _ => Err(format!("Invalid numerical value for Suit: {}", num).into()),
+            _ => Err(CardError::InvalidSuitNumber(num)),
         }
     }
 
@@ -82,6 +84,36 @@ impl Suit {
             Suit::Spade => "s",
         }
     }
+
+    /// Returns the symbol of the suit, e.g. '♠' for spades.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Suit;
+    ///
+    /// assert_eq!(Suit::Spade.symbol(), '♠');
+    /// assert_eq!(Suit::Diamond.symbol(), '♦');
+    /// ```
+    pub fn symbol(&self) -> char {
+        match self {
+            Suit::Heart => '♥',
+            Suit::Diamond => '♦',
+            Suit::Club => '♣',
+            Suit::Spade => '♠',
+        }
+    }
+
+    /// Returns the zero-based index of the suit, the inverse of
+    /// `Suit::new_from_num`.
+    pub fn to_index(&self) -> usize {
+        match self {
+            Suit::Club => 0,
+            Suit::Diamond => 1,
+            Suit::Heart => 2,
+            Suit::Spade => 3,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,122 @@
+//! Iterators over the k-element subsets of a slice.
+
+use alloc::vec::Vec;
+
+/// An iterator over all combinations of `k` elements of a slice.
+///
+/// The combinations are yielded in lexicographic order of the positions of
+/// their elements, and the elements of each combination keep the order of
+/// the slice. Created by `combinations`.
+#[derive(Debug, Clone)]
+pub struct Combinations<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    done: bool,
+}
+
+/// Returns an iterator over all combinations of `k` elements of `items`.
+///
+/// Nothing is yielded if `k` is larger than the number of items.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::combinations::combinations;
+///
+/// let pairs: Vec<Vec<u8>> = combinations(&[1, 2, 3], 2).collect();
+/// assert_eq!(pairs, vec![vec![1, 2], vec![1, 3], vec![2, 3]]);
+/// ```
+pub fn combinations<T: Copy>(items: &[T], k: usize) -> Combinations<'_, T> {
+    Combinations {
+        items,
+        indices: (0..k).collect(),
+        done: k > items.len(),
+    }
+}
+
+/// Returns an iterator over all combinations of `K` elements of `items` as
+/// arrays, in the same order as `combinations`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::combinations::combinations_array;
+///
+/// let pairs: Vec<[u8; 2]> = combinations_array(&[1, 2, 3]).collect();
+/// assert_eq!(pairs, vec![[1, 2], [1, 3], [2, 3]]);
+/// ```
+pub fn combinations_array<T: Copy, const K: usize>(
+    items: &[T],
+) -> impl Iterator<Item = [T; K]> + '_ {
+    combinations(items, K).map(|combination| core::array::from_fn(|i| combination[i]))
+}
+
+impl<T> Combinations<'_, T> {
+    /// Moves the indices to the next combination or marks the iterator as
+    /// done after the last one.
+    fn advance(&mut self) {
+        let n = self.items.len();
+        let k = self.indices.len();
+
+        // Find the rightmost index that can still be moved to the right.
+        let mut i = k;
+        loop {
+            if i == 0 {
+                self.done = true;
+                return;
+            }
+            i -= 1;
+            if self.indices[i] < n - k + i {
+                break;
+            }
+        }
+
+        self.indices[i] += 1;
+        for j in (i + 1)..k {
+            self.indices[j] = self.indices[j - 1] + 1;
+        }
+    }
+}
+
+impl<T: Copy> Iterator for Combinations<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let combination = self.indices.iter().map(|&i| self.items[i]).collect();
+        self.advance();
+        Some(combination)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts() {
+        let items: Vec<u8> = (0..52).collect();
+        assert_eq!(combinations(&items, 2).count(), 1326);
+        assert_eq!(combinations(&items[..7], 5).count(), 21);
+        assert_eq!(combinations(&items[..5], 5).count(), 1);
+        assert_eq!(combinations(&items[..5], 0).count(), 1);
+        assert_eq!(combinations(&items[..5], 6).count(), 0);
+        assert_eq!(combinations_array::<u8, 3>(&items[..6]).count(), 20);
+    }
+
+    #[test]
+    fn test_order() {
+        let combos: Vec<Vec<char>> = combinations(&['a', 'b', 'c', 'd'], 3).collect();
+        assert_eq!(
+            combos,
+            vec![
+                vec!['a', 'b', 'c'],
+                vec!['a', 'b', 'd'],
+                vec!['a', 'c', 'd'],
+                vec!['b', 'c', 'd']
+            ]
+        );
+    }
+}
@@ -0,0 +1,339 @@
+//! Counting and indexing combinations.
+//!
+//! Combinations of `k` out of `n` elements are written as strictly increasing
+//! element indices. They are numbered in colexicographic order, i.e. ordered
+//! by their largest element first, so the index of a combination does not
+//! depend on `n`: `[0, 1, 2]` is 0, `[0, 1, 3]` is 1, `[0, 2, 3]` is 2 and so
+//! on.
+//!
+//! Permutations of a multiset, i.e. of elements that may repeat, are counted
+//! by `multiset_permutations_count` and yielded in lexicographic order by
+//! `multiset_permutations`, every distinct arrangement exactly once.
+//!
+//! The lazy iterator over the combinations of a slice lives in
+//! `pkr::combinations` and is re-exported here.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+pub use crate::combinations::{combinations, combinations_array, Combinations};
+
+/// Returns the number of ways to choose `k` of `n` elements.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::combinatorics::n_choose_k;
+///
+/// assert_eq!(n_choose_k(52, 5), 2_598_960);
+/// assert_eq!(n_choose_k(52, 7), 133_784_560);
+/// assert_eq!(n_choose_k(5, 7), 0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the result does not fit into a `u64`.
+pub fn n_choose_k(n: u64, k: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    // Every intermediate result is a smaller binomial coefficient, so the
+    // multiplication cannot overflow a u128 before the result exceeds a u64.
+    let mut result: u128 = 1;
+    for i in 0..k {
+        result = result * (n - i) as u128 / (i + 1) as u128;
+        assert!(
+            result <= u64::MAX as u128,
+            "{} choose {} does not fit into a u64",
+            n,
+            k
+        );
+    }
+    result as u64
+}
+
+/// Returns the colexicographic index of a combination of `n` elements.
+///
+/// # Arguments
+///
+/// * `combination` - The strictly increasing indices of the chosen elements.
+/// * `n` - The number of elements to choose from.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::combinatorics::combination_to_index;
+///
+/// assert_eq!(combination_to_index(&[0, 1, 2], 52), 0);
+/// assert_eq!(combination_to_index(&[0, 1, 3], 52), 1);
+/// assert_eq!(combination_to_index(&[47, 48, 49, 50, 51], 52), 2_598_959);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the indices are not strictly increasing or not below `n`.
+pub fn combination_to_index(combination: &[usize], n: usize) -> u64 {
+    assert!(
+        combination.windows(2).all(|pair| pair[0] < pair[1]),
+        "The combination must be strictly increasing"
+    );
+    assert!(
+        combination.last().is_none_or(|&last| last < n),
+        "The combination must only hold indices below {}",
+        n
+    );
+    combination
+        .iter()
+        .enumerate()
+        .map(|(i, &element)| n_choose_k(element as u64, i as u64 + 1))
+        .sum()
+}
+
+/// Returns the combination of `k` out of `n` elements with the given
+/// colexicographic index, the inverse of `combination_to_index`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::combinatorics::index_to_combination;
+///
+/// assert_eq!(index_to_combination(2, 52, 3), [0, 2, 3]);
+/// assert_eq!(index_to_combination(2_598_959, 52, 5), [47, 48, 49, 50, 51]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `index` is not below `n_choose_k(n, k)`.
+pub fn index_to_combination(index: u64, n: usize, k: usize) -> Vec<usize> {
+    assert!(
+        index < n_choose_k(n as u64, k as u64),
+        "Index {} is out of range for {} choose {}",
+        index,
+        n,
+        k
+    );
+    let mut combination = vec![0; k];
+    let mut remaining = index;
+    let mut upper = n;
+    for i in (0..k).rev() {
+        // Find the largest element whose coefficient still fits.
+        let mut element = upper - 1;
+        while n_choose_k(element as u64, i as u64 + 1) > remaining {
+            element -= 1;
+        }
+        remaining -= n_choose_k(element as u64, i as u64 + 1);
+        combination[i] = element;
+        upper = element;
+    }
+    combination
+}
+
+/// Returns the number of distinct permutations of a multiset, given how
+/// many times every element repeats.
+///
+/// This is the multinomial coefficient `n! / (k1! * k2! * ...)` where `n` is
+/// the sum of the multiplicities.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::combinatorics::multiset_permutations_count;
+///
+/// // The arrangements of the suits of a pair and three other cards.
+/// assert_eq!(multiset_permutations_count(&[2, 1, 1, 1]), 60);
+/// assert_eq!(multiset_permutations_count(&[1, 1, 1]), 6);
+/// assert_eq!(multiset_permutations_count(&[]), 1);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the result does not fit into a `u64`.
+pub fn multiset_permutations_count(multiplicities: &[u64]) -> u64 {
+    // Every element fills its repetitions into the free positions left by
+    // the previous ones: the product of C(n1 + ... + ni, ni).
+    let mut total = 0u64;
+    let mut result = 1u64;
+    for &multiplicity in multiplicities {
+        total = total
+            .checked_add(multiplicity)
+            .expect("The number of elements does not fit into a u64");
+        result = result
+            .checked_mul(n_choose_k(total, multiplicity))
+            .unwrap_or_else(|| {
+                panic!(
+                    "The permutations of {:?} do not fit into a u64",
+                    multiplicities
+                )
+            });
+    }
+    result
+}
+
+/// An iterator over the distinct permutations of a multiset in
+/// lexicographic order. Created by `multiset_permutations`.
+#[derive(Debug, Clone)]
+pub struct MultisetPermutations<T> {
+    next: Option<Vec<T>>,
+}
+
+/// Returns an iterator over the distinct permutations of `items` in
+/// lexicographic order.
+///
+/// Repeated elements are indistinguishable, so every arrangement is yielded
+/// once, `multiset_permutations_count` times in total. The empty slice has
+/// a single, empty permutation.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::combinatorics::multiset_permutations;
+///
+/// let permutations: Vec<Vec<char>> = multiset_permutations(&['b', 'a', 'a']).collect();
+/// assert_eq!(
+///     permutations,
+///     vec![vec!['a', 'a', 'b'], vec!['a', 'b', 'a'], vec!['b', 'a', 'a']]
+/// );
+/// ```
+pub fn multiset_permutations<T: Ord + Clone>(items: &[T]) -> MultisetPermutations<T> {
+    let mut first = items.to_vec();
+    first.sort();
+    MultisetPermutations { next: Some(first) }
+}
+
+impl<T: Ord + Clone> Iterator for MultisetPermutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        let current = self.next.take()?;
+        let mut next = current.clone();
+        // Find the rightmost element smaller than its successor, swap it with
+        // the rightmost larger element and reverse the tail after it.
+        if let Some(i) = (1..next.len()).rev().find(|&i| next[i - 1] < next[i]) {
+            let j = (i..next.len())
+                .rev()
+                .find(|&j| next[i - 1] < next[j])
+                .expect("The successor is larger");
+            next.swap(i - 1, j);
+            next[i..].reverse();
+            self.next = Some(next);
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_n_choose_k() {
+        assert_eq!(n_choose_k(52, 5), 2_598_960);
+        assert_eq!(n_choose_k(52, 2), 1326);
+        assert_eq!(n_choose_k(0, 0), 1);
+        assert_eq!(n_choose_k(4, 0), 1);
+        assert_eq!(n_choose_k(4, 4), 1);
+        assert_eq!(n_choose_k(3, 4), 0);
+        assert_eq!(n_choose_k(67, 33), 14_226_520_737_620_288_370);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_n_choose_k_overflow() {
+        n_choose_k(68, 34);
+    }
+
+    #[test]
+    fn test_round_trip_52_choose_5() {
+        let total = n_choose_k(52, 5);
+        assert_eq!(total, 2_598_960);
+
+        let elements: Vec<usize> = (0..52).collect();
+        let mut seen = vec![false; total as usize];
+        for combination in combinations(&elements, 5) {
+            let index = combination_to_index(&combination, 52);
+            assert!(!seen[index as usize]);
+            seen[index as usize] = true;
+        }
+        assert!(seen.iter().all(|&seen| seen));
+
+        for index in (0..total).step_by(997).chain([total - 1]) {
+            let combination = index_to_combination(index, 52, 5);
+            assert_eq!(combination_to_index(&combination, 52), index);
+        }
+    }
+
+    #[test]
+    fn test_colex_order() {
+        let indices: Vec<u64> = combinations(&[0, 1, 2, 3], 2)
+            .map(|combination| combination_to_index(&combination, 4))
+            .collect();
+        // [0, 1], [0, 2], [0, 3], [1, 2], [1, 3], [2, 3]
+        assert_eq!(indices, [0, 1, 3, 2, 4, 5]);
+        assert!(index_to_combination(0, 3, 0).is_empty());
+    }
+
+    #[test]
+    fn test_multiset_permutations_count() {
+        assert_eq!(multiset_permutations_count(&[]), 1);
+        assert_eq!(multiset_permutations_count(&[0, 3]), 1);
+        assert_eq!(multiset_permutations_count(&[1; 5]), 120);
+        assert_eq!(multiset_permutations_count(&[2, 2]), 6);
+        // The orders of the four suits of four ranks, 16! / 4!^4.
+        assert_eq!(multiset_permutations_count(&[4; 4]), 63_063_000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_multiset_permutations_count_overflow() {
+        multiset_permutations_count(&[1; 21]);
+    }
+
+    #[test]
+    fn test_multiset_permutations() {
+        let permutations: Vec<Vec<u8>> = multiset_permutations(&[2, 1, 1, 2]).collect();
+        assert_eq!(
+            permutations,
+            [
+                [1, 1, 2, 2],
+                [1, 2, 1, 2],
+                [1, 2, 2, 1],
+                [2, 1, 1, 2],
+                [2, 1, 2, 1],
+                [2, 2, 1, 1],
+            ]
+        );
+        assert_eq!(multiset_permutations(&[7]).collect::<Vec<_>>(), [vec![7]]);
+        assert_eq!(
+            multiset_permutations::<u8>(&[]).collect::<Vec<_>>(),
+            [Vec::<u8>::new()]
+        );
+    }
+
+    #[test]
+    fn test_multiset_permutations_match_count() {
+        for items in [
+            &[0, 0, 0, 1, 1, 2][..],
+            &[0, 1, 2, 3, 4, 5],
+            &[3, 3, 3, 3],
+            &[0, 1, 1, 2, 2, 2, 3],
+        ] {
+            let permutations: Vec<Vec<u8>> = multiset_permutations(items).collect();
+            let multiplicities: Vec<u64> = (0..6)
+                .map(|element| items.iter().filter(|&&item| item == element).count() as u64)
+                .collect();
+            assert_eq!(
+                permutations.len() as u64,
+                multiset_permutations_count(&multiplicities)
+            );
+            // Strictly increasing, so every permutation is distinct.
+            assert!(permutations.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unsorted_combination() {
+        combination_to_index(&[2, 1], 52);
+    }
+}
@@ -0,0 +1,254 @@
+//! Reading and writing the comma separated values of the `to_csv` and
+//! `from_csv` methods.
+//!
+//! Every table starts with a header row naming its columns. Fields holding
+//! a comma, a double quote or a line break are enclosed in double quotes,
+//! with the double quotes inside doubled, and rows end in "\n".
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+/// An error reading a table written by a `to_csv` method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvError {
+    /// The one-based line number of the row, 0 if reading failed before.
+    pub line: usize,
+    /// What went wrong.
+    pub kind: CsvErrorKind,
+}
+
+/// The reason a table could not be read, see `CsvError`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvErrorKind {
+    /// The header row does not name the expected columns.
+    InvalidHeader { expected: String, found: String },
+    /// A row does not have a field per column.
+    InvalidFieldCount { expected: usize, found: usize },
+    /// A field is not a valid value of its column.
+    InvalidField { column: &'static str, value: String },
+    /// A quoted field is not closed.
+    UnclosedQuote,
+    /// The table has fewer data rows than expected.
+    MissingRow,
+    /// The table has more data rows than expected.
+    UnexpectedRow,
+    /// Reading the input failed.
+    Io(io::ErrorKind),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Line {}: ", self.line)?;
+        match &self.kind {
+            CsvErrorKind::InvalidHeader { expected, found } => {
+                write!(
+                    f,
+                    "Expected the header \"{}\", found \"{}\".",
+                    expected, found
+                )
+            }
+            CsvErrorKind::InvalidFieldCount { expected, found } => {
+                write!(f, "Expected {} fields, found {}.", expected, found)
+            }
+            CsvErrorKind::InvalidField { column, value } => {
+                write!(f, "Invalid value \"{}\" in column {}.", value, column)
+            }
+            CsvErrorKind::UnclosedQuote => write!(f, "A quoted field is not closed."),
+            CsvErrorKind::MissingRow => write!(f, "Expected another row."),
+            CsvErrorKind::UnexpectedRow => write!(f, "Expected no more rows."),
+            CsvErrorKind::Io(kind) => write!(f, "Failed to read the input ({}).", kind),
+        }
+    }
+}
+
+impl Error for CsvError {}
+
+/// Writes the fields as a row, quoting the fields that need it.
+pub(crate) fn write_row<W: Write>(mut w: W, fields: &[&str]) -> io::Result<()> {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            w.write_all(b",")?;
+        }
+        if field.contains([',', '"', '\n', '\r']) {
+            write!(w, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            w.write_all(field.as_bytes())?;
+        }
+    }
+    w.write_all(b"\n")
+}
+
+/// Reads a table with the given header and returns its data rows with
+/// their line numbers.
+pub(crate) fn read_table<R: Read>(
+    mut r: R,
+    header: &[&str],
+) -> Result<Vec<(usize, Vec<String>)>, CsvError> {
+    let mut input = String::new();
+    r.read_to_string(&mut input).map_err(|e| CsvError {
+        line: 0,
+        kind: CsvErrorKind::Io(e.kind()),
+    })?;
+    let mut rows = parse_rows(&input)?.into_iter();
+    let (line, found) = rows.next().ok_or(CsvError {
+        line: 1,
+        kind: CsvErrorKind::MissingRow,
+    })?;
+    if found != header {
+        return Err(CsvError {
+            line,
+            kind: CsvErrorKind::InvalidHeader {
+                expected: header.join(","),
+                found: found.join(","),
+            },
+        });
+    }
+    rows.map(|(line, fields)| {
+        if fields.len() == header.len() {
+            Ok((line, fields))
+        } else {
+            Err(CsvError {
+                line,
+                kind: CsvErrorKind::InvalidFieldCount {
+                    expected: header.len(),
+                    found: fields.len(),
+                },
+            })
+        }
+    })
+    .collect()
+}
+
+/// Reads a table with the given header and exactly one data row.
+pub(crate) fn read_single_row<R: Read>(
+    r: R,
+    header: &[&str],
+) -> Result<(usize, Vec<String>), CsvError> {
+    let mut rows = read_table(r, header)?.into_iter();
+    let row = rows.next().ok_or(CsvError {
+        line: 2,
+        kind: CsvErrorKind::MissingRow,
+    })?;
+    match rows.next() {
+        Some((line, _)) => Err(CsvError {
+            line,
+            kind: CsvErrorKind::UnexpectedRow,
+        }),
+        None => Ok(row),
+    }
+}
+
+/// Parses a field of the column, reporting the line of its row on failure.
+pub(crate) fn parse_field<T: std::str::FromStr>(
+    line: usize,
+    column: &'static str,
+    value: &str,
+) -> Result<T, CsvError> {
+    value.parse().map_err(|_| CsvError {
+        line,
+        kind: CsvErrorKind::InvalidField {
+            column,
+            value: value.to_string(),
+        },
+    })
+}
+
+/// Splits the input into rows of fields, skipping blank lines.
+fn parse_rows(input: &str) -> Result<Vec<(usize, Vec<String>)>, CsvError> {
+    let mut rows = Vec::new();
+    let mut chars = input.chars().peekable();
+    let mut line = 1;
+    while chars.peek().is_some() {
+        let start = line;
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        loop {
+            match chars.next() {
+                Some('"') if quoted => {
+                    if chars.peek() == Some(&'"') {
+                        chars.next();
+                        field.push('"');
+                    } else {
+                        quoted = false;
+                    }
+                }
+                Some('"') if field.is_empty() => quoted = true,
+                Some(',') if !quoted => fields.push(std::mem::take(&mut field)),
+                Some('\n') if !quoted => {
+                    line += 1;
+                    break;
+                }
+                Some('\r') if !quoted && chars.peek() == Some(&'\n') => {}
+                Some(c) => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+                None if quoted => {
+                    return Err(CsvError {
+                        line: start,
+                        kind: CsvErrorKind::UnclosedQuote,
+                    })
+                }
+                None => break,
+            }
+        }
+        if !fields.is_empty() || !field.is_empty() {
+            fields.push(field);
+            rows.push((start, fields));
+        }
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quoting_round_trip() {
+        let fields = ["plain", "a,b", "say \"hi\"", "two\nlines", ""];
+        let mut out = Vec::new();
+        write_row(&mut out, &["x", "y", "z", "w", "v"]).unwrap();
+        write_row(&mut out, &fields).unwrap();
+        write_row(&mut out, &fields).unwrap();
+        assert_eq!(
+            String::from_utf8(out.clone()).unwrap(),
+            "x,y,z,w,v\nplain,\"a,b\",\"say \"\"hi\"\"\",\"two\nlines\",\n\
+             plain,\"a,b\",\"say \"\"hi\"\"\",\"two\nlines\",\n"
+        );
+
+        let rows = read_table(out.as_slice(), &["x", "y", "z", "w", "v"]).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].0, 2);
+        assert_eq!(rows[1].0, 4);
+        assert_eq!(rows[1].1, fields);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = read_table("a,b\n1\n".as_bytes(), &["a", "b"]).unwrap_err();
+        assert_eq!(
+            error.kind,
+            CsvErrorKind::InvalidFieldCount {
+                expected: 2,
+                found: 1
+            }
+        );
+        assert_eq!(error.line, 2);
+        let error = read_table("a,c\n".as_bytes(), &["a", "b"]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 1: Expected the header \"a,b\", found \"a,c\"."
+        );
+        let error = read_table("a,b\n\"1,2\n".as_bytes(), &["a", "b"]).unwrap_err();
+        assert_eq!(error.kind, CsvErrorKind::UnclosedQuote);
+        let error = read_single_row("a\r\n\r\n".as_bytes(), &["a"]).unwrap_err();
+        assert_eq!(error.kind, CsvErrorKind::MissingRow);
+        let error = read_single_row("a\n1\n2\n".as_bytes(), &["a"]).unwrap_err();
+        assert_eq!(error.kind, CsvErrorKind::UnexpectedRow);
+    }
+}
@@ -0,0 +1,388 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::board::Board;
+use crate::card::{Card, Rank, Suit};
+use crate::hand::Hand;
+#[cfg(feature = "std")]
+use crate::hand::{HandRank, HoleCards};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::DeckError;
+
+/// Represents a deck of standard 52 playing cards.
+///
+/// A deck can be shuffled and cards can be dealt from it.
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deck {
+    /// Creates a new deck of 52 standard playing cards.
+    pub fn new() -> Self {
+        let mut cards = Vec::with_capacity(52);
+        for suit in &[Suit::Heart, Suit::Diamond, Suit::Club, Suit::Spade] {
+            for rank in &[
+                Rank::Two,
+                Rank::Three,
+                Rank::Four,
+                Rank::Five,
+                Rank::Six,
+                Rank::Seven,
+                Rank::Eight,
+                Rank::Nine,
+                Rank::Ten,
+                Rank::Jack,
+                Rank::Queen,
+                Rank::King,
+                Rank::Ace,
+            ] {
+                cards.push(Card::new(*rank, *suit));
+            }
+        }
+        Self { cards }
+    }
+
+    /// Shuffles the deck with the thread local random number generator.
+    ///
+    /// Without the `std` feature use `shuffle_with` and an external generator.
+    #[cfg(feature = "std")]
+    pub fn shuffle(&mut self) {
+        let mut rng = rand::thread_rng();
+        self.cards.shuffle(&mut rng);
+    }
+
+    /// Shuffles the deck with the given random number generator.
+    ///
+    /// Using a seeded generator makes the resulting order reproducible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::deck::Deck;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut deck1 = Deck::new();
+    /// deck1.shuffle_with(&mut StdRng::seed_from_u64(42));
+    ///
+    /// let mut deck2 = Deck::new();
+    /// deck2.shuffle_with(&mut StdRng::seed_from_u64(42));
+    ///
+    /// assert_eq!(deck1.get_cards(), deck2.get_cards());
+    /// ```
+    pub fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+    }
+
+    /// Deals the top card from the deck.
+    ///
+    /// Returns `None` if the deck is empty.
+    pub fn deal(&mut self) -> Option<Card> {
+        self.cards.pop()
+    }
+
+    /// Returns the top card of the deck without dealing it.
+    ///
+    /// Returns `None` if the deck is empty.
+    pub fn peek(&self) -> Option<&Card> {
+        self.cards.last()
+    }
+
+    /// Returns a reference to the cards remaining in the deck.
+    ///
+    /// The last card of the slice is the next card to be dealt.
+    pub fn get_cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Checks that every card of the hand is still in the deck, so the hand
+    /// can be claimed with `claim_hand`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::deck::{Deck, DeckError};
+    /// use pkr::hand::Hand;
+    ///
+    /// let mut deck = Deck::new();
+    /// let card = deck.deal().unwrap();
+    ///
+    /// let hand = Hand::new_from_str("As Kd").unwrap();
+    /// assert!(hand.contains(&card));
+    /// assert_eq!(deck.validate_hand(&hand), Err(DeckError::CardNotInDeck(card)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DeckError::CardNotInDeck` naming the first card of the hand
+    /// that was already dealt or claimed.
+    pub fn validate_hand(&self, hand: &Hand) -> Result<(), DeckError> {
+        match hand.iter().find(|card| !self.cards.contains(card)) {
+            Some(card) => Err(DeckError::CardNotInDeck(*card)),
+            None => Ok(()),
+        }
+    }
+
+    /// Removes the cards of the hand from the deck, e.g. for a hand that was
+    /// built by hand instead of being dealt.
+    ///
+    /// The order of the remaining cards is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::deck::Deck;
+    /// use pkr::hand::Hand;
+    ///
+    /// let mut deck = Deck::new();
+    /// let hand = Hand::new_from_str("As Kd").unwrap();
+    /// deck.claim_hand(&hand).unwrap();
+    /// assert_eq!(deck.get_cards().len(), 50);
+    ///
+    /// assert!(deck.claim_hand(&Hand::new_from_str("Kd Qc").unwrap()).is_err());
+    /// assert_eq!(deck.get_cards().len(), 50);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `validate_hand`. No card is removed on
+    /// error.
+    pub fn claim_hand(&mut self, hand: &Hand) -> Result<(), DeckError> {
+        self.validate_hand(hand)?;
+        self.cards.retain(|card| !hand.contains(card));
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Deck {
+    /// Returns the cards that would have completed the board to the river if
+    /// the hand had been played out, in the order they would have been dealt.
+    ///
+    /// The deck is not changed. Cards that are already on the board are
+    /// skipped, so the deck does not need to be updated after dealing a board
+    /// by hand. Fewer cards are returned if the deck runs out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::board::Board;
+    /// use pkr::deck::Deck;
+    ///
+    /// let deck = Deck::new();
+    /// let board = Board::new_from_str("As 7d 2c").unwrap();
+    ///
+    /// let rabbit: Vec<String> = deck.rabbit_hunt(&board).iter().map(|card| card.as_str()).collect();
+    /// assert_eq!(rabbit, ["Ks", "Qs"]);
+    /// assert_eq!(deck.get_cards().len(), 52);
+    /// ```
+    pub fn rabbit_hunt(&self, board: &Board) -> Vec<Card> {
+        self.rabbit_cards(board.cards())
+            .take(5 - board.cards().len())
+            .collect()
+    }
+
+    /// Returns the cards of `rabbit_hunt` together with the category the hero
+    /// would have made once each of them was dealt.
+    ///
+    /// The hero's cards are skipped like the board cards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::board::Board;
+    /// use pkr::deck::Deck;
+    /// use pkr::hand::{HandRank, HoleCards};
+    ///
+    /// let deck = Deck::new();
+    /// let hero = HoleCards::new_from_str("AhKh").unwrap();
+    /// let board = Board::new_from_str("Qd 7c 2c").unwrap();
+    ///
+    /// let made = deck.would_have_made(&hero, &board);
+    /// assert_eq!(made[0].0.as_str(), "As");
+    /// assert_eq!(made[0].1, HandRank::OnePair);
+    /// assert_eq!(made[1].0.as_str(), "Ks");
+    /// assert_eq!(made[1].1, HandRank::TwoPair);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a hole card of the hero is also on the board.
+    pub fn would_have_made(&self, hero: &HoleCards, board: &Board) -> Vec<(Card, HandRank)> {
+        let hole = hero.get_cards();
+        let mut dead = board.cards().to_vec();
+        dead.extend_from_slice(&hole);
+
+        let mut cards = board.cards().to_vec();
+        self.rabbit_cards(&dead)
+            .take(5 - board.cards().len())
+            .map(|card| {
+                cards.push(card);
+                let hand = Hand::from_hole_and_board(&hole, &cards)
+                    .expect("Hole cards and board must be distinct");
+                (card, hand.get_hand_rank())
+            })
+            .collect()
+    }
+
+    /// Returns the cards in the order they would be dealt, skipping dead
+    /// cards.
+    fn rabbit_cards<'a>(&'a self, dead: &'a [Card]) -> impl Iterator<Item = Card> + 'a {
+        self.cards
+            .iter()
+            .rev()
+            .filter(move |card| !dead.contains(card))
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_shuffle() {
+        let mut deck = Deck::new();
+        let original_deck = deck.cards.clone();
+
+        deck.shuffle();
+
+        // The deck still contains 52 unique cards after shuffling
+        assert_eq!(deck.cards.len(), 52);
+
+        // The deck is not in the same order as before
+        assert_ne!(deck.cards, original_deck);
+    }
+
+    #[test]
+    fn test_deal() {
+        let mut deck = Deck::new();
+
+        // Check that dealing cards reduces the deck size
+        let card = deck.deal();
+        assert_eq!(deck.cards.len(), 51);
+        assert!(card.is_some());
+
+        // Check that dealing cards eventually empties the deck
+        for _ in 0..51 {
+            let card = deck.deal();
+            assert!(card.is_some());
+        }
+        assert_eq!(deck.cards.len(), 0);
+
+        // Check that dealing from an empty deck returns None
+        let card = deck.deal();
+        assert!(card.is_none());
+    }
+
+    #[test]
+    fn test_claim_hand() {
+        let mut deck = Deck::new();
+        let hand = Hand::new_from_str("As Kd 7c").unwrap();
+        deck.validate_hand(&hand).unwrap();
+        deck.claim_hand(&hand).unwrap();
+
+        let mut expected = Deck::new().cards;
+        expected.retain(|card| !hand.contains(card));
+        assert_eq!(deck.cards, expected);
+
+        let overlapping = Hand::new_from_str("2h 3h 7c").unwrap();
+        let seven = Card::new_from_str("7c").unwrap();
+        assert_eq!(
+            deck.claim_hand(&overlapping),
+            Err(DeckError::CardNotInDeck(seven))
+        );
+        assert_eq!(deck.cards, expected);
+    }
+
+    #[test]
+    fn test_validate_dealt_cards() {
+        let mut deck = Deck::new();
+        let first = deck.deal().unwrap();
+        let second = deck.deal().unwrap();
+        let hand = Hand::new(vec![second, first]).unwrap();
+        assert_eq!(
+            deck.validate_hand(&hand),
+            Err(DeckError::CardNotInDeck(second))
+        );
+
+        let hand = Hand::new_from_str("2h 3h").unwrap();
+        deck.claim_hand(&hand).unwrap();
+        assert_eq!(
+            deck.validate_hand(&hand),
+            Err(DeckError::CardNotInDeck(hand[0]))
+        );
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut deck = Deck::new();
+        let top = *deck.peek().unwrap();
+        assert_eq!(deck.get_cards().len(), 52);
+        assert_eq!(deck.deal(), Some(top));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_rabbit_hunt() {
+        let as_strs = |cards: &[Card]| cards.iter().map(Card::as_str).collect::<Vec<_>>();
+        let deck = Deck::new();
+
+        assert_eq!(
+            as_strs(&deck.rabbit_hunt(&Board::Preflop)),
+            ["As", "Ks", "Qs", "Js", "Ts"]
+        );
+        let turn = Board::new_from_str("Ks 9d 4c 2h").unwrap();
+        assert_eq!(as_strs(&deck.rabbit_hunt(&turn)), ["As"]);
+        let river = Board::new_from_str("Ks 9d 4c 2h 3h").unwrap();
+        assert!(deck.rabbit_hunt(&river).is_empty());
+
+        let mut stacked = Deck::new();
+        stacked
+            .claim_hand(&Hand::new_from_str("As Ks Qs Js").unwrap())
+            .unwrap();
+        let flop = Board::new_from_str("Ts 9s 2c").unwrap();
+        assert_eq!(as_strs(&stacked.rabbit_hunt(&flop)), ["8s", "7s"]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_would_have_made() {
+        let deck = Deck::new();
+        let hero = HoleCards::new_from_str("JsTs").unwrap();
+        let board = Board::new_from_str("As Ks 2c").unwrap();
+        let made = deck.would_have_made(&hero, &board);
+        let made: Vec<(String, HandRank)> = made
+            .into_iter()
+            .map(|(card, rank)| (card.as_str(), rank))
+            .collect();
+        assert_eq!(
+            made,
+            [
+                ("Qs".to_string(), HandRank::StraightFlush),
+                ("9s".to_string(), HandRank::StraightFlush),
+            ]
+        );
+
+        let mut stacked = Deck::new();
+        stacked
+            .claim_hand(&Hand::new_from_str("As Ks Qs Js Ts").unwrap())
+            .unwrap();
+        let hero = HoleCards::new_from_str("9h9d").unwrap();
+        let board = Board::new_from_str("Kh 7c 2d").unwrap();
+        let made = stacked.would_have_made(&hero, &board);
+        assert_eq!(made[0].0.as_str(), "9s");
+        assert_eq!(made[0].1, HandRank::ThreeOfAKind);
+        assert_eq!(made[1].0.as_str(), "8s");
+        assert_eq!(made[1].1, HandRank::ThreeOfAKind);
+    }
+}
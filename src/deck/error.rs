@@ -0,0 +1,23 @@
+use core::fmt;
+
+use crate::card::Card;
+
+/// The error returned when cards cannot be taken from a deck.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckError {
+    /// The card is not in the deck, usually because it was already dealt.
+    CardNotInDeck(Card),
+}
+
+impl fmt::Display for DeckError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeckError::CardNotInDeck(card) => {
+                write!(f, "Card is not in the deck: {}", card.as_str())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DeckError {}
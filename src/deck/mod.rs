@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod deck;
+mod error;
+
+pub use deck::Deck;
+pub use error::DeckError;
@@ -0,0 +1,231 @@
+//! The encodings of hand scores.
+//!
+//! `evaluate`, `Hand::get_score` and every `Evaluator` return `V1` scores.
+//! Persisted scores stay valid as long as they are read with the encoding
+//! they were written with, and `convert` translates between encodings. All
+//! encodings order hands alike.
+//!
+//! # Examples
+//!
+//! ```
+//! use pkr::encoding::{convert, ClassRank, Encoding, V1};
+//! use pkr::hand::Hand;
+//!
+//! let hand = Hand::new_from_str("As Ks Qs Js Ts").unwrap();
+//! let score = V1::encode(hand.get_value());
+//! assert_eq!(score, hand.get_score());
+//! assert_eq!(ClassRank::encode(hand.get_value()), Some(7462));
+//! assert_eq!(convert(score, Encoding::V1, Encoding::ClassRank), Some(7462));
+//! assert_eq!(convert(7462, Encoding::ClassRank, Encoding::V1), Some(score));
+//! ```
+
+use crate::hand::HandValue;
+
+/// The number of distinct five card hands, the largest `ClassRank` score.
+const CLASSES: u32 = 7462;
+
+/// The `V1` scores of all 7,462 distinct five card hands in ascending order,
+/// as big endian `u32`s. The position of a score is its `ClassRank` minus
+/// one.
+const CLASS_SCORES: &[u8] = include_bytes!("class_scores.bin");
+
+/// An encoding of hand scores, for choosing one at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// See `V1`.
+    V1,
+    /// See `ClassRank`.
+    ClassRank,
+}
+
+impl Encoding {
+    /// Returns the score of the value in this encoding, or `None` if the
+    /// encoding has no score for it.
+    pub fn encode(&self, value: HandValue) -> Option<u32> {
+        match self {
+            Encoding::V1 => Some(V1::encode(value)),
+            Encoding::ClassRank => ClassRank::encode(value),
+        }
+    }
+
+    /// Returns the value of a score of this encoding, or `None` if the
+    /// score is not valid in it.
+    pub fn decode(&self, score: u32) -> Option<HandValue> {
+        match self {
+            Encoding::V1 => V1::decode(score),
+            Encoding::ClassRank => ClassRank::decode(score),
+        }
+    }
+}
+
+/// The score encoding returned by `evaluate`, see its "Score encoding"
+/// section.
+///
+/// The score is the `HandRank` value of the category plus the ranks
+/// deciding ties within it, packed into nibbles. Hands of fewer than five
+/// cards have scores of their own, below the five card hands they can be
+/// completed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct V1;
+
+impl V1 {
+    /// Returns the `V1` score of the value.
+    pub fn encode(value: HandValue) -> u32 {
+        value.get_score()
+    }
+
+    /// Returns the value of a `V1` score, or `None` if the score is outside
+    /// of the score range of every category, see `HandRank::score_range`.
+    ///
+    /// Scores within a range are not checked further.
+    pub fn decode(score: u32) -> Option<HandValue> {
+        let value = HandValue::new(score);
+        value
+            .get_hand_rank()
+            .score_range()
+            .contains(&score)
+            .then_some(value)
+    }
+}
+
+/// A dense encoding numbering the 7,462 distinct five card hands from 1 for
+/// "7 5 4 3 2" to 7,462 for a royal flush.
+///
+/// Unlike the well known ranks of Cactus Kev's evaluator, which count from
+/// the best hand, a higher class rank is a better hand, so class ranks
+/// compare like `V1` scores. Hands of fewer than five cards have no class
+/// rank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClassRank;
+
+impl ClassRank {
+    /// Returns the class rank of the value, or `None` if it is the value of
+    /// a hand of fewer than five cards.
+    pub fn encode(value: HandValue) -> Option<u32> {
+        let score = value.get_score();
+        let (mut low, mut high) = (0, CLASSES);
+        while low < high {
+            let middle = (low + high) / 2;
+            match class_score(middle).cmp(&score) {
+                core::cmp::Ordering::Less => low = middle + 1,
+                core::cmp::Ordering::Equal => return Some(middle + 1),
+                core::cmp::Ordering::Greater => high = middle,
+            }
+        }
+        None
+    }
+
+    /// Returns the value of a class rank, or `None` if it is not between 1
+    /// and 7,462.
+    pub fn decode(class: u32) -> Option<HandValue> {
+        (1..=CLASSES)
+            .contains(&class)
+            .then(|| HandValue::new(class_score(class - 1)))
+    }
+}
+
+/// Converts a score from one encoding into another.
+///
+/// # Returns
+///
+/// * `Option<u32>` - The score in the target encoding, or `None` if the
+///   score is not valid in its encoding or the target encoding has no score
+///   for the hand.
+pub fn convert(score: u32, from: Encoding, to: Encoding) -> Option<u32> {
+    to.encode(from.decode(score)?)
+}
+
+/// Returns the `V1` score of the five card hand at the given position of
+/// the ascending order of all of them.
+fn class_score(index: u32) -> u32 {
+    let start = index as usize * 4;
+    let bytes = CLASS_SCORES[start..start + 4]
+        .try_into()
+        .expect("Four bytes per score");
+    u32::from_be_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::combinations::combinations;
+    use crate::deck::Deck;
+    use crate::hand::{Evaluator, FastEvaluator, Hand, HandRank};
+
+    #[test]
+    fn test_class_table() {
+        assert_eq!(CLASS_SCORES.len(), CLASSES as usize * 4);
+        let scores: Vec<u32> = (0..CLASSES).map(class_score).collect();
+        assert!(scores.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(
+            ClassRank::decode(1),
+            Some(Hand::new_from_str("7s 5h 4d 3c 2s").unwrap().get_value())
+        );
+        assert_eq!(ClassRank::decode(0), None);
+        assert_eq!(ClassRank::decode(CLASSES + 1), None);
+    }
+
+    #[test]
+    fn test_all_five_card_hands() {
+        // Every five card hand has a class rank, and class ranks order the
+        // hands like their V1 scores.
+        let deck = Deck::new();
+        let mut pairs: Vec<(u32, u32)> = combinations(deck.get_cards(), 5)
+            .map(|cards| {
+                let value = HandValue::new(FastEvaluator.score(&cards));
+                let class = ClassRank::encode(value).expect("Five card hands have a class");
+                (V1::encode(value), class)
+            })
+            .collect();
+        pairs.sort_unstable();
+        pairs.dedup();
+        assert_eq!(pairs.len(), CLASSES as usize);
+        for (i, (score, class)) in pairs.into_iter().enumerate() {
+            assert_eq!(class, i as u32 + 1);
+            assert_eq!(
+                convert(class, Encoding::ClassRank, Encoding::V1),
+                Some(score)
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trips() {
+        for s in [
+            "7s 5h 4d 3c 2s",
+            "As Ah 7d 4c 2s 9h",
+            "Ks Kd Kc 2h 2s",
+            "5s 4s 3s 2s As",
+            "As Ks Qs Js Ts 9s 8s",
+        ] {
+            let hand = Hand::new_from_str(s).unwrap();
+            let score = hand.get_score();
+            let class = convert(score, Encoding::V1, Encoding::ClassRank).unwrap();
+            assert_eq!(
+                convert(class, Encoding::ClassRank, Encoding::V1),
+                Some(score)
+            );
+            assert_eq!(
+                hand.get_score_with(&FastEvaluator),
+                FastEvaluator
+                    .score_in(hand.as_slice(), Encoding::V1)
+                    .unwrap()
+            );
+            assert_eq!(
+                FastEvaluator.score_in(hand.as_slice(), Encoding::ClassRank),
+                Some(class)
+            );
+        }
+
+        // Hands of fewer than five cards only have V1 scores.
+        let pair = Hand::new_from_str("As Ah").unwrap();
+        assert_eq!(ClassRank::encode(pair.get_value()), None);
+        assert_eq!(
+            convert(pair.get_score(), Encoding::V1, Encoding::V1),
+            Some(pair.get_score())
+        );
+        assert_eq!(V1::decode(*HandRank::Flush.score_range().end() + 1), None);
+    }
+}
@@ -0,0 +1,324 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::time::Instant;
+
+use crate::board::Board;
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::hand::HoleCards;
+use crate::showdown::BoardContext;
+
+use super::report::BATCHES;
+use super::{Equity, EquityError, SimulationReport};
+
+/// Calculates the exact equity of every hand by enumerating all possible
+/// runouts of the board.
+///
+/// # Arguments
+///
+/// * `hands` - The hole cards of every player in the pot.
+/// * `board` - The community cards dealt so far.
+///
+/// # Returns
+///
+/// * `Vec<Equity>` - The equity of every hand, in the order of `hands`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::board::Board;
+/// use pkr::equity::equity_exact;
+/// use pkr::hand::HoleCards;
+///
+/// let hands = [
+///     HoleCards::new_from_str("AhKh").unwrap(),
+///     HoleCards::new_from_str("9c9d").unwrap(),
+/// ];
+/// let board = Board::new_from_str("Qs Js 2d 7c").unwrap();
+///
+/// let equities = equity_exact(&hands, &board).unwrap();
+/// assert_eq!(equities[0].get_trials(), 44);
+/// assert_eq!(equities[0].win(), 10.0 / 44.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns `EquityError::NoHands` if no hand is given and
+/// `EquityError::DuplicateCard` if a card appears more than once among the
+/// hands and the board.
+pub fn equity_exact(hands: &[HoleCards], board: &Board) -> Result<Vec<Equity>, EquityError> {
+    let live = live_cards(hands, board)?;
+    let missing = 5 - board.cards().len();
+
+    let mut equities = vec![Equity::new(); hands.len()];
+    let mut runout = board.cards().to_vec();
+    enumerate_runouts(&live, missing, &mut runout, &mut |full_board| {
+        record_showdown(hands, full_board, &mut equities)
+    });
+
+    Ok(equities)
+}
+
+/// Estimates the equity of every hand by dealing random runouts of the board.
+///
+/// The runouts are drawn from a random number generator seeded with `seed`,
+/// so the same arguments always produce the same result.
+///
+/// # Arguments
+///
+/// * `hands` - The hole cards of every player in the pot.
+/// * `board` - The community cards dealt so far.
+/// * `trials` - The number of runouts to deal.
+/// * `seed` - The seed of the random number generator.
+///
+/// # Returns
+///
+/// * `Vec<Equity>` - The estimated equity of every hand, in the order of
+///   `hands`.
+///
+/// # Errors
+///
+/// Returns `EquityError::NoHands` if no hand is given and
+/// `EquityError::DuplicateCard` if a card appears more than once among the
+/// hands and the board.
+pub fn equity_monte_carlo(
+    hands: &[HoleCards],
+    board: &Board,
+    trials: usize,
+    seed: u64,
+) -> Result<Vec<Equity>, EquityError> {
+    equity_monte_carlo_with_report(hands, board, trials, seed).map(|(equities, _)| equities)
+}
+
+/// Estimates the equity of every hand like `equity_monte_carlo` and reports
+/// how well the simulation converged.
+///
+/// The equities are exactly those of `equity_monte_carlo` with the same
+/// arguments.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::board::Board;
+/// use pkr::equity::equity_monte_carlo_with_report;
+/// use pkr::hand::HoleCards;
+///
+/// let hands = [
+///     HoleCards::new_from_str("AhKh").unwrap(),
+///     HoleCards::new_from_str("9c9d").unwrap(),
+/// ];
+/// let (equities, report) =
+///     equity_monte_carlo_with_report(&hands, &Board::Preflop, 5000, 42).unwrap();
+/// assert_eq!(report.trials, 5000);
+/// assert_eq!(report.batch_means.len(), 10);
+/// assert!(!report.suspicious);
+/// println!("{}", report);
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as `equity_monte_carlo`.
+pub fn equity_monte_carlo_with_report(
+    hands: &[HoleCards],
+    board: &Board,
+    trials: usize,
+    seed: u64,
+) -> Result<(Vec<Equity>, SimulationReport), EquityError> {
+    let start = Instant::now();
+    let live = live_cards(hands, board)?;
+    let missing = 5 - board.cards().len();
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut equities = vec![Equity::new(); hands.len()];
+    let mut batches = Vec::with_capacity(BATCHES);
+    let mut full_board = board.cards().to_vec();
+    for batch in 0..BATCHES {
+        let batch_trials = trials / BATCHES + usize::from(batch < trials % BATCHES);
+        let mut batch_equities = vec![Equity::new(); hands.len()];
+        for _ in 0..batch_trials {
+            full_board.truncate(board.cards().len());
+            full_board.extend(live.choose_multiple(&mut rng, missing));
+            record_showdown(hands, &full_board, &mut batch_equities);
+        }
+        for (equity, batch_equity) in equities.iter_mut().zip(&batch_equities) {
+            equity.merge(batch_equity);
+        }
+        batches.push(batch_equities);
+    }
+
+    let report = SimulationReport::new(seed, start.elapsed(), &batches);
+    Ok((equities, report))
+}
+
+/// Returns the cards not held by any player and not on the board, in the
+/// order of a fresh `Deck`.
+pub(super) fn live_cards(hands: &[HoleCards], board: &Board) -> Result<Vec<Card>, EquityError> {
+    if hands.is_empty() {
+        return Err(EquityError::NoHands);
+    }
+
+    let mut dead: Vec<Card> = board.cards().to_vec();
+    for card in hands.iter().flat_map(|hole_cards| hole_cards.get_cards()) {
+        if dead.contains(&card) {
+            return Err(EquityError::DuplicateCard(card));
+        }
+        dead.push(card);
+    }
+
+    Ok(Deck::new()
+        .get_cards()
+        .iter()
+        .filter(|card| !dead.contains(card))
+        .copied()
+        .collect())
+}
+
+/// Calls `f` with every board completed by `missing` of the `live` cards.
+fn enumerate_runouts(
+    live: &[Card],
+    missing: usize,
+    runout: &mut Vec<Card>,
+    f: &mut impl FnMut(&[Card]),
+) {
+    if missing == 0 {
+        f(runout);
+        return;
+    }
+
+    for i in 0..live.len() {
+        runout.push(live[i]);
+        enumerate_runouts(&live[i + 1..], missing - 1, runout, f);
+        runout.pop();
+    }
+}
+
+pub(super) fn record_showdown(hands: &[HoleCards], board: &[Card], equities: &mut [Equity]) {
+    let context = BoardContext::from_cards(board);
+    let scores: Vec<u32> = hands
+        .iter()
+        .map(|hole_cards| context.score(hole_cards))
+        .collect();
+
+    let best = *scores.iter().max().expect("At least one hand is given");
+    let winners = scores.iter().filter(|&&score| score == best).count();
+
+    for (score, equity) in scores.iter().zip(equities.iter_mut()) {
+        if *score < best {
+            equity.record_loss();
+        } else if winners == 1 {
+            equity.record_win();
+        } else {
+            equity.record_tie(winners);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hands(s: &[&str]) -> Vec<HoleCards> {
+        s.iter()
+            .map(|hand| HoleCards::new_from_str(hand).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_exact_on_the_river() {
+        let hands = hands(&["AhKh", "9c9d"]);
+        let board = Board::new_from_str("Qs Js Ts 2d 3c").unwrap();
+        let equities = equity_exact(&hands, &board).unwrap();
+        assert_eq!(equities[0].equity(), 1.0);
+        assert_eq!(equities[1].equity(), 0.0);
+        assert_eq!(equities[0].get_trials(), 1);
+    }
+
+    #[test]
+    fn test_exact_on_the_flop() {
+        // Every turn and river card leaves the straight on board for both.
+        let hands = hands(&["2c3d", "2h3s"]);
+        let board = Board::new_from_str("Ah Kd Qc").unwrap();
+        let equities = equity_exact(&hands, &board).unwrap();
+        assert_eq!(equities[0].get_trials(), 990);
+        assert_eq!(equities[0].equity() + equities[1].equity(), 1.0);
+        assert_eq!(equities[0].win(), equities[1].win());
+    }
+
+    #[test]
+    fn test_monte_carlo_matches_exact() {
+        let hands = hands(&["AhKh", "9c9d", "7s6s"]);
+        let board = Board::new_from_str("Qs Js 2d").unwrap();
+
+        let exact = equity_exact(&hands, &board).unwrap();
+        let sampled = equity_monte_carlo(&hands, &board, 20_000, 42).unwrap();
+        for (exact, sampled) in exact.iter().zip(sampled.iter()) {
+            assert_eq!(sampled.get_trials(), 20_000);
+            assert!((exact.equity() - sampled.equity()).abs() < 0.01);
+        }
+
+        let total: f64 = sampled.iter().map(|equity| equity.equity()).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert_eq!(
+            sampled,
+            equity_monte_carlo(&hands, &board, 20_000, 42).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let board = Board::new_from_str("Qs Js 2d").unwrap();
+        assert_eq!(equity_exact(&[], &board), Err(EquityError::NoHands));
+        assert_eq!(
+            equity_exact(&hands(&["AhKh", "Ah9d"]), &board),
+            Err(EquityError::DuplicateCard(
+                Card::new_from_str("Ah").unwrap()
+            ))
+        );
+        assert_eq!(
+            equity_monte_carlo(&hands(&["QsKh", "9c9d"]), &board, 10, 0),
+            Err(EquityError::DuplicateCard(
+                Card::new_from_str("Qs").unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let hands = hands(&["AhKh", "9c9d"]);
+        let board = Board::new_from_str("Qs Js 2d").unwrap();
+
+        let (equities, report) = equity_monte_carlo_with_report(&hands, &board, 4321, 7).unwrap();
+        assert_eq!(
+            equities,
+            equity_monte_carlo(&hands, &board, 4321, 7).unwrap()
+        );
+        assert_eq!(report.trials, 4321);
+        assert_eq!(report.seed, 7);
+        assert_eq!(report.batch_means.len(), 10);
+        assert_eq!(report.r_hat.len(), 2);
+        assert!(!report.suspicious, "{}", report);
+        let running = report.running_means(0);
+        assert!((running[9] - equities[0].equity()).abs() < 0.01);
+
+        let (_, again) = equity_monte_carlo_with_report(&hands, &board, 4321, 7).unwrap();
+        assert_eq!(again.batch_means, report.batch_means);
+        assert_eq!(again.r_hat, report.r_hat);
+        let (_, other) = equity_monte_carlo_with_report(&hands, &board, 4321, 8).unwrap();
+        assert_ne!(other.batch_means, report.batch_means);
+    }
+
+    #[test]
+    fn test_report_flags_tiny_simulations() {
+        let hands = hands(&["AhKh", "9c9d"]);
+        let (_, report) = equity_monte_carlo_with_report(&hands, &Board::Preflop, 15, 1).unwrap();
+        assert_eq!(report.trials, 15);
+        assert!(report.suspicious);
+        assert!(report.to_string().ends_with("(suspicious)"));
+
+        let (_, report) = equity_monte_carlo_with_report(&hands, &Board::Preflop, 0, 1).unwrap();
+        assert_eq!(report.trials, 0);
+        assert!(report.batch_means.is_empty());
+        assert!(report.suspicious);
+    }
+}
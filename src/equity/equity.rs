@@ -0,0 +1,288 @@
+use std::io::{self, Read, Write};
+
+use crate::csv::{parse_field, read_single_row, write_row, CsvError, CsvErrorKind};
+
+/// The equity of a hand accumulated over a number of showdowns.
+///
+/// Every showdown is either won outright, split between several players or
+/// lost. A split pot is credited with the hero's share of it, i.e. half the
+/// pot in a two-way split and a third in a three-way split.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::equity::Equity;
+///
+/// let mut equity = Equity::new();
+/// equity.record_win();
+/// equity.record_tie(2);
+/// equity.record_loss();
+/// equity.record_loss();
+///
+/// assert_eq!(equity.win(), 0.25);
+/// assert_eq!(equity.tie(), 0.25);
+/// assert_eq!(equity.equity(), 0.375);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Equity {
+    trials: u64,
+    wins: u64,
+    ties: u64,
+    tie_shares: f64,
+}
+
+impl Equity {
+    /// Creates a new `Equity` without any recorded showdown.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an equity of heads-up showdowns from the number of showdowns,
+    /// wins and two-way ties.
+    pub(crate) fn heads_up(trials: u64, wins: u64, ties: u64) -> Self {
+        Self {
+            trials,
+            wins,
+            ties,
+            tie_shares: ties as f64 / 2.0,
+        }
+    }
+
+    /// Returns the number of showdowns won outright and the number of ties.
+    pub(crate) fn get_wins_and_ties(&self) -> (u64, u64) {
+        (self.wins, self.ties)
+    }
+
+    /// Records a showdown won outright.
+    pub fn record_win(&mut self) {
+        self.trials += 1;
+        self.wins += 1;
+    }
+
+    /// Records a showdown split between `players` players, including the
+    /// hero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `players` is zero.
+    pub fn record_tie(&mut self, players: usize) {
+        assert!(players > 0, "A split pot has at least one player");
+        self.trials += 1;
+        self.ties += 1;
+        self.tie_shares += 1.0 / players as f64;
+    }
+
+    /// Records a lost showdown.
+    pub fn record_loss(&mut self) {
+        self.trials += 1;
+    }
+
+    /// Adds the showdowns recorded in `other`, e.g. by another thread or
+    /// batch.
+    pub fn merge(&mut self, other: &Equity) {
+        self.trials += other.trials;
+        self.wins += other.wins;
+        self.ties += other.ties;
+        self.tie_shares += other.tie_shares;
+    }
+
+    /// Returns the number of recorded showdowns.
+    pub fn get_trials(&self) -> u64 {
+        self.trials
+    }
+
+    /// Returns the fraction of showdowns won outright.
+    pub fn win(&self) -> f64 {
+        self.fraction(self.wins as f64)
+    }
+
+    /// Returns the fraction of showdowns ending in a split pot.
+    pub fn tie(&self) -> f64 {
+        self.fraction(self.ties as f64)
+    }
+
+    /// Returns the fraction of showdowns lost.
+    pub fn lose(&self) -> f64 {
+        self.fraction((self.trials - self.wins - self.ties) as f64)
+    }
+
+    /// Returns the expected share of the pot, counting every split pot with
+    /// the hero's share of it.
+    ///
+    /// Returns 0.0 if no showdown was recorded.
+    pub fn equity(&self) -> f64 {
+        self.fraction(self.wins as f64 + self.tie_shares)
+    }
+
+    /// The columns of `to_csv`.
+    pub(crate) const CSV_HEADER: [&'static str; 8] = [
+        "trials",
+        "wins",
+        "ties",
+        "tie_shares",
+        "win",
+        "tie",
+        "lose",
+        "equity",
+    ];
+
+    /// Writes the equity as a CSV table of a header and one row.
+    ///
+    /// The columns are `trials`, `wins`, `ties` and `tie_shares`, the
+    /// recorded counts, followed by the fractions `win`, `tie`, `lose` and
+    /// `equity`, see the methods of the same names. Fractions are written
+    /// with as many digits as needed to read them back exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::equity::Equity;
+    ///
+    /// let mut equity = Equity::new();
+    /// equity.record_win();
+    /// equity.record_tie(2);
+    ///
+    /// let mut csv = Vec::new();
+    /// equity.to_csv(&mut csv).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(csv.clone()).unwrap(),
+    ///     "trials,wins,ties,tie_shares,win,tie,lose,equity\n2,1,1,0.5,0.5,0.5,0,0.75\n"
+    /// );
+    /// assert_eq!(Equity::from_csv(csv.as_slice()).unwrap(), equity);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the writer.
+    pub fn to_csv<W: Write>(&self, mut w: W) -> io::Result<()> {
+        write_row(&mut w, &Self::CSV_HEADER)?;
+        write_row(&mut w, &self.csv_fields().each_ref().map(String::as_str))
+    }
+
+    /// Reads an equity written by `to_csv`.
+    ///
+    /// Only the counts are read, the fractions are calculated from them.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CsvError` if the table does not have the header of
+    /// `to_csv` and exactly one row of valid counts, i.e. with no more wins
+    /// and ties than trials.
+    pub fn from_csv<R: Read>(r: R) -> Result<Self, CsvError> {
+        let (line, fields) = read_single_row(r, &Self::CSV_HEADER)?;
+        Self::from_csv_fields(line, &fields)
+    }
+
+    /// Returns the fields of the row of `to_csv`.
+    pub(crate) fn csv_fields(&self) -> [String; 8] {
+        [
+            self.trials.to_string(),
+            self.wins.to_string(),
+            self.ties.to_string(),
+            self.tie_shares.to_string(),
+            self.win().to_string(),
+            self.tie().to_string(),
+            self.lose().to_string(),
+            self.equity().to_string(),
+        ]
+    }
+
+    /// Reads the counts of the fields of a row of `to_csv`.
+    pub(crate) fn from_csv_fields(line: usize, fields: &[String]) -> Result<Self, CsvError> {
+        let equity = Self {
+            trials: parse_field(line, "trials", &fields[0])?,
+            wins: parse_field(line, "wins", &fields[1])?,
+            ties: parse_field(line, "ties", &fields[2])?,
+            tie_shares: parse_field(line, "tie_shares", &fields[3])?,
+        };
+        if equity
+            .wins
+            .checked_add(equity.ties)
+            .is_none_or(|n| n > equity.trials)
+        {
+            return Err(CsvError {
+                line,
+                kind: CsvErrorKind::InvalidField {
+                    column: "trials",
+                    value: fields[0].clone(),
+                },
+            });
+        }
+        Ok(equity)
+    }
+
+    fn fraction(&self, count: f64) -> f64 {
+        if self.trials == 0 {
+            return 0.0;
+        }
+        count / self.trials as f64
+    }
+}
+
+/// Serializes the equity as `{"trials": 4, "win": 0.25, "tie": 0.25, "lose":
+/// 0.5, "equity": 0.375}`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Equity {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Equity", 5)?;
+        state.serialize_field("trials", &self.trials)?;
+        state.serialize_field("win", &self.win())?;
+        state.serialize_field("tie", &self.tie())?;
+        state.serialize_field("lose", &self.lose())?;
+        state.serialize_field("equity", &self.equity())?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_round_trip() {
+        let mut equity = Equity::new();
+        equity.record_win();
+        equity.record_tie(3);
+        equity.record_loss();
+        let mut csv = Vec::new();
+        equity.to_csv(&mut csv).unwrap();
+        assert_eq!(Equity::from_csv(csv.as_slice()), Ok(equity));
+        assert_eq!(
+            Equity::from_csv(csv.as_slice()).unwrap().equity(),
+            equity.equity()
+        );
+
+        let csv = "trials,wins,ties,tie_shares,win,tie,lose,equity\n2,2,1,0.5,1,0.5,0,1\n";
+        let error = Equity::from_csv(csv.as_bytes()).unwrap_err();
+        assert_eq!(error.line, 2);
+        let csv = "trials,wins,ties,tie_shares,win,tie,lose,equity\n2,x,1,0.5,1,0.5,0,1\n";
+        assert_eq!(
+            Equity::from_csv(csv.as_bytes()).unwrap_err().kind,
+            CsvErrorKind::InvalidField {
+                column: "wins",
+                value: "x".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_equity() {
+        let equity = Equity::new();
+        assert_eq!(equity.get_trials(), 0);
+        assert_eq!(equity.equity(), 0.0);
+
+        let mut equity = Equity::new();
+        equity.record_win();
+        equity.record_tie(3);
+        equity.record_tie(3);
+        equity.record_tie(2);
+        equity.record_loss();
+        assert_eq!(equity.get_trials(), 5);
+        assert_eq!(equity.win(), 0.2);
+        assert_eq!(equity.tie(), 0.6);
+        assert_eq!(equity.lose(), 0.2);
+        assert!((equity.equity() - (1.0 + 2.0 / 3.0 + 0.5) / 5.0).abs() < 1e-12);
+    }
+}
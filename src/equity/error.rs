@@ -0,0 +1,34 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::card::Card;
+
+/// The error returned when the input of an equity calculation is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EquityError {
+    /// No hand was given.
+    NoHands,
+    /// A card appears more than once among the hands and the board.
+    DuplicateCard(Card),
+    /// Every combination of a range has weight zero or is blocked by the
+    /// other cards.
+    EmptyRange,
+    /// The ranges share so many cards that no deal giving every range a
+    /// combination was found within the allowed number of attempts.
+    RangesCollide,
+}
+
+impl fmt::Display for EquityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EquityError::NoHands => write!(f, "At least one hand is needed to calculate equity."),
+            EquityError::DuplicateCard(card) => write!(f, "Duplicate card: {}", card.as_str()),
+            EquityError::EmptyRange => write!(f, "The range has no live combination."),
+            EquityError::RangesCollide => {
+                write!(f, "The ranges could not be dealt without sharing a card.")
+            }
+        }
+    }
+}
+
+impl Error for EquityError {}
@@ -0,0 +1,12 @@
+mod engine;
+#[allow(clippy::module_inception)]
+mod equity;
+mod error;
+mod report;
+mod weighted;
+
+pub use engine::{equity_exact, equity_monte_carlo, equity_monte_carlo_with_report};
+pub use equity::Equity;
+pub use error::EquityError;
+pub use report::SimulationReport;
+pub use weighted::{equity_vs_weighted_range, equity_vs_weighted_range_monte_carlo};
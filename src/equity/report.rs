@@ -0,0 +1,148 @@
+use std::fmt;
+use std::time::Duration;
+
+use super::Equity;
+
+/// The number of batches a Monte Carlo simulation is split into for the
+/// convergence check.
+pub(crate) const BATCHES: usize = 10;
+
+/// The fewest showdowns per batch for the convergence check to be
+/// meaningful.
+const MIN_BATCH_TRIALS: u64 = 100;
+
+/// The largest potential scale reduction of a hand that is not flagged.
+const MAX_R_HAT: f64 = 1.1;
+
+/// Diagnostics of a Monte Carlo equity simulation, see
+/// `equity_monte_carlo_with_report`.
+///
+/// The trials are dealt in `BATCHES` consecutive batches. The batch means of
+/// every hand are compared with a Gelman-Rubin style check: if the variance
+/// between the batches is large compared to the variance within them, the
+/// potential scale reduction `r_hat` of the hand grows above 1 and the
+/// simulation is flagged as suspicious. Simulations with fewer than 100
+/// trials per batch are always flagged, since the check is meaningless for
+/// them.
+///
+/// With the `serde` feature the report serializes with all its fields, the
+/// wall time as `{"secs": 0, "nanos": 1500000}`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SimulationReport {
+    /// The number of showdowns dealt.
+    pub trials: u64,
+    /// The seed of the random number generator.
+    pub seed: u64,
+    /// The time the simulation took.
+    pub wall_time: Duration,
+    /// The equity of every hand within every batch, indexed by batch and
+    /// then by hand. Empty batches are left out.
+    pub batch_means: Vec<Vec<f64>>,
+    /// The potential scale reduction of every hand, 1.0 for a converged hand.
+    pub r_hat: Vec<f64>,
+    /// Whether the simulation looks like it has not converged.
+    pub suspicious: bool,
+}
+
+impl SimulationReport {
+    /// Creates the report from the equities recorded in every batch.
+    pub(crate) fn new(seed: u64, wall_time: Duration, batches: &[Vec<Equity>]) -> Self {
+        let batches: Vec<&Vec<Equity>> = batches
+            .iter()
+            .filter(|batch| batch.first().is_some_and(|equity| equity.get_trials() > 0))
+            .collect();
+        let trials = batches.iter().map(|batch| batch[0].get_trials()).sum();
+        let batch_means: Vec<Vec<f64>> = batches
+            .iter()
+            .map(|batch| batch.iter().map(Equity::equity).collect())
+            .collect();
+        let num_hands = batch_means.first().map_or(0, Vec::len);
+        let r_hat: Vec<f64> = (0..num_hands)
+            .map(|hand| potential_scale_reduction(&batches, hand))
+            .collect();
+
+        let too_few_trials = batches.len() < BATCHES
+            || batches
+                .iter()
+                .any(|batch| batch[0].get_trials() < MIN_BATCH_TRIALS);
+        let suspicious = too_few_trials
+            || r_hat
+                .iter()
+                .any(|&r_hat| r_hat.is_nan() || r_hat > MAX_R_HAT);
+
+        Self {
+            trials,
+            seed,
+            wall_time,
+            batch_means,
+            r_hat,
+            suspicious,
+        }
+    }
+
+    /// Returns the equity of the hand after each batch, converging to its
+    /// final equity.
+    pub fn running_means(&self, hand: usize) -> Vec<f64> {
+        let mut sum = 0.0;
+        self.batch_means
+            .iter()
+            .enumerate()
+            .map(|(i, means)| {
+                sum += means[hand];
+                sum / (i + 1) as f64
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for SimulationReport {
+    /// Formats a one line summary, e.g. "10000 trials, seed 42, 10 batches
+    /// in 12.5ms, max R-hat 1.002".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let max_r_hat = self.r_hat.iter().copied().fold(f64::NAN, f64::max);
+        write!(
+            f,
+            "{} trials, seed {}, {} batches in {:?}, max R-hat {:.3}",
+            self.trials,
+            self.seed,
+            self.batch_means.len(),
+            self.wall_time,
+            max_r_hat
+        )?;
+        if self.suspicious {
+            write!(f, " (suspicious)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the Gelman-Rubin potential scale reduction of a hand over the
+/// batches, treating every showdown as won or lost.
+///
+/// Returns NaN for fewer than two batches or showdowns per batch.
+fn potential_scale_reduction(batches: &[&Vec<Equity>], hand: usize) -> f64 {
+    let m = batches.len() as f64;
+    let n = batches
+        .iter()
+        .map(|batch| batch[hand].get_trials())
+        .sum::<u64>() as f64
+        / m;
+    if m < 2.0 || n < 2.0 {
+        return f64::NAN;
+    }
+
+    let means: Vec<f64> = batches.iter().map(|batch| batch[hand].equity()).collect();
+    let grand_mean = means.iter().sum::<f64>() / m;
+    let between = means
+        .iter()
+        .map(|mean| (mean - grand_mean).powi(2))
+        .sum::<f64>()
+        / (m - 1.0);
+    let within = means.iter().map(|mean| mean * (1.0 - mean)).sum::<f64>() / m * n / (n - 1.0);
+
+    if within == 0.0 {
+        return if between == 0.0 { 1.0 } else { f64::INFINITY };
+    }
+    (((n - 1.0) / n * within + between) / within).sqrt()
+}
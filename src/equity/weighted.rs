@@ -0,0 +1,229 @@
+use rand::distributions::WeightedIndex;
+use rand::prelude::Distribution;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::board::Board;
+use crate::hand::HoleCards;
+use crate::range::Range;
+
+use super::engine::{live_cards, record_showdown};
+use super::{equity_exact, Equity, EquityError};
+
+/// Calculates the exact equity of the hero against a weighted range by
+/// enumerating every combination of the range and every runout of the board.
+///
+/// Every combination counts with its weight, see `Range::weighted_by`.
+/// Combinations blocked by the hero or the board are left out and the
+/// weights of the others are renormalized.
+///
+/// # Arguments
+///
+/// * `hero` - The hole cards of the hero.
+/// * `range` - The weighted range of the villain.
+/// * `board` - The community cards dealt so far.
+///
+/// # Returns
+///
+/// * `f64` - The hero's expected share of the pot.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::board::Board;
+/// use pkr::equity::equity_vs_weighted_range;
+/// use pkr::hand::HoleCards;
+/// use pkr::range::{Range, StartingHand};
+///
+/// let hero = HoleCards::new_from_str("QhQd").unwrap();
+/// let board = Board::new_from_str("7c 4d 2s 9h 3c").unwrap();
+/// let range = Range::new_from_str("AA, 55").unwrap();
+///
+/// assert_eq!(equity_vs_weighted_range(&hero, &range, &board).unwrap(), 0.5);
+///
+/// // Opponents that never play fives.
+/// let fives = StartingHand::new_from_str("55").unwrap();
+/// let range = range.weighted_by(|hand| if hand == fives { 0.0 } else { 1.0 });
+/// assert_eq!(equity_vs_weighted_range(&hero, &range, &board).unwrap(), 0.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns `EquityError::DuplicateCard` if a card of the hero is on the
+/// board and `EquityError::EmptyRange` if no combination of the range is
+/// left.
+pub fn equity_vs_weighted_range(
+    hero: &HoleCards,
+    range: &Range,
+    board: &Board,
+) -> Result<f64, EquityError> {
+    let combos = live_combos(hero, range, board)?;
+    let mut total = 0.0;
+    let mut share = 0.0;
+    for (villain, weight) in combos {
+        let equity = equity_exact(&[*hero, villain], board)?[0].equity();
+        total += weight;
+        share += weight * equity;
+    }
+    Ok(share / total)
+}
+
+/// Estimates the equity of the hero against a weighted range like
+/// `equity_vs_weighted_range` by dealing random villain hands and runouts.
+///
+/// Every trial draws a combination of the range with a probability
+/// proportional to its weight. The same arguments always produce the same
+/// result.
+///
+/// # Errors
+///
+/// Returns the same errors as `equity_vs_weighted_range`.
+pub fn equity_vs_weighted_range_monte_carlo(
+    hero: &HoleCards,
+    range: &Range,
+    board: &Board,
+    trials: usize,
+    seed: u64,
+) -> Result<f64, EquityError> {
+    let combos = live_combos(hero, range, board)?;
+    let distribution = WeightedIndex::new(combos.iter().map(|(_, weight)| *weight))
+        .map_err(|_| EquityError::EmptyRange)?;
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let live = live_cards(&[*hero], board)?;
+    let missing = 5 - board.cards().len();
+
+    let mut equities = [Equity::new(); 2];
+    let mut full_board = board.cards().to_vec();
+    let mut runout_cards = Vec::with_capacity(live.len());
+    for _ in 0..trials {
+        let villain = combos[distribution.sample(&mut rng)].0;
+        runout_cards.clear();
+        runout_cards.extend(live.iter().filter(|card| !villain.contains(card)));
+        full_board.truncate(board.cards().len());
+        full_board.extend(runout_cards.choose_multiple(&mut rng, missing));
+        record_showdown(&[*hero, villain], &full_board, &mut equities);
+    }
+    Ok(equities[0].equity())
+}
+
+/// Returns the combinations of the range with a positive weight that are not
+/// blocked by the hero or the board.
+fn live_combos(
+    hero: &HoleCards,
+    range: &Range,
+    board: &Board,
+) -> Result<Vec<(HoleCards, f64)>, EquityError> {
+    if let Some(card) = hero
+        .get_cards()
+        .iter()
+        .find(|card| board.cards().contains(card))
+    {
+        return Err(EquityError::DuplicateCard(*card));
+    }
+    let combos: Vec<(HoleCards, f64)> = range
+        .get_combos()
+        .iter()
+        .filter(|(combo, weight)| {
+            *weight > 0.0
+                && !combo
+                    .iter()
+                    .any(|card| hero.contains(card) || board.cards().contains(card))
+        })
+        .map(|&(combo, weight)| {
+            let villain = HoleCards::new(combo[0], combo[1]).expect("Range combos are distinct");
+            (villain, weight)
+        })
+        .collect();
+    if combos.is_empty() {
+        return Err(EquityError::EmptyRange);
+    }
+    Ok(combos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::Card;
+    use crate::range::StartingHand;
+
+    fn hole(s: &str) -> HoleCards {
+        HoleCards::new_from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_zero_weighted_half() {
+        let hero = hole("QhQd");
+        let board = Board::new_from_str("7c 4d 2s").unwrap();
+        let range = Range::new_from_str("AA, 55").unwrap();
+        let fives = StartingHand::new_from_str("55").unwrap();
+        let weighted = range.weighted_by(|hand| if hand == fives { 0.0 } else { 1.0 });
+
+        let expected =
+            equity_vs_weighted_range(&hero, &Range::new_from_str("AA").unwrap(), &board).unwrap();
+        let equity = equity_vs_weighted_range(&hero, &weighted, &board).unwrap();
+        assert!((equity - expected).abs() < 1e-12);
+        assert!(equity < 0.25);
+
+        let sampled =
+            equity_vs_weighted_range_monte_carlo(&hero, &weighted, &board, 5000, 1).unwrap();
+        assert!(
+            (sampled - expected).abs() < 0.03,
+            "{} {}",
+            sampled,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_blocked_combos_are_renormalized() {
+        let hero = hole("AhKh");
+        let board = Board::new_from_str("Qs Jd 8c 7c 3h").unwrap();
+        let range = Range::new_from_str("AK, 22")
+            .unwrap()
+            .weighted_by(|hand| match hand {
+                StartingHand::Pair(_) => 1.0,
+                _ => 3.0,
+            });
+
+        // Nine unblocked combinations of ace king tie, six of deuces win
+        // against the hero.
+        let expected = (3.0 * 9.0 * 0.5) / (3.0 * 9.0 + 6.0);
+        let equity = equity_vs_weighted_range(&hero, &range, &board).unwrap();
+        assert!((equity - expected).abs() < 1e-12, "{}", equity);
+
+        let sampled = equity_vs_weighted_range_monte_carlo(&hero, &range, &board, 4000, 2).unwrap();
+        assert!(
+            (sampled - expected).abs() < 0.03,
+            "{} {}",
+            sampled,
+            expected
+        );
+        assert_eq!(
+            sampled,
+            equity_vs_weighted_range_monte_carlo(&hero, &range, &board, 4000, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let board = Board::new_from_str("Ah 7c 2d").unwrap();
+        let range = Range::new_from_str("AA").unwrap();
+        assert_eq!(
+            equity_vs_weighted_range(&hole("AsAd"), &range, &board),
+            Err(EquityError::EmptyRange)
+        );
+        assert_eq!(
+            equity_vs_weighted_range(&hole("AhKd"), &range, &board),
+            Err(EquityError::DuplicateCard(
+                Card::new_from_str("Ah").unwrap()
+            ))
+        );
+        let empty = range.weighted_by(|_| 0.0);
+        assert_eq!(
+            equity_vs_weighted_range_monte_carlo(&hole("KsKd"), &empty, &board, 10, 0),
+            Err(EquityError::EmptyRange)
+        );
+    }
+}
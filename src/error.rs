@@ -0,0 +1,327 @@
+use core::fmt;
+
+#[cfg(feature = "std")]
+use crate::board::BoardError;
+use crate::card::CardError;
+#[cfg(feature = "std")]
+use crate::csv::CsvError;
+use crate::deck::DeckError;
+#[cfg(feature = "std")]
+use crate::equity::EquityError;
+#[cfg(feature = "std")]
+use crate::game::{AuditError, IcmError, TableError};
+use crate::hand::{HandError, ParseError};
+#[cfg(feature = "std")]
+use crate::range::RangeError;
+#[cfg(feature = "std")]
+use crate::showdown::ShowdownError;
+#[cfg(feature = "std")]
+use crate::spot::SpotError;
+
+/// The error type of the crate, unifying the errors of the individual modules.
+///
+/// Every module error converts into an `Error` with `?`, and with the `std`
+/// feature an `Error` converts into a `Box<dyn std::error::Error>`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::hand::{Hand, HandError};
+///
+/// fn parse(s: &str) -> Result<u32, pkr::Error> {
+///     Ok(Hand::new_from_str(s)?.get_score())
+/// }
+///
+/// match parse("As Kx Qd") {
+///     Err(pkr::Error::Hand(HandError::InvalidCard { index, token, .. })) => {
+///         assert_eq!(index, 1);
+///         assert_eq!(token, "Kx");
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A card, rank or suit is invalid.
+    Card(CardError),
+    /// A hand or hole cards are invalid.
+    Hand(HandError),
+    /// Cards cannot be taken from a deck.
+    Deck(DeckError),
+    /// A record of a list of hands cannot be parsed.
+    Parse(ParseError),
+    /// The input of an equity calculation is invalid.
+    #[cfg(feature = "std")]
+    Equity(EquityError),
+    /// A board is invalid or cannot be dealt to.
+    #[cfg(feature = "std")]
+    Board(BoardError),
+    /// A range or starting hand is invalid.
+    #[cfg(feature = "std")]
+    Range(RangeError),
+    /// A spot is invalid.
+    #[cfg(feature = "std")]
+    Spot(SpotError),
+    /// A table cannot be created.
+    #[cfg(feature = "std")]
+    Table(TableError),
+    /// The cards of a game state do not form exactly one deck.
+    #[cfg(feature = "std")]
+    Audit(AuditError),
+    /// A showdown cannot be evaluated.
+    #[cfg(feature = "std")]
+    Showdown(ShowdownError),
+    /// The stacks of an ICM calculation are invalid.
+    #[cfg(feature = "std")]
+    Icm(IcmError),
+    /// A table written by a `to_csv` method cannot be read.
+    #[cfg(feature = "std")]
+    Csv(CsvError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Card(e) => write!(f, "{}", e),
+            Error::Hand(e) => write!(f, "{}", e),
+            Error::Deck(e) => write!(f, "{}", e),
+            Error::Parse(e) => write!(f, "{}", e),
+            #[cfg(feature = "std")]
+            Error::Equity(e) => write!(f, "{}", e),
+            #[cfg(feature = "std")]
+            Error::Board(e) => write!(f, "{}", e),
+            #[cfg(feature = "std")]
+            Error::Range(e) => write!(f, "{}", e),
+            #[cfg(feature = "std")]
+            Error::Spot(e) => write!(f, "{}", e),
+            #[cfg(feature = "std")]
+            Error::Table(e) => write!(f, "{}", e),
+            #[cfg(feature = "std")]
+            Error::Audit(e) => write!(f, "{}", e),
+            #[cfg(feature = "std")]
+            Error::Showdown(e) => write!(f, "{}", e),
+            #[cfg(feature = "std")]
+            Error::Icm(e) => write!(f, "{}", e),
+            #[cfg(feature = "std")]
+            Error::Csv(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Card(e) => Some(e),
+            Error::Hand(e) => Some(e),
+            Error::Deck(e) => Some(e),
+            Error::Parse(e) => Some(e),
+            Error::Equity(e) => Some(e),
+            Error::Board(e) => Some(e),
+            Error::Range(e) => Some(e),
+            Error::Spot(e) => Some(e),
+            Error::Table(e) => Some(e),
+            Error::Audit(e) => Some(e),
+            Error::Showdown(e) => Some(e),
+            Error::Icm(e) => Some(e),
+            Error::Csv(e) => Some(e),
+        }
+    }
+}
+
+impl From<CardError> for Error {
+    fn from(e: CardError) -> Self {
+        Error::Card(e)
+    }
+}
+
+impl From<HandError> for Error {
+    fn from(e: HandError) -> Self {
+        Error::Hand(e)
+    }
+}
+
+impl From<DeckError> for Error {
+    fn from(e: DeckError) -> Self {
+        Error::Deck(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<EquityError> for Error {
+    fn from(e: EquityError) -> Self {
+        Error::Equity(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<BoardError> for Error {
+    fn from(e: BoardError) -> Self {
+        Error::Board(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<RangeError> for Error {
+    fn from(e: RangeError) -> Self {
+        Error::Range(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<SpotError> for Error {
+    fn from(e: SpotError) -> Self {
+        Error::Spot(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<TableError> for Error {
+    fn from(e: TableError) -> Self {
+        Error::Table(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<AuditError> for Error {
+    fn from(e: AuditError) -> Self {
+        Error::Audit(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ShowdownError> for Error {
+    fn from(e: ShowdownError) -> Self {
+        Error::Showdown(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<IcmError> for Error {
+    fn from(e: IcmError) -> Self {
+        Error::Icm(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<CsvError> for Error {
+    fn from(e: CsvError) -> Self {
+        Error::Csv(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand::Hand;
+
+    #[test]
+    fn test_from_hand_error() {
+        let error: Error = Hand::new_from_str("As Kx Qd").err().unwrap().into();
+        assert_eq!(
+            error,
+            Error::Hand(HandError::InvalidCard {
+                index: 1,
+                token: "Kx".into(),
+                source: CardError::InvalidSuit("x".into()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_parse_error() {
+        fn parse(s: &str) -> Result<Vec<Hand>, Error> {
+            Ok(Hand::parse_many(s).into_iter().collect::<Result<_, _>>()?)
+        }
+        let error = parse("As Ks\nAs As").unwrap_err();
+        assert!(matches!(error, Error::Parse(ParseError { line: 2, .. })));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_board_error() {
+        let error: Error = crate::board::Board::new_from_str("Ah Kd")
+            .err()
+            .unwrap()
+            .into();
+        assert_eq!(error, Error::Board(BoardError::InvalidCardCount(2)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_audit_error() {
+        use crate::board::Board;
+        use crate::card::Card;
+        use crate::game::{audit, AuditError};
+
+        fn check(hands: &[Hand]) -> Result<(), Error> {
+            audit(&crate::deck::Deck::new(), hands, &Board::Preflop)?;
+            Ok(())
+        }
+        let hands = [Hand::new_from_str("As Ks").unwrap()];
+        assert_eq!(
+            check(&hands),
+            Err(Error::Audit(AuditError::DuplicateCard(
+                Card::new_from_str("As").unwrap()
+            )))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_showdown_error() {
+        use crate::board::Board;
+        use crate::card::Card;
+        use crate::showdown::{double_board, PlayerId, ShowdownError};
+
+        fn check(board_a: &str, board_b: &str) -> Result<(), Error> {
+            let hole_cards = Hand::new_from_str("As Ad").unwrap();
+            let players = [(PlayerId(0), [hole_cards[0], hole_cards[1]])];
+            double_board(
+                &Board::new_from_str(board_a)?,
+                &Board::new_from_str(board_b)?,
+                &players,
+            )?;
+            Ok(())
+        }
+        assert_eq!(
+            check("Ah 9d 4s", "Ah 8s 3d"),
+            Err(Error::Showdown(ShowdownError::DuplicateCard(
+                Card::new_from_str("Ah").unwrap()
+            )))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_csv_error() {
+        use crate::csv::CsvErrorKind;
+        use crate::equity::Equity;
+
+        fn read(csv: &str) -> Result<Equity, Error> {
+            Ok(Equity::from_csv(csv.as_bytes())?)
+        }
+        assert!(matches!(
+            read("trials\n"),
+            Err(Error::Csv(CsvError {
+                line: 1,
+                kind: CsvErrorKind::InvalidHeader { .. }
+            }))
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_into_boxed_error() {
+        let error: Error = Hand::new_from_str("As Kx Qd").err().unwrap().into();
+        let boxed: Box<dyn std::error::Error> = error.clone().into();
+        assert_eq!(boxed.to_string(), error.to_string());
+        assert!(boxed.source().is_some());
+    }
+}
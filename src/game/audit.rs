@@ -0,0 +1,353 @@
+use std::fmt;
+
+use crate::board::Board;
+use crate::card::{Card, Rank};
+use crate::deck::Deck;
+use crate::hand::Hand;
+
+/// The error returned when the cards of a game state do not form exactly one
+/// deck.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditError {
+    /// The card appears more than once among the deck, the hands and the
+    /// board.
+    DuplicateCard(Card),
+    /// The card appears nowhere in the deck, the hands or the board.
+    MissingCard(Card),
+    /// The card was exposed while dealing but is in a hand or on the board
+    /// instead of back in the deck.
+    ExposedCard(Card),
+    /// The card is not part of the audited kind of deck, e.g. a two in a
+    /// short deck.
+    ForeignCard(Card),
+}
+
+/// The kind of deck a game is dealt from, which decides the cards an audit
+/// expects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeckKind {
+    /// A standard deck of 52 cards.
+    Standard,
+    /// A short deck of 36 cards without the twos to fives, as in six plus
+    /// hold'em.
+    Short,
+    /// The given number of standard decks shuffled together, holding every
+    /// card that many times.
+    Multi(usize),
+}
+
+impl DeckKind {
+    /// Returns the number of copies of the card in the deck.
+    fn copies(&self, card: &Card) -> usize {
+        match self {
+            DeckKind::Standard => 1,
+            DeckKind::Short => usize::from(card.rank >= Rank::Six),
+            DeckKind::Multi(decks) => *decks,
+        }
+    }
+}
+
+impl fmt::Display for AuditError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuditError::DuplicateCard(card) => write!(f, "Duplicate card: {}", card.as_str()),
+            AuditError::MissingCard(card) => write!(f, "Missing card: {}", card.as_str()),
+            AuditError::ExposedCard(card) => write!(f, "Exposed card in play: {}", card.as_str()),
+            AuditError::ForeignCard(card) => {
+                write!(f, "Card not in the deck: {}", card.as_str())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AuditError {}
+
+/// Audits a game state by checking that the remaining deck, the dealt hands
+/// and the board together hold every card of a standard 52 card deck
+/// exactly once.
+///
+/// This catches bugs in external dealing code, e.g. a card dealt twice or a
+/// card lost while burning, as early as possible.
+///
+/// # Arguments
+///
+/// * `deck` - The cards left in the deck.
+/// * `hands` - The cards dealt to the players.
+/// * `board` - The community cards.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::board::Board;
+/// use pkr::card::Card;
+/// use pkr::deck::Deck;
+/// use pkr::game::{audit, AuditError};
+/// use pkr::hand::Hand;
+///
+/// let mut deck = Deck::new();
+/// let hand = Hand::new_from_str("As Ks").unwrap();
+/// deck.claim_hand(&hand).unwrap();
+/// let hands = [hand];
+/// assert_eq!(audit(&deck, &hands, &Board::Preflop), Ok(()));
+///
+/// let lost = Hand::new_from_str("Qs Js").unwrap();
+/// deck.claim_hand(&lost).unwrap();
+/// assert_eq!(
+///     audit(&deck, &hands, &Board::Preflop),
+///     Err(AuditError::MissingCard(Card::new_from_str("Js").unwrap()))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns `AuditError::DuplicateCard` with the first card seen twice,
+/// looking at the deck, the hands and the board in that order. Otherwise
+/// returns `AuditError::MissingCard` with the first missing card in the
+/// order of a new `Deck`.
+pub fn audit(deck: &Deck, hands: &[Hand], board: &Board) -> Result<(), AuditError> {
+    audit_with(deck.get_cards(), hands, board, DeckKind::Standard)
+}
+
+/// Audits a game state like `audit` for any kind of deck, checking that the
+/// remaining cards, the dealt hands and the board together hold every card
+/// of the deck as many times as the deck does.
+///
+/// The remaining cards are a slice rather than a `Deck`, since a short or
+/// multi deck is kept by the dealing code itself.
+///
+/// # Arguments
+///
+/// * `remaining` - The cards left in the deck.
+/// * `hands` - The cards dealt to the players.
+/// * `board` - The community cards.
+/// * `kind` - The kind of deck the game is dealt from.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::board::Board;
+/// use pkr::card::Card;
+/// use pkr::deck::Deck;
+/// use pkr::game::{audit_with, AuditError, DeckKind};
+///
+/// let two_decks: Vec<Card> = [Deck::new(), Deck::new()]
+///     .iter()
+///     .flat_map(|deck| deck.get_cards().to_vec())
+///     .collect();
+/// assert_eq!(audit_with(&two_decks, &[], &Board::Preflop, DeckKind::Multi(2)), Ok(()));
+/// assert_eq!(
+///     audit_with(&two_decks, &[], &Board::Preflop, DeckKind::Short),
+///     Err(AuditError::ForeignCard(Card::new_from_str("2h").unwrap()))
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns `AuditError::ForeignCard` with the first card that is not part of
+/// the deck and `AuditError::DuplicateCard` with the first card seen more
+/// often than the deck holds it, looking at the remaining cards, the hands
+/// and the board in that order. Otherwise returns `AuditError::MissingCard`
+/// with the first missing card in the order of a new `Deck`.
+pub fn audit_with(
+    remaining: &[Card],
+    hands: &[Hand],
+    board: &Board,
+    kind: DeckKind,
+) -> Result<(), AuditError> {
+    let mut seen = [0usize; 52];
+    let cards = remaining
+        .iter()
+        .chain(hands.iter().flat_map(|hand| hand.as_slice()))
+        .chain(board.cards());
+    for card in cards {
+        let copies = kind.copies(card);
+        if copies == 0 {
+            return Err(AuditError::ForeignCard(*card));
+        }
+        let index = card.to_byte() as usize;
+        if seen[index] == copies {
+            return Err(AuditError::DuplicateCard(*card));
+        }
+        seen[index] += 1;
+    }
+
+    match Deck::new()
+        .get_cards()
+        .iter()
+        .find(|card| seen[card.to_byte() as usize] < kind.copies(card))
+    {
+        Some(card) => Err(AuditError::MissingCard(*card)),
+        None => Ok(()),
+    }
+}
+
+/// Audits a game state like `audit` and additionally checks that no card
+/// exposed while dealing, e.g. one flipped face up, was kept in a hand or
+/// on the board.
+///
+/// # Arguments
+///
+/// * `deck` - The cards left in the deck.
+/// * `hands` - The cards dealt to the players.
+/// * `board` - The community cards.
+/// * `exposed` - The cards exposed while dealing.
+///
+/// # Errors
+///
+/// Returns the errors of `audit` first, then `AuditError::ExposedCard`
+/// with the first exposed card found in play.
+pub fn audit_exposed(
+    deck: &Deck,
+    hands: &[Hand],
+    board: &Board,
+    exposed: &[Card],
+) -> Result<(), AuditError> {
+    audit(deck, hands, board)?;
+    match exposed.iter().find(|card| !deck.get_cards().contains(card)) {
+        Some(card) => Err(AuditError::ExposedCard(*card)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(s: &str) -> Card {
+        Card::new_from_str(s).unwrap()
+    }
+
+    /// Deals the hands and the board from a new deck.
+    fn deal(hands: &[&str], board: &str) -> (Deck, Vec<Hand>, Board) {
+        let mut deck = Deck::new();
+        let hands: Vec<Hand> = hands
+            .iter()
+            .map(|s| Hand::new_from_str(s).unwrap())
+            .collect();
+        let board = Board::new_from_str(board).unwrap();
+        for hand in &hands {
+            deck.claim_hand(hand).unwrap();
+        }
+        deck.claim_hand(&Hand::new(board.cards().to_vec()).unwrap())
+            .unwrap();
+        (deck, hands, board)
+    }
+
+    #[test]
+    fn test_consistent_state() {
+        let (deck, hands, board) = deal(&["As Ks", "Qd Qc"], "2h 7c 9d");
+        assert_eq!(deck.get_cards().len(), 45);
+        assert_eq!(audit(&deck, &hands, &board), Ok(()));
+        assert_eq!(audit(&Deck::new(), &[], &Board::Preflop), Ok(()));
+    }
+
+    #[test]
+    fn test_duplicate_across_hands() {
+        let (deck, mut hands, board) = deal(&["As Ks", "Qd Qc"], "2h 7c 9d");
+        hands[1] = Hand::new_from_str("Qd Ks").unwrap();
+        assert_eq!(
+            audit(&deck, &hands, &board),
+            Err(AuditError::DuplicateCard(card("Ks")))
+        );
+
+        // A card dealt to a player that is still in the deck.
+        assert_eq!(
+            audit(&Deck::new(), &hands[..1], &Board::Preflop),
+            Err(AuditError::DuplicateCard(card("As")))
+        );
+    }
+
+    #[test]
+    fn test_missing_card() {
+        let (deck, hands, board) = deal(&["As Ks", "Qd Qc"], "2h 7c 9d");
+        assert_eq!(
+            audit(&deck, &hands, &Board::Preflop),
+            Err(AuditError::MissingCard(card("2h")))
+        );
+        assert_eq!(
+            AuditError::MissingCard(card("9d")).to_string(),
+            "Missing card: 9d"
+        );
+        assert_eq!(audit(&deck, &hands, &board), Ok(()));
+    }
+
+    /// Returns the cards of a kind of deck in the order of a new `Deck`.
+    fn cards_of(kind: DeckKind) -> Vec<Card> {
+        Deck::new()
+            .get_cards()
+            .iter()
+            .flat_map(|card| std::iter::repeat_n(*card, kind.copies(card)))
+            .collect()
+    }
+
+    /// Removes the dealt cards once each from the remaining cards.
+    fn remove(remaining: &mut Vec<Card>, dealt: &[Card]) {
+        for card in dealt {
+            let index = remaining.iter().position(|c| c == card).unwrap();
+            remaining.remove(index);
+        }
+    }
+
+    #[test]
+    fn test_short_deck() {
+        let mut remaining = cards_of(DeckKind::Short);
+        assert_eq!(remaining.len(), 36);
+        let hands = [Hand::new_from_str("As Ks").unwrap()];
+        let board = Board::new_from_str("6h 7c 9d").unwrap();
+        remove(&mut remaining, hands[0].as_slice());
+        remove(&mut remaining, board.cards());
+        assert_eq!(
+            audit_with(&remaining, &hands, &board, DeckKind::Short),
+            Ok(())
+        );
+
+        // A five is not part of a short deck.
+        let low = Board::new_from_str("6h 7c 5d").unwrap();
+        assert_eq!(
+            audit_with(&remaining, &hands, &low, DeckKind::Short),
+            Err(AuditError::ForeignCard(card("5d")))
+        );
+        assert_eq!(
+            audit_with(&remaining, &hands, &Board::Preflop, DeckKind::Short),
+            Err(AuditError::MissingCard(card("6h")))
+        );
+        assert_eq!(
+            AuditError::ForeignCard(card("5d")).to_string(),
+            "Card not in the deck: 5d"
+        );
+    }
+
+    #[test]
+    fn test_multi_deck() {
+        let mut remaining = cards_of(DeckKind::Multi(2));
+        assert_eq!(remaining.len(), 104);
+        // Both copies of the ace of spades are dealt.
+        let hands = [
+            Hand::new_from_str("As Ks").unwrap(),
+            Hand::new_from_str("As Qd").unwrap(),
+        ];
+        for hand in &hands {
+            remove(&mut remaining, hand.as_slice());
+        }
+        let board = Board::Preflop;
+        assert_eq!(
+            audit_with(&remaining, &hands, &board, DeckKind::Multi(2)),
+            Ok(())
+        );
+
+        // A third ace of spades.
+        let mut extra = remaining.clone();
+        extra.push(card("As"));
+        assert_eq!(
+            audit_with(&extra, &hands, &board, DeckKind::Multi(2)),
+            Err(AuditError::DuplicateCard(card("As")))
+        );
+
+        // One copy of the king of spades is lost.
+        remove(&mut remaining, &[card("Ks")]);
+        assert_eq!(
+            audit_with(&remaining, &hands, &board, DeckKind::Multi(2)),
+            Err(AuditError::MissingCard(card("Ks")))
+        );
+    }
+}
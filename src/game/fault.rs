@@ -0,0 +1,199 @@
+use crate::card::Card;
+use crate::deck::Deck;
+
+/// A dealing irregularity a `FaultInjector` introduces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// The card is dealt a second time right after it was dealt, without
+    /// taking a card from the deck. `audit` reports it as
+    /// `AuditError::DuplicateCard`, and claiming the dealt cards from a
+    /// shadow deck fails with `DeckError::CardNotInDeck`.
+    DuplicateCard,
+    /// The card is dealt face up. It is recorded in
+    /// `FaultInjector::exposed`, and `audit_exposed` reports it as
+    /// `AuditError::ExposedCard` unless it was put back into the deck.
+    ExposedCard,
+    /// The card falls off the deck and is never dealt, the next card is
+    /// dealt in its place. `audit` reports it as `AuditError::MissingCard`.
+    ShortDeck,
+}
+
+/// Deals from a `Deck` like `Deck::deal`, but introduces a dealing
+/// irregularity at a given deal, so that test suites of game servers can
+/// check that their validation catches it.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::board::Board;
+/// use pkr::deck::Deck;
+/// use pkr::game::{audit, AuditError, Fault, FaultInjector};
+/// use pkr::hand::Hand;
+///
+/// let mut dealer = FaultInjector::with_fault(Deck::new(), Fault::DuplicateCard, 1);
+/// let hands: Vec<Hand> = (0..2)
+///     .map(|_| Hand::new(vec![dealer.deal().unwrap(), dealer.deal().unwrap()]).unwrap())
+///     .collect();
+///
+/// let duplicate = hands[0].as_slice()[1];
+/// assert_eq!(hands[1].as_slice()[0], duplicate);
+/// assert_eq!(
+///     audit(dealer.deck(), &hands, &Board::Preflop),
+///     Err(AuditError::DuplicateCard(duplicate))
+/// );
+/// ```
+pub struct FaultInjector {
+    deck: Deck,
+    fault: Option<(Fault, usize)>,
+    dealt: usize,
+    duplicate: Option<Card>,
+    exposed: Vec<Card>,
+}
+
+impl FaultInjector {
+    /// Creates a dealer that deals the deck without irregularities.
+    pub fn new(deck: Deck) -> Self {
+        Self {
+            deck,
+            fault: None,
+            dealt: 0,
+            duplicate: None,
+            exposed: Vec::new(),
+        }
+    }
+
+    /// Creates a dealer that introduces the fault at the deal of the given
+    /// zero-based index.
+    ///
+    /// # Arguments
+    ///
+    /// * `deck` - The deck to deal from.
+    /// * `fault` - The irregularity to introduce.
+    /// * `at` - The number of cards dealt before the faulty deal.
+    pub fn with_fault(deck: Deck, fault: Fault, at: usize) -> Self {
+        Self {
+            fault: Some((fault, at)),
+            ..Self::new(deck)
+        }
+    }
+
+    /// Deals the next card, introducing the configured fault at its index.
+    ///
+    /// Returns `None` if the deck is empty.
+    pub fn deal(&mut self) -> Option<Card> {
+        let index = self.dealt;
+        self.dealt += 1;
+        if let Some(card) = self.duplicate.take() {
+            return Some(card);
+        }
+
+        match self.fault {
+            Some((Fault::DuplicateCard, at)) if at == index => {
+                let card = self.deck.deal()?;
+                self.duplicate = Some(card);
+                Some(card)
+            }
+            Some((Fault::ExposedCard, at)) if at == index => {
+                let card = self.deck.deal()?;
+                self.exposed.push(card);
+                Some(card)
+            }
+            Some((Fault::ShortDeck, at)) if at == index => {
+                self.deck.deal()?;
+                self.deck.deal()
+            }
+            _ => self.deck.deal(),
+        }
+    }
+
+    /// Returns the cards left in the deck.
+    pub fn deck(&self) -> &Deck {
+        &self.deck
+    }
+
+    /// Returns the cards that were dealt face up.
+    pub fn exposed(&self) -> &[Card] {
+        &self.exposed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::board::Board;
+    use crate::deck::DeckError;
+    use crate::game::{audit_exposed, AuditError};
+    use crate::hand::Hand;
+
+    /// The cards dealt and the validation results of a hand of three
+    /// players.
+    struct Deal {
+        cards: Vec<Card>,
+        audit: Result<(), AuditError>,
+        claim: Result<(), DeckError>,
+    }
+
+    /// Deals two cards to each of three players and a board of five cards,
+    /// claiming every hand from a shadow deck like a server tracking the
+    /// cards in play would.
+    fn deal(fault: Option<(Fault, usize)>) -> Deal {
+        let mut deck = Deck::new();
+        deck.shuffle_with(&mut StdRng::seed_from_u64(210));
+        let mut dealer = match fault {
+            Some((fault, at)) => FaultInjector::with_fault(deck, fault, at),
+            None => FaultInjector::new(deck),
+        };
+        let cards: Vec<Card> = (0..11).map(|_| dealer.deal().unwrap()).collect();
+
+        let hands: Vec<Hand> = cards[..6]
+            .chunks(2)
+            .map(|hole| Hand::new(hole.to_vec()).unwrap())
+            .collect();
+        let board = Board::try_from(&cards[6..]).unwrap();
+        let mut shadow = Deck::new();
+        let claim = hands
+            .iter()
+            .chain([&Hand::new(board.cards().to_vec()).unwrap()])
+            .try_for_each(|hand| shadow.claim_hand(hand));
+        Deal {
+            audit: audit_exposed(dealer.deck(), &hands, &board, dealer.exposed()),
+            cards,
+            claim,
+        }
+    }
+
+    #[test]
+    fn test_no_fault() {
+        let deal = deal(None);
+        assert_eq!(deal.audit, Ok(()));
+        assert_eq!(deal.claim, Ok(()));
+    }
+
+    #[test]
+    fn test_duplicate_card() {
+        let deal = deal(Some((Fault::DuplicateCard, 3)));
+        let duplicate = deal.cards[3];
+        assert_eq!(deal.cards[4], duplicate);
+        assert_eq!(deal.audit, Err(AuditError::DuplicateCard(duplicate)));
+        assert_eq!(deal.claim, Err(DeckError::CardNotInDeck(duplicate)));
+    }
+
+    #[test]
+    fn test_exposed_card() {
+        let deal = deal(Some((Fault::ExposedCard, 5)));
+        assert_eq!(deal.audit, Err(AuditError::ExposedCard(deal.cards[5])));
+        assert_eq!(deal.claim, Ok(()));
+    }
+
+    #[test]
+    fn test_short_deck() {
+        let lost = deal(None).cards[6];
+        let deal = deal(Some((Fault::ShortDeck, 6)));
+        assert!(!deal.cards.contains(&lost));
+        assert_eq!(deal.audit, Err(AuditError::MissingCard(lost)));
+        assert_eq!(deal.claim, Ok(()));
+    }
+}
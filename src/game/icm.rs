@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::combinatorics::n_choose_k;
+
+/// The most sets of finished players `icm` tracks for a single place.
+pub const MAX_ICM_STATES: u64 = 1 << 20;
+
+/// The error returned when the stacks of an ICM calculation are invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IcmError {
+    /// The total of the stacks does not fit into a `u64`.
+    ChipOverflow,
+    /// There are more than 64 players.
+    TooManyPlayers(usize),
+    /// The stacks before and after a hand belong to a different number of
+    /// players.
+    LengthMismatch {
+        /// The number of stacks before the hand.
+        before: usize,
+        /// The number of stacks after the hand.
+        after: usize,
+    },
+    /// There are so many players and paid places that the sets of players
+    /// finishing in the paid places exceed `MAX_ICM_STATES`.
+    TooManyStates {
+        /// The number of players.
+        players: usize,
+        /// The number of paid places.
+        places: usize,
+    },
+}
+
+impl fmt::Display for IcmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IcmError::ChipOverflow => write!(f, "The total of the stacks overflows."),
+            IcmError::TooManyPlayers(found) => {
+                write!(f, "ICM supports at most 64 players, found {}.", found)
+            }
+            IcmError::LengthMismatch { before, after } => write!(
+                f,
+                "Found {} stacks before the hand but {} after.",
+                before, after
+            ),
+            IcmError::TooManyStates { players, places } => write!(
+                f,
+                "ICM of {} players with {} paid places is too expensive.",
+                players, places
+            ),
+        }
+    }
+}
+
+impl std::error::Error for IcmError {}
+
+/// Computes the tournament equity of every player with the Malmuth-Harville
+/// independent chip model.
+///
+/// A player finishes first with a probability proportional to their stack.
+/// Given the players who already finished, every remaining player takes the
+/// next place with a probability proportional to their stack among the
+/// remaining stacks. Players without chips finish after everyone else and
+/// share the places left to them evenly.
+///
+/// # Arguments
+///
+/// * `stacks` - The chip stack of every player.
+/// * `payouts` - The prize of every place, starting with the winner. Prizes
+///   for places beyond the number of players are ignored.
+///
+/// # Returns
+///
+/// * `Vec<f64>` - The expected prize of every player, in the order of
+///   `stacks`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::game::icm;
+///
+/// let equities = icm(&[5_000, 5_000], &[100, 0]).unwrap();
+/// assert_eq!(equities, vec![50.0, 50.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `IcmError::TooManyPlayers` if there are more than 64 players,
+/// `IcmError::TooManyStates` if there are too many players for the paid
+/// places, see below, and `IcmError::ChipOverflow` if the stacks sum up to
+/// more than `u64::MAX` chips.
+///
+/// # Performance
+///
+/// The probability of every set of players finishing in the top places is
+/// tracked, which for `n` players and `k` paid places are up to `n choose k`
+/// sets. Trailing places paying nothing are skipped. If the largest number
+/// of sets exceeds `MAX_ICM_STATES`, e.g. for 64 players with five paid
+/// places or 25 players who are all paid, the calculation is refused.
+pub fn icm(stacks: &[u64], payouts: &[u64]) -> Result<Vec<f64>, IcmError> {
+    if stacks.len() > 64 {
+        return Err(IcmError::TooManyPlayers(stacks.len()));
+    }
+    stacks
+        .iter()
+        .try_fold(0u64, |total, &stack| total.checked_add(stack))
+        .ok_or(IcmError::ChipOverflow)?;
+
+    let mut equities = vec![0.0; stacks.len()];
+    let paid = payouts
+        .iter()
+        .rposition(|&payout| payout > 0)
+        .map_or(0, |i| i + 1);
+    let places = paid.min(stacks.len());
+    // The number of sets grows with the places up to half of the players.
+    let largest = places.min(stacks.len() / 2);
+    if n_choose_k(stacks.len() as u64, largest as u64) > MAX_ICM_STATES {
+        return Err(IcmError::TooManyStates {
+            players: stacks.len(),
+            places,
+        });
+    }
+
+    // Maps the set of players who finished in the top places so far to the
+    // probability of exactly that set finishing there.
+    let mut finished: HashMap<u64, f64> = HashMap::new();
+    finished.insert(0, 1.0);
+
+    for &payout in payouts.iter().take(places) {
+        let mut next: HashMap<u64, f64> = HashMap::new();
+
+        for (&mask, &probability) in &finished {
+            let remaining: Vec<usize> = (0..stacks.len())
+                .filter(|&i| mask & (1 << i) == 0)
+                .collect();
+            // Never overflows, the sum of all stacks was checked above.
+            let total: u64 = remaining.iter().map(|&i| stacks[i]).sum();
+
+            for &i in &remaining {
+                let chance = if total > 0 {
+                    stacks[i] as f64 / total as f64
+                } else {
+                    1.0 / remaining.len() as f64
+                };
+                if chance == 0.0 {
+                    continue;
+                }
+
+                let p = probability * chance;
+                equities[i] += p * payout as f64;
+                *next.entry(mask | (1 << i)).or_insert(0.0) += p;
+            }
+        }
+
+        finished = next;
+    }
+
+    Ok(equities)
+}
+
+/// Computes the change in tournament equity of every player caused by a
+/// change of the stacks, e.g. the result of an all-in.
+///
+/// # Arguments
+///
+/// * `before` - The chip stacks before the hand.
+/// * `after` - The chip stacks after the hand.
+/// * `payouts` - The prize of every place, starting with the winner.
+///
+/// # Returns
+///
+/// * `Vec<f64>` - The equity after the hand minus the equity before, for
+///   every player.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::game::icm_delta;
+///
+/// // The gains and losses of all players cancel out.
+/// let delta =
+///     icm_delta(&[2_000, 4_000, 4_000], &[4_000, 2_000, 4_000], &[50, 30, 20]).unwrap();
+/// assert!(delta[0] > 0.0);
+/// assert!((delta.iter().sum::<f64>()).abs() < 1e-9);
+/// ```
+///
+/// # Errors
+///
+/// Returns `IcmError::LengthMismatch` if `before` and `after` have different
+/// lengths, and the errors of `icm` if there are more than 64 players or the
+/// stacks before or after sum up to more than `u64::MAX` chips.
+pub fn icm_delta(before: &[u64], after: &[u64], payouts: &[u64]) -> Result<Vec<f64>, IcmError> {
+    if before.len() != after.len() {
+        return Err(IcmError::LengthMismatch {
+            before: before.len(),
+            after: after.len(),
+        });
+    }
+
+    Ok(icm(after, payouts)?
+        .into_iter()
+        .zip(icm(before, payouts)?)
+        .map(|(after, before)| after - before)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 0.01, "{:?} vs {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_equal_stacks() {
+        assert_eq!(icm(&[1_000, 1_000], &[100, 0]).unwrap(), vec![50.0, 50.0]);
+        assert_close(
+            &icm(&[10, 10, 10], &[60, 30, 10]).unwrap(),
+            &[33.33, 33.33, 33.33],
+        );
+    }
+
+    #[test]
+    fn test_three_players() {
+        let equities = icm(&[5_000, 3_000, 2_000], &[50, 30, 20]).unwrap();
+        assert_close(&equities, &[38.39, 32.75, 28.86]);
+        assert!((equities.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pathological_inputs() {
+        assert!(icm(&[], &[100]).unwrap().is_empty());
+        assert_eq!(icm(&[100, 300], &[]).unwrap(), vec![0.0, 0.0]);
+
+        // A busted player only gets the places nobody else can take.
+        assert_eq!(icm(&[0, 500], &[70, 30]).unwrap(), vec![30.0, 70.0]);
+        assert_eq!(icm(&[0, 0], &[70, 30]).unwrap(), vec![50.0, 50.0]);
+
+        // Prizes beyond the number of players are never paid.
+        assert_eq!(icm(&[500, 500], &[50, 30, 20]).unwrap(), vec![40.0, 40.0]);
+    }
+
+    #[test]
+    fn test_chip_overflow() {
+        assert_eq!(icm(&[u64::MAX, 1], &[100]), Err(IcmError::ChipOverflow));
+        assert_eq!(
+            icm_delta(&[1, 1], &[u64::MAX, 1], &[100]),
+            Err(IcmError::ChipOverflow)
+        );
+        assert_eq!(icm(&[u64::MAX, 0], &[100]).unwrap(), vec![100.0, 0.0]);
+    }
+
+    #[test]
+    fn test_too_many_players() {
+        let stacks = [1_000; 65];
+        assert_eq!(icm(&stacks, &[100]), Err(IcmError::TooManyPlayers(65)));
+        assert_eq!(
+            icm_delta(&stacks, &stacks, &[100]),
+            Err(IcmError::TooManyPlayers(65))
+        );
+        assert_eq!(icm(&stacks[..64], &[]).unwrap().len(), 64);
+    }
+
+    #[test]
+    fn test_too_many_states() {
+        let stacks = [1_000; 64];
+        assert_eq!(icm(&stacks, &[50, 30, 20]).unwrap().len(), 64);
+        assert_eq!(
+            icm(&stacks, &[50, 30, 20, 10, 5]),
+            Err(IcmError::TooManyStates {
+                players: 64,
+                places: 5
+            })
+        );
+        // Unpaid places are not tracked.
+        let mut payouts = vec![0; 64];
+        payouts[0] = 100;
+        assert_close(&icm(&stacks, &payouts).unwrap(), &[100.0 / 64.0; 64]);
+
+        assert_eq!(
+            icm(&[1_000; 25], &[1; 25]),
+            Err(IcmError::TooManyStates {
+                players: 25,
+                places: 25
+            })
+        );
+        assert_close(&icm(&[1_000; 12], &[1; 12]).unwrap(), &[1.0; 12]);
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        assert_eq!(
+            icm_delta(&[5_000, 5_000], &[10_000], &[65, 35]),
+            Err(IcmError::LengthMismatch {
+                before: 2,
+                after: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_icm_delta() {
+        let delta = icm_delta(&[5_000, 5_000], &[10_000, 0], &[65, 35]).unwrap();
+        assert_close(&delta, &[15.0, -15.0]);
+    }
+}
@@ -0,0 +1,19 @@
+mod audit;
+mod fault;
+mod icm;
+mod odds;
+mod report;
+mod simulate;
+mod table;
+mod video_poker;
+
+pub use crate::board::Street;
+pub use audit::{audit, audit_exposed, audit_with, AuditError, DeckKind};
+pub use fault::{Fault, FaultInjector};
+pub use icm::{icm, icm_delta, IcmError, MAX_ICM_STATES};
+pub use odds::{ev_call, ev_shove, pot_odds, required_equity};
+pub use report::{report, HandReport, PlayerReport};
+pub use simulate::{simulate, HandRecord, Statistics};
+pub use table::{Table, TableError};
+pub(crate) use video_poker::payout_of_score;
+pub use video_poker::{video_poker_payout, PayTable};
@@ -0,0 +1,182 @@
+use crate::equity::Equity;
+
+/// Returns the pot odds offered to a player facing a bet, as the ratio of the
+/// pot to the amount to call.
+///
+/// A pot of 300 with 100 to call offers odds of 3.0, i.e. 3 to 1.
+///
+/// # Arguments
+///
+/// * `pot` - The chips in the pot, including the bet to be called.
+/// * `to_call` - The chips needed to call.
+///
+/// # Returns
+///
+/// * `f64` - The pot odds, or `f64::INFINITY` if there is nothing to call.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::game::pot_odds;
+///
+/// assert_eq!(pot_odds(300, 100), 3.0);
+/// assert_eq!(pot_odds(300, 0), f64::INFINITY);
+/// ```
+pub fn pot_odds(pot: u64, to_call: u64) -> f64 {
+    if to_call == 0 {
+        return f64::INFINITY;
+    }
+    pot as f64 / to_call as f64
+}
+
+/// Returns the equity needed for a call to break even.
+///
+/// # Arguments
+///
+/// * `pot` - The chips in the pot, including the bet to be called.
+/// * `to_call` - The chips needed to call.
+///
+/// # Returns
+///
+/// * `f64` - The share of the final pot the caller puts in, between 0.0 and
+///   1.0. It is 0.0 if there is nothing to call.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::game::required_equity;
+///
+/// assert_eq!(required_equity(300, 100), 0.25);
+/// assert_eq!(required_equity(300, 0), 0.0);
+/// ```
+pub fn required_equity(pot: u64, to_call: u64) -> f64 {
+    if to_call == 0 {
+        return 0.0;
+    }
+    // Summing as floats cannot overflow, unlike the chip counts.
+    to_call as f64 / (pot as f64 + to_call as f64)
+}
+
+/// Returns the expected value of calling a bet, relative to folding.
+///
+/// Split pots are credited with the caller's share of the pot, as given by
+/// `Equity::equity`.
+///
+/// # Arguments
+///
+/// * `equity` - The equity of the caller's hand.
+/// * `pot` - The chips in the pot, including the bet to be called.
+/// * `to_call` - The chips needed to call.
+///
+/// # Returns
+///
+/// * `f64` - The expected number of chips won by calling.
+pub fn ev_call(equity: Equity, pot: u64, to_call: u64) -> f64 {
+    equity.equity() * (pot as f64 + to_call as f64) - to_call as f64
+}
+
+/// Returns the expected value of going all-in, relative to folding.
+///
+/// The opponent either folds and the pot is won right away, or calls the full
+/// amount and the hand goes to showdown.
+///
+/// # Arguments
+///
+/// * `equity` - The equity of the shoving hand when called.
+/// * `pot` - The chips in the pot before the shove.
+/// * `shove` - The chips put in with the shove.
+/// * `fold_equity` - The probability that the opponent folds, between 0.0
+///   and 1.0.
+///
+/// # Returns
+///
+/// * `f64` - The expected number of chips won by shoving.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::equity::Equity;
+/// use pkr::game::ev_shove;
+///
+/// let mut equity = Equity::new();
+/// equity.record_loss();
+///
+/// // Without showdown equity, folding half the time only breaks even.
+/// assert_eq!(ev_shove(equity, 100, 100, 0.5), 0.0);
+/// ```
+pub fn ev_shove(equity: Equity, pot: u64, shove: u64, fold_equity: f64) -> f64 {
+    let called = equity.equity() * (pot as f64 + 2.0 * shove as f64) - shove as f64;
+    fold_equity * pot as f64 + (1.0 - fold_equity) * called
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn equity(wins: u64, ties: u64, losses: u64, tie_players: usize) -> Equity {
+        let mut equity = Equity::new();
+        for _ in 0..wins {
+            equity.record_win();
+        }
+        for _ in 0..ties {
+            equity.record_tie(tie_players);
+        }
+        for _ in 0..losses {
+            equity.record_loss();
+        }
+        equity
+    }
+
+    #[test]
+    fn test_pot_odds() {
+        assert_eq!(pot_odds(150, 50), 3.0);
+        assert_eq!(required_equity(150, 50), 0.25);
+        assert_eq!(pot_odds(0, 50), 0.0);
+        assert_eq!(required_equity(0, 50), 1.0);
+    }
+
+    #[test]
+    fn test_huge_stacks() {
+        assert_eq!(required_equity(u64::MAX, u64::MAX), 0.5);
+        assert_eq!(
+            ev_call(equity(1, 0, 0, 2), u64::MAX, u64::MAX),
+            u64::MAX as f64
+        );
+        assert_eq!(
+            ev_shove(equity(0, 0, 1, 2), u64::MAX, u64::MAX, 1.0),
+            u64::MAX as f64
+        );
+    }
+
+    #[test]
+    fn test_ev_call() {
+        // A flush draw with 9 outs on the river calls 50 into 150.
+        let draw = equity(9, 0, 37, 2);
+        let ev = ev_call(draw, 150, 50);
+        assert!((ev - (9.0 / 46.0 * 200.0 - 50.0)).abs() < 1e-9);
+        assert!(ev < 0.0);
+
+        assert_eq!(ev_call(equity(1, 0, 0, 2), 150, 0), 150.0);
+        assert_eq!(ev_call(Equity::new(), 150, 50), -50.0);
+    }
+
+    #[test]
+    fn test_ev_call_with_ties() {
+        // The board plays most of the time, so ignoring the split pots would
+        // value the call at 0.05 * 200 - 100 = -90.
+        let chop = equity(5, 90, 5, 2);
+        assert!(ev_call(chop, 100, 100).abs() < 1e-9);
+
+        // A three-way split only returns a third of the pot.
+        let chop = equity(0, 1, 0, 3);
+        assert!(ev_call(chop, 200, 100).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ev_shove() {
+        let coin_flip = equity(1, 0, 1, 2);
+        assert_eq!(ev_shove(coin_flip, 100, 500, 0.0), 50.0);
+        assert_eq!(ev_shove(coin_flip, 100, 500, 1.0), 100.0);
+        assert_eq!(ev_shove(coin_flip, 100, 500, 0.5), 75.0);
+    }
+}
@@ -0,0 +1,311 @@
+use std::fmt;
+
+use crate::analysis::{made_hand_usage, EquityCurve, HandUsage};
+use crate::board::{Board, Street};
+use crate::card::{Card, Rank};
+use crate::equity::{equity_exact, EquityError};
+use crate::hand::{HandRank, HandValue, HoleCards};
+use crate::showdown::{split_pot, OddChipRule, PlayerId};
+
+use super::HandRecord;
+
+/// The summary of a finished hand, see `report`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HandReport {
+    /// The board on the river.
+    pub board: Board,
+    /// The pot that was played for.
+    pub pot: u64,
+    /// The summary of every player, indexed by seat.
+    pub players: Vec<PlayerReport>,
+    /// The players holding the best hand.
+    pub winners: Vec<PlayerId>,
+    /// The chips every winner receives, with odd chips going to the winners
+    /// in seat order.
+    pub payouts: Vec<(PlayerId, u64)>,
+}
+
+/// The summary of a single player of a `HandReport`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PlayerReport {
+    pub id: PlayerId,
+    pub hole_cards: [Card; 2],
+    /// The best five cards on the river, from the highest rank down.
+    pub best_five: Vec<Card>,
+    /// The description of the final hand, e.g. "Two Pair, Nines and Fours".
+    pub description: String,
+    /// The earliest street on which the player held the final hand, i.e.
+    /// its category made of the same ranks. Kickers are not compared.
+    pub made_on: Street,
+    /// The exact equity of the player against all others on every street.
+    pub equity: EquityCurve,
+}
+
+/// Summarizes a recorded hand: the final hand of every player, the
+/// winners and their share of the pot, and how the equities developed from
+/// street to street.
+///
+/// The equities are enumerated exactly on every street, which takes a while
+/// preflop without optimizations.
+///
+/// # Arguments
+///
+/// * `record` - The hole cards of every player and the board on the river.
+/// * `pot` - The pot that was played for.
+///
+/// # Examples
+///
+/// ```no_run
+/// use pkr::board::{Board, Street};
+/// use pkr::game::{report, HandRecord};
+/// use pkr::hand::HoleCards;
+/// use pkr::showdown::PlayerId;
+///
+/// let hole_cards = ["AhAd", "KsKc"].map(|s| HoleCards::new_from_str(s).unwrap().get_cards());
+/// let record = HandRecord {
+///     hole_cards: hole_cards.to_vec(),
+///     board: Board::new_from_str("7c 8d 2h 4s Kh").unwrap(),
+///     winners: vec![PlayerId(1)],
+///     values: Vec::new(),
+/// };
+///
+/// let report = report(&record, 200).unwrap();
+/// assert_eq!(report.payouts, vec![(PlayerId(1), 200)]);
+/// assert_eq!(report.players[1].made_on, Street::River);
+/// println!("{}", report);
+/// ```
+///
+/// # Errors
+///
+/// Returns `EquityError::NoHands` if there are no players and
+/// `EquityError::DuplicateCard` if a card appears more than once among the
+/// hole cards and the board.
+///
+/// # Panics
+///
+/// Panics if the board is not complete.
+pub fn report(record: &HandRecord, pot: u64) -> Result<HandReport, EquityError> {
+    let full_board: [Card; 5] = record
+        .board
+        .cards()
+        .try_into()
+        .expect("The board of a recorded hand must be complete");
+    let hands = record
+        .hole_cards
+        .iter()
+        .map(|&[first, second]| {
+            HoleCards::new(first, second).map_err(|_| EquityError::DuplicateCard(first))
+        })
+        .collect::<Result<Vec<HoleCards>, _>>()?;
+
+    let boards: Vec<Board> = [0, 3, 4, 5]
+        .iter()
+        .map(|&n| Board::try_from(&full_board[..n]).expect("The board has distinct cards"))
+        .collect();
+    // The river is enumerated first, so duplicate cards fail without
+    // enumerating the earlier streets.
+    let mut equities = vec![Vec::new(); 4];
+    for street in (0..4).rev() {
+        equities[street] = equity_exact(&hands, &boards[street])?;
+    }
+
+    let usages: Vec<HandUsage> = hands
+        .iter()
+        .map(|hole| made_hand_usage(hole, &record.board))
+        .collect();
+    let players: Vec<PlayerReport> = hands
+        .iter()
+        .zip(usages.iter())
+        .enumerate()
+        .map(|(seat, (hole, usage))| {
+            let value = HandValue::new(usage.score);
+            let made = made_ranks(&value);
+            let made_on = [Street::Preflop, Street::Flop, Street::Turn, Street::River]
+                .into_iter()
+                .zip(&boards)
+                .find(|(_, board)| {
+                    made_ranks(&HandValue::new(made_hand_usage(hole, board).score)) == made
+                })
+                .map_or(Street::River, |(street, _)| street);
+            PlayerReport {
+                id: PlayerId(seat),
+                hole_cards: hole.get_cards(),
+                best_five: usage.best_five.clone(),
+                description: value.describe(),
+                made_on,
+                equity: EquityCurve {
+                    preflop: equities[0][seat].equity(),
+                    flop: equities[1][seat].equity(),
+                    turn: equities[2][seat].equity(),
+                    river: equities[3][seat].equity(),
+                },
+            }
+        })
+        .collect();
+
+    let best = usages.iter().map(|usage| usage.score).max();
+    let winners: Vec<PlayerId> = usages
+        .iter()
+        .enumerate()
+        .filter(|(_, usage)| Some(usage.score) == best)
+        .map(|(seat, _)| PlayerId(seat))
+        .collect();
+    let payouts = split_pot(pot, &winners, OddChipRule::OneEach);
+
+    Ok(HandReport {
+        board: record.board,
+        pot,
+        players,
+        winners,
+        payouts,
+    })
+}
+
+/// Returns the category of the value with the ranks that make it, leaving
+/// out the kickers, e.g. the ranks of both pairs of two pair.
+fn made_ranks(value: &HandValue) -> (HandRank, Vec<Rank>) {
+    let category = value.get_hand_rank();
+    let made = match category {
+        HandRank::TwoPair | HandRank::FullHouse => 2,
+        HandRank::Flush => 5,
+        _ => 1,
+    };
+    let mut ranks = value.get_ranks();
+    ranks.truncate(made);
+    (category, ranks)
+}
+
+impl fmt::Display for HandReport {
+    /// Formats one line for the board, one per player and one per winner,
+    /// e.g.
+    ///
+    /// ```text
+    /// Board: 9h 2c 7d | Kh | 3h
+    /// Seat 0: AhAd, One Pair, Aces (made preflop) -~__ 61.0% 68.5% 4.8% 0.0%
+    /// Seat 1: KsKc, Three of a Kind, Kings (made on the turn) ._~_ 17.8% 7.3% 73.8% 0.0%
+    /// Seat 2: 6h5h, Flush, King high (made on the river) ...^ 21.2% 24.1% 21.4% 100.0%
+    /// Seat 2 wins 301
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Board: {}", self.board)?;
+        for player in &self.players {
+            let [first, second] = player.hole_cards;
+            let made_on = match player.made_on {
+                Street::Preflop => "made preflop",
+                Street::Flop => "made on the flop",
+                Street::Turn => "made on the turn",
+                Street::River => "made on the river",
+            };
+            writeln!(
+                f,
+                "Seat {}: {}{}, {} ({}) {}",
+                player.id.0,
+                first.as_str(),
+                second.as_str(),
+                player.description,
+                made_on,
+                player.equity
+            )?;
+        }
+        for (i, (PlayerId(seat), chips)) in self.payouts.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "Seat {} wins {}", seat, chips)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn three_way_record() -> HandRecord {
+        HandRecord {
+            hole_cards: ["AhAd", "KsKc", "6h5h"]
+                .iter()
+                .map(|s| HoleCards::new_from_str(s).unwrap().get_cards())
+                .collect(),
+            board: Board::new_from_str("9h 2c 7d Kh 3h").unwrap(),
+            winners: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_three_way_report() {
+        let report = report(&three_way_record(), 301).unwrap();
+        assert_eq!(report.winners, vec![PlayerId(2)]);
+        assert_eq!(report.payouts, vec![(PlayerId(2), 301)]);
+
+        let descriptions: Vec<&str> = report
+            .players
+            .iter()
+            .map(|player| player.description.as_str())
+            .collect();
+        assert_eq!(
+            descriptions,
+            [
+                "One Pair, Aces",
+                "Three of a Kind, Kings",
+                "Flush, King high"
+            ]
+        );
+        let made_on: Vec<Street> = report.players.iter().map(|player| player.made_on).collect();
+        assert_eq!(made_on, [Street::Preflop, Street::Turn, Street::River]);
+        let best_five: Vec<String> = report.players[2]
+            .best_five
+            .iter()
+            .map(Card::as_str)
+            .collect();
+        assert_eq!(best_five, ["Kh", "9h", "6h", "5h", "3h"]);
+
+        for street in 0..4 {
+            let total: f64 = report
+                .players
+                .iter()
+                .map(|player| player.equity.values()[street])
+                .sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+        // Of the 42 unseen river cards the aces win with the two other aces,
+        // the six five with the six hearts that do not fill up the kings and
+        // the three other eights.
+        assert!((report.players[0].equity.turn - 2.0 / 42.0).abs() < 1e-12);
+        assert!((report.players[2].equity.turn - 9.0 / 42.0).abs() < 1e-12);
+        assert_eq!(report.players[2].equity.river, 1.0);
+
+        assert_eq!(report.to_string().lines().last(), Some("Seat 2 wins 301"));
+    }
+
+    #[test]
+    fn test_made_on_compares_made_ranks() {
+        // Two pair from the flop on, but the final two pair of kings and
+        // nines only on the river.
+        let record = HandRecord {
+            hole_cards: ["2s2d", "AsQs"]
+                .iter()
+                .map(|s| HoleCards::new_from_str(s).unwrap().get_cards())
+                .collect(),
+            board: Board::new_from_str("9h 9c 4d Kh Kd").unwrap(),
+            winners: Vec::new(),
+            values: Vec::new(),
+        };
+        let report = report(&record, 100).unwrap();
+        assert_eq!(report.players[0].description, "Two Pair, Kings and Nines");
+        assert_eq!(report.players[0].made_on, Street::River);
+    }
+
+    #[test]
+    fn test_duplicate_card() {
+        let mut record = three_way_record();
+        record.hole_cards[1][0] = record.board.cards()[0];
+        assert_eq!(
+            report(&record, 0),
+            Err(EquityError::DuplicateCard(record.board.cards()[0]))
+        );
+    }
+}
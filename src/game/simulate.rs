@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use strum::IntoEnumIterator;
+
+use crate::board::{Board, Street};
+use crate::card::Card;
+use crate::hand::{HandRank, HandValue};
+use crate::showdown::PlayerId;
+
+use super::{Table, TableError};
+
+/// The complete record of a single simulated hand.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandRecord {
+    /// The hole cards of every player, indexed by seat.
+    pub hole_cards: Vec<[Card; 2]>,
+    /// The board on the river.
+    pub board: Board,
+    /// The players holding the best hand.
+    pub winners: Vec<PlayerId>,
+    /// The final hand of every player, indexed by seat.
+    pub values: Vec<HandValue>,
+}
+
+/// Deals `n_hands` complete hands to a table and passes the record of every
+/// hand to the collector.
+///
+/// The hands are dealt from a random number generator seeded with `seed`, so
+/// the same arguments always produce the same records.
+///
+/// # Arguments
+///
+/// * `n_hands` - The number of hands to deal.
+/// * `players` - The number of players at the table.
+/// * `seed` - The seed of the random number generator.
+/// * `collector` - Called once with the record of every hand.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::game::{simulate, Statistics};
+///
+/// let mut statistics = Statistics::new();
+/// simulate(1_000, 6, 42, |record| statistics.record(record)).unwrap();
+///
+/// assert_eq!(statistics.get_hands(), 1_000);
+/// assert!(statistics.average_winners() >= 1.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns `TableError::InvalidPlayerCount` if the number of players is not
+/// supported by `Table`.
+pub fn simulate(
+    n_hands: usize,
+    players: usize,
+    seed: u64,
+    mut collector: impl FnMut(&HandRecord),
+) -> Result<(), TableError> {
+    let mut table = Table::new(players)?;
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    for _ in 0..n_hands {
+        table.deal(&mut rng);
+        let result = table.showdown();
+
+        let record = HandRecord {
+            hole_cards: result
+                .players
+                .iter()
+                .map(|player| player.hole_cards)
+                .collect(),
+            board: table.get_board(Street::River),
+            winners: result.winners,
+            values: result.players.iter().map(|player| player.value).collect(),
+        };
+        collector(&record);
+    }
+
+    Ok(())
+}
+
+/// Collects statistics over simulated hands.
+///
+/// Pass `Statistics::record` as the collector of `simulate` to count the
+/// categories of the winning hands and of all hands dealt.
+#[derive(Debug, Clone, Default)]
+pub struct Statistics {
+    hands: usize,
+    winners: usize,
+    winning_categories: HashMap<HandRank, usize>,
+    categories: HashMap<HandRank, usize>,
+}
+
+impl Statistics {
+    /// Creates a new `Statistics` without any recorded hand.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a simulated hand to the statistics.
+    pub fn record(&mut self, record: &HandRecord) {
+        self.hands += 1;
+        self.winners += record.winners.len();
+
+        if let Some(PlayerId(seat)) = record.winners.first() {
+            *self
+                .winning_categories
+                .entry(record.values[*seat].get_hand_rank())
+                .or_insert(0) += 1;
+        }
+        for value in &record.values {
+            *self.categories.entry(value.get_hand_rank()).or_insert(0) += 1;
+        }
+    }
+
+    /// Returns the number of recorded hands.
+    pub fn get_hands(&self) -> usize {
+        self.hands
+    }
+
+    /// Returns the average number of players sharing the pot per hand, or 0.0
+    /// if no hand was recorded.
+    pub fn average_winners(&self) -> f64 {
+        if self.hands == 0 {
+            return 0.0;
+        }
+        self.winners as f64 / self.hands as f64
+    }
+
+    /// Returns the fraction of hands won with each category, from
+    /// `HandRank::HighCard` to `HandRank::StraightFlush`.
+    pub fn winning_category_distribution(&self) -> Vec<(HandRank, f64)> {
+        distribution(&self.winning_categories)
+    }
+
+    /// Returns the fraction of all dealt hands, winning or not, falling into
+    /// each category, from `HandRank::HighCard` to `HandRank::StraightFlush`.
+    pub fn category_distribution(&self) -> Vec<(HandRank, f64)> {
+        distribution(&self.categories)
+    }
+}
+
+fn distribution(counts: &HashMap<HandRank, usize>) -> Vec<(HandRank, f64)> {
+    let total: usize = counts.values().sum();
+    HandRank::iter()
+        .map(|hand_rank| {
+            let count = counts.get(&hand_rank).copied().unwrap_or(0);
+            let fraction = if total > 0 {
+                count as f64 / total as f64
+            } else {
+                0.0
+            };
+            (hand_rank, fraction)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records(n_hands: usize, seed: u64) -> Vec<HandRecord> {
+        let mut records = Vec::new();
+        simulate(n_hands, 3, seed, |record| records.push(record.clone())).unwrap();
+        records
+    }
+
+    #[test]
+    fn test_seeded_records_are_stable() {
+        let first = records(5, 42);
+        assert_eq!(first, records(5, 42));
+        assert_ne!(first, records(5, 43));
+
+        assert_eq!(first[0].board.to_string(), "6s 3h 2c | 3d | 9h");
+        for record in &first {
+            assert_eq!(record.hole_cards.len(), 3);
+            assert_eq!(record.values.len(), 3);
+            let best = record.values.iter().max().unwrap();
+            for PlayerId(seat) in &record.winners {
+                assert_eq!(&record.values[*seat], best);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_player_count() {
+        assert_eq!(
+            simulate(1, 1, 0, |_| {}),
+            Err(TableError::InvalidPlayerCount(1))
+        );
+    }
+
+    #[test]
+    fn test_category_distribution() {
+        // The probabilities of each category for a random 7-card hand.
+        let expected = [
+            (HandRank::HighCard, 0.1741),
+            (HandRank::OnePair, 0.4383),
+            (HandRank::TwoPair, 0.2350),
+            (HandRank::ThreeOfAKind, 0.0483),
+            (HandRank::Straight, 0.0462),
+            (HandRank::Flush, 0.0303),
+            (HandRank::FullHouse, 0.0260),
+            (HandRank::FourOfAKind, 0.0017),
+            (HandRank::StraightFlush, 0.0003),
+        ];
+
+        let mut statistics = Statistics::new();
+        simulate(100_000, 2, 7, |record| statistics.record(record)).unwrap();
+        assert_eq!(statistics.get_hands(), 100_000);
+
+        let distribution = statistics.category_distribution();
+        for ((hand_rank, fraction), (expected_rank, expected_fraction)) in
+            distribution.iter().zip(expected.iter())
+        {
+            assert_eq!(hand_rank, expected_rank);
+            assert!(
+                (fraction - expected_fraction).abs() < 0.005,
+                "{:?}: {} vs {}",
+                hand_rank,
+                fraction,
+                expected_fraction
+            );
+        }
+
+        let winning: f64 = statistics
+            .winning_category_distribution()
+            .iter()
+            .map(|(_, fraction)| fraction)
+            .sum();
+        assert!((winning - 1.0).abs() < 1e-9);
+        assert!(statistics.average_winners() > 1.0 && statistics.average_winners() < 1.1);
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt;
+
+use rand::Rng;
+
+use crate::board::{Board, Street};
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::showdown::{showdown, PlayerId, ShowdownResult};
+
+/// The error returned when a table cannot be created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableError {
+    /// The number of players is not between `Table::MIN_PLAYERS` and
+    /// `Table::MAX_PLAYERS`.
+    InvalidPlayerCount(usize),
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TableError::InvalidPlayerCount(found) => write!(
+                f,
+                "A table must have between {} and {} players, got {}.",
+                Table::MIN_PLAYERS,
+                Table::MAX_PLAYERS,
+                found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TableError {}
+
+/// Represents a hold'em table dealing complete hands to a fixed number of
+/// players.
+///
+/// Cards are dealt like at a real table: one card to each player in turn,
+/// then a second card to each player, and a burn card before the flop, the
+/// turn and the river.
+pub struct Table {
+    num_players: usize,
+    deck: Deck,
+    hole_cards: Vec<[Card; 2]>,
+    board: Vec<Card>,
+}
+
+impl Table {
+    // The minimum and maximum number of players at a table.
+    pub const MIN_PLAYERS: usize = 2;
+    pub const MAX_PLAYERS: usize = 10;
+
+    /// Creates a new `Table` for the given number of players.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TableError::InvalidPlayerCount` if the number of players is
+    /// not between `MIN_PLAYERS` and `MAX_PLAYERS`.
+    pub fn new(num_players: usize) -> Result<Self, TableError> {
+        if !(Self::MIN_PLAYERS..=Self::MAX_PLAYERS).contains(&num_players) {
+            return Err(TableError::InvalidPlayerCount(num_players));
+        }
+
+        Ok(Table {
+            num_players,
+            deck: Deck::new(),
+            hole_cards: Vec::new(),
+            board: Vec::new(),
+        })
+    }
+
+    /// Deals a complete hand from a freshly shuffled deck.
+    ///
+    /// Every player receives two hole cards and the full board up to the
+    /// river is dealt. A previous hand is discarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator used to shuffle the deck.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::game::{Street, Table};
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut table = Table::new(6).unwrap();
+    /// table.deal(&mut StdRng::seed_from_u64(7));
+    ///
+    /// assert_eq!(table.get_board(Street::Flop).cards().len(), 3);
+    /// assert_eq!(table.get_board(Street::River).cards().len(), 5);
+    /// assert!(table.get_hole_cards(5).is_some());
+    /// ```
+    pub fn deal<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let mut deck = Deck::new();
+        deck.shuffle_with(rng);
+
+        let mut first_cards = Vec::with_capacity(self.num_players);
+        for _ in 0..self.num_players {
+            first_cards.push(deal_card(&mut deck));
+        }
+        self.hole_cards = first_cards
+            .into_iter()
+            .map(|first| [first, deal_card(&mut deck)])
+            .collect();
+
+        self.board = Vec::with_capacity(5);
+        for street_size in [3, 1, 1] {
+            deal_card(&mut deck);
+            for _ in 0..street_size {
+                self.board.push(deal_card(&mut deck));
+            }
+        }
+
+        self.deck = deck;
+    }
+
+    /// Returns the number of players at the table.
+    pub fn get_num_players(&self) -> usize {
+        self.num_players
+    }
+
+    /// Returns the hole cards of a player, or `None` if no hand was dealt or
+    /// the seat does not exist.
+    pub fn get_hole_cards(&self, seat: usize) -> Option<&[Card; 2]> {
+        self.hole_cards.get(seat)
+    }
+
+    /// Returns the board as seen on the given street.
+    ///
+    /// The board is always preflop before the first deal.
+    pub fn get_board(&self, street: Street) -> Board {
+        let size = match street {
+            Street::Preflop => 0,
+            Street::Flop => 3,
+            Street::Turn => 4,
+            Street::River => 5,
+        };
+        Board::try_from(&self.board[..size.min(self.board.len())])
+            .expect("The dealt board holds distinct cards")
+    }
+
+    /// Returns the deck holding the cards that were not dealt.
+    pub fn get_deck(&self) -> &Deck {
+        &self.deck
+    }
+
+    /// Evaluates the dealt hand at showdown on the river.
+    ///
+    /// Every seat takes part, identified by `PlayerId(seat)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no hand was dealt yet.
+    pub fn showdown(&self) -> ShowdownResult {
+        assert!(!self.hole_cards.is_empty(), "No hand has been dealt yet.");
+
+        let players: Vec<(PlayerId, [Card; 2])> = self
+            .hole_cards
+            .iter()
+            .enumerate()
+            .map(|(seat, hole_cards)| (PlayerId(seat), *hole_cards))
+            .collect();
+        showdown(&self.board, &players).expect("A dealt hand is a valid showdown")
+    }
+}
+
+fn deal_card(deck: &mut Deck) -> Card {
+    deck.deal()
+        .expect("A deck holds enough cards for a full table")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand::Hand;
+    use crate::showdown::winners;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_new_table() {
+        assert_eq!(Table::new(1).err(), Some(TableError::InvalidPlayerCount(1)));
+        assert_eq!(
+            Table::new(11).err(),
+            Some(TableError::InvalidPlayerCount(11))
+        );
+        assert!(Table::new(2).is_ok());
+        assert!(Table::new(10).is_ok());
+    }
+
+    #[test]
+    fn test_deal_without_duplicates() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut table = Table::new(9).unwrap();
+
+        for _ in 0..100 {
+            table.deal(&mut rng);
+
+            let mut dealt: Vec<Card> = table.get_board(Street::River).cards().to_vec();
+            for seat in 0..9 {
+                dealt.extend_from_slice(table.get_hole_cards(seat).unwrap());
+            }
+            dealt.extend_from_slice(table.get_deck().get_cards());
+
+            let mut unique = dealt.clone();
+            unique.sort();
+            unique.dedup();
+            assert_eq!(unique.len(), dealt.len());
+        }
+    }
+
+    #[test]
+    fn test_deal_consumes_expected_cards() {
+        // 18 hole cards, 5 board cards and 3 burn cards.
+        let mut table = Table::new(9).unwrap();
+        table.deal(&mut StdRng::seed_from_u64(2));
+        assert_eq!(table.get_deck().get_cards().len(), 52 - 26);
+
+        assert!(table.get_hole_cards(8).is_some());
+        assert!(table.get_hole_cards(9).is_none());
+        assert_eq!(table.get_board(Street::Preflop), Board::Preflop);
+        assert_eq!(table.get_board(Street::Turn).cards().len(), 4);
+    }
+
+    #[test]
+    fn test_showdown_matches_manual_evaluation() {
+        let mut table = Table::new(4).unwrap();
+        table.deal(&mut StdRng::seed_from_u64(3));
+        let result = table.showdown();
+
+        let hands: Vec<Hand> = (0..4)
+            .map(|seat| {
+                let mut cards = table.get_hole_cards(seat).unwrap().to_vec();
+                cards.extend_from_slice(table.get_board(Street::River).cards());
+                Hand::new(cards).unwrap()
+            })
+            .collect();
+        let expected: Vec<PlayerId> = winners(&hands).into_iter().map(PlayerId).collect();
+
+        assert_eq!(result.winners, expected);
+        for (seat, player) in result.players.iter().enumerate() {
+            assert_eq!(player.value, hands[seat].get_value());
+        }
+    }
+}
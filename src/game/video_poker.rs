@@ -0,0 +1,133 @@
+use crate::card::Rank;
+use crate::hand::{Hand, HandRank};
+
+/// The payouts of a video poker machine per coin bet.
+///
+/// A hand pays according to its category, a royal flush is paid separately
+/// from other straight flushes and a single pair only pays if it is at least
+/// of the rank `min_pair`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PayTable {
+    /// The coins paid per coin bet for a royal flush.
+    pub royal_flush: u32,
+    /// The coins paid per coin bet for a straight flush other than a royal
+    /// flush.
+    pub straight_flush: u32,
+    /// The coins paid per coin bet for four of a kind.
+    pub four_of_a_kind: u32,
+    /// The coins paid per coin bet for a full house.
+    pub full_house: u32,
+    /// The coins paid per coin bet for a flush.
+    pub flush: u32,
+    /// The coins paid per coin bet for a straight.
+    pub straight: u32,
+    /// The coins paid per coin bet for three of a kind.
+    pub three_of_a_kind: u32,
+    /// The coins paid per coin bet for two pair.
+    pub two_pair: u32,
+    /// The coins paid per coin bet for a pair of at least `min_pair`. A
+    /// payout of 1 returns the bet.
+    pub pair: u32,
+    /// The lowest rank of a pair that pays, e.g. `Rank::Jack` for Jacks or
+    /// Better. Lower pairs pay nothing.
+    pub min_pair: Rank,
+}
+
+impl PayTable {
+    /// The "full pay" 9/6 Jacks or Better table, paying 9 for a full house
+    /// and 6 for a flush.
+    pub const JACKS_OR_BETTER_9_6: PayTable = PayTable {
+        royal_flush: 250,
+        straight_flush: 50,
+        four_of_a_kind: 25,
+        full_house: 9,
+        flush: 6,
+        straight: 4,
+        three_of_a_kind: 3,
+        two_pair: 2,
+        pair: 1,
+        min_pair: Rank::Jack,
+    };
+}
+
+/// Returns the payout per coin of a video poker hand.
+///
+/// # Arguments
+///
+/// * `hand` - The final hand, usually of five cards.
+/// * `table` - The pay table of the machine.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::game::{video_poker_payout, PayTable};
+/// use pkr::hand::Hand;
+///
+/// let table = PayTable::JACKS_OR_BETTER_9_6;
+/// let payout = |s| video_poker_payout(&Hand::new_from_str(s).unwrap(), &table);
+/// assert_eq!(payout("Ks Kh 7c 7d Kd"), 9);
+/// assert_eq!(payout("Ts Th 7c 4d 2s"), 0);
+/// ```
+pub fn video_poker_payout(hand: &Hand, table: &PayTable) -> u32 {
+    payout_of_score(hand.get_score(), table)
+}
+
+/// Returns the payout per coin of a video poker hand with the given score.
+pub(crate) fn payout_of_score(score: u32, table: &PayTable) -> u32 {
+    // The first rank of a score is the rank of the straight flush or pair.
+    let mut first_rank = score % 1_000_000;
+    while first_rank > 0xF {
+        first_rank >>= 4;
+    }
+    match HandRank::from_score(score) {
+        HandRank::StraightFlush if first_rank == Rank::Ace as u32 => table.royal_flush,
+        HandRank::StraightFlush => table.straight_flush,
+        HandRank::FourOfAKind => table.four_of_a_kind,
+        HandRank::FullHouse => table.full_house,
+        HandRank::Flush => table.flush,
+        HandRank::Straight => table.straight,
+        HandRank::ThreeOfAKind => table.three_of_a_kind,
+        HandRank::TwoPair => table.two_pair,
+        HandRank::OnePair if first_rank >= table.min_pair as u32 => table.pair,
+        HandRank::OnePair | HandRank::HighCard => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jacks_or_better_9_6() {
+        let table = PayTable::JACKS_OR_BETTER_9_6;
+        for (hand, payout) in [
+            ("As Ks Qs Js Ts", 250),
+            ("9h 8h 7h 6h 5h", 50),
+            ("5d 4d 3d 2d Ad", 50),
+            ("7s 7h 7c 7d 2s", 25),
+            ("Ks Kh 7c 7d Kd", 9),
+            ("Ac 9c 7c 4c 2c", 6),
+            ("Ac Kd Qh Js Tc", 4),
+            ("5d 4h 3c 2s Ad", 4),
+            ("3s 3h 3c Ad Ks", 3),
+            ("3s 3h 2c 2d Ks", 2),
+            ("Js Jh 7c 4d 2s", 1),
+            ("As Ah 7c 4d 2s", 1),
+            ("Ts Th 7c 4d 2s", 0),
+            ("As Kh 7c 4d 2s", 0),
+        ] {
+            let hand = Hand::new_from_str(hand).unwrap();
+            assert_eq!(video_poker_payout(&hand, &table), payout, "{}", hand);
+        }
+    }
+
+    #[test]
+    fn test_custom_table() {
+        let table = PayTable {
+            min_pair: Rank::Queen,
+            ..PayTable::JACKS_OR_BETTER_9_6
+        };
+        let jacks = Hand::new_from_str("Js Jh 7c 4d 2s").unwrap();
+        assert_eq!(video_poker_payout(&jacks, &table), 0);
+    }
+}
@@ -0,0 +1,206 @@
+use alloc::vec::Vec;
+
+use crate::card::Card;
+
+use super::hand::parse_cards;
+use super::{Hand, HandError};
+
+/// Builds a hand from cards that arrive one at a time, e.g. from a parser or
+/// a dealing loop.
+///
+/// Every card is checked against the cards already added, but the number of
+/// cards is only checked by `build`, so a builder may hold fewer than
+/// `Hand::MIN_CARDS` cards while it is filled.
+///
+/// The chaining methods `card` and `cards` keep the first duplicate card
+/// and ignore every card after it, `build` then returns the duplicate. Use
+/// `push` to be notified about a duplicate immediately.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Card;
+/// use pkr::hand::{HandBuilder, HandError};
+///
+/// let ace = Card::new_from_str("As").unwrap();
+/// let hand = HandBuilder::new()
+///     .card(ace)
+///     .parse("Ks Qs")?
+///     .build()?;
+/// assert_eq!(hand.as_str(), "As Ks Qs");
+///
+/// let duplicate = HandBuilder::new().card(ace).card(ace).build();
+/// assert_eq!(duplicate.err(), Some(HandError::DuplicateCard(ace)));
+/// # Ok::<(), HandError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HandBuilder {
+    cards: Vec<Card>,
+    error: Option<HandError>,
+}
+
+impl HandBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a card.
+    pub fn card(mut self, card: Card) -> Self {
+        if self.error.is_none() {
+            self.error = self.push(card).err();
+        }
+        self
+    }
+
+    /// Adds the cards in the given order.
+    pub fn cards(self, cards: &[Card]) -> Self {
+        cards.iter().fold(self, |builder, &card| builder.card(card))
+    }
+
+    /// Adds the cards of a string in the notation of `Hand::new_from_str`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the duplicate card kept by an earlier call of `card` or
+    /// `cards`, a `HandError::InvalidCard` with the position of the first
+    /// invalid card in `s` and a `HandError::DuplicateCard` if a card of `s`
+    /// was already added.
+    pub fn parse(mut self, s: &str) -> Result<Self, HandError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        for card in parse_cards(s)? {
+            self.push(card)?;
+        }
+        Ok(self)
+    }
+
+    /// Adds a card, failing immediately if it was already added.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::DuplicateCard` if the card was already added.
+    pub fn push(&mut self, card: Card) -> Result<(), HandError> {
+        if self.cards.contains(&card) {
+            return Err(HandError::DuplicateCard(card));
+        }
+        self.cards.push(card);
+        Ok(())
+    }
+
+    /// Returns the number of cards added so far.
+    pub fn get_count(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Builds the hand.
+    ///
+    /// # Errors
+    ///
+    /// Returns the duplicate card kept by `card` or `cards` and a
+    /// `HandError::InvalidCardCount` if the builder does not hold between
+    /// `Hand::MIN_CARDS` and `Hand::MAX_CARDS` cards.
+    pub fn build(self) -> Result<Hand, HandError> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Hand::new(self.cards),
+        }
+    }
+
+    /// Builds a hand of exactly `n` cards.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `build` and a `HandError::InvalidCardCount`
+    /// if the builder does not hold exactly `n` cards.
+    pub fn build_exact(self, n: usize) -> Result<Hand, HandError> {
+        if self.error.is_none() && self.cards.len() != n {
+            return Err(HandError::InvalidCardCount {
+                min: n,
+                max: n,
+                found: self.cards.len(),
+            });
+        }
+        self.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::CardError;
+
+    fn card(s: &str) -> Card {
+        Card::new_from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_build() {
+        let hand = HandBuilder::new()
+            .card(card("As"))
+            .cards(&[card("Ks"), card("Qs")])
+            .parse("Js, 10s")
+            .unwrap()
+            .card(card("2c"))
+            .build()
+            .unwrap();
+        assert_eq!(hand.as_str(), "As Ks Qs Js Ts 2c");
+
+        let builder = HandBuilder::new().card(card("As"));
+        assert_eq!(builder.get_count(), 1);
+        assert_eq!(
+            builder.build().err(),
+            Some(HandError::InvalidCardCount {
+                min: Hand::MIN_CARDS,
+                max: Hand::MAX_CARDS,
+                found: 1
+            })
+        );
+
+        let builder = HandBuilder::new().parse("As Ks Qs").unwrap();
+        assert_eq!(
+            builder.clone().build_exact(2).err(),
+            Some(HandError::InvalidCardCount {
+                min: 2,
+                max: 2,
+                found: 3
+            })
+        );
+        assert_eq!(builder.build_exact(3).unwrap().get_count(), 3);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let mut builder = HandBuilder::new().parse("As Ks").unwrap();
+        assert_eq!(
+            builder.push(card("Ks")),
+            Err(HandError::DuplicateCard(card("Ks")))
+        );
+        assert_eq!(builder.get_count(), 2);
+        assert_eq!(
+            builder.clone().parse("Qs As").err(),
+            Some(HandError::DuplicateCard(card("As")))
+        );
+
+        let builder = builder.cards(&[card("Qs"), card("As"), card("Ks"), card("Js")]);
+        assert_eq!(builder.get_count(), 3);
+        assert_eq!(
+            builder.clone().parse("Ts").err(),
+            Some(HandError::DuplicateCard(card("As")))
+        );
+        assert_eq!(
+            builder.build_exact(3).err(),
+            Some(HandError::DuplicateCard(card("As")))
+        );
+
+        assert_eq!(
+            HandBuilder::new().parse("As Kx").err(),
+            Some(HandError::InvalidCard {
+                index: 1,
+                token: "Kx".into(),
+                source: CardError::InvalidSuit("x".into()),
+            })
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::card::Card;
+
+/// The canonical form of a hand, identical for all hands that only differ in
+/// the order of their cards or in a relabeling of the suits.
+///
+/// Suits only matter for flushes, which only depend on whether cards share a
+/// suit, so all hands with the same canonical form have the same score. This
+/// makes the canonical form a good key to memoize evaluations and equities.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::hand::Hand;
+///
+/// let hand = Hand::new_from_str("Ah Kh 7d").unwrap();
+/// let other = Hand::new_from_str("7c Ks As").unwrap();
+/// assert_eq!(hand.canonical(), other.canonical());
+/// assert_eq!(hand.canonical().to_string(), "7c Kd Ad");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CanonicalHand {
+    cards: Vec<Card>,
+}
+
+impl CanonicalHand {
+    /// Creates the canonical form of the cards.
+    ///
+    /// The canonical form is the smallest sequence of card bytes, see
+    /// `Card::to_byte`, over all 24 relabelings of the suits, with the cards
+    /// sorted in ascending order.
+    pub(crate) fn new(cards: &[Card]) -> Self {
+        let mut best: Option<Vec<u8>> = None;
+        for permutation in suit_permutations() {
+            let mut bytes: Vec<u8> = cards
+                .iter()
+                .map(|card| card.to_byte() / 4 * 4 + permutation[card.suit.to_index()])
+                .collect();
+            bytes.sort_unstable();
+            if best.as_ref().is_none_or(|best| bytes < *best) {
+                best = Some(bytes);
+            }
+        }
+
+        let cards = best
+            .unwrap_or_default()
+            .into_iter()
+            .map(|byte| Card::from_byte(byte).expect("Relabeled cards are valid"))
+            .collect();
+        Self { cards }
+    }
+
+    /// Returns the cards of the canonical form in ascending order.
+    pub fn as_slice(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Returns a key identifying the canonical form.
+    ///
+    /// The key packs six bits per card, so different canonical forms always
+    /// have different keys. The key is stable across versions of the crate
+    /// and can be used to shard persistent caches.
+    pub fn key(&self) -> u64 {
+        self.cards
+            .iter()
+            .fold(0, |key, card| key << 6 | (card.to_byte() as u64 + 1))
+    }
+}
+
+impl fmt::Display for CanonicalHand {
+    /// Formats the canonical form like a hand, e.g. "7c Kd Ad".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, card) in self.cards.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", card.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns all 24 permutations of the suit indices.
+fn suit_permutations() -> impl Iterator<Item = [u8; 4]> {
+    (0..256u32)
+        .map(|n| [0, 1, 2, 3].map(|i| (n >> (2 * i) & 3) as u8))
+        .filter(|p| (0..4).all(|i| !p[..i].contains(&p[i])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suit_permutations() {
+        let permutations: Vec<[u8; 4]> = suit_permutations().collect();
+        assert_eq!(permutations.len(), 24);
+        assert!(permutations.contains(&[0, 1, 2, 3]));
+        assert!(permutations.contains(&[3, 2, 1, 0]));
+    }
+}
@@ -0,0 +1,12 @@
+//! The detectors the evaluator uses to find the categories of a hand.
+//!
+//! Every detector expects its input in the order stated in its
+//! preconditions, which is checked in debug builds. The `_unsorted`
+//! variants sort and deduplicate their input first, for standalone use.
+
+pub use super::evaluator::{
+    find_flush, find_flush_unsorted, find_four_of_a_kind, find_four_of_a_kind_unsorted,
+    find_full_house, find_full_house_unsorted, find_pair, find_pair_unsorted, find_straight,
+    find_straight_unsorted, find_three_of_a_kind, find_three_of_a_kind_unsorted, find_two_pair,
+    find_two_pair_unsorted,
+};
@@ -0,0 +1,90 @@
+//! The compact binary encoding of a list of cards.
+//!
+//! A list of cards is encoded as one length byte followed by one byte per
+//! card as returned by `Card::to_byte`, so "As Kd" is encoded as
+//! `[2, 51, 45]`. The encoding is stable across versions of the crate.
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::card::Card;
+
+use super::HandError;
+
+/// Encodes the cards as a length byte followed by one byte per card.
+pub(crate) fn encode_cards(cards: &[Card]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(cards.len() + 1);
+    bytes.push(cards.len() as u8);
+    bytes.extend(cards.iter().map(Card::to_byte));
+    bytes
+}
+
+/// Decodes cards encoded by `encode_cards`.
+///
+/// Returns a `HandError::InvalidLength` if the length byte does not match
+/// the number of bytes, a `HandError::InvalidCard` if a byte is not a valid
+/// card and a `HandError::DuplicateCard` if a card appears more than once.
+pub(crate) fn decode_cards(bytes: &[u8]) -> Result<Vec<Card>, HandError> {
+    let (&count, card_bytes) = bytes.split_first().ok_or(HandError::InvalidLength {
+        expected: 1,
+        found: 0,
+    })?;
+    if card_bytes.len() != count as usize {
+        return Err(HandError::InvalidLength {
+            expected: count as usize + 1,
+            found: bytes.len(),
+        });
+    }
+
+    let mut cards: Vec<Card> = Vec::with_capacity(card_bytes.len());
+    for (index, &byte) in card_bytes.iter().enumerate() {
+        let card = Card::from_byte(byte).map_err(|source| HandError::InvalidCard {
+            index,
+            token: byte.to_string(),
+            source,
+        })?;
+        if cards.contains(&card) {
+            return Err(HandError::DuplicateCard(card));
+        }
+        cards.push(card);
+    }
+    Ok(cards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::CardError;
+
+    #[test]
+    fn test_round_trip() {
+        let cards = [
+            Card::new_from_str("As").unwrap(),
+            Card::new_from_str("Kd").unwrap(),
+        ];
+        let bytes = encode_cards(&cards);
+        assert_eq!(bytes, [2, 51, 45]);
+        assert_eq!(decode_cards(&bytes).unwrap(), cards);
+        assert!(decode_cards(&[0]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let length = |expected, found| Err(HandError::InvalidLength { expected, found });
+        assert_eq!(decode_cards(&[]), length(1, 0));
+        assert_eq!(decode_cards(&[2, 51]), length(3, 2));
+        assert_eq!(decode_cards(&[1, 51, 50]), length(2, 3));
+        assert_eq!(
+            decode_cards(&[2, 51, 52]),
+            Err(HandError::InvalidCard {
+                index: 1,
+                token: "52".to_string(),
+                source: CardError::InvalidByte(52),
+            })
+        );
+        assert_eq!(
+            decode_cards(&[2, 51, 51]),
+            Err(HandError::DuplicateCard(Card::new_from_str("As").unwrap()))
+        );
+    }
+}
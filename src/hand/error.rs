@@ -0,0 +1,196 @@
+use alloc::string::String;
+use core::fmt;
+
+use crate::card::{Card, CardError};
+
+/// The error returned when creating or changing a hand fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandError {
+    /// The number of cards is outside of the allowed range.
+    InvalidCardCount {
+        /// The smallest allowed number of cards.
+        min: usize,
+        /// The largest allowed number of cards.
+        max: usize,
+        /// The number of cards found.
+        found: usize,
+    },
+    /// A token of a hand string is not a valid card.
+    InvalidCard {
+        /// The zero-based position of the token.
+        index: usize,
+        /// The offending token.
+        token: String,
+        /// The reason the token is not a valid card.
+        source: CardError,
+    },
+    /// The same card was given more than once.
+    DuplicateCard(Card),
+    /// Adding the cards would exceed `Hand::MAX_CARDS` cards.
+    TooManyCards {
+        /// The number of cards in the hand.
+        count: usize,
+        /// The number of cards that were to be added.
+        adding: usize,
+        /// The largest allowed number of cards.
+        max: usize,
+    },
+    /// Removing the cards would leave fewer than `Hand::MIN_CARDS` cards.
+    TooFewCards {
+        /// The number of cards in the hand.
+        count: usize,
+        /// The number of cards that were to be removed.
+        removing: usize,
+        /// The smallest allowed number of cards.
+        min: usize,
+    },
+    /// The card is not in the hand.
+    CardNotFound(Card),
+    /// The index is not smaller than the number of cards in the hand.
+    IndexOutOfBounds {
+        /// The requested index.
+        index: usize,
+        /// The number of cards in the hand.
+        count: usize,
+    },
+    /// The length of an encoded hand does not match its length byte.
+    InvalidLength {
+        /// The number of bytes announced by the length byte.
+        expected: usize,
+        /// The number of bytes found.
+        found: usize,
+    },
+    /// A bit of a card bit mask above bit 51 is set.
+    InvalidBitmask(u64),
+    /// The id is not the id of a `HandRank`.
+    InvalidHandRankId(u8),
+    /// The id is not the id of a `StartingHand`.
+    InvalidStartingHandId(u8),
+}
+
+impl fmt::Display for HandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HandError::InvalidCardCount { min, max, found } if min == max => {
+                write!(f, "Expected {} {}, got {}.", min, cards(*min), found)
+            }
+            HandError::InvalidCardCount { min, max, found } => write!(
+                f,
+                "A poker hand must have between {} and {} cards, got {}.",
+                min, max, found
+            ),
+            HandError::InvalidCard {
+                index,
+                token,
+                source,
+            } => write!(f, "{} at token {} ({:?})", source, index, token),
+            HandError::DuplicateCard(card) => write!(f, "Duplicate card: {}", card.as_str()),
+            HandError::TooManyCards { count, adding, max } => write!(
+                f,
+                "Adding {} {} to a hand of {} {} would exceed the maximum of {} {}.",
+                adding,
+                cards(*adding),
+                count,
+                cards(*count),
+                max,
+                cards(*max)
+            ),
+            HandError::TooFewCards {
+                count,
+                removing,
+                min,
+            } => write!(
+                f,
+                "Removing {} {} from a hand of {} {} would go below the minimum of {} {}.",
+                removing,
+                cards(*removing),
+                count,
+                cards(*count),
+                min,
+                cards(*min)
+            ),
+            HandError::CardNotFound(card) => {
+                write!(f, "Card not found in the hand: {}", card.as_str())
+            }
+            HandError::IndexOutOfBounds { index, count } => write!(
+                f,
+                "Index {} is out of bounds for a hand of {} {}.",
+                index,
+                count,
+                cards(*count)
+            ),
+            HandError::InvalidLength { expected, found } => {
+                write!(f, "Expected {} bytes, got {}.", expected, found)
+            }
+            HandError::InvalidBitmask(mask) => {
+                write!(f, "Invalid card bit mask: {:#x}", mask)
+            }
+            HandError::InvalidHandRankId(id) => write!(f, "Invalid hand rank id: {}", id),
+            HandError::InvalidStartingHandId(id) => {
+                write!(f, "Invalid starting hand id: {}", id)
+            }
+        }
+    }
+}
+
+/// Returns the noun for a number of cards, "card" for one and "cards"
+/// otherwise.
+fn cards(count: usize) -> &'static str {
+    if count == 1 {
+        "card"
+    } else {
+        "cards"
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HandError::InvalidCard { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The error of a single record of `Hand::parse_many`, telling on which line
+/// of the input it occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The one-based line number of the record.
+    pub line: usize,
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+}
+
+/// The reason a record of `Hand::parse_many` could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The record is not a valid hand.
+    Hand(HandError),
+    /// Reading the line failed, which ends the parsing.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::Hand(e) => write!(f, "Line {}: {}", self.line, e),
+            #[cfg(feature = "std")]
+            ParseErrorKind::Io(kind) => {
+                write!(f, "Line {}: Failed to read the line ({}).", self.line, kind)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::Hand(e) => Some(e),
+            ParseErrorKind::Io(_) => None,
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::card::Card;
+#[cfg(feature = "std")]
+use crate::deck::Deck;
+use crate::encoding::Encoding;
+#[cfg(feature = "std")]
+use crate::hand::Hand;
+use crate::hand::HandValue;
+
+use super::evaluator::evaluate_cards;
+
+/// An algorithm scoring the best hand that can be made from a set of cards.
+///
+/// Every evaluator must return the same scores as `ReferenceEvaluator`, see
+/// `Hand::get_score` for the encoding. Code that is generic over the
+/// evaluator can then switch between the reference algorithm, a faster one
+/// or a custom one for a variant without changing any results.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Card;
+/// use pkr::hand::{Evaluator, FastEvaluator, Hand, ReferenceEvaluator};
+///
+/// let hand = Hand::new_from_str("As Ks Qs Js Ts 2c").unwrap();
+/// assert_eq!(ReferenceEvaluator.score(hand.as_slice()), 8_000_014);
+/// assert_eq!(hand.get_score_with(&FastEvaluator), 8_000_014);
+/// ```
+pub trait Evaluator {
+    /// Returns the score of the best hand made from the cards.
+    ///
+    /// The cards must be distinct and there must be between
+    /// `Hand::MIN_CARDS` and `Hand::MAX_CARDS` of them, which evaluators do
+    /// not need to check.
+    fn score(&self, cards: &[Card]) -> u32;
+
+    /// Returns the score of the best hand made from the cards in the given
+    /// encoding, or `None` if the encoding has no score for the hand, see
+    /// `Encoding::encode`.
+    ///
+    /// `score` returns `Encoding::V1` scores.
+    fn score_in(&self, cards: &[Card], encoding: Encoding) -> Option<u32> {
+        encoding.encode(HandValue::new(self.score(cards)))
+    }
+
+    /// Compares the scores of this evaluator with another one on random
+    /// hands of every supported size.
+    ///
+    /// The same seed always produces the same hands.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The evaluator to compare with.
+    /// * `sample_size` - The number of random hands to compare.
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Disagreement>` - The first hand the evaluators score
+    ///   differently, or `None` if they agree on every hand.
+    #[cfg(feature = "std")]
+    fn verify_against(
+        &self,
+        other: &dyn Evaluator,
+        sample_size: usize,
+        seed: u64,
+    ) -> Option<Disagreement> {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let deck = Deck::new();
+        (0..sample_size).find_map(|_| {
+            let count = rng.gen_range(Hand::MIN_CARDS..=Hand::MAX_CARDS);
+            let cards: Vec<Card> = deck
+                .get_cards()
+                .choose_multiple(&mut rng, count)
+                .copied()
+                .collect();
+            let score = self.score(&cards);
+            let other_score = other.score(&cards);
+            (score != other_score).then_some(Disagreement {
+                cards,
+                score,
+                other_score,
+            })
+        })
+    }
+}
+
+/// The evaluator behind `Hand::get_score`, built from the detectors in
+/// `hand::detectors`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReferenceEvaluator;
+
+impl Evaluator for ReferenceEvaluator {
+    fn score(&self, cards: &[Card]) -> u32 {
+        evaluate_cards(cards)
+    }
+}
+
+/// Cards two evaluators score differently, see `Evaluator::verify_against`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub cards: Vec<Card>,
+    /// The score of the evaluator `verify_against` was called on.
+    pub score: u32,
+    /// The score of the other evaluator.
+    pub other_score: u32,
+}
+
+impl fmt::Display for Disagreement {
+    /// Formats the disagreement, e.g. "As Ks Qs Js Ts scored 8000014 and
+    /// 5974010".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, card) in self.cards.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", card.as_str())?;
+        }
+        write!(f, " scored {} and {}", self.score, self.other_score)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Disagreement {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::hand::evaluator::FastEvaluator;
+    use crate::hand::HandRank;
+
+    /// Scores every flush as a straight flush.
+    struct BrokenEvaluator;
+
+    impl Evaluator for BrokenEvaluator {
+        fn score(&self, cards: &[Card]) -> u32 {
+            let score = evaluate_cards(cards);
+            if HandRank::from_score(score) == HandRank::Flush {
+                score + 3_000_000
+            } else {
+                score
+            }
+        }
+    }
+
+    #[test]
+    fn test_reference_agrees_with_itself() {
+        assert_eq!(
+            ReferenceEvaluator.verify_against(&ReferenceEvaluator, 2000, 1),
+            None
+        );
+        assert_eq!(
+            ReferenceEvaluator.verify_against(&FastEvaluator, 20000, 2),
+            None
+        );
+    }
+
+    #[test]
+    fn test_catches_broken_evaluator() {
+        let disagreement = ReferenceEvaluator
+            .verify_against(&BrokenEvaluator, 20000, 3)
+            .unwrap();
+        assert_eq!(HandRank::from_score(disagreement.score), HandRank::Flush);
+        assert_eq!(disagreement.other_score, disagreement.score + 3_000_000);
+        assert_eq!(
+            disagreement.score,
+            Hand::new(disagreement.cards.clone()).unwrap().get_score()
+        );
+
+        // The same seed finds the same hand.
+        assert_eq!(
+            ReferenceEvaluator.verify_against(&BrokenEvaluator, 20000, 3),
+            Some(disagreement)
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+
+use crate::card::Card;
+use crate::hand::bitmask;
+
+use super::backend::Evaluator;
+use super::fast::FastEvaluator;
+
+/// The number of independently locked parts of an `EvalCache`.
+const SHARDS: usize = 16;
+
+/// A thread-safe cache of the scores of an evaluator, keyed by the set of
+/// cards regardless of their order.
+///
+/// The entries are spread over several shards with their own lock, so
+/// threads sharing one cache behind an `Arc` rarely wait for each other.
+/// Lookups only take a read lock. Once a shard holds its share of
+/// `max_entries` it is cleared before the next score is inserted, which
+/// bounds the memory of long running services.
+///
+/// An `EvalCache` is itself an `Evaluator`, so it can be used wherever the
+/// evaluator it wraps can.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// use pkr::hand::{EvalCache, Evaluator, Hand};
+///
+/// let cache = Arc::new(EvalCache::new(1_000_000));
+/// let hand = Hand::new_from_str("As Ks Qs Js Ts 2c 3d").unwrap();
+///
+/// let workers: Vec<_> = (0..4)
+///     .map(|_| {
+///         let cache = Arc::clone(&cache);
+///         let hand = hand.clone();
+///         thread::spawn(move || cache.score(hand.as_slice()))
+///     })
+///     .collect();
+/// for worker in workers {
+///     assert_eq!(worker.join().unwrap(), 8_000_014);
+/// }
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct EvalCache<E: Evaluator = FastEvaluator> {
+    evaluator: E,
+    shards: [RwLock<HashMap<u64, u32>>; SHARDS],
+    hasher: RandomState,
+    max_entries_per_shard: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl EvalCache {
+    /// Creates an empty cache of the scores of the `FastEvaluator`, holding
+    /// about `max_entries` scores at most.
+    pub fn new(max_entries: usize) -> Self {
+        EvalCache::with_evaluator(FastEvaluator, max_entries)
+    }
+}
+
+impl<E: Evaluator> EvalCache<E> {
+    /// Creates an empty cache of the scores of the given evaluator, holding
+    /// about `max_entries` scores at most.
+    pub fn with_evaluator(evaluator: E, max_entries: usize) -> Self {
+        Self {
+            evaluator,
+            shards: core::array::from_fn(|_| RwLock::new(HashMap::new())),
+            hasher: RandomState::new(),
+            max_entries_per_shard: max_entries.div_ceil(SHARDS).max(1),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the number of cached scores.
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().expect("Cache lock poisoned").len())
+            .sum()
+    }
+
+    /// Returns whether no score is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every cached score, keeping the hit and miss counts.
+    pub fn clear(&self) {
+        for shard in &self.shards {
+            shard.write().expect("Cache lock poisoned").clear();
+        }
+    }
+
+    /// Returns how many scores were found in the cache and how many had to
+    /// be calculated.
+    pub fn stats(&self) -> (u64, u64) {
+        (
+            self.hits.load(Ordering::Relaxed),
+            self.misses.load(Ordering::Relaxed),
+        )
+    }
+}
+
+impl<E: Evaluator> Evaluator for EvalCache<E> {
+    fn score(&self, cards: &[Card]) -> u32 {
+        let key = bitmask(cards);
+        let shard = &self.shards[self.hasher.hash_one(key) as usize % SHARDS];
+
+        if let Some(&score) = shard.read().expect("Cache lock poisoned").get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return score;
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let score = self.evaluator.score(cards);
+        let mut shard = shard.write().expect("Cache lock poisoned");
+        if shard.len() >= self.max_entries_per_shard {
+            shard.clear();
+        }
+        shard.insert(key, score);
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::board::Board;
+    use crate::deck::Deck;
+    use crate::hand::{Hand, HoleCards, ReferenceEvaluator};
+    use crate::range::Range;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_types_are_send_and_sync() {
+        assert_send_sync::<Card>();
+        assert_send_sync::<Hand>();
+        assert_send_sync::<HoleCards>();
+        assert_send_sync::<Board>();
+        assert_send_sync::<Deck>();
+        assert_send_sync::<Range>();
+        assert_send_sync::<FastEvaluator>();
+        assert_send_sync::<ReferenceEvaluator>();
+        assert_send_sync::<EvalCache>();
+        assert_send_sync::<EvalCache<ReferenceEvaluator>>();
+    }
+
+    fn random_hands(count: usize, seed: u64) -> Vec<Vec<Card>> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let deck = Deck::new();
+        (0..count)
+            .map(|_| {
+                let n = rng.gen_range(Hand::MIN_CARDS..=7);
+                deck.get_cards()
+                    .choose_multiple(&mut rng, n)
+                    .copied()
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_order_of_cards_is_ignored() {
+        let cache = EvalCache::with_evaluator(ReferenceEvaluator, 100);
+        let hand = Hand::new_from_str("As Kd 7c 7h 2s").unwrap();
+        let mut reversed = hand.as_slice().to_vec();
+        reversed.reverse();
+        assert_eq!(cache.score(hand.as_slice()), hand.get_score());
+        assert_eq!(cache.score(&reversed), hand.get_score());
+        assert_eq!(cache.stats(), (1, 1));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_size_is_bounded() {
+        let cache = EvalCache::new(64);
+        for cards in random_hands(2000, 1) {
+            assert_eq!(cache.score(&cards), FastEvaluator.score(&cards));
+        }
+        assert!(cache.len() <= 64);
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_shared_between_threads() {
+        let hands = Arc::new(random_hands(5000, 2));
+        let expected: Vec<u32> = hands
+            .iter()
+            .map(|cards| ReferenceEvaluator.score(cards))
+            .collect();
+        let cache = Arc::new(EvalCache::new(100_000));
+
+        let workers: Vec<_> = (0..8)
+            .map(|worker| {
+                let hands = Arc::clone(&hands);
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    // Every worker walks the hands from a different offset
+                    // so the threads contend for the same entries.
+                    let offset = worker * hands.len() / 8;
+                    (0..hands.len())
+                        .map(|i| {
+                            let i = (i + offset) % hands.len();
+                            (i, cache.score(&hands[i]))
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        for worker in workers {
+            for (i, score) in worker.join().unwrap() {
+                assert_eq!(score, expected[i]);
+            }
+        }
+
+        let (hits, misses) = cache.stats();
+        assert_eq!(hits + misses, 8 * 5000);
+        assert!(misses >= cache.len() as u64);
+    }
+}
@@ -0,0 +1,184 @@
+use crate::card::Rank;
+
+use super::score::HandRank;
+
+/// Determines the category of the best hand of the cards from the number of
+/// cards of every rank, indexed by `Rank::to_index`, and whether the cards
+/// hold a flush or a straight.
+///
+/// The sorted counts decide most hands directly, e.g. `[4, 1, 1, 1]` is four
+/// of a kind, `[3, 2, 2]` a full house and `[2, 2, 2, 1]` two pair. A flush
+/// or a straight only counts if the counts make no better hand.
+///
+/// A straight flush cannot be told apart from a straight and a flush of
+/// different cards, so it is never returned. Check the flush ranks for a
+/// straight first.
+///
+/// # Arguments
+///
+/// * `counts` - The number of cards of every rank.
+/// * `flush` - Whether five or more cards share a suit.
+/// * `straight` - The highest card of the best straight, if any.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Rank;
+/// use pkr::hand::{classify_counts, HandRank};
+///
+/// let mut counts = [0; 13];
+/// counts[Rank::King.to_index()] = 3;
+/// counts[Rank::Seven.to_index()] = 2;
+/// counts[Rank::Two.to_index()] = 2;
+/// assert_eq!(classify_counts(&counts, false, None), HandRank::FullHouse);
+///
+/// let counts = [1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+/// assert_eq!(classify_counts(&counts, false, Some(Rank::Five)), HandRank::Straight);
+/// ```
+pub fn classify_counts(counts: &[u8; 13], flush: bool, straight: Option<Rank>) -> HandRank {
+    let mut quads = 0;
+    let mut trips = 0;
+    let mut pairs = 0;
+    for &count in counts {
+        match count {
+            0 | 1 => {}
+            2 => pairs += 1,
+            3 => trips += 1,
+            _ => quads += 1,
+        }
+    }
+
+    if quads > 0 {
+        HandRank::FourOfAKind
+    } else if trips > 0 && trips + pairs > 1 {
+        HandRank::FullHouse
+    } else if flush {
+        HandRank::Flush
+    } else if straight.is_some() {
+        HandRank::Straight
+    } else if trips > 0 {
+        HandRank::ThreeOfAKind
+    } else if pairs > 1 {
+        HandRank::TwoPair
+    } else if pairs == 1 {
+        HandRank::OnePair
+    } else {
+        HandRank::HighCard
+    }
+}
+
+/// Counts the cards of every rank, indexed by `Rank::to_index`.
+pub(super) fn rank_counts(ranks: &[Rank]) -> [u8; 13] {
+    let mut counts = [0; 13];
+    for rank in ranks {
+        counts[rank.to_index()] += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::Card;
+    use crate::combinations::combinations;
+    use crate::deck::Deck;
+    use crate::hand::evaluator::backend::Evaluator;
+    use crate::hand::evaluator::evaluator::evaluate_cards;
+    use crate::hand::evaluator::fast::FastEvaluator;
+    use crate::hand::evaluator::flush::find_flush_unsorted;
+    use crate::hand::evaluator::straight::find_straight_unsorted;
+    use crate::hand::Hand;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    fn classify(s: &str) -> HandRank {
+        let hand = Hand::new_from_str(s).unwrap();
+        let ranks: Vec<Rank> = hand.as_slice().iter().map(|card| card.rank).collect();
+        classify_counts(
+            &rank_counts(&ranks),
+            find_flush_unsorted(hand.as_slice()).is_some(),
+            find_straight_unsorted(&ranks),
+        )
+    }
+
+    #[test]
+    fn test_counts() {
+        assert_eq!(classify("7s 7h 7c 7d Ks 2s"), HandRank::FourOfAKind);
+        assert_eq!(classify("7s 7h 7c Kd Ks Kh"), HandRank::FullHouse);
+        assert_eq!(classify("7s 7h 7c Kd Ks 2s 2h"), HandRank::FullHouse);
+        assert_eq!(classify("Ks Qs Ts 8s 6s 6d 6c"), HandRank::Flush);
+        assert_eq!(classify("9s 8h 7c 6d 5s 5h 5c"), HandRank::Straight);
+        assert_eq!(classify("Qs Qh Qc 7d 2s"), HandRank::ThreeOfAKind);
+        assert_eq!(classify("Qs Qh 7c 7d 2s 2h 4c"), HandRank::TwoPair);
+        assert_eq!(classify("Qs Qh 7c 4d 2s"), HandRank::OnePair);
+        assert_eq!(classify("As Qh"), HandRank::HighCard);
+    }
+
+    #[test]
+    fn test_one_trips_and_two_pairs_differ() {
+        // Both have two duplicate cards.
+        assert_eq!(classify("Qs Qh Qc 7d 2s"), HandRank::ThreeOfAKind);
+        assert_eq!(classify("Qs Qh 7c 7d 2s"), HandRank::TwoPair);
+    }
+
+    #[test]
+    fn test_small_hands_against_evaluator() {
+        let deck = Deck::new();
+        for count in 2..=4 {
+            for cards in combinations(deck.get_cards(), count) {
+                let ranks: Vec<Rank> = cards.iter().map(|card| card.rank).collect();
+                assert_eq!(
+                    classify_counts(&rank_counts(&ranks), false, None),
+                    HandRank::from_score(evaluate_cards(&cards))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_five_card_hands() {
+        let deck = Deck::new();
+        for cards in combinations(deck.get_cards(), 5) {
+            let score = FastEvaluator.score(&cards);
+            assert_eq!(evaluate_cards(&cards), score, "{:?}", cards);
+
+            // Five cards of a flush and a straight are a straight flush,
+            // which the counts classify as a flush.
+            let expected = match HandRank::from_score(score) {
+                HandRank::StraightFlush => HandRank::Flush,
+                hand_rank => hand_rank,
+            };
+            let ranks: Vec<Rank> = cards.iter().map(|card| card.rank).collect();
+            assert_eq!(
+                classify_counts(
+                    &rank_counts(&ranks),
+                    find_flush_unsorted(&cards).is_some(),
+                    find_straight_unsorted(&ranks),
+                ),
+                expected,
+                "{:?}",
+                cards
+            );
+        }
+    }
+
+    #[test]
+    fn test_random_seven_card_hands() {
+        let mut rng = StdRng::seed_from_u64(206);
+        let deck = Deck::new();
+        for _ in 0..50_000 {
+            let cards: Vec<Card> = deck
+                .get_cards()
+                .choose_multiple(&mut rng, 7)
+                .copied()
+                .collect();
+            assert_eq!(
+                evaluate_cards(&cards),
+                FastEvaluator.score(&cards),
+                "{:?}",
+                cards
+            );
+        }
+    }
+}
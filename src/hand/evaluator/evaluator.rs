@@ -1,14 +1,24 @@
-use crate::card::Rank;
+use alloc::format;
+use core::cmp::Ordering;
+
+use crate::card::Card;
+use crate::hand::hand::rank_multiplicities;
 use crate::hand::Hand;
 
-use super::flush::find_flush;
-use super::four_of_a_kind::find_four_of_a_kind;
-use super::full_house::find_full_house;
-use super::pair::find_pair;
-use super::score::{calculate_hand_score, HandRank};
+use super::classify::{classify_counts, rank_counts};
+use super::flush::flush_ranks;
+use super::four_of_a_kind::four_of_a_kind_ranks;
+use super::full_house::full_house_ranks;
+use super::pair::pair_ranks;
+use super::rank_list::RankList;
+use super::score::{
+    score_flush, score_full_house, score_high_card, score_pair, score_quads, score_straight,
+    score_straight_flush, score_trips, score_two_pair, HandRank,
+};
 use super::straight::find_straight;
-use super::three_of_a_kind::find_three_of_a_kind;
-use super::two_pair::find_two_pair;
+use super::three_of_a_kind::three_of_a_kind_ranks;
+use super::trace::{describe_rank, describe_ranks, Tracer};
+use super::two_pair::two_pair_ranks;
 
 /// Evaluates a given poker hand and returns its score as a u32.
 ///
@@ -23,94 +33,169 @@ use super::two_pair::find_two_pair;
 /// # Remarks
 ///
 /// This function evaluates the given Hand object based on the rules of Poker.
-/// The hand is evaluated in the following order:
-/// 1. Straight Flush
-/// 2. Four of a Kind
-/// 3. Full House
-/// 4. Flush
-/// 5. Straight
-/// 6. Three of a Kind
-/// 7. Two Pair
-/// 8. One Pair
-/// 9. High Card
-///
-/// The function is designed to early return as soon as a matching hand rank
-/// is found. This minimizes unnecessary computation.
-/// If no match is found for the above hand ranks, the hand is evaluated as a
-/// high card hand.
-///
-/// # Panics
-///
-/// This function may panic in two scenarios:
-/// 1. Failed to sort the hand by rank.
-/// 2. In the case where it's expecting a paired hand (i.e., One Pair,
-/// Two Pair, Three of a Kind), but none is found.
+/// After checking for a straight flush, the number of cards of every rank
+/// decides the category, see `classify_counts`, and only the detector of
+/// that category runs to find the ranks deciding ties.
+///
+/// # Score encoding
+///
+/// The function returns `encoding::V1` scores: the value of the `HandRank`
+/// of the hand, a multiple of 1,000,000, plus the rank values of the cards
+/// deciding ties within the category packed into nibbles, the most
+/// significant first. E.g. two pair of kings and fives with an ace kicker
+/// scores `2_000_000 + 0xD5E`.
+///
+/// The encoding is stable across versions of the crate, so scores can be
+/// persisted and compared with scores of later versions. A
+/// `verify::check_golden` run over an embedded corpus of scored hands
+/// guards it. Other encodings, e.g. the dense `encoding::ClassRank`, are
+/// available through `Evaluator::score_in`, and `encoding::convert` maps
+/// scores between them.
+///
+/// # Monotonicity
+///
+/// Adding a card to a hand never lowers its score. The best hand of the old
+/// cards can still be made, and a hand of fewer than five cards scores below
+/// the same hand with an additional kicker.
 pub fn evaluate(hand: &Hand) -> u32 {
-    let mut hand_desc = hand.clone();
-    hand_desc
-        .sort_by_rank(false)
-        .expect("Failed to sort by rank");
+    evaluate_cards(hand.as_slice())
+}
+
+/// Compares the strength of two hands.
+///
+/// Unlike the `Ord` implementation of `Hand`, which orders hands of equal
+/// strength by their cards, hands with the same score compare as equal.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use pkr::{compare, Hand};
+///
+/// let aces = Hand::new_from_str("As Ad 7c").unwrap();
+/// let kings = Hand::new_from_str("Ks Kd 7d").unwrap();
+/// assert_eq!(compare(&aces, &kings), Ordering::Greater);
+///
+/// let other_aces = Hand::new_from_str("Ah Ac 7h").unwrap();
+/// assert_eq!(compare(&aces, &other_aces), Ordering::Equal);
+/// ```
+pub fn compare(first: &Hand, second: &Hand) -> Ordering {
+    evaluate(first).cmp(&evaluate(second))
+}
+
+/// Evaluates the cards like `evaluate` without requiring a `Hand`.
+///
+/// The cards must be distinct and there must be between `Hand::MIN_CARDS`
+/// and `Hand::MAX_CARDS` of them, which is not checked.
+///
+/// The evaluation does not allocate: the ranks are sorted in a `RankList`
+/// on the stack and the detectors return theirs in one as well.
+pub fn evaluate_cards(cards: &[Card]) -> u32 {
+    evaluate_cards_with(cards, &mut ())
+}
 
+/// Evaluates the cards like `evaluate_cards`, reporting every detector that
+/// was invoked or skipped to the tracer.
+pub(crate) fn evaluate_cards_with<T: Tracer>(cards: &[Card], tracer: &mut T) -> u32 {
     // Check for a flush before a straight flush for performance reasons.
-    let flush_ranks_desc = find_flush(&hand_desc);
+    let flush_ranks_desc = flush_ranks(cards).map(|mut ranks| {
+        ranks.sort_desc();
+        ranks
+    });
+    tracer.ran(
+        "find_flush",
+        || format!("{} cards", cards.len()),
+        || describe_ranks(flush_ranks_desc.as_deref()),
+    );
 
     // If a straight flush is found, calculate and return the score.
     if let Some(flush_ranks) = &flush_ranks_desc {
-        if let Some(straight_flush_rank) = find_straight(&flush_ranks) {
-            return calculate_hand_score(vec![straight_flush_rank], HandRank::StraightFlush);
+        let straight_flush_rank = find_straight(flush_ranks);
+        tracer.ran(
+            "find_straight",
+            || "flush ranks".into(),
+            || describe_rank(straight_flush_rank),
+        );
+        if let Some(straight_flush_rank) = straight_flush_rank {
+            return score_straight_flush(straight_flush_rank);
         }
     }
 
-    let ranks_desc = hand_desc.get_ranks();
-    let mut ranks_desc_no_dup = ranks_desc.clone();
-    ranks_desc_no_dup.dedup();
-    let num_duplicates = ranks_desc.len() - ranks_desc_no_dup.len();
+    let mut ranks_desc: RankList = cards.iter().map(|card| card.rank).collect();
+    ranks_desc.sort_desc();
+    let ranks_input = || format!("{} ranks", ranks_desc.len());
 
-    // Check for four of a kind or full house.
-    if num_duplicates > 2 {
-        if let Some(four_of_a_kind) = find_four_of_a_kind(&ranks_desc) {
-            return calculate_hand_score(four_of_a_kind, HandRank::FourOfAKind);
-        }
-        if let Some(full_house) = find_full_house(&ranks_desc) {
-            return calculate_hand_score(full_house, HandRank::FullHouse);
-        }
-    }
-
-    // Check for a flush.
-    if let Some(ref flush_ranks_desc) = flush_ranks_desc {
-        let flush_ranks = &flush_ranks_desc[0..5];
-        return calculate_hand_score(flush_ranks.to_vec(), HandRank::Flush);
-    }
+    // A straight never beats a flush, so it is only looked for without one.
+    let straight_rank = if flush_ranks_desc.is_some() {
+        tracer.skipped("find_straight", || "flush".into());
+        None
+    } else {
+        let mut ranks_desc_no_dup = ranks_desc;
+        ranks_desc_no_dup.dedup();
+        let straight_rank = find_straight(&ranks_desc_no_dup);
+        tracer.ran(
+            "find_straight",
+            || format!("{} distinct ranks", ranks_desc_no_dup.len()),
+            || describe_rank(straight_rank),
+        );
+        straight_rank
+    };
 
-    // Check for a straight.
-    if let Some(straight_rank) = find_straight(&ranks_desc_no_dup) {
-        return calculate_hand_score(vec![straight_rank], HandRank::Straight);
-    }
+    // The counts per rank decide the category, so only the detector of that
+    // category has to run.
+    let counts = rank_counts(&ranks_desc);
+    let category = classify_counts(&counts, flush_ranks_desc.is_some(), straight_rank);
+    tracer.ran("classify_counts", ranks_input, || format!("{:?}", category));
 
-    // Check for three of a kind, two pair, or one pair.
-    if num_duplicates > 1 {
-        if let Some(three_of_a_kind) = find_three_of_a_kind(&ranks_desc) {
-            return calculate_hand_score(three_of_a_kind, HandRank::ThreeOfAKind);
+    match category {
+        HandRank::FourOfAKind => {
+            let four_of_a_kind = four_of_a_kind_ranks(rank_multiplicities(&counts));
+            tracer.ran("find_four_of_a_kind", ranks_input, || {
+                describe_ranks(four_of_a_kind.as_deref())
+            });
+            let four_of_a_kind = four_of_a_kind.expect("The counts hold four of a kind");
+            score_quads(four_of_a_kind[0], four_of_a_kind.get(1).copied())
         }
-        if let Some(two_pair) = find_two_pair(&ranks_desc) {
-            return calculate_hand_score(two_pair, HandRank::TwoPair);
+        HandRank::FullHouse => {
+            let full_house = full_house_ranks(&ranks_desc);
+            tracer.ran("find_full_house", ranks_input, || {
+                describe_ranks(full_house.as_deref())
+            });
+            let full_house = full_house.expect("The counts hold a full house");
+            score_full_house(full_house[0], full_house[1])
         }
-        panic!("No paired hand found but expected.");
-    }
-
-    if num_duplicates > 0 {
-        if let Some(pair) = find_pair(&ranks_desc) {
-            return calculate_hand_score(pair, HandRank::OnePair);
+        HandRank::Flush => {
+            let flush_ranks_desc = flush_ranks_desc.expect("The cards hold a flush");
+            let flush_ranks = flush_ranks_desc[0..5]
+                .try_into()
+                .expect("A flush has at least five cards");
+            score_flush(flush_ranks)
         }
-        panic!("No paired hand found but expected.");
-    }
-
-    // Return score for high cards.
-    let high_cards: Vec<Rank>;
-    if ranks_desc.len() < 5 {
-        high_cards = ranks_desc.clone();
-    } else {
-        high_cards = ranks_desc[0..5].to_vec();
+        HandRank::Straight => score_straight(straight_rank.expect("The cards hold a straight")),
+        HandRank::ThreeOfAKind => {
+            let three_of_a_kind = three_of_a_kind_ranks(&ranks_desc);
+            tracer.ran("find_three_of_a_kind", ranks_input, || {
+                describe_ranks(three_of_a_kind.as_deref())
+            });
+            let three_of_a_kind = three_of_a_kind.expect("The counts hold three of a kind");
+            score_trips(three_of_a_kind[0], &three_of_a_kind[1..])
+        }
+        HandRank::TwoPair => {
+            let two_pair = two_pair_ranks(&ranks_desc);
+            tracer.ran("find_two_pair", ranks_input, || {
+                describe_ranks(two_pair.as_deref())
+            });
+            let two_pair = two_pair.expect("The counts hold two pair");
+            score_two_pair(two_pair[0], two_pair[1], two_pair.get(2).copied())
+        }
+        HandRank::OnePair => {
+            let pair = pair_ranks(&ranks_desc);
+            tracer.ran("find_pair", ranks_input, || describe_ranks(pair.as_deref()));
+            let pair = pair.expect("The counts hold a pair");
+            score_pair(pair[0], &pair[1..])
+        }
+        HandRank::HighCard => score_high_card(&ranks_desc[..ranks_desc.len().min(5)]),
+        HandRank::StraightFlush => unreachable!("The counts never make a straight flush"),
     }
-    return calculate_hand_score(high_cards.to_vec(), HandRank::HighCard);
 }
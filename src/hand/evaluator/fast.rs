@@ -0,0 +1,212 @@
+use crate::card::Card;
+
+use super::backend::Evaluator;
+use super::score::HandRank;
+
+/// An evaluator that scores cards without allocating, using one bit mask of
+/// ranks per suit and a count per rank.
+///
+/// It returns the same scores as `ReferenceEvaluator` but skips the sorting
+/// and the vectors of the detectors, which matters in simulations
+/// evaluating millions of hands.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::hand::{Evaluator, FastEvaluator, Hand};
+///
+/// let hand = Hand::new_from_str("Ks Kd Kh 2c 2s 7d").unwrap();
+/// assert_eq!(FastEvaluator.score(hand.as_slice()), hand.get_score());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FastEvaluator;
+
+impl Evaluator for FastEvaluator {
+    fn score(&self, cards: &[Card]) -> u32 {
+        let counts = CardCounts::new(cards);
+        counts.score(counts.flush_suit(5))
+    }
+}
+
+/// The ranks of some cards as one bit mask of ranks per suit and a count per
+/// rank, which more cards can be added to before scoring.
+///
+/// This lets several hands sharing cards, like the hands of the players at a
+/// showdown, count the shared cards only once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct CardCounts {
+    // Bit `r` of a mask is set for a card of rank value `r`.
+    suit_masks: [u16; 4],
+    counts: [u8; 15],
+}
+
+impl CardCounts {
+    /// Counts the cards.
+    pub(crate) fn new(cards: &[Card]) -> Self {
+        let mut counts = Self::default();
+        for card in cards {
+            counts.add(*card);
+        }
+        counts
+    }
+
+    /// Counts one more card, which must not have been counted yet.
+    #[inline]
+    pub(crate) fn add(&mut self, card: Card) {
+        let rank = card.rank as usize;
+        self.suit_masks[card.suit.to_index()] |= 1 << rank;
+        self.counts[rank] += 1;
+    }
+
+    /// Returns the index of the first suit with at least `count` cards.
+    pub(crate) fn flush_suit(&self, count: u32) -> Option<usize> {
+        self.suit_masks
+            .iter()
+            .position(|mask| mask.count_ones() >= count)
+    }
+
+    /// Scores the best hand of the cards like `FastEvaluator`, looking for a
+    /// flush only in the suit of the given index.
+    pub(crate) fn score(&self, flush_suit: Option<usize>) -> u32 {
+        let ranks_mask = self.suit_masks.iter().fold(0, |mask, suit| mask | suit);
+        let flush_mask = flush_suit
+            .map(|suit| self.suit_masks[suit])
+            .filter(|mask| mask.count_ones() >= 5);
+        if let Some(flush_mask) = flush_mask {
+            if let Some(high) = straight_high(flush_mask) {
+                return HandRank::StraightFlush as u32 + high;
+            }
+        }
+
+        score_counts(&self.counts, ranks_mask, flush_mask)
+    }
+}
+
+/// Scores the best hand of the counts per rank value, where `ranks_mask` has
+/// the bit of every rank with a card set. A flush only counts if a
+/// `flush_mask` of its suit is given.
+pub(super) fn score_counts(counts: &[u8; 15], ranks_mask: u16, flush_mask: Option<u16>) -> u32 {
+    // The ranks with at least the given count, from the highest down.
+    let with_count = |count: u8| {
+        (2..=14u32)
+            .rev()
+            .filter(move |&r| counts[r as usize] >= count)
+    };
+
+    if let Some(quads) = with_count(4).next() {
+        let kicker = with_count(1).find(|&r| r != quads);
+        return score(HandRank::FourOfAKind, [quads].into_iter().chain(kicker));
+    }
+    let trips = with_count(3).next();
+    if let Some(trips) = trips {
+        if let Some(pair) = with_count(2).find(|&r| r != trips) {
+            return score(HandRank::FullHouse, [trips, pair]);
+        }
+    }
+    if let Some(flush_mask) = flush_mask {
+        return score(HandRank::Flush, mask_ranks(flush_mask).take(5));
+    }
+    if let Some(high) = straight_high(ranks_mask) {
+        return HandRank::Straight as u32 + high;
+    }
+    if let Some(trips) = trips {
+        let kickers = with_count(1).filter(|&r| r != trips).take(2);
+        return score(HandRank::ThreeOfAKind, [trips].into_iter().chain(kickers));
+    }
+
+    let mut pairs = with_count(2);
+    match (pairs.next(), pairs.next()) {
+        (Some(high), Some(low)) => {
+            let kicker = with_count(1).find(|&r| r != high && r != low);
+            score(HandRank::TwoPair, [high, low].into_iter().chain(kicker))
+        }
+        (Some(pair), None) => {
+            let kickers = with_count(1).filter(|&r| r != pair).take(3);
+            score(HandRank::OnePair, [pair].into_iter().chain(kickers))
+        }
+        _ => score(HandRank::HighCard, with_count(1).take(5)),
+    }
+}
+
+/// Packs the ranks into the nibbles of a score of the category.
+pub(super) fn score(hand_rank: HandRank, ranks: impl IntoIterator<Item = u32>) -> u32 {
+    hand_rank as u32 + ranks.into_iter().fold(0, |score, rank| score << 4 | rank)
+}
+
+/// Returns the rank values set in the mask, from the highest down.
+pub(super) fn mask_ranks(mask: u16) -> impl Iterator<Item = u32> {
+    (2..=14u32).rev().filter(move |&r| mask & (1 << r) != 0)
+}
+
+/// Returns the rank value of the highest card of the best straight in the
+/// mask, counting an ace as low card of the wheel.
+fn straight_high(mask: u16) -> Option<u32> {
+    let mask = mask | (mask >> 14 & 1) << 1;
+    (5..=14u32).rev().find(|&high| {
+        let straight = 0b11111 << (high - 4);
+        mask & straight == straight
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combinations::combinations;
+    use crate::deck::Deck;
+    use crate::hand::evaluator::evaluator::evaluate_cards;
+    use crate::hand::Hand;
+
+    fn assert_same(s: &str) {
+        let hand = Hand::new_from_str(s).unwrap();
+        assert_eq!(
+            FastEvaluator.score(hand.as_slice()),
+            hand.get_score(),
+            "{}",
+            s
+        );
+    }
+
+    #[test]
+    fn test_categories() {
+        for s in [
+            "As Ks Qs Js Ts 9s Ah",
+            "5d 4d 3d 2d Ad Kd",
+            "9s 9h 9c 9d 5s 5h 5c",
+            "9s 9h 9c 9d",
+            "7s 7h 7c 5d 5s 5h 2c",
+            "7s 7h 7c 5d 5s 3h 3c",
+            "As 9s 7s 5s 2s Ks 3h",
+            "As 2h 3c 4d 5s 9c",
+            "Ts Jh Qc Kd As 9c",
+            "Ts Th Tc 2d 3s",
+            "Ts Th Tc",
+            "Ts Th 2c 2d 4s 4h 3c",
+            "Ks Kd Qc Qd",
+            "Ks Kd 5c 3d 2s",
+            "Ks Kd",
+            "As Kd 9c 7d 5s 3h 2c",
+            "As Kd",
+        ] {
+            assert_same(s);
+        }
+    }
+
+    #[test]
+    fn test_all_small_hands() {
+        let deck = Deck::new();
+        for count in 2..=4 {
+            for cards in combinations(deck.get_cards(), count) {
+                assert_eq!(FastEvaluator.score(&cards), evaluate_cards(&cards));
+            }
+        }
+    }
+
+    #[test]
+    #[ignore = "Evaluates all 2,598,960 five card hands"]
+    fn test_all_five_card_hands() {
+        let deck = Deck::new();
+        for cards in combinations(deck.get_cards(), 5) {
+            assert_eq!(FastEvaluator.score(&cards), evaluate_cards(&cards));
+        }
+    }
+}
@@ -1,25 +1,125 @@
+use alloc::vec::Vec;
+
 use strum::IntoEnumIterator;
 
-use crate::card::{Rank, Suit};
-use crate::hand::Hand;
+use crate::card::{Card, Rank, Suit};
+
+use super::rank_list::RankList;
 
-/// Finds the ranks of the flush cards in a `hand` in the order they were passed
-/// if a flush exists or returns None if a hand does not contain a flush.
+/// Finds the ranks of the flush cards in `cards` in the order they were passed
+/// if a flush exists or returns None if the cards do not contain a flush.
+///
+/// # Preconditions
+///
+/// The cards must be distinct, which is checked in debug builds. Pass the
+/// cards in descending order of rank to get the flush ranks in descending
+/// order, or use `find_flush_unsorted`.
 ///
 /// # Arguments
 ///
-/// * `hand` - A hand of cards.
+/// * `cards` - The cards of a hand.
 ///
 /// # Returns
 ///
 /// * The ranks of the flush cards in the order they were passed if a flush
-/// exists or None if not.
-pub fn find_flush(hand: &Hand) -> Option<Vec<Rank>> {
-    for suit in Suit::iter() {
-        let flush_cards = hand.cards_of_suit(suit);
-        if flush_cards.len() >= 5 {
-            return Some(flush_cards.into_iter().map(|card| card.rank).collect());
-        }
+///   exists or None if not.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Card;
+/// use pkr::card::Rank::*;
+/// use pkr::hand::detectors::find_flush;
+///
+/// let cards: Vec<Card> = ["Ah", "Kh", "9h", "7c", "5h", "2h"]
+///     .iter()
+///     .map(|s| Card::new_from_str(s).unwrap())
+///     .collect();
+/// assert_eq!(find_flush(&cards), Some(vec![Ace, King, Nine, Five, Two]));
+/// assert_eq!(find_flush(&cards[..5]), None);
+/// ```
+pub fn find_flush(cards: &[Card]) -> Option<Vec<Rank>> {
+    flush_ranks(cards).map(|ranks| ranks.to_vec())
+}
+
+/// Finds the ranks of the flush cards like `find_flush` without allocating.
+pub(super) fn flush_ranks(cards: &[Card]) -> Option<RankList> {
+    debug_assert!(
+        cards
+            .iter()
+            .enumerate()
+            .all(|(i, card)| !cards[..i].contains(card)),
+        "Cards must be distinct"
+    );
+
+    Suit::iter().find_map(|suit| {
+        let flush_ranks: RankList = cards
+            .iter()
+            .filter(|card| card.suit == suit)
+            .map(|card| card.rank)
+            .collect();
+        (flush_ranks.len() >= 5).then_some(flush_ranks)
+    })
+}
+
+/// Finds the ranks of the flush cards in descending order like `find_flush`,
+/// for cards in any order. Cards given more than once count once.
+pub fn find_flush_unsorted(cards: &[Card]) -> Option<Vec<Rank>> {
+    let mut cards = cards.to_vec();
+    cards.sort_by_key(|card| (core::cmp::Reverse(card.rank), card.suit));
+    cards.dedup();
+    find_flush(&cards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+
+    fn cards(s: &str) -> Vec<Card> {
+        s.split_whitespace()
+            .map(|card| Card::new_from_str(card).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_flush() {
+        assert_eq!(
+            find_flush(&cards("As Qs 9s 5s 3s")),
+            Some(vec![Ace, Queen, Nine, Five, Three])
+        );
+        assert_eq!(
+            find_flush(&cards("Ad Kd Qd Jd 9d 7d 2d")),
+            Some(vec![Ace, King, Queen, Jack, Nine, Seven, Two])
+        );
+    }
+
+    #[test]
+    fn test_no_flush() {
+        assert_eq!(find_flush(&cards("As Qs 9s 5s 3h")), None);
+        assert_eq!(find_flush(&cards("As Qs 9s 5s")), None);
+        assert_eq!(find_flush(&[]), None);
+    }
+
+    #[test]
+    fn test_unsorted() {
+        let unsorted = cards("3c 9c Ac 5c Qc Kh");
+        assert_eq!(
+            find_flush(&unsorted),
+            Some(vec![Three, Nine, Ace, Five, Queen])
+        );
+        assert_eq!(
+            find_flush_unsorted(&unsorted),
+            Some(vec![Ace, Queen, Nine, Five, Three])
+        );
+    }
+
+    #[test]
+    fn test_unsorted_duplicates() {
+        assert_eq!(find_flush_unsorted(&cards("3c 9c Ac 5c 3c")), None);
+        assert_eq!(
+            find_flush_unsorted(&cards("3c 9c Ac 5c 3c Qc")),
+            Some(vec![Ace, Queen, Nine, Five, Three])
+        );
     }
-    None
 }
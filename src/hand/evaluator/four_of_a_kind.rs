@@ -1,45 +1,105 @@
-use std::option::Option;
+use alloc::vec::Vec;
 
 use crate::card::Rank;
 
-/// Finds "Four of a Kind" in a hand of poker cards.
+use crate::hand::hand::rank_multiplicities;
+
+use super::classify::rank_counts;
+use super::preconditions::{is_desc, sorted_desc};
+use super::rank_list::RankList;
+
+/// Finds "Four of a Kind" in the provided ranks in descending order.
 ///
-/// The function takes a vector of Rank sorted in descending order.
-/// It checks for the occurrence of four cards of the same rank.
-/// If the hand has less than five cards, it returns None, except when the hand
-/// consists of four cards of the same rank.
 /// When a "four of a kind" is found, it returns a vector consisting of two ranks:
 /// The first represents the value of the four of a kind, and the second
 /// represents the highest card that is not part of the four of a kind (kicker).
+/// A hand of only four cards of the same rank has no kicker.
+///
+/// # Preconditions
+///
+/// The ranks must be sorted in descending order, which is checked in debug
+/// builds. Use `find_four_of_a_kind_unsorted` for ranks in any order.
 ///
 /// # Arguments
 ///
-/// * `mut ranks` - A mutable vector of Rank representing the ranks of a hand
-/// of cards in descending order.
-pub fn find_four_of_a_kind(ranks: &Vec<Rank>) -> Option<Vec<Rank>> {
-    let ranks_len = ranks.len();
+/// * `ranks_desc` - A slice of `Rank` values sorted in descending order.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Rank::*;
+/// use pkr::hand::detectors::find_four_of_a_kind;
+///
+/// assert_eq!(
+///     find_four_of_a_kind(&[Ace, Nine, Nine, Nine, Nine, Two]),
+///     Some(vec![Nine, Ace])
+/// );
+/// assert_eq!(find_four_of_a_kind(&[Ace, Nine, Nine, Nine, Two]), None);
+/// ```
+pub fn find_four_of_a_kind(ranks_desc: &[Rank]) -> Option<Vec<Rank>> {
+    debug_assert!(
+        is_desc(ranks_desc),
+        "Ranks must be sorted in descending order"
+    );
+
+    four_of_a_kind_ranks(rank_multiplicities(&rank_counts(ranks_desc))).map(|ranks| ranks.to_vec())
+}
 
-    if ranks_len < 4 {
+/// Finds "Four of a Kind" like `find_four_of_a_kind` without allocating,
+/// given the rank multiplicities of a hand as returned by
+/// `rank_multiplicities`, so four of a kind can only be the first entry.
+pub(super) fn four_of_a_kind_ranks(
+    multiplicities: impl IntoIterator<Item = (Rank, u8)>,
+) -> Option<RankList> {
+    let mut multiplicities = multiplicities.into_iter();
+    let (rank, count) = multiplicities.next()?;
+    if count < 4 {
         return None;
     }
 
-    for i in 0..(ranks.len() - 3) {
-        if ranks[i] == ranks[i + 1] && ranks[i + 1] == ranks[i + 2] && ranks[i + 2] == ranks[i + 3]
-        {
-            let mut four_of_a_kind: Vec<Rank> = Vec::new();
-            four_of_a_kind.push(ranks[i]);
-
-            if ranks_len > 4 {
-                // Find the highest card that is not part of the four of a kind
-                let kicker = ranks.iter().filter(|&&rank| rank != ranks[i]).max();
-                match kicker {
-                    Some(k) => four_of_a_kind.push(*k),
-                    None => return None,
-                }
-            }
-            return Some(four_of_a_kind);
-        }
+    let mut four_of_a_kind: RankList = [rank].into_iter().collect();
+    // Find the highest card that is not part of the four of a kind
+    if let Some(kicker) = multiplicities.map(|(rank, _)| rank).max() {
+        four_of_a_kind.push(kicker);
     }
+    Some(four_of_a_kind)
+}
+
+/// Finds "Four of a Kind" like `find_four_of_a_kind`, for ranks in any order.
+pub fn find_four_of_a_kind_unsorted(ranks: &[Rank]) -> Option<Vec<Rank>> {
+    find_four_of_a_kind(&sorted_desc(ranks))
+}
 
-    None
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+
+    #[test]
+    fn test_four_of_a_kind() {
+        assert_eq!(
+            find_four_of_a_kind(&[King, King, King, King, Three]),
+            Some(vec![King, Three])
+        );
+        assert_eq!(
+            find_four_of_a_kind(&[Ace, Ace, Ace, Two, Two, Two, Two]),
+            Some(vec![Two, Ace])
+        );
+    }
+
+    #[test]
+    fn test_without_kicker() {
+        assert_eq!(find_four_of_a_kind(&[Six, Six, Six, Six]), Some(vec![Six]));
+        assert_eq!(find_four_of_a_kind(&[Six, Six, Six]), None);
+        assert_eq!(find_four_of_a_kind(&[]), None);
+    }
+
+    #[test]
+    fn test_unsorted() {
+        assert_eq!(
+            find_four_of_a_kind_unsorted(&[Two, Jack, Two, Ace, Two, Two]),
+            Some(vec![Two, Ace])
+        );
+        assert_eq!(find_four_of_a_kind_unsorted(&[Two, Jack, Two, Two]), None);
+    }
 }
@@ -1,6 +1,11 @@
+use alloc::vec::Vec;
+
 use crate::card::Rank;
 
-/// Finds in a given descending sorted `Vec<Rank>` a full house or returns None.
+use super::preconditions::{is_desc, sorted_desc};
+use super::rank_list::RankList;
+
+/// Finds in a given descending sorted slice of ranks a full house or returns None.
 ///
 /// A full house in poker is a hand consisting of a three-of-a-kind and a pair.
 /// If the length of `ranks_desc` is less than 5, it immediately returns `None`.
@@ -8,16 +13,44 @@ use crate::card::Rank;
 /// If a full house is found, it returns a `Vec<Rank>` where the first rank is
 /// that of the three-of-a-kind, and the second rank is that of the pair
 ///
+/// # Preconditions
+///
+/// The ranks must be sorted in descending order, which is checked in debug
+/// builds. Use `find_full_house_unsorted` for ranks in any order.
+///
 /// # Arguments
 ///
-/// * `ranks_desc` - A vector of ranks sorted in descending order.
+/// * `ranks_desc` - A slice of ranks sorted in descending order.
 ///
 /// # Returns
 ///
 /// * An `Option<Vec<Rank>>` which is `Some(Vec<Rank>)` containing the rank of
-/// the three of a kind and the rank of the pair if a full house is found, or
-/// `None` if no full house is found.
-pub fn find_full_house(ranks_desc: &Vec<Rank>) -> Option<Vec<Rank>> {
+///   the three of a kind and the rank of the pair if a full house is found, or
+///   `None` if no full house is found.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Rank::*;
+/// use pkr::hand::detectors::find_full_house;
+///
+/// assert_eq!(
+///     find_full_house(&[King, Seven, Seven, Seven, Two, Two]),
+///     Some(vec![Seven, Two])
+/// );
+/// assert_eq!(find_full_house(&[King, Seven, Seven, Seven, Two]), None);
+/// ```
+pub fn find_full_house(ranks_desc: &[Rank]) -> Option<Vec<Rank>> {
+    full_house_ranks(ranks_desc).map(|ranks| ranks.to_vec())
+}
+
+/// Finds a full house like `find_full_house` without allocating.
+pub(super) fn full_house_ranks(ranks_desc: &[Rank]) -> Option<RankList> {
+    debug_assert!(
+        is_desc(ranks_desc),
+        "Ranks must be sorted in descending order"
+    );
+
     if ranks_desc.len() < 5 {
         return None;
     }
@@ -31,15 +64,55 @@ pub fn find_full_house(ranks_desc: &Vec<Rank>) -> Option<Vec<Rank>> {
         }
     }
 
-    if three_of_a_kind_rank.is_none() {
-        return None;
-    }
+    let three_of_a_kind_rank = three_of_a_kind_rank?;
 
     for i in 0..ranks_desc.len() - 1 {
-        if ranks_desc[i] == ranks_desc[i + 1] && ranks_desc[i] != three_of_a_kind_rank.unwrap() {
-            return Some(vec![three_of_a_kind_rank.unwrap(), ranks_desc[i]]);
+        if ranks_desc[i] == ranks_desc[i + 1] && ranks_desc[i] != three_of_a_kind_rank {
+            return Some([three_of_a_kind_rank, ranks_desc[i]].into_iter().collect());
         }
     }
 
-    return None;
+    None
+}
+
+/// Finds a full house like `find_full_house`, for ranks in any order.
+pub fn find_full_house_unsorted(ranks: &[Rank]) -> Option<Vec<Rank>> {
+    find_full_house(&sorted_desc(ranks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+
+    #[test]
+    fn test_full_house() {
+        assert_eq!(
+            find_full_house(&[Ace, Ace, Ace, King, King]),
+            Some(vec![Ace, King])
+        );
+        assert_eq!(
+            find_full_house(&[Queen, Queen, Five, Five, Five, Two, Two]),
+            Some(vec![Five, Queen])
+        );
+    }
+
+    #[test]
+    fn test_two_trips() {
+        assert_eq!(
+            find_full_house(&[Nine, Nine, Nine, Four, Four, Four, Two]),
+            Some(vec![Nine, Four])
+        );
+        assert_eq!(find_full_house(&[Nine, Nine, Nine, Four, Three]), None);
+        assert_eq!(find_full_house(&[Nine, Nine, Four, Four]), None);
+    }
+
+    #[test]
+    fn test_unsorted() {
+        assert_eq!(
+            find_full_house_unsorted(&[Two, Ace, Two, Ace, Two]),
+            Some(vec![Two, Ace])
+        );
+        assert_eq!(find_full_house_unsorted(&[Two, Ace, Three, Ace, Two]), None);
+    }
 }
@@ -0,0 +1,103 @@
+use crate::board::Board;
+use crate::hand::{HandValue, HoleCards};
+
+use super::evaluator::evaluate_cards;
+
+/// Evaluates the hole cards of a hold'em player together with the board
+/// without building a `Hand`.
+///
+/// The score is the same as the one of `Hand::from_hole_and_board` for the
+/// same cards. Preflop only the two hole cards are evaluated, like a hand of
+/// two cards.
+///
+/// # Arguments
+///
+/// * `hole` - The hole cards of the player.
+/// * `board` - The community cards.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::board::Board;
+/// use pkr::hand::{evaluate_holdem, Hand, HoleCards};
+///
+/// let hole = HoleCards::new_from_str("AhAd").unwrap();
+/// let board = Board::new_from_str("As 7c 2d").unwrap();
+/// let hand = Hand::from_hole_and_board(&hole.get_cards(), board.cards()).unwrap();
+/// assert_eq!(evaluate_holdem(&hole, &board), hand.get_score());
+/// ```
+///
+/// # Panics
+///
+/// Panics in debug builds if a hole card is also on the board. Use
+/// `Hand::from_hole_and_board` to handle such cards.
+pub fn evaluate_holdem(hole: &HoleCards, board: &Board) -> u32 {
+    let [first, second] = hole.get_cards();
+    let board = board.cards();
+    debug_assert!(
+        !board.iter().any(|card| hole.contains(card)),
+        "A hole card is also on the board"
+    );
+
+    let mut cards = [first; 7];
+    cards[1] = second;
+    cards[2..2 + board.len()].copy_from_slice(board);
+    evaluate_cards(&cards[..2 + board.len()])
+}
+
+/// Returns the `HandValue` of the hole cards together with the board, see
+/// `evaluate_holdem`.
+pub fn evaluate_holdem_value(hole: &HoleCards, board: &Board) -> HandValue {
+    HandValue::new(evaluate_holdem(hole, board))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::Card;
+    use crate::deck::Deck;
+    use crate::hand::Hand;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn combined_score(hole: &HoleCards, board: &Board) -> u32 {
+        Hand::from_hole_and_board(&hole.get_cards(), board.cards())
+            .unwrap()
+            .get_score()
+    }
+
+    #[test]
+    fn test_streets() {
+        let hole = HoleCards::new_from_str("AhKh").unwrap();
+        for (board, description) in [
+            ("", "High Card, Ace"),
+            ("Qh Jh 2c", "High Card, Ace"),
+            ("Qh Jh 2c Ad", "One Pair, Aces"),
+            ("Qh Jh 2c Ad Th", "Royal Flush"),
+        ] {
+            let board = Board::new_from_str(board).unwrap();
+            assert_eq!(
+                evaluate_holdem(&hole, &board),
+                combined_score(&hole, &board)
+            );
+            assert_eq!(evaluate_holdem_value(&hole, &board).describe(), description);
+        }
+    }
+
+    #[test]
+    fn test_random_deals() {
+        let mut rng = StdRng::seed_from_u64(161);
+        for i in 0..2000 {
+            let mut deck = Deck::new();
+            deck.shuffle_with(&mut rng);
+            let mut deal = || deck.deal().unwrap();
+            let hole = HoleCards::new(deal(), deal()).unwrap();
+            let cards: Vec<Card> = (0..[0, 3, 4, 5][i % 4]).map(|_| deal()).collect();
+            let board = Board::try_from(cards.as_slice()).unwrap();
+            assert_eq!(
+                evaluate_holdem(&hole, &board),
+                combined_score(&hole, &board)
+            );
+        }
+    }
+}
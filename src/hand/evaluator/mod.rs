@@ -1,9 +1,45 @@
+mod backend;
+#[cfg(feature = "std")]
+mod cache;
+mod classify;
+#[allow(clippy::module_inception)]
 pub mod evaluator;
+mod fast;
 mod flush;
 mod four_of_a_kind;
 mod full_house;
+#[cfg(feature = "std")]
+mod holdem;
 mod pair;
+mod preconditions;
+mod rank_list;
+mod rank_only;
 mod score;
 mod straight;
 mod three_of_a_kind;
+mod trace;
 mod two_pair;
+mod wild;
+
+pub use backend::{Disagreement, Evaluator, ReferenceEvaluator};
+#[cfg(feature = "std")]
+pub use cache::EvalCache;
+pub use classify::classify_counts;
+pub use evaluator::{compare, evaluate};
+#[cfg(feature = "std")]
+pub(crate) use fast::CardCounts;
+pub use fast::FastEvaluator;
+pub use flush::{find_flush, find_flush_unsorted};
+pub use four_of_a_kind::{find_four_of_a_kind, find_four_of_a_kind_unsorted};
+pub use full_house::{find_full_house, find_full_house_unsorted};
+#[cfg(feature = "std")]
+pub use holdem::{evaluate_holdem, evaluate_holdem_value};
+pub use pair::{find_pair, find_pair_unsorted};
+pub use rank_only::evaluate_rank_only;
+pub use score::HandRank;
+pub use straight::{find_straight, find_straight_unsorted};
+pub use three_of_a_kind::{find_three_of_a_kind, find_three_of_a_kind_unsorted};
+pub(crate) use trace::parse_ranks;
+pub use trace::{evaluate_traced, EvalTrace, TraceStep};
+pub use two_pair::{find_two_pair, find_two_pair_unsorted};
+pub use wild::{evaluate_with_wild_set, WildSpec, FIVE_OF_A_KIND};
@@ -1,24 +1,59 @@
+use alloc::vec::Vec;
+
 use crate::card::Rank;
 
+use super::preconditions::{is_desc, sorted_desc};
+use super::rank_list::RankList;
+
 /// Finds a single pair and the kickers in descending order from the provided
 /// ranks in descending order.
 ///
+/// # Preconditions
+///
+/// The ranks must be sorted in descending order, which is checked in debug
+/// builds. Use `find_pair_unsorted` for ranks in any order. The evaluator
+/// checks for stronger hands first, so the highest repeated rank is taken as
+/// the pair.
+///
 /// # Arguments
 ///
-/// * `ranks_desc` - A vector of `Rank` values sorted in descending order.
+/// * `ranks_desc` - A slice of `Rank` values sorted in descending order.
 ///
 /// # Returns
 ///
 /// * `Some(Vec<Rank>)` - The pair and the kickers in descending order if found,
 ///   or `None` if not found.
-pub fn find_pair(ranks_desc: &Vec<Rank>) -> Option<Vec<Rank>> {
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Rank::*;
+/// use pkr::hand::detectors::find_pair;
+///
+/// assert_eq!(
+///     find_pair(&[Ace, Jack, Jack, Nine, Five, Two]),
+///     Some(vec![Jack, Ace, Nine, Five])
+/// );
+/// assert_eq!(find_pair(&[Ace, Jack, Nine]), None);
+/// ```
+pub fn find_pair(ranks_desc: &[Rank]) -> Option<Vec<Rank>> {
+    pair_ranks(ranks_desc).map(|ranks| ranks.to_vec())
+}
+
+/// Finds a pair like `find_pair` without allocating.
+pub(super) fn pair_ranks(ranks_desc: &[Rank]) -> Option<RankList> {
+    debug_assert!(
+        is_desc(ranks_desc),
+        "Ranks must be sorted in descending order"
+    );
+
     let ranks_len = ranks_desc.len();
 
     if ranks_len < 2 {
         return None;
     }
 
-    let mut result = Vec::new();
+    let mut result = RankList::new();
 
     for i in 0..ranks_len - 1 {
         if ranks_desc[i] == ranks_desc[i + 1] {
@@ -28,24 +63,54 @@ pub fn find_pair(ranks_desc: &Vec<Rank>) -> Option<Vec<Rank>> {
     }
 
     if result.len() == 1 {
+        let pair = result[0];
+        let kickers = ranks_desc.iter().filter(|&&rank| rank != pair).copied();
         if ranks_len < 5 {
-            let kickers: Vec<Rank> = ranks_desc
-                .iter()
-                .filter(|&&rank| rank != result[0])
-                .copied()
-                .collect();
             result.extend(kickers);
         } else {
-            let kickers: Vec<Rank> = ranks_desc
-                .iter()
-                .filter(|&&rank| rank != result[0])
-                .take(3) // Take the highest three kickers
-                .copied()
-                .collect();
-            result.extend(kickers);
+            result.extend(kickers.take(3)); // Take the highest three kickers
         }
         Some(result)
     } else {
         None
     }
 }
+
+/// Finds a pair like `find_pair`, for ranks in any order.
+pub fn find_pair_unsorted(ranks: &[Rank]) -> Option<Vec<Rank>> {
+    find_pair(&sorted_desc(ranks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+
+    #[test]
+    fn test_pair() {
+        assert_eq!(
+            find_pair(&[King, King, Nine, Seven, Four]),
+            Some(vec![King, Nine, Seven, Four])
+        );
+        assert_eq!(
+            find_pair(&[Ace, King, Queen, Eight, Eight, Four, Three]),
+            Some(vec![Eight, Ace, King, Queen])
+        );
+    }
+
+    #[test]
+    fn test_short_hands() {
+        assert_eq!(find_pair(&[Two, Two]), Some(vec![Two]));
+        assert_eq!(find_pair(&[Ace, Two, Two]), Some(vec![Two, Ace]));
+        assert_eq!(find_pair(&[Ace]), None);
+    }
+
+    #[test]
+    fn test_unsorted() {
+        assert_eq!(
+            find_pair_unsorted(&[Four, Ace, Three, Four, King]),
+            Some(vec![Four, Ace, King, Three])
+        );
+        assert_eq!(find_pair_unsorted(&[Four, Ace, Three]), None);
+    }
+}
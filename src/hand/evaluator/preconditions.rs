@@ -0,0 +1,36 @@
+use alloc::vec::Vec;
+
+use crate::card::Rank;
+
+/// Returns whether the ranks are sorted in descending order.
+pub(crate) fn is_desc(ranks: &[Rank]) -> bool {
+    ranks.windows(2).all(|pair| pair[0] >= pair[1])
+}
+
+/// Returns whether the ranks are sorted in descending order without
+/// duplicates.
+pub(crate) fn is_desc_nodup(ranks: &[Rank]) -> bool {
+    ranks.windows(2).all(|pair| pair[0] > pair[1])
+}
+
+/// Returns the ranks sorted in descending order.
+pub(crate) fn sorted_desc(ranks: &[Rank]) -> Vec<Rank> {
+    let mut ranks = ranks.to_vec();
+    ranks.sort_unstable_by_key(|&rank| core::cmp::Reverse(rank));
+    ranks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+
+    #[test]
+    fn test_preconditions() {
+        assert!(is_desc(&[Ace, Ace, Two]));
+        assert!(!is_desc(&[Two, Ace]));
+        assert!(is_desc_nodup(&[Ace, King, Two]));
+        assert!(!is_desc_nodup(&[Ace, Ace, Two]));
+        assert_eq!(sorted_desc(&[Two, Ace, Two]), [Ace, Two, Two]);
+    }
+}
@@ -0,0 +1,113 @@
+use core::cmp::Reverse;
+use core::ops::{Deref, DerefMut};
+
+use crate::card::Rank;
+
+/// Up to `RankList::CAPACITY` ranks kept on the stack, which lets the
+/// evaluator run without allocating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct RankList {
+    ranks: [Rank; RankList::CAPACITY],
+    len: usize,
+}
+
+impl RankList {
+    /// The largest number of ranks, enough for the cards of a hand and for
+    /// every card of a suit.
+    pub(super) const CAPACITY: usize = 13;
+
+    /// Creates an empty list.
+    pub(super) fn new() -> Self {
+        Self {
+            ranks: [Rank::Two; Self::CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// Appends a rank.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list already holds `CAPACITY` ranks.
+    pub(super) fn push(&mut self, rank: Rank) {
+        assert!(
+            self.len < Self::CAPACITY,
+            "A rank list holds at most {} ranks",
+            Self::CAPACITY
+        );
+        self.ranks[self.len] = rank;
+        self.len += 1;
+    }
+
+    /// Sorts the ranks in descending order.
+    pub(super) fn sort_desc(&mut self) {
+        self.sort_unstable_by_key(|&rank| Reverse(rank));
+    }
+
+    /// Removes consecutive repeated ranks, like `Vec::dedup`.
+    pub(super) fn dedup(&mut self) {
+        let mut len = 0;
+        for i in 0..self.len {
+            if len == 0 || self.ranks[i] != self.ranks[len - 1] {
+                self.ranks[len] = self.ranks[i];
+                len += 1;
+            }
+        }
+        self.len = len;
+    }
+}
+
+impl Deref for RankList {
+    type Target = [Rank];
+
+    fn deref(&self) -> &[Rank] {
+        &self.ranks[..self.len]
+    }
+}
+
+impl DerefMut for RankList {
+    fn deref_mut(&mut self) -> &mut [Rank] {
+        &mut self.ranks[..self.len]
+    }
+}
+
+impl Extend<Rank> for RankList {
+    fn extend<I: IntoIterator<Item = Rank>>(&mut self, ranks: I) {
+        for rank in ranks {
+            self.push(rank);
+        }
+    }
+}
+
+impl FromIterator<Rank> for RankList {
+    fn from_iter<I: IntoIterator<Item = Rank>>(ranks: I) -> Self {
+        let mut list = RankList::new();
+        list.extend(ranks);
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+
+    #[test]
+    fn test_sort_and_dedup() {
+        let mut ranks: RankList = [Two, Ace, King, Two, Ace].into_iter().collect();
+        ranks.sort_desc();
+        assert_eq!(*ranks, [Ace, Ace, King, Two, Two]);
+        ranks.dedup();
+        assert_eq!(*ranks, [Ace, King, Two]);
+
+        let mut empty = RankList::new();
+        empty.dedup();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "A rank list holds at most 13 ranks")]
+    fn test_push_beyond_capacity() {
+        let _: RankList = [Ace; 14].into_iter().collect();
+    }
+}
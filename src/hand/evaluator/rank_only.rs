@@ -0,0 +1,99 @@
+use crate::card::Rank;
+
+use super::fast::score_counts;
+
+/// Scores the best hand of the ranks as if no suits existed.
+///
+/// The scores form their own scale: they use the categories and kicker
+/// packing of `evaluate`, but flushes and straight flushes are left out of
+/// the ladder, so five suited cards score as their best other category and
+/// a straight flush as a straight. This buckets hands that only differ in
+/// their suits together, e.g. for abstractions that are isomorphic in the
+/// ranks. Do not compare these scores with the ones of `evaluate`.
+///
+/// `Rank::AceLow` counts as an ace.
+///
+/// # Arguments
+///
+/// * `ranks` - The ranks of the cards.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Rank;
+/// use pkr::hand::{evaluate_rank_only, Hand, HandRank};
+///
+/// let ranks = [Rank::Ace, Rank::Nine, Rank::Seven, Rank::Four, Rank::Two];
+/// assert_eq!(HandRank::from_score(evaluate_rank_only(&ranks)), HandRank::HighCard);
+///
+/// let hand = Hand::new_from_str("As 9s 7s 4s 2s").unwrap();
+/// assert_eq!(hand.rank_only_score(), evaluate_rank_only(&ranks));
+/// ```
+pub fn evaluate_rank_only(ranks: &[Rank]) -> u32 {
+    // Bit `r` of the mask is set for a card of rank value `r`.
+    let mut ranks_mask = 0u16;
+    let mut counts = [0u8; 15];
+    for rank in ranks {
+        let rank = rank.to_index() + 2;
+        ranks_mask |= 1 << rank;
+        counts[rank] = counts[rank].saturating_add(1);
+    }
+    score_counts(&counts, ranks_mask, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand::{Hand, HandRank};
+
+    fn rank_only(s: &str) -> u32 {
+        Hand::new_from_str(s).unwrap().rank_only_score()
+    }
+
+    fn score(s: &str) -> u32 {
+        Hand::new_from_str(s).unwrap().get_score()
+    }
+
+    #[test]
+    fn test_flush_scores_as_best_other_category() {
+        assert_eq!(rank_only("As 9s 7s 4s 2s"), score("As 9h 7s 4s 2s"));
+        assert_eq!(rank_only("As 9s 7s 4s 2s 9d"), score("As 9h 7s 4s 2s 9d"));
+        // A straight flush is still a straight.
+        assert_eq!(rank_only("9s 8s 7s 6s 5s"), score("9s 8h 7s 6s 5s"));
+        assert_eq!(
+            HandRank::from_score(rank_only("9s 8s 7s 6s 5s")),
+            HandRank::Straight
+        );
+        // With a flush skipped, two pair beats nothing less than trips.
+        assert_eq!(
+            HandRank::from_score(rank_only("As Ks 7s 4s 2s Ad Kd")),
+            HandRank::TwoPair
+        );
+    }
+
+    #[test]
+    fn test_straights() {
+        assert_eq!(rank_only("5d 4c 3h 2s Ad"), score("5d 4c 3h 2s Ad"));
+        assert_eq!(rank_only("Ad Kd Qd Jd Td 9d"), score("Ah Kd Qd Jd Tc 9d"));
+        assert_eq!(
+            evaluate_rank_only(&[Rank::AceLow, Rank::Two, Rank::Three, Rank::Four, Rank::Five]),
+            rank_only("5d 4c 3h 2s Ad")
+        );
+    }
+
+    #[test]
+    fn test_kickers_match_the_evaluator() {
+        for (s, t) in [
+            ("Ks Kd 9c 7h 3s 2d", "Ks Kd 9c 7h 4s 2d"),
+            ("Ks Kd 9c 9h 3s 2d", "Ks Kd 9c 9h 4s 2d"),
+            ("Ks Kd Kc 7h 3s 2d", "Ks Kd Kc 8h 3s 2d"),
+            ("Qs Qd Qc Qh As 2d", "Ks Kd Kc Kh 3s 2d"),
+            ("Qs Qd Qc Ah As 3d", "Ks Kd Kc 2h 2s 3d"),
+            ("As Qd 9c 7h 3s 2d", "As Qd 9c 7h 4s 2d"),
+        ] {
+            assert_eq!(rank_only(s), score(s), "{}", s);
+            assert_eq!(rank_only(t), score(t), "{}", t);
+            assert!(rank_only(s) < rank_only(t), "{} {}", s, t);
+        }
+    }
+}
@@ -1,4 +1,11 @@
+use core::ops::RangeInclusive;
+
+use strum_macros::{EnumCount, EnumIter};
+
 use crate::card::Rank;
+use crate::hand::HandError;
+
+use super::preconditions::is_desc_nodup;
 
 /// An enumeration representing the rank of a poker hand.
 ///
@@ -6,7 +13,10 @@ use crate::card::Rank;
 /// values assigned to each variant represent their relative strength, with a
 /// higher number indicating a stronger hand. These values can be used to compare
 /// hands and determine the winner in a game of poker.
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// Human readable serde formats use the name of the variant, e.g. "Flush",
+/// binary formats the id of `HandRank::id` as a single byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, EnumIter, EnumCount)]
 pub enum HandRank {
     HighCard = 0,
     OnePair = 1_000_000,
@@ -19,71 +29,315 @@ pub enum HandRank {
     StraightFlush = 8_000_000,
 }
 
-/// Calculates the final score for a hand of cards.
+impl HandRank {
+    /// Returns the `HandRank` of a score calculated by `calculate_hand_score`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::{Hand, HandRank};
+    ///
+    /// let hand = Hand::new_from_str("As Ks Qs Js 9s").unwrap();
+    /// assert_eq!(HandRank::from_score(hand.get_score()), HandRank::Flush);
+    /// ```
+    pub fn from_score(score: u32) -> HandRank {
+        match score / 1_000_000 {
+            0 => HandRank::HighCard,
+            1 => HandRank::OnePair,
+            2 => HandRank::TwoPair,
+            3 => HandRank::ThreeOfAKind,
+            4 => HandRank::Straight,
+            5 => HandRank::Flush,
+            6 => HandRank::FullHouse,
+            7 => HandRank::FourOfAKind,
+            _ => HandRank::StraightFlush,
+        }
+    }
+
+    /// Returns the position of the category from `HighCard` at 0 to
+    /// `StraightFlush` at 8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::HandRank;
+    ///
+    /// assert_eq!(HandRank::HighCard.to_index(), 0);
+    /// assert_eq!(HandRank::Flush.to_index(), 5);
+    /// ```
+    pub fn to_index(&self) -> usize {
+        *self as usize / 1_000_000
+    }
+
+    /// Returns the id of the category, from 0 for `HighCard` to 8 for
+    /// `StraightFlush`.
+    ///
+    /// Unlike the discriminants and the names, the ids are guaranteed to
+    /// never change, so they can be used as keys of persisted data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::HandRank;
+    ///
+    /// assert_eq!(HandRank::Flush.id(), 5);
+    /// assert_eq!(HandRank::from_id(5), Some(HandRank::Flush));
+    /// assert_eq!(HandRank::try_from(9), Err(pkr::hand::HandError::InvalidHandRankId(9)));
+    /// ```
+    pub fn id(&self) -> u8 {
+        match self {
+            HandRank::HighCard => 0,
+            HandRank::OnePair => 1,
+            HandRank::TwoPair => 2,
+            HandRank::ThreeOfAKind => 3,
+            HandRank::Straight => 4,
+            HandRank::Flush => 5,
+            HandRank::FullHouse => 6,
+            HandRank::FourOfAKind => 7,
+            HandRank::StraightFlush => 8,
+        }
+    }
+
+    /// Returns the category of an id returned by `HandRank::id`, or `None`
+    /// if the id is not smaller than 9.
+    pub fn from_id(id: u8) -> Option<HandRank> {
+        match id {
+            0 => Some(HandRank::HighCard),
+            1 => Some(HandRank::OnePair),
+            2 => Some(HandRank::TwoPair),
+            3 => Some(HandRank::ThreeOfAKind),
+            4 => Some(HandRank::Straight),
+            5 => Some(HandRank::Flush),
+            6 => Some(HandRank::FullHouse),
+            7 => Some(HandRank::FourOfAKind),
+            8 => Some(HandRank::StraightFlush),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the variant, e.g. "FullHouse".
+    #[cfg(feature = "serde")]
+    fn name(&self) -> &'static str {
+        match self {
+            HandRank::HighCard => "HighCard",
+            HandRank::OnePair => "OnePair",
+            HandRank::TwoPair => "TwoPair",
+            HandRank::ThreeOfAKind => "ThreeOfAKind",
+            HandRank::Straight => "Straight",
+            HandRank::Flush => "Flush",
+            HandRank::FullHouse => "FullHouse",
+            HandRank::FourOfAKind => "FourOfAKind",
+            HandRank::StraightFlush => "StraightFlush",
+        }
+    }
+
+    /// Returns the lowest and the highest score a hand of the category can
+    /// have.
+    ///
+    /// Hands of fewer than five cards are included, so the lowest high card
+    /// is "3 2" and the lowest four of a kind has no kicker. The ranges of
+    /// the categories never overlap and are ordered like the categories.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::{Hand, HandRank};
+    ///
+    /// let range = HandRank::Flush.score_range();
+    /// assert!(range.contains(&Hand::new_from_str("As Js 9s 6s 3s").unwrap().get_score()));
+    /// assert!(range.end() < HandRank::FullHouse.score_range().start());
+    /// ```
+    pub fn score_range(&self) -> RangeInclusive<u32> {
+        use Rank::*;
+
+        match self {
+            HandRank::HighCard => {
+                score_high_card(&[Three, Two])..=score_high_card(&[Ace, King, Queen, Jack, Nine])
+            }
+            HandRank::OnePair => score_pair(Two, &[])..=score_pair(Ace, &[King, Queen, Jack]),
+            HandRank::TwoPair => {
+                score_two_pair(Three, Two, None)..=score_two_pair(Ace, King, Some(Queen))
+            }
+            HandRank::ThreeOfAKind => score_trips(Two, &[])..=score_trips(Ace, &[King, Queen]),
+            HandRank::Straight => score_straight(Five)..=score_straight(Ace),
+            HandRank::Flush => {
+                score_flush(&[Seven, Five, Four, Three, Two])
+                    ..=score_flush(&[Ace, King, Queen, Jack, Nine])
+            }
+            HandRank::FullHouse => score_full_house(Two, Three)..=score_full_house(Ace, King),
+            HandRank::FourOfAKind => score_quads(Two, None)..=score_quads(Ace, Some(King)),
+            HandRank::StraightFlush => score_straight_flush(Five)..=score_straight_flush(Ace),
+        }
+    }
+}
+
+impl TryFrom<u8> for HandRank {
+    type Error = HandError;
+
+    /// Converts an id returned by `HandRank::id` into its category.
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        HandRank::from_id(id).ok_or(HandError::InvalidHandRankId(id))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HandRank {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_unit_variant("HandRank", self.id() as u32, self.name())
+        } else {
+            serializer.serialize_u8(self.id())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HandRank {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HandRankVisitor)
+        } else {
+            deserializer.deserialize_u8(HandRankVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct HandRankVisitor;
+
+#[cfg(feature = "serde")]
+impl serde::de::Visitor<'_> for HandRankVisitor {
+    type Value = HandRank;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "a hand rank like \"Flush\" or its id")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<HandRank, E> {
+        (0..9)
+            .filter_map(HandRank::from_id)
+            .find(|rank| rank.name() == s)
+            .ok_or_else(|| E::custom(alloc::format!("Invalid hand rank: {}", s)))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, id: u64) -> Result<HandRank, E> {
+        u8::try_from(id)
+            .ok()
+            .and_then(HandRank::from_id)
+            .ok_or_else(|| E::custom(alloc::format!("Invalid hand rank id: {}", id)))
+    }
+}
+
+/// Scores one pair with up to three kickers in descending order.
 ///
-/// This score is computed by adding the value of the hand's rank (represented
-/// by a value from the `HandRank` enum) to the score of the card ranks (computed
-/// by the `calculate_rank_score` function).
+/// # Panics
 ///
-/// This scoring system is able to account for all possible poker hands, as
-/// it allows for differentiating between hands with different combinations
-/// of ranks and for identifying the relative value of hands with the same
-/// combination of ranks.
+/// Panics if there are more than three kickers. In debug builds, also
+/// panics if the kickers are not distinct ranks in descending order
+/// besides the pair.
+pub(crate) fn score_pair(pair: Rank, kickers: &[Rank]) -> u32 {
+    assert!(
+        kickers.len() <= 3,
+        "One pair has at most 3 kickers, got {}",
+        kickers.len()
+    );
+    debug_assert!(is_desc_nodup(kickers) && !kickers.contains(&pair));
+    HandRank::OnePair as u32 + pack([pair].iter().chain(kickers))
+}
+
+/// Scores two pair with an optional kicker.
 ///
-/// # Arguments
+/// # Panics
 ///
-/// * `ranks` - A vector of card ranks, not necessarily in order.
-/// * `hand_rank` - The rank of the hand, as a value from the `HandRank` enum.
+/// In debug builds, panics if the ranks are not three distinct ranks with
+/// `high` above `low`.
+pub(crate) fn score_two_pair(high: Rank, low: Rank, kicker: Option<Rank>) -> u32 {
+    debug_assert!(high > low && kicker.is_none_or(|k| k != high && k != low));
+    HandRank::TwoPair as u32 + pack([high, low].iter().chain(kicker.as_ref()))
+}
+
+/// Scores three of a kind with up to two kickers in descending order.
 ///
-/// # Returns
+/// # Panics
 ///
-/// * The final score of the hand as an u32 integer.
-pub fn calculate_hand_score(ranks: Vec<Rank>, hand_rank: HandRank) -> u32 {
-    let rank_score = calculate_rank_score(ranks);
-    let hand_score = hand_rank as u32;
+/// Panics if there are more than two kickers. In debug builds, also panics
+/// if the kickers are not distinct ranks in descending order besides the
+/// three of a kind.
+pub(crate) fn score_trips(trips: Rank, kickers: &[Rank]) -> u32 {
+    assert!(
+        kickers.len() <= 2,
+        "Three of a kind has at most 2 kickers, got {}",
+        kickers.len()
+    );
+    debug_assert!(is_desc_nodup(kickers) && !kickers.contains(&trips));
+    HandRank::ThreeOfAKind as u32 + pack([trips].iter().chain(kickers))
+}
 
-    hand_score + rank_score
+/// Scores a straight by the rank of its highest card, `Rank::Five` for the
+/// wheel.
+pub(crate) fn score_straight(high: Rank) -> u32 {
+    HandRank::Straight as u32 + pack(&[high])
 }
 
-/// Calculates the score from a list of card ranks.
-///
-/// This score is computed by shifting a 32-bit integer left by 4 bits for each
-/// rank in the list, and then adding the numeric value of the rank to the
-/// integer.
-///
-/// This process effectively converts a list of ranks into a single number that
-/// represents the order and frequency of ranks in the list.
+/// Scores a flush by its five ranks in descending order.
 ///
-/// This scoring system is able to account for all possible poker hands, as
-/// it allows for differentiating between hands with different combinations
-/// of ranks and for identifying the relative value of hands with the same
-/// combination of ranks.
+/// # Panics
 ///
-/// It is assumed that ranks are passed in an order that represents the desired
-/// priority for scoring. For example for a full house "2, 2, 2, A, A", the Ace
-/// should come before the 2 in the ranks list, etc. Note that this function
-/// does not sort the ranks before calculating the score.
+/// In debug builds, panics if the ranks are not distinct and descending.
+pub(crate) fn score_flush(ranks: &[Rank; 5]) -> u32 {
+    debug_assert!(is_desc_nodup(ranks));
+    HandRank::Flush as u32 + pack(ranks)
+}
+
+/// Scores a full house by the ranks of its three of a kind and its pair.
 ///
-/// # Arguments
+/// # Panics
 ///
-/// * `ranks` - A vector of card ranks, not necessarily in order.
+/// In debug builds, panics if the ranks are equal.
+pub(crate) fn score_full_house(trips: Rank, pair: Rank) -> u32 {
+    debug_assert_ne!(trips, pair);
+    HandRank::FullHouse as u32 + pack(&[trips, pair])
+}
+
+/// Scores four of a kind with an optional kicker.
 ///
-/// # Returns
+/// # Panics
 ///
-/// * The score of the ranks as an u32 integer.
-///   If the list of ranks is empty, returns 0.
-fn calculate_rank_score(ranks: Vec<Rank>) -> u32 {
-    if ranks.is_empty() {
-        return 0;
-    }
+/// In debug builds, panics if the kicker has the rank of the four of a
+/// kind.
+pub(crate) fn score_quads(quads: Rank, kicker: Option<Rank>) -> u32 {
+    debug_assert_ne!(Some(quads), kicker);
+    HandRank::FourOfAKind as u32 + pack([quads].iter().chain(kicker.as_ref()))
+}
 
-    let mut score: u32 = 0;
+/// Scores a straight flush by the rank of its highest card, `Rank::Five`
+/// for the steel wheel.
+pub(crate) fn score_straight_flush(high: Rank) -> u32 {
+    HandRank::StraightFlush as u32 + pack(&[high])
+}
 
-    for rank in ranks.into_iter() {
-        score = (score << 4) | (rank as u32);
-    }
+/// Scores up to five unpaired ranks in descending order.
+///
+/// # Panics
+///
+/// Panics if there are more than five ranks. In debug builds, also panics
+/// if the ranks are not distinct and descending.
+pub(crate) fn score_high_card(ranks: &[Rank]) -> u32 {
+    assert!(
+        ranks.len() <= 5,
+        "A high card hand has at most 5 ranks, got {}",
+        ranks.len()
+    );
+    debug_assert!(is_desc_nodup(ranks));
+    HandRank::HighCard as u32 + pack(ranks)
+}
 
-    score
+/// Packs the ranks into the nibbles of a score, the first rank into the
+/// most significant one.
+fn pack<'a>(ranks: impl IntoIterator<Item = &'a Rank>) -> u32 {
+    ranks
+        .into_iter()
+        .fold(0, |score, &rank| (score << 4) | rank as u32)
 }
 
 #[cfg(test)]
@@ -91,42 +345,139 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_calculate_rank_score() {
+    fn test_pack() {
         // check non-empty list of ranks
-        let ranks = vec![Rank::Ace, Rank::Ace, Rank::Ace, Rank::Ace, Rank::King];
-        assert_eq!(calculate_rank_score(ranks), 978669);
+        let ranks = [Rank::Ace, Rank::Ace, Rank::Ace, Rank::Ace, Rank::King];
+        assert_eq!(pack(&ranks), 978669);
 
-        let ranks = vec![Rank::Ace, Rank::Ace, Rank::Ace, Rank::Ace, Rank::Queen];
-        assert_eq!(calculate_rank_score(ranks), 978668);
+        let ranks = [Rank::Ace, Rank::Ace, Rank::Ace, Rank::Ace, Rank::Queen];
+        assert_eq!(pack(&ranks), 978668);
 
-        let ranks = vec![Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Ten];
-        assert_eq!(calculate_rank_score(ranks), 974010);
+        let ranks = [Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Ten];
+        assert_eq!(pack(&ranks), 974010);
 
-        let ranks = vec![Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Nine];
-        assert_eq!(calculate_rank_score(ranks), 974009);
+        let ranks = [Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Nine];
+        assert_eq!(pack(&ranks), 974009);
 
-        let score = calculate_rank_score(vec![Rank::Ace, Rank::King, Rank::Queen]);
+        let score = pack(&[Rank::Ace, Rank::King, Rank::Queen]);
         assert_eq!(score, 0b1110_1101_1100);
 
-        let score = calculate_rank_score(vec![Rank::Two, Rank::Three, Rank::Four]);
+        let score = pack(&[Rank::Two, Rank::Three, Rank::Four]);
         assert_eq!(score, 0b0010_0011_0100);
 
-        let score = calculate_rank_score(vec![Rank::Ten, Rank::Nine, Rank::Eight]);
+        let score = pack(&[Rank::Ten, Rank::Nine, Rank::Eight]);
         assert_eq!(score, 0b1010_1001_1000);
 
         // check ranks out of order
-        let score = calculate_rank_score(vec![Rank::Two, Rank::Ace, Rank::Three]);
+        let score = pack(&[Rank::Two, Rank::Ace, Rank::Three]);
         assert_eq!(score, 0b0010_1110_0011);
 
         // check with duplicates
-        let score = calculate_rank_score(vec![Rank::Ace, Rank::Ace, Rank::King]);
+        let score = pack(&[Rank::Ace, Rank::Ace, Rank::King]);
         assert_eq!(score, 0b1110_1110_1101);
     }
 
     #[test]
-    fn test_calculate_rank_score_empty() {
+    fn test_hand_rank_from_score() {
+        let score = score_high_card(&[Rank::Ace, Rank::King]);
+        assert_eq!(HandRank::from_score(score), HandRank::HighCard);
+
+        let score = score_full_house(Rank::Two, Rank::Three);
+        assert_eq!(HandRank::from_score(score), HandRank::FullHouse);
+
+        let score = score_straight_flush(Rank::Ace);
+        assert_eq!(HandRank::from_score(score), HandRank::StraightFlush);
+    }
+
+    #[test]
+    fn test_score_constructors() {
+        use Rank::*;
+
+        assert_eq!(score_high_card(&[Ace, King, Nine, Seven, Two]), 0xED972);
+        assert_eq!(score_high_card(&[Ace, King]), 0xED);
+        assert_eq!(score_pair(Queen, &[Ace, Nine, Three]), 1_000_000 + 0xCE93);
+        assert_eq!(score_pair(Queen, &[]), 1_000_000 + 0xC);
+        assert_eq!(score_two_pair(King, Five, Some(Ace)), 2_000_000 + 0xD5E);
+        assert_eq!(score_two_pair(King, Five, None), 2_000_000 + 0xD5);
+        assert_eq!(score_trips(Seven, &[Ace, Two]), 3_000_000 + 0x7E2);
+        assert_eq!(score_straight(Five), 4_000_005);
+        assert_eq!(score_straight(Ace), 4_000_014);
+        assert_eq!(
+            score_flush(&[Ace, Jack, Nine, Six, Three]),
+            5_000_000 + 0xEB963
+        );
+        assert_eq!(score_full_house(Two, Ace), 6_000_000 + 0x2E);
+        assert_eq!(score_quads(Ace, Some(King)), 7_000_237);
+        assert_eq!(score_straight_flush(Ace), 8_000_014);
+    }
+
+    #[test]
+    fn test_score_constructors_order_categories() {
+        use Rank::*;
+
+        // The best hand of every category loses to the worst of the next.
+        let ladder = [
+            (
+                score_high_card(&[Ace, King, Queen, Jack, Nine]),
+                score_pair(Two, &[Five, Four, Three]),
+            ),
+            (
+                score_pair(Ace, &[King, Queen, Jack]),
+                score_two_pair(Three, Two, Some(Four)),
+            ),
+            (
+                score_two_pair(Ace, King, Some(Queen)),
+                score_trips(Two, &[Four, Three]),
+            ),
+            (score_trips(Ace, &[King, Queen]), score_straight(Five)),
+            (
+                score_straight(Ace),
+                score_flush(&[Seven, Five, Four, Three, Two]),
+            ),
+            (
+                score_flush(&[Ace, King, Queen, Jack, Nine]),
+                score_full_house(Two, Three),
+            ),
+            (score_full_house(Ace, King), score_quads(Two, Some(Three))),
+            (score_quads(Ace, Some(King)), score_straight_flush(Five)),
+        ];
+        for (best, next_worst) in ladder {
+            assert!(best < next_worst, "{} {}", best, next_worst);
+        }
+
+        // Within a category, the made ranks outweigh every kicker of hands
+        // with as many cards.
+        assert!(score_pair(Three, &[Five, Four, Two]) > score_pair(Two, &[Ace, King, Queen]));
+        assert!(score_two_pair(King, Three, Some(Two)) > score_two_pair(Queen, Jack, Some(Ace)));
+        assert!(score_quads(Three, Some(Two)) > score_quads(Two, Some(Ace)));
+        // A partial hand scores below the same hand with another kicker.
+        assert!(score_pair(Queen, &[Ace]) < score_pair(Queen, &[Ace, Two]));
+    }
+
+    #[test]
+    #[should_panic(expected = "at most 3 kickers")]
+    fn test_score_pair_validates_kickers() {
+        use Rank::*;
+
+        score_pair(Two, &[Ace, King, Queen, Jack]);
+    }
+
+    #[test]
+    fn test_pack_empty() {
         // check empty list of ranks
-        let result = calculate_rank_score(vec![]);
+        let result = pack(&[]);
         assert_eq!(result, 0);
     }
+
+    #[test]
+    fn test_ids() {
+        use strum::IntoEnumIterator;
+
+        for (id, rank) in HandRank::iter().enumerate() {
+            assert_eq!(rank.id() as usize, id);
+            assert_eq!(rank.id() as usize, rank.to_index());
+            assert_eq!(HandRank::try_from(rank.id()), Ok(rank));
+        }
+        assert_eq!(HandRank::from_id(9), None);
+    }
 }
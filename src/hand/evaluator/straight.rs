@@ -1,5 +1,9 @@
+use alloc::vec::Vec;
+
 use crate::card::Rank;
 
+use super::preconditions::{is_desc_nodup, sorted_desc};
+
 /// Finds in a descending ordered and duplicate-free rank vector straight or
 /// returns None.
 ///
@@ -9,16 +13,38 @@ use crate::card::Rank;
 /// A special case, Ace low straight (Five, Four, Three, Two, Ace), is also
 /// handled by this function.
 ///
+/// # Preconditions
+///
+/// The ranks must be sorted in descending order without duplicates, which is
+/// checked in debug builds. Use `find_straight_unsorted` for ranks in any
+/// order and with duplicates.
+///
 /// # Arguments
 ///
-/// * `ranks_desc` - A vector of `Rank` values sorted in descending order and
+/// * `ranks_desc_nodup` - A slice of `Rank` values sorted in descending order and
 ///   without duplicates.
 ///
 /// # Returns
 ///
 /// * An `Option<Rank>` which is `Some(Rank)` of the highest card in the
 ///   straight if a straight is found, or `None` if no straight is found.
-pub fn find_straight(ranks_desc_nodup: &Vec<Rank>) -> Option<Rank> {
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Rank::*;
+/// use pkr::hand::detectors::find_straight;
+///
+/// assert_eq!(find_straight(&[King, Nine, Eight, Seven, Six, Five]), Some(Nine));
+/// assert_eq!(find_straight(&[Ace, Five, Four, Three, Two]), Some(Five));
+/// assert_eq!(find_straight(&[Ace, King, Queen, Jack, Nine]), None);
+/// ```
+pub fn find_straight(ranks_desc_nodup: &[Rank]) -> Option<Rank> {
+    debug_assert!(
+        is_desc_nodup(ranks_desc_nodup),
+        "Ranks must be sorted in descending order without duplicates"
+    );
+
     let ranks_len = ranks_desc_nodup.len();
 
     if ranks_len < 5 {
@@ -40,5 +66,52 @@ pub fn find_straight(ranks_desc_nodup: &Vec<Rank>) -> Option<Rank> {
         return Some(Rank::Five);
     }
 
-    return None;
+    None
+}
+
+/// Finds a straight like `find_straight`, for ranks in any order and with
+/// duplicates.
+pub fn find_straight_unsorted(ranks: &[Rank]) -> Option<Rank> {
+    let mut ranks: Vec<Rank> = sorted_desc(ranks);
+    ranks.dedup();
+    find_straight(&ranks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+
+    #[test]
+    fn test_straight() {
+        assert_eq!(find_straight(&[Ace, King, Queen, Jack, Ten]), Some(Ace));
+        assert_eq!(
+            find_straight(&[Ace, King, Queen, Jack, Ten, Nine, Eight]),
+            Some(Ace)
+        );
+        assert_eq!(
+            find_straight(&[Jack, Nine, Eight, Seven, Six, Five, Two]),
+            Some(Nine)
+        );
+    }
+
+    #[test]
+    fn test_wheel() {
+        assert_eq!(find_straight(&[Ace, Five, Four, Three, Two]), Some(Five));
+        assert_eq!(
+            find_straight(&[Ace, Six, Five, Four, Three, Two]),
+            Some(Six)
+        );
+        assert_eq!(find_straight(&[Ace, King, Five, Four, Three]), None);
+    }
+
+    #[test]
+    fn test_unsorted() {
+        assert_eq!(
+            find_straight_unsorted(&[Two, Ace, Four, Four, Five, Three]),
+            Some(Five)
+        );
+        assert_eq!(find_straight_unsorted(&[Two, Ace, Four, Four, Five]), None);
+        assert_eq!(find_straight_unsorted(&[]), None);
+    }
 }
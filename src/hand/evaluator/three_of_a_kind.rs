@@ -1,17 +1,50 @@
+use alloc::vec::Vec;
+
 use crate::card::Rank;
 
+use super::preconditions::{is_desc, sorted_desc};
+use super::rank_list::RankList;
+
 /// Finds the highest three of a kind and the kickers in descending order from
 /// the provided ranks in descending order.
 ///
+/// # Preconditions
+///
+/// The ranks must be sorted in descending order, which is checked in debug
+/// builds. Use `find_three_of_a_kind_unsorted` for ranks in any order.
+///
 /// # Arguments
 ///
-/// * `ranks_desc` - A vector of `Rank` values sorted in descending order.
+/// * `ranks_desc` - A slice of `Rank` values sorted in descending order.
 ///
 /// # Returns
 ///
 /// * `Some(Vec<Rank>)` - The highest three of a kind and the kickers in
 ///   descending order if found or `None` if not found.
-pub fn find_three_of_a_kind(ranks_desc: &Vec<Rank>) -> Option<Vec<Rank>> {
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Rank::*;
+/// use pkr::hand::detectors::find_three_of_a_kind;
+///
+/// assert_eq!(
+///     find_three_of_a_kind(&[Ace, Six, Six, Six, Four, Two]),
+///     Some(vec![Six, Ace, Four])
+/// );
+/// assert_eq!(find_three_of_a_kind(&[Ace, Six, Six, Four, Two]), None);
+/// ```
+pub fn find_three_of_a_kind(ranks_desc: &[Rank]) -> Option<Vec<Rank>> {
+    three_of_a_kind_ranks(ranks_desc).map(|ranks| ranks.to_vec())
+}
+
+/// Finds three of a kind like `find_three_of_a_kind` without allocating.
+pub(super) fn three_of_a_kind_ranks(ranks_desc: &[Rank]) -> Option<RankList> {
+    debug_assert!(
+        is_desc(ranks_desc),
+        "Ranks must be sorted in descending order"
+    );
+
     let ranks_len = ranks_desc.len();
     if ranks_len < 3 {
         return None;
@@ -19,19 +52,18 @@ pub fn find_three_of_a_kind(ranks_desc: &Vec<Rank>) -> Option<Vec<Rank>> {
 
     for i in 0..ranks_len - 2 {
         if ranks_desc[i] == ranks_desc[i + 2] {
-            let mut result = vec![ranks_desc[i]];
+            let mut result: RankList = [ranks_desc[i]].into_iter().collect();
             if ranks_len == 3 {
                 return Some(result);
             } else if ranks_len == 4 {
                 result.push(ranks_desc[(i + 3) % ranks_len]);
                 return Some(result);
             } else {
-                let kickers: Vec<Rank> = ranks_desc
+                let kickers = ranks_desc
                     .iter()
                     .filter(|&&rank| rank != ranks_desc[i])
                     .take(2) // Take the highest two kickers
-                    .copied()
-                    .collect();
+                    .copied();
                 result.extend(kickers);
                 return Some(result);
             }
@@ -40,3 +72,46 @@ pub fn find_three_of_a_kind(ranks_desc: &Vec<Rank>) -> Option<Vec<Rank>> {
 
     None
 }
+
+/// Finds three of a kind like `find_three_of_a_kind`, for ranks in any
+/// order.
+pub fn find_three_of_a_kind_unsorted(ranks: &[Rank]) -> Option<Vec<Rank>> {
+    find_three_of_a_kind(&sorted_desc(ranks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+
+    #[test]
+    fn test_three_of_a_kind() {
+        assert_eq!(
+            find_three_of_a_kind(&[Jack, Jack, Jack, Eight, Three]),
+            Some(vec![Jack, Eight, Three])
+        );
+        assert_eq!(
+            find_three_of_a_kind(&[King, Queen, Five, Five, Five, Four, Two]),
+            Some(vec![Five, King, Queen])
+        );
+    }
+
+    #[test]
+    fn test_short_hands() {
+        assert_eq!(find_three_of_a_kind(&[Two, Two, Two]), Some(vec![Two]));
+        assert_eq!(
+            find_three_of_a_kind(&[Ace, Two, Two, Two]),
+            Some(vec![Two, Ace])
+        );
+        assert_eq!(find_three_of_a_kind(&[Two, Two]), None);
+    }
+
+    #[test]
+    fn test_unsorted() {
+        assert_eq!(
+            find_three_of_a_kind_unsorted(&[Nine, Ace, Nine, Two, Nine]),
+            Some(vec![Nine, Ace, Two])
+        );
+        assert_eq!(find_three_of_a_kind_unsorted(&[Nine, Ace, Nine, Two]), None);
+    }
+}
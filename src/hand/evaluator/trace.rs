@@ -0,0 +1,304 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::card::Rank;
+use crate::hand::Hand;
+
+use super::evaluator::evaluate_cards_with;
+
+/// Evaluates a hand like `Hand::get_score` and records the decision path of
+/// the evaluator.
+///
+/// Tracing only costs anything when this function is called, the untraced
+/// evaluation does not record or format anything.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::hand::{evaluate_traced, Hand};
+///
+/// let hand = Hand::new_from_str("Ks Qs Ts 8s 6s 6d 2c").unwrap();
+/// let (score, trace) = evaluate_traced(&hand);
+/// assert_eq!(score, hand.get_score());
+/// assert_eq!(
+///     trace.to_string(),
+///     "find_flush(7 cards) → Some([K, Q, T, 8, 6])\n\
+///      find_straight(flush ranks) → None\n\
+///      find_straight skipped (flush)\n\
+///      classify_counts(7 ranks) → Flush"
+/// );
+/// ```
+pub fn evaluate_traced(hand: &Hand) -> (u32, EvalTrace) {
+    let mut trace = EvalTrace::default();
+    let score = evaluate_cards_with(hand.as_slice(), &mut trace);
+    (score, trace)
+}
+
+/// The detectors the evaluator invoked or skipped, in order, see
+/// `evaluate_traced`.
+///
+/// Displays one step per line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EvalTrace {
+    steps: Vec<TraceStep>,
+}
+
+impl EvalTrace {
+    /// Returns the steps in the order the evaluator took them.
+    pub fn steps(&self) -> &[TraceStep] {
+        &self.steps
+    }
+}
+
+impl fmt::Display for EvalTrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, step) in self.steps.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", step)?;
+        }
+        Ok(())
+    }
+}
+
+/// A single step of an `EvalTrace`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceStep {
+    /// A detector was invoked on the described input and returned the
+    /// described output.
+    Ran {
+        detector: &'static str,
+        input: String,
+        output: String,
+    },
+    /// A detector was not invoked because its hand could not be the best.
+    Skipped {
+        detector: &'static str,
+        reason: String,
+    },
+}
+
+impl fmt::Display for TraceStep {
+    /// Formats the step, e.g. "find_straight(5 distinct ranks) → Some(9)" or
+    /// "find_straight skipped (flush)".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TraceStep::Ran {
+                detector,
+                input,
+                output,
+            } => write!(f, "{}({}) → {}", detector, input, output),
+            TraceStep::Skipped { detector, reason } => {
+                write!(f, "{} skipped ({})", detector, reason)
+            }
+        }
+    }
+}
+
+/// Receives the steps of an evaluation.
+///
+/// The descriptions are passed as closures so that the no-op tracer `()`
+/// compiles down to the plain evaluation.
+pub(crate) trait Tracer {
+    fn ran(
+        &mut self,
+        detector: &'static str,
+        input: impl FnOnce() -> String,
+        output: impl FnOnce() -> String,
+    );
+
+    fn skipped(&mut self, detector: &'static str, reason: impl FnOnce() -> String);
+}
+
+impl Tracer for () {
+    #[inline(always)]
+    fn ran(
+        &mut self,
+        _detector: &'static str,
+        _input: impl FnOnce() -> String,
+        _output: impl FnOnce() -> String,
+    ) {
+    }
+
+    #[inline(always)]
+    fn skipped(&mut self, _detector: &'static str, _reason: impl FnOnce() -> String) {}
+}
+
+impl Tracer for EvalTrace {
+    fn ran(
+        &mut self,
+        detector: &'static str,
+        input: impl FnOnce() -> String,
+        output: impl FnOnce() -> String,
+    ) {
+        self.steps.push(TraceStep::Ran {
+            detector,
+            input: input(),
+            output: output(),
+        });
+    }
+
+    fn skipped(&mut self, detector: &'static str, reason: impl FnOnce() -> String) {
+        self.steps.push(TraceStep::Skipped {
+            detector,
+            reason: reason(),
+        });
+    }
+}
+
+/// Describes the output of a detector returning ranks, e.g. "Some([A, K])".
+pub(crate) fn describe_ranks(ranks: Option<&[Rank]>) -> String {
+    match ranks {
+        Some(ranks) => {
+            let ranks: Vec<&str> = ranks.iter().map(Rank::as_str).collect();
+            alloc::format!("Some([{}])", ranks.join(", "))
+        }
+        None => "None".into(),
+    }
+}
+
+/// Describes the output of a detector returning a single rank, e.g.
+/// "Some(A)".
+pub(crate) fn describe_rank(rank: Option<Rank>) -> String {
+    match rank {
+        Some(rank) => alloc::format!("Some({})", rank.as_str()),
+        None => "None".into(),
+    }
+}
+
+/// Reads back the ranks of a detector output described by `describe_ranks`
+/// or `describe_rank`, returning `None` for "None" and for any other output.
+pub(crate) fn parse_ranks(output: &str) -> Option<Vec<Rank>> {
+    let inner = output.strip_prefix("Some(")?.strip_suffix(')')?;
+    let inner = inner
+        .strip_prefix('[')
+        .and_then(|list| list.strip_suffix(']'))
+        .unwrap_or(inner);
+    inner
+        .split(", ")
+        .map(|rank| Rank::new_from_str(rank).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace(s: &str) -> String {
+        let hand = Hand::new_from_str(s).unwrap();
+        let (score, trace) = evaluate_traced(&hand);
+        assert_eq!(score, hand.get_score());
+        trace.to_string()
+    }
+
+    #[test]
+    fn test_parse_ranks() {
+        let ranks = [Rank::Ace, Rank::Ten, Rank::Two];
+        assert_eq!(
+            parse_ranks(&describe_ranks(Some(&ranks))),
+            Some(ranks.to_vec())
+        );
+        assert_eq!(
+            parse_ranks(&describe_rank(Some(Rank::Nine))),
+            Some(vec![Rank::Nine])
+        );
+        assert_eq!(parse_ranks(&describe_ranks(None)), None);
+        assert_eq!(parse_ranks("FullHouse"), None);
+    }
+
+    #[test]
+    fn test_straight_flush() {
+        assert_eq!(
+            trace("9h 8h 7h 6h 5h Ad"),
+            "find_flush(6 cards) → Some([9, 8, 7, 6, 5])\n\
+             find_straight(flush ranks) → Some(9)"
+        );
+    }
+
+    #[test]
+    fn test_four_of_a_kind() {
+        assert_eq!(
+            trace("7s 7h 7c 7d Ks 2s"),
+            "find_flush(6 cards) → None\n\
+             find_straight(3 distinct ranks) → None\n\
+             classify_counts(6 ranks) → FourOfAKind\n\
+             find_four_of_a_kind(6 ranks) → Some([7, K])"
+        );
+    }
+
+    #[test]
+    fn test_full_house() {
+        assert_eq!(
+            trace("Ks Kh Kd 7c 7d 2s"),
+            "find_flush(6 cards) → None\n\
+             find_straight(3 distinct ranks) → None\n\
+             classify_counts(6 ranks) → FullHouse\n\
+             find_full_house(6 ranks) → Some([K, 7])"
+        );
+    }
+
+    #[test]
+    fn test_flush() {
+        assert_eq!(
+            trace("Ks Qs Ts 8s 6s 6d 2c"),
+            "find_flush(7 cards) → Some([K, Q, T, 8, 6])\n\
+             find_straight(flush ranks) → None\n\
+             find_straight skipped (flush)\n\
+             classify_counts(7 ranks) → Flush"
+        );
+    }
+
+    #[test]
+    fn test_straight() {
+        assert_eq!(
+            trace("9s 8h 7c 6d 5s"),
+            "find_flush(5 cards) → None\n\
+             find_straight(5 distinct ranks) → Some(9)\n\
+             classify_counts(5 ranks) → Straight"
+        );
+    }
+
+    #[test]
+    fn test_three_of_a_kind() {
+        assert_eq!(
+            trace("Qs Qh Qc 7d 2s"),
+            "find_flush(5 cards) → None\n\
+             find_straight(3 distinct ranks) → None\n\
+             classify_counts(5 ranks) → ThreeOfAKind\n\
+             find_three_of_a_kind(5 ranks) → Some([Q, 7, 2])"
+        );
+    }
+
+    #[test]
+    fn test_two_pair() {
+        assert_eq!(
+            trace("Qs Qh 7c 7d 2s"),
+            "find_flush(5 cards) → None\n\
+             find_straight(3 distinct ranks) → None\n\
+             classify_counts(5 ranks) → TwoPair\n\
+             find_two_pair(5 ranks) → Some([Q, 7, 2])"
+        );
+    }
+
+    #[test]
+    fn test_one_pair() {
+        assert_eq!(
+            trace("Qs Qh 7c 4d 2s"),
+            "find_flush(5 cards) → None\n\
+             find_straight(4 distinct ranks) → None\n\
+             classify_counts(5 ranks) → OnePair\n\
+             find_pair(5 ranks) → Some([Q, 7, 4, 2])"
+        );
+    }
+
+    #[test]
+    fn test_high_card() {
+        let trace = trace("As Qh 7c 4d 2s");
+        assert!(trace.ends_with(
+            "find_straight(5 distinct ranks) → None\n\
+             classify_counts(5 ranks) → HighCard"
+        ));
+    }
+}
@@ -1,46 +1,140 @@
+use alloc::vec::Vec;
+
 use crate::card::Rank;
 
-/// Finds the two pairs and the kicker in descending order from the provided
-/// ranks in descending order.
+use super::preconditions::{is_desc, sorted_desc};
+use super::rank_list::RankList;
+
+/// Finds the two highest pairs and the kicker in descending order from the
+/// provided ranks in descending order.
+///
+/// Only ranks that appear exactly twice are pairs. Ranks that appear three
+/// or more times are skipped, so the function gives a meaningful result even
+/// if it is called without checking for three of a kind first. With three
+/// pairs the two highest pairs are used and the third pair may provide the
+/// kicker.
+///
+/// # Preconditions
+///
+/// The ranks must be sorted in descending order, which is checked in debug
+/// builds. Use `find_two_pair_unsorted` for ranks in any order.
 ///
 /// # Arguments
 ///
-/// * `ranks_desc` - A vector of `Rank` values sorted in descending order.
+/// * `ranks_desc` - A slice of `Rank` values sorted in descending order.
 ///
 /// # Returns
 ///
-/// * `Some(Vec<Rank>)` - The two pairs and the kicker in descending order if
-///   found, or `None` if not found.
-pub fn find_two_pair(ranks_desc: &Vec<Rank>) -> Option<Vec<Rank>> {
-    let ranks_len = ranks_desc.len();
+/// * `Some(Vec<Rank>)` - The high pair, the low pair and, for five or more
+///   ranks, the highest kicker, or `None` if there are fewer than two pairs.
+///   Four ranks have no kicker.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Rank::*;
+/// use pkr::hand::detectors::find_two_pair;
+///
+/// assert_eq!(
+///     find_two_pair(&[Ace, Ace, King, King, Queen, Queen, Jack]),
+///     Some(vec![Ace, King, Queen])
+/// );
+/// assert_eq!(find_two_pair(&[Ace, Ace, King, Queen, Jack]), None);
+/// ```
+pub fn find_two_pair(ranks_desc: &[Rank]) -> Option<Vec<Rank>> {
+    two_pair_ranks(ranks_desc).map(|ranks| ranks.to_vec())
+}
+
+/// Finds two pair like `find_two_pair` without allocating.
+pub(super) fn two_pair_ranks(ranks_desc: &[Rank]) -> Option<RankList> {
+    debug_assert!(
+        is_desc(ranks_desc),
+        "Ranks must be sorted in descending order"
+    );
 
-    if ranks_len < 4 {
+    let mut result: RankList = ranks_desc
+        .chunk_by(|a, b| a == b)
+        .filter(|group| group.len() == 2)
+        .map(|group| group[0])
+        .take(2)
+        .collect();
+    if result.len() < 2 {
         return None;
     }
 
-    let mut result = Vec::new();
+    if ranks_desc.len() > 4 {
+        let kicker = ranks_desc
+            .iter()
+            .find(|rank| !result.contains(rank))
+            .copied()
+            .expect("Five ranks with two pairs have a kicker");
+        result.push(kicker);
+    }
+    Some(result)
+}
+
+/// Finds two pair like `find_two_pair`, for ranks in any order.
+pub fn find_two_pair_unsorted(ranks: &[Rank]) -> Option<Vec<Rank>> {
+    find_two_pair(&sorted_desc(ranks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Rank::*;
+
+    #[test]
+    fn test_three_pairs() {
+        assert_eq!(
+            find_two_pair(&[Ace, Ace, King, King, Queen, Queen, Jack]),
+            Some(vec![Ace, King, Queen])
+        );
+        assert_eq!(
+            find_two_pair(&[Ace, Ace, King, King, Queen, Queen, Two]),
+            Some(vec![Ace, King, Queen])
+        );
+        assert_eq!(
+            find_two_pair(&[Ace, King, King, Queen, Queen, Three, Three]),
+            Some(vec![King, Queen, Ace])
+        );
+    }
+
+    #[test]
+    fn test_trips_are_not_pairs() {
+        assert_eq!(find_two_pair(&[Seven, Seven, Seven, Five, Five]), None);
+        assert_eq!(
+            find_two_pair(&[Ace, Seven, Seven, Seven, Five, Five, Three, Three]),
+            Some(vec![Five, Three, Ace])
+        );
+        assert_eq!(
+            find_two_pair(&[Nine, Nine, Nine, Nine, Five, Five, Three]),
+            None
+        );
+    }
 
-    for i in 0..ranks_len - 1 {
-        if ranks_desc[i] == ranks_desc[i + 1] {
-            result.push(ranks_desc[i]);
-            if result.len() == 2 {
-                break;
-            }
-        }
+    #[test]
+    fn test_kicker() {
+        assert_eq!(
+            find_two_pair(&[Ace, Ace, King, King]),
+            Some(vec![Ace, King])
+        );
+        assert_eq!(
+            find_two_pair(&[Ace, Ace, King, King, Two]),
+            Some(vec![Ace, King, Two])
+        );
+        assert_eq!(
+            find_two_pair(&[Queen, Jack, Jack, Four, Four, Three, Two]),
+            Some(vec![Jack, Four, Queen])
+        );
+        assert_eq!(find_two_pair(&[Ace, Ace, King, Queen, Jack]), None);
     }
 
-    if result.len() == 2 {
-        if ranks_len > 4 {
-            let kicker: Vec<Rank> = ranks_desc
-                .iter()
-                .filter(|&&rank| !result.contains(&rank))
-                .take(1) // Take the highest kicker
-                .copied()
-                .collect();
-            result.extend(kicker);
-        }
-        Some(result)
-    } else {
-        None
+    #[test]
+    fn test_unsorted() {
+        assert_eq!(
+            find_two_pair_unsorted(&[Two, King, Nine, Two, King]),
+            Some(vec![King, Two, Nine])
+        );
+        assert_eq!(find_two_pair_unsorted(&[Two, King, Nine, Two]), None);
     }
 }
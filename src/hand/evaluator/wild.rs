@@ -0,0 +1,295 @@
+use alloc::vec::Vec;
+
+use crate::card::{Card, Rank, Suit};
+use crate::hand::Hand;
+
+use super::fast::{mask_ranks, score};
+use super::score::HandRank;
+
+/// The category of five of a kind, which only wild cards can make.
+///
+/// Five of a kind ranks above every straight flush, with the rank value of
+/// the five cards added, so five aces score `FIVE_OF_A_KIND + 14`. Since the
+/// standard categories of `HandRank` have no five of a kind,
+/// `HandRank::from_score` reports these scores as a `StraightFlush`.
+pub const FIVE_OF_A_KIND: u32 = 9_000_000;
+
+/// The cards that are wild in a hand, see `evaluate_with_wild_set`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WildSpec {
+    /// The given cards are wild.
+    Cards(Vec<Card>),
+    /// Every card of the rank is wild, e.g. the deuces in deuces wild.
+    Rank(Rank),
+    /// Every card of the suit is wild.
+    Suit(Suit),
+}
+
+impl WildSpec {
+    /// Returns whether the card is wild.
+    pub fn is_wild(&self, card: &Card) -> bool {
+        match self {
+            WildSpec::Cards(cards) => cards.contains(card),
+            WildSpec::Rank(rank) => card.rank == *rank,
+            WildSpec::Suit(suit) => card.suit == *suit,
+        }
+    }
+}
+
+/// Scores the best hand that can be made from the cards when the cards of
+/// the wild set can stand for any card.
+///
+/// A wild card may stand for a card already in the hand, which makes five
+/// of a kind possible, see `FIVE_OF_A_KIND`. Other scores are the same as
+/// those of `Hand::get_score`, so a hand without wild cards scores exactly
+/// like it does without a wild set. Instead of trying every substitution,
+/// every category from five of a kind down is checked for whether the wild
+/// cards can complete it, and the first one that can is completed with the
+/// highest ranks.
+///
+/// If every card is wild, a hand of five or more cards is five aces and a
+/// smaller hand is aces of its size. Wild cards beyond the fifth cannot
+/// improve a five card hand and are ignored.
+///
+/// # Arguments
+///
+/// * `hand` - The hand to evaluate.
+/// * `wild` - The cards that are wild.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Rank;
+/// use pkr::hand::{evaluate_with_wild_set, Hand, HandRank, WildSpec, FIVE_OF_A_KIND};
+///
+/// let deuces = WildSpec::Rank(Rank::Two);
+///
+/// let hand = Hand::new_from_str("Ks Kh 2c 2d 7s").unwrap();
+/// let score = evaluate_with_wild_set(&hand, &deuces);
+/// assert_eq!(HandRank::from_score(score), HandRank::FourOfAKind);
+///
+/// let hand = Hand::new_from_str("Ks Kh 2c 2d 2s").unwrap();
+/// assert_eq!(evaluate_with_wild_set(&hand, &deuces), FIVE_OF_A_KIND + 13);
+/// ```
+pub fn evaluate_with_wild_set(hand: &Hand, wild: &WildSpec) -> u32 {
+    let (wilds, naturals): (Vec<Card>, Vec<Card>) =
+        hand.iter().partition(|card| wild.is_wild(card));
+    score_with_wilds(&naturals, wilds.len())
+}
+
+/// Scores the natural cards completed by the given number of wild cards.
+fn score_with_wilds(naturals: &[Card], wilds: usize) -> u32 {
+    let total = naturals.len() + wilds;
+    let wilds = wilds.min(5) as u32;
+
+    // Bit `r` of a mask is set for a card of rank value `r`.
+    let mut suit_masks = [0u16; 4];
+    let mut counts = [0u32; 15];
+    for card in naturals {
+        let rank = card.rank as usize;
+        suit_masks[card.suit.to_index()] |= 1 << rank;
+        counts[rank] += 1;
+    }
+    let ranks_mask = suit_masks.iter().fold(0, |mask, suit| mask | suit);
+
+    // The wild cards needed to hold `count` cards of the rank.
+    let needed = |rank: u32, count: u32| count.saturating_sub(counts[rank as usize]);
+    let ranks_desc = || (2..=14u32).rev();
+    // The natural ranks except the given ones, from the highest down.
+    let kickers = |except: &[u32]| {
+        let except = except.to_vec();
+        ranks_desc().filter(move |&r| counts[r as usize] > 0 && !except.contains(&r))
+    };
+
+    if total >= 5 {
+        if let Some(rank) = ranks_desc().find(|&r| needed(r, 5) <= wilds) {
+            return FIVE_OF_A_KIND + rank;
+        }
+        let straight_flush = (5..=14u32).rev().find(|&high| {
+            suit_masks
+                .iter()
+                .any(|&mask| missing_for_straight(mask, high) <= wilds)
+        });
+        if let Some(high) = straight_flush {
+            return HandRank::StraightFlush as u32 + high;
+        }
+    }
+    if total >= 4 {
+        if let Some(quads) = ranks_desc().find(|&r| needed(r, 4) <= wilds) {
+            return score(
+                HandRank::FourOfAKind,
+                [quads].into_iter().chain(kickers(&[quads]).take(1)),
+            );
+        }
+    }
+    if total >= 5 {
+        let full_house = ranks_desc().find_map(|trips| {
+            ranks_desc()
+                .filter(|&pair| pair != trips)
+                .find(|&pair| needed(trips, 3) + needed(pair, 2) <= wilds)
+                .map(|pair| (trips, pair))
+        });
+        if let Some((trips, pair)) = full_house {
+            return score(HandRank::FullHouse, [trips, pair]);
+        }
+
+        let flush = suit_masks
+            .iter()
+            .filter(|mask| mask.count_ones() + wilds >= 5)
+            .map(|&mask| {
+                // The wild cards take the highest ranks missing in the suit.
+                let missing = ranks_desc().filter(|&r| mask & (1 << r) == 0);
+                let mut ranks: Vec<u32> = mask_ranks(mask)
+                    .chain(missing.take(wilds as usize))
+                    .collect();
+                ranks.sort_unstable_by(|a, b| b.cmp(a));
+                ranks.truncate(5);
+                ranks
+            })
+            .max();
+        if let Some(ranks) = flush {
+            return score(HandRank::Flush, ranks);
+        }
+
+        let straight = (5..=14u32)
+            .rev()
+            .find(|&high| missing_for_straight(ranks_mask, high) <= wilds);
+        if let Some(high) = straight {
+            return HandRank::Straight as u32 + high;
+        }
+    }
+    if total >= 3 {
+        if let Some(trips) = ranks_desc().find(|&r| needed(r, 3) <= wilds) {
+            return score(
+                HandRank::ThreeOfAKind,
+                [trips].into_iter().chain(kickers(&[trips]).take(2)),
+            );
+        }
+    }
+    if total >= 4 {
+        let two_pair = ranks_desc().find_map(|high| {
+            (2..high)
+                .rev()
+                .find(|&low| needed(high, 2) + needed(low, 2) <= wilds)
+                .map(|low| (high, low))
+        });
+        if let Some((high, low)) = two_pair {
+            return score(
+                HandRank::TwoPair,
+                [high, low].into_iter().chain(kickers(&[high, low]).take(1)),
+            );
+        }
+    }
+    if let Some(pair) = ranks_desc().find(|&r| needed(r, 2) <= wilds) {
+        return score(
+            HandRank::OnePair,
+            [pair].into_iter().chain(kickers(&[pair]).take(3)),
+        );
+    }
+    score(HandRank::HighCard, kickers(&[]).take(5))
+}
+
+/// Returns how many cards the mask lacks for the straight to the given
+/// high card, counting an ace as low card of the wheel.
+fn missing_for_straight(mask: u16, high: u32) -> u32 {
+    let mask = mask | (mask >> 14 & 1) << 1;
+    let straight = 0b11111 << (high - 4);
+    5 - (mask & straight).count_ones()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::Deck;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+
+    fn wild_score(s: &str, wild: &WildSpec) -> u32 {
+        evaluate_with_wild_set(&Hand::new_from_str(s).unwrap(), wild)
+    }
+
+    fn category(s: &str, wild: &WildSpec) -> HandRank {
+        HandRank::from_score(wild_score(s, wild))
+    }
+
+    #[test]
+    fn test_without_wild_cards() {
+        let mut rng = StdRng::seed_from_u64(195);
+        let deck = Deck::new();
+        let nothing = WildSpec::Cards(Vec::new());
+        for _ in 0..20_000 {
+            let n = rng.gen_range(Hand::MIN_CARDS..=Hand::MAX_CARDS);
+            let cards: Vec<Card> = deck
+                .get_cards()
+                .choose_multiple(&mut rng, n)
+                .copied()
+                .collect();
+            let hand = Hand::new(cards).unwrap();
+            assert_eq!(
+                evaluate_with_wild_set(&hand, &nothing),
+                hand.get_score(),
+                "{}",
+                hand
+            );
+        }
+    }
+
+    #[test]
+    fn test_deuces_wild() {
+        let deuces = WildSpec::Rank(Rank::Two);
+        assert_eq!(
+            wild_score("Ks Kh 2c 2d 7s", &deuces),
+            Hand::new_from_str("Ks Kh Kc Kd 7s").unwrap().get_score()
+        );
+        assert_eq!(wild_score("Ks Kh 2c 2d 2s", &deuces), FIVE_OF_A_KIND + 13);
+        assert!(wild_score("Ks Kh 2c 2d 2s", &deuces) > wild_score("As Ks Qs Js Ts", &deuces));
+
+        // Three deuces and two suited cards make a straight flush rather than
+        // quads, and a single deuce fills a gapped straight.
+        assert_eq!(wild_score("9s 7s 2c 2d 2h", &deuces), 8_000_011);
+        assert_eq!(wild_score("9s 8h 6c 5d 2h", &deuces), 4_000_009);
+        // With a pair and a deuce, the full house of the higher trips wins.
+        assert_eq!(
+            wild_score("Qs Qh 9c 9d 2h", &deuces),
+            Hand::new_from_str("Qs Qh Qc 9d 9s").unwrap().get_score()
+        );
+    }
+
+    #[test]
+    fn test_wild_suit() {
+        let clubs = WildSpec::Suit(Suit::Club);
+        // The king of clubs is worth more as the six of hearts than as a
+        // card of the ace high flush.
+        assert_eq!(wild_score("Ah 9h 8h 7h 5h Kc", &clubs), 8_000_009);
+        assert_eq!(category("Ah 9h 8h 4h 3s Kc", &clubs), HandRank::Flush);
+        assert_eq!(
+            wild_score("Ah 9h 8h 4h 3s Kc", &clubs),
+            Hand::new_from_str("Ah Kh 9h 8h 4h").unwrap().get_score()
+        );
+    }
+
+    #[test]
+    fn test_everything_wild() {
+        let spades = WildSpec::Suit(Suit::Spade);
+        assert_eq!(
+            wild_score("As Ks Qs Js Ts 9s", &spades),
+            FIVE_OF_A_KIND + 14
+        );
+        assert_eq!(
+            wild_score("2s 3s 4s 5s", &spades),
+            Hand::new_from_str("As Ah Ad Ac").unwrap().get_score()
+        );
+        assert_eq!(
+            wild_score("2s 3s", &spades),
+            Hand::new_from_str("As Ah").unwrap().get_score()
+        );
+    }
+
+    #[test]
+    fn test_specific_cards() {
+        let jokers = WildSpec::Cards(vec![Card::new_from_str("Jc").unwrap()]);
+        assert_eq!(category("Jc Ah Ad 7s 4h", &jokers), HandRank::ThreeOfAKind);
+        assert_eq!(category("Jd Ah Ad 7s 4h", &jokers), HandRank::OnePair);
+    }
+}
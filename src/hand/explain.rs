@@ -0,0 +1,320 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use strum::IntoEnumIterator;
+
+use crate::card::{Rank, Suit};
+
+use super::evaluator::parse_ranks;
+use super::value::{rank_name, rank_plural};
+use super::{evaluate_traced, Hand, HandRank, TraceStep};
+
+/// Translates the trace of evaluating the hand into English sentences, one
+/// per step followed by the best hand, see `Hand::explain`.
+pub(super) fn explain(hand: &Hand) -> Vec<String> {
+    let (_, trace) = evaluate_traced(hand);
+    // The suit with the most cards, the flush suit if there is a flush.
+    let (suit, suited) = Suit::iter()
+        .map(|suit| (suit, hand.count_suit(suit)))
+        .max_by_key(|&(_, count)| count)
+        .expect("There are four suits");
+
+    let mut lines: Vec<String> = trace
+        .steps()
+        .iter()
+        .flat_map(|step| explain_step(step, suit, suited))
+        .collect();
+    lines.push(format!("Best hand: {}", hand.get_value().describe()));
+    lines
+}
+
+/// Returns the sentences explaining a single step of the evaluator.
+fn explain_step(step: &TraceStep, suit: Suit, suited: usize) -> Vec<String> {
+    let suit = suit_plural(suit);
+    match step {
+        TraceStep::Ran {
+            detector, output, ..
+        } => {
+            let ranks = parse_ranks(output);
+            match (*detector, ranks) {
+                ("find_flush", Some(ranks)) => vec![
+                    format!(
+                        "You have {} {} → flush possible",
+                        count_word(ranks.len()),
+                        suit
+                    ),
+                    format!("Your five highest {} are {}", suit, list_ranks(&ranks[..5])),
+                ],
+                ("find_flush", None) if suited == 4 => {
+                    vec![format!(
+                        "You have four {} → one card short of a flush",
+                        suit
+                    )]
+                }
+                ("find_flush", None) => vec!["No five cards share a suit → no flush".to_string()],
+                ("find_straight", ranks) if input_is_flush(step) => match ranks {
+                    Some(high) => vec![format!(
+                        "The {} run five in a row, {} high → straight flush",
+                        suit,
+                        rank_name(high[0])
+                    )],
+                    None => vec![format!(
+                        "No straight within the {} → not a straight flush",
+                        suit
+                    )],
+                },
+                ("find_straight", Some(high)) => vec![format!(
+                    "Five ranks in a row, {} high → straight possible",
+                    rank_name(high[0])
+                )],
+                ("find_straight", None) => vec!["No five ranks in a row → no straight".to_string()],
+                ("classify_counts", _) => explain_category(output).into_iter().collect(),
+                ("find_four_of_a_kind", Some(ranks)) => vec![format!(
+                    "Four {}{}",
+                    rank_plural(ranks[0]),
+                    kickers(&ranks[1..])
+                )],
+                ("find_full_house", Some(ranks)) => vec![format!(
+                    "{} full of {}",
+                    rank_plural(ranks[0]),
+                    rank_plural(ranks[1])
+                )],
+                ("find_three_of_a_kind", Some(ranks)) => vec![format!(
+                    "Three {}{}",
+                    rank_plural(ranks[0]),
+                    kickers(&ranks[1..])
+                )],
+                ("find_two_pair", Some(ranks)) => vec![format!(
+                    "{} and {}{}",
+                    rank_plural(ranks[0]),
+                    rank_plural(ranks[1]),
+                    kickers(&ranks[2..])
+                )],
+                ("find_pair", Some(ranks)) => vec![format!(
+                    "A pair of {}{}",
+                    rank_plural(ranks[0]),
+                    kickers(&ranks[1..])
+                )],
+                _ => Vec::new(),
+            }
+        }
+        TraceStep::Skipped { detector, .. } if *detector == "find_straight" => {
+            vec!["A flush beats every straight → no need to look for one".to_string()]
+        }
+        TraceStep::Skipped { .. } => Vec::new(),
+    }
+}
+
+/// Returns whether the step looked at the ranks of the flush cards.
+fn input_is_flush(step: &TraceStep) -> bool {
+    matches!(step, TraceStep::Ran { input, .. } if input == "flush ranks")
+}
+
+/// Explains the category the counts per rank decided on.
+fn explain_category(output: &str) -> Option<String> {
+    let category = HandRank::iter().find(|category| format!("{:?}", category) == output)?;
+    let sentence = match category {
+        HandRank::FourOfAKind => "Four cards share a rank → four of a kind",
+        HandRank::FullHouse => "Three cards share a rank and two another → full house",
+        HandRank::Flush => "No four of a kind or full house → the flush plays",
+        HandRank::Straight => "No four of a kind or full house → the straight plays",
+        HandRank::ThreeOfAKind => "Three cards share a rank → three of a kind",
+        HandRank::TwoPair => "Two ranks are paired → two pair",
+        HandRank::OnePair => "Two cards share a rank → one pair",
+        HandRank::HighCard => "No two cards share a rank → high card",
+        HandRank::StraightFlush => return None,
+    };
+    Some(sentence.to_string())
+}
+
+/// Lists the kickers after a made hand, e.g. " with kickers 7 2".
+fn kickers(ranks: &[Rank]) -> String {
+    match ranks.len() {
+        0 => String::new(),
+        1 => format!(" with kicker {}", list_ranks(ranks)),
+        _ => format!(" with kickers {}", list_ranks(ranks)),
+    }
+}
+
+/// Lists the ranks separated by spaces, e.g. "A K Q J 9".
+fn list_ranks(ranks: &[Rank]) -> String {
+    ranks.iter().map(Rank::as_str).collect::<Vec<_>>().join(" ")
+}
+
+/// Returns the plural English name of a suit.
+fn suit_plural(suit: Suit) -> &'static str {
+    match suit {
+        Suit::Club => "clubs",
+        Suit::Diamond => "diamonds",
+        Suit::Heart => "hearts",
+        Suit::Spade => "spades",
+    }
+}
+
+/// Returns the English word of a number of suited cards.
+///
+/// A flush has at least five and a hand at most nine cards.
+fn count_word(count: usize) -> &'static str {
+    match count {
+        5 => "five",
+        6 => "six",
+        7 => "seven",
+        8 => "eight",
+        _ => "nine",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explain(s: &str) -> Vec<String> {
+        Hand::new_from_str(s).unwrap().explain()
+    }
+
+    #[test]
+    fn test_straight_flush() {
+        assert_eq!(
+            explain("9h 8h 7h 6h 5h Ad"),
+            [
+                "You have five hearts → flush possible",
+                "Your five highest hearts are 9 8 7 6 5",
+                "The hearts run five in a row, Nine high → straight flush",
+                "Best hand: Straight Flush, Nine high",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_four_of_a_kind() {
+        assert_eq!(
+            explain("7s 7h 7c 7d Ks 2s"),
+            [
+                "No five cards share a suit → no flush",
+                "No five ranks in a row → no straight",
+                "Four cards share a rank → four of a kind",
+                "Four Sevens with kicker K",
+                "Best hand: Four of a Kind, Sevens",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_full_house() {
+        assert_eq!(
+            explain("Ks Kh Kd 7c 7d 2s"),
+            [
+                "No five cards share a suit → no flush",
+                "No five ranks in a row → no straight",
+                "Three cards share a rank and two another → full house",
+                "Kings full of Sevens",
+                "Best hand: Full House, Kings full of Sevens",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_flush() {
+        assert_eq!(
+            explain("Ks Qs Ts 8s 6s 6d 2c"),
+            [
+                "You have five spades → flush possible",
+                "Your five highest spades are K Q T 8 6",
+                "No straight within the spades → not a straight flush",
+                "A flush beats every straight → no need to look for one",
+                "No four of a kind or full house → the flush plays",
+                "Best hand: Flush, King high",
+            ]
+        );
+        assert_eq!(
+            explain("As Ks Qs Js 9s 3s 2s")[..2],
+            [
+                "You have seven spades → flush possible",
+                "Your five highest spades are A K Q J 9",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_straight() {
+        assert_eq!(
+            explain("9s 8h 7c 6d 5s"),
+            [
+                "No five cards share a suit → no flush",
+                "Five ranks in a row, Nine high → straight possible",
+                "No four of a kind or full house → the straight plays",
+                "Best hand: Straight, Nine high",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_three_of_a_kind() {
+        assert_eq!(
+            explain("Qs Qh Qc 7d 2s"),
+            [
+                "No five cards share a suit → no flush",
+                "No five ranks in a row → no straight",
+                "Three cards share a rank → three of a kind",
+                "Three Queens with kickers 7 2",
+                "Best hand: Three of a Kind, Queens",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_two_pair() {
+        assert_eq!(
+            explain("Qs Qh 7c 7d 2s"),
+            [
+                "No five cards share a suit → no flush",
+                "No five ranks in a row → no straight",
+                "Two ranks are paired → two pair",
+                "Queens and Sevens with kicker 2",
+                "Best hand: Two Pair, Queens and Sevens",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_one_pair() {
+        assert_eq!(
+            explain("Qs Qh 7c 4d 2s"),
+            [
+                "No five cards share a suit → no flush",
+                "No five ranks in a row → no straight",
+                "Two cards share a rank → one pair",
+                "A pair of Queens with kickers 7 4 2",
+                "Best hand: One Pair, Queens",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_high_card() {
+        assert_eq!(
+            explain("As Qh 7c 4d 2s"),
+            [
+                "No five cards share a suit → no flush",
+                "No five ranks in a row → no straight",
+                "No two cards share a rank → high card",
+                "Best hand: High Card, Ace",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_four_flush() {
+        assert_eq!(
+            explain("Ah Jh 8h 3h Kc 9d 2s"),
+            [
+                "You have four hearts → one card short of a flush",
+                "No five ranks in a row → no straight",
+                "No two cards share a rank → high card",
+                "Best hand: High Card, Ace",
+            ]
+        );
+    }
+}
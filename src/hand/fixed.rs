@@ -0,0 +1,241 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Index;
+
+use crate::card::Card;
+
+use super::evaluator::{Evaluator, FastEvaluator};
+use super::hand::check_duplicates;
+use super::{Hand, HandError, HandRank};
+
+/// A hand of exactly `N` cards stored inline, without allocating.
+///
+/// Engines that always evaluate the same number of cards, like the seven
+/// cards of a hold'em showdown, can use a `FixedHand` to avoid the vector of
+/// `Hand` and to have the number of cards checked by the compiler. `N` must
+/// be between `Hand::MIN_CARDS` and `Hand::MAX_CARDS`, other sizes fail to
+/// compile:
+///
+/// ```compile_fail
+/// use pkr::card::Card;
+/// use pkr::hand::FixedHand;
+///
+/// let card = Card::new_from_str("As").unwrap();
+/// let hand = FixedHand::new([card]);
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Card;
+/// use pkr::hand::{FixedHand, Hand, SevenCardHand};
+///
+/// let cards = ["As", "Ks", "Qs", "Js", "Ts", "2c", "3d"].map(|c| Card::new_from_str(c).unwrap());
+/// let hand: SevenCardHand = FixedHand::new(cards).unwrap();
+/// assert_eq!(hand.score(), 8_000_014);
+/// assert_eq!(Hand::from(hand).get_score(), 8_000_014);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedHand<const N: usize> {
+    cards: [Card; N],
+}
+
+/// A hand of five cards, see `FixedHand`.
+pub type FiveCardHand = FixedHand<5>;
+
+/// A hand of seven cards, see `FixedHand`.
+pub type SevenCardHand = FixedHand<7>;
+
+impl<const N: usize> FixedHand<N> {
+    /// Fails to compile if `N` is not a valid number of cards of a hand.
+    const VALID_SIZE: () = assert!(
+        N >= Hand::MIN_CARDS && N <= Hand::MAX_CARDS,
+        "A FixedHand must have between Hand::MIN_CARDS and Hand::MAX_CARDS cards"
+    );
+
+    /// Creates a new `FixedHand` from an array of cards.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::DuplicateCard` if a card is given more than
+    /// once.
+    pub fn new(cards: [Card; N]) -> Result<Self, HandError> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_SIZE;
+        check_duplicates(&cards)?;
+        Ok(Self { cards })
+    }
+
+    /// Returns the score of the best hand made from the cards, calculated
+    /// by the allocation-free `FastEvaluator`.
+    ///
+    /// The score is the same as `Hand::get_score` of a hand holding the same
+    /// cards.
+    pub fn score(&self) -> u32 {
+        self.score_with(&FastEvaluator)
+    }
+
+    /// Returns the score of the hand calculated by the given evaluator.
+    pub fn score_with(&self, evaluator: &impl Evaluator) -> u32 {
+        evaluator.score(&self.cards)
+    }
+
+    /// Returns the category of the best hand made from the cards.
+    pub fn get_hand_rank(&self) -> HandRank {
+        HandRank::from_score(self.score())
+    }
+
+    /// Returns the cards.
+    pub fn get_cards(&self) -> &[Card; N] {
+        &self.cards
+    }
+
+    /// Returns the cards as a slice.
+    pub fn as_slice(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Returns whether the given card is in the hand.
+    pub fn contains(&self, card: &Card) -> bool {
+        self.cards.contains(card)
+    }
+}
+
+impl<const N: usize> AsRef<[Card]> for FixedHand<N> {
+    fn as_ref(&self) -> &[Card] {
+        &self.cards
+    }
+}
+
+/// Returns the card at the given position.
+///
+/// # Panics
+///
+/// Panics if `index` is not smaller than `N`.
+impl<const N: usize> Index<usize> for FixedHand<N> {
+    type Output = Card;
+
+    fn index(&self, index: usize) -> &Card {
+        &self.cards[index]
+    }
+}
+
+impl<const N: usize> TryFrom<[Card; N]> for FixedHand<N> {
+    type Error = HandError;
+
+    fn try_from(cards: [Card; N]) -> Result<Self, Self::Error> {
+        FixedHand::new(cards)
+    }
+}
+
+/// Converts a hand of exactly `N` cards, keeping the order of the cards.
+///
+/// # Errors
+///
+/// Returns a `HandError::InvalidCardCount` if the hand does not hold
+/// exactly `N` cards.
+impl<const N: usize> TryFrom<&Hand> for FixedHand<N> {
+    type Error = HandError;
+
+    fn try_from(hand: &Hand) -> Result<Self, Self::Error> {
+        let cards: [Card; N] =
+            hand.as_slice()
+                .try_into()
+                .map_err(|_| HandError::InvalidCardCount {
+                    min: N,
+                    max: N,
+                    found: hand.get_count(),
+                })?;
+        FixedHand::new(cards)
+    }
+}
+
+impl<const N: usize> TryFrom<Hand> for FixedHand<N> {
+    type Error = HandError;
+
+    fn try_from(hand: Hand) -> Result<Self, Self::Error> {
+        FixedHand::try_from(&hand)
+    }
+}
+
+impl<const N: usize> From<FixedHand<N>> for Hand {
+    fn from(hand: FixedHand<N>) -> Self {
+        // The cards of a `FixedHand` are distinct and `N` is a valid size.
+        Hand::new_unchecked(Vec::from(hand.cards))
+    }
+}
+
+/// Formats the cards like `Hand`, e.g. "As Ks 7d".
+impl<const N: usize> fmt::Display for FixedHand<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, card) in self.cards.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", card.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combinations::combinations_array;
+    use crate::deck::Deck;
+
+    #[test]
+    fn test_round_trip() {
+        let hand = Hand::new_from_str("As Kd 7c 7h 2s").unwrap();
+        let fixed = FiveCardHand::try_from(&hand).unwrap();
+        assert_eq!(fixed.as_slice(), hand.as_slice());
+        assert_eq!(fixed.to_string(), "As Kd 7c 7h 2s");
+        assert!(Hand::from(fixed).eq_ordered(&hand));
+        assert_eq!(FixedHand::try_from(*fixed.get_cards()), Ok(fixed));
+    }
+
+    #[test]
+    fn test_wrong_count() {
+        let hand = Hand::new_from_str("As Kd 7c 7h 2s").unwrap();
+        assert_eq!(
+            SevenCardHand::try_from(hand),
+            Err(HandError::InvalidCardCount {
+                min: 7,
+                max: 7,
+                found: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_duplicate_card() {
+        let ace = Card::new_from_str("As").unwrap();
+        let king = Card::new_from_str("Kd").unwrap();
+        assert_eq!(
+            FixedHand::new([ace, king, ace]),
+            Err(HandError::DuplicateCard(ace))
+        );
+    }
+
+    #[test]
+    fn test_score_matches_hand() {
+        let deck = Deck::new();
+        // Every fifth card keeps all suits and ranks while bounding the
+        // number of hands.
+        let cards: Vec<Card> = deck.get_cards().iter().step_by(5).copied().collect();
+        for cards in combinations_array::<Card, 5>(&cards) {
+            let fixed = FixedHand::new(cards).unwrap();
+            assert_eq!(fixed.score(), Hand::from(fixed).get_score());
+        }
+        for s in [
+            "As Ks Qs Js Ts 2c 3d",
+            "7s 7h 7c 5d 5s 5h 2c",
+            "As 2h 3c 4d 5s 9c Kd",
+        ] {
+            let hand = Hand::new_from_str(s).unwrap();
+            let fixed = SevenCardHand::try_from(&hand).unwrap();
+            assert_eq!(fixed.score(), hand.get_score());
+            assert_eq!(fixed.get_hand_rank(), hand.get_hand_rank());
+        }
+    }
+}
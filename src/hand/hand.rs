@@ -1,22 +1,53 @@
-use std::error::Error;
-
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Index;
+
+#[cfg(feature = "std")]
+use crate::analysis::{nut_score, percentile};
+#[cfg(feature = "std")]
+use crate::board::Board;
 use crate::card::{Card, Rank, Suit};
+use crate::combinations::{combinations, combinations_array, Combinations};
+use crate::deck::Deck;
+use rand::seq::SliceRandom;
+use rand::Rng;
 
 use super::evaluator::evaluator::evaluate;
-
-// The minimum and maximum number of cards a hand can consist of.
-const MIN_CARDS: usize = 2;
-const MAX_CARDS: usize = 9;
+use super::evaluator::{evaluate_rank_only, Evaluator, HandRank};
+use super::explain::explain;
+use super::{
+    decode_cards, encode_cards, CanonicalHand, HandError, HandValue, ParseError, ParseErrorKind,
+    PartialToken, Qualifier,
+};
 
 /// Represents a poker hand.
 ///
-/// A poker hand consists of `MIN_CARDS` to `MAX_CARDS` number of cards.
+/// A poker hand consists of `Hand::MIN_CARDS` to `Hand::MAX_CARDS` number of
+/// cards.
+///
+/// Two hands are equal if they hold the same cards, regardless of their
+/// order. Use `Hand::eq_ordered` to also compare the order.
+///
+/// Hands are ordered from the weakest to the strongest. Hands of equal
+/// strength but with different cards are ordered by their sorted cards, so
+/// the order is total and consistent with equality. Compare `get_score` to
+/// find ties in strength.
 #[derive(Clone)]
 pub struct Hand {
     cards: Vec<Card>,
 }
 
 impl Hand {
+    /// The minimum number of cards a hand can consist of.
+    pub const MIN_CARDS: usize = 2;
+
+    /// The maximum number of cards a hand can consist of.
+    pub const MAX_CARDS: usize = 9;
+
     /// Creates a new `Hand` from a vector of cards.
     ///
     /// # Examples
@@ -35,28 +66,59 @@ impl Hand {
     ///
     /// let hand = Hand::new(cards).unwrap();
     ///
-    /// assert_eq!(hand.get_cards().len(), 5);
+    /// assert_eq!(hand.as_slice().len(), 5);
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns a `Box<dyn Error>` if the hand does not have between `MIN_CARDS`
-    /// and `MAX_CARDS` number of cards.
-    pub fn new(cards: Vec<Card>) -> Result<Hand, Box<dyn Error>> {
+    /// Returns a `HandError::InvalidCardCount` if the hand does not have
+    /// between `MIN_CARDS` and `MAX_CARDS` number of cards and a
+    /// `HandError::DuplicateCard` if a card is given more than once.
+    pub fn new(cards: Vec<Card>) -> Result<Hand, HandError> {
         let num_cards = cards.len();
-        if num_cards < MIN_CARDS || num_cards > MAX_CARDS {
-            return Err(format!(
-                "A poker hand must have between {} and {} cards.",
-                MIN_CARDS, MAX_CARDS
-            )
-            .into());
+        if !(Hand::MIN_CARDS..=Hand::MAX_CARDS).contains(&num_cards) {
+            return Err(HandError::InvalidCardCount {
+                min: Hand::MIN_CARDS,
+                max: Hand::MAX_CARDS,
+                found: num_cards,
+            });
         }
+        check_duplicates(&cards)?;
 
         Ok(Hand { cards })
     }
 
+    /// Creates a new `Hand` from a vector of cards without validating it.
+    ///
+    /// This skips the checks of `Hand::new` for callers that already know the
+    /// cards are distinct and between `MIN_CARDS` and `MAX_CARDS` in number.
+    /// Evaluating a hand that violates this gives a meaningless score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Card;
+    /// use pkr::hand::Hand;
+    ///
+    /// let cards = vec![
+    ///     Card::new_from_str("Ah").unwrap(),
+    ///     Card::new_from_str("Ad").unwrap(),
+    /// ];
+    ///
+    /// let hand = Hand::new_unchecked(cards);
+    /// assert_eq!(hand.get_count(), 2);
+    /// ```
+    pub fn new_unchecked(cards: Vec<Card>) -> Hand {
+        Hand { cards }
+    }
+
     /// Creates a new `Hand` from a string.
     ///
+    /// Cards may be separated by whitespace or commas or be written without
+    /// any separator, the whole hand may be enclosed in square brackets and
+    /// "10" may be used instead of "T" for tens. Use `Hand::parse_strict` to
+    /// only accept cards separated by whitespace.
+    ///
     /// # Arguments
     ///
     /// * `s` - A string slice that holds the card identifiers.
@@ -67,670 +129,3452 @@ impl Hand {
     /// use pkr::hand::Hand;
     ///
     /// let hand = Hand::new_from_str("As Ks Qs Js Ts").unwrap();
-    /// assert_eq!(hand.get_cards().len(), 5);
+    /// assert_eq!(hand.as_slice().len(), 5);
+    ///
+    /// assert!(hand == Hand::new_from_str("AsKsQsJsTs").unwrap());
+    /// assert!(hand == Hand::new_from_str("[As, Ks, Qs, Js, 10s]").unwrap());
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns a `Box<dyn Error>` if the string does not represent a valid hand
-    /// the hand does not have between `MIN_CARDS` and `MAX_CARDS` number of cards.
-    pub fn new_from_str(s: &str) -> Result<Self, Box<dyn Error>> {
-        let strings: Vec<&str> = s.split_whitespace().collect();
-        if strings.len() < MIN_CARDS || strings.len() > MAX_CARDS {
-            return Err(format!(
-                "A poker hand must have between {} and {} cards.",
-                MIN_CARDS, MAX_CARDS
-            )
-            .into());
-        }
-        let mut cards = Vec::new();
-        for s in strings {
-            let card = Card::new_from_str(s).map_err(|_| format!("Invalid card string: {}", s))?;
-            cards.push(card);
-        }
-        Ok(Hand { cards })
+    /// Every card is parsed before the number of cards is checked, so a
+    /// string with both problems reports the invalid card:
+    ///
+    /// * `HandError::InvalidCard` with the zero-based index and the text of
+    ///   the first card that is not valid.
+    /// * `HandError::InvalidCardCount` with the number of cards found if the
+    ///   hand does not have between `MIN_CARDS` and `MAX_CARDS` cards.
+    /// * `HandError::DuplicateCard` with the first card given more than once.
+    pub fn new_from_str(s: &str) -> Result<Self, HandError> {
+        Hand::new(parse_cards(s)?)
     }
 
-    /// Adds a single card to the hand.
+    /// Creates a new `Hand` from a string of cards separated by whitespace.
     ///
-    /// # Arguments
+    /// # Examples
     ///
-    /// * `new_card` - A card to be added to the hand.
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// assert!(Hand::parse_strict("As Ks Qs").is_ok());
+    /// assert!(Hand::parse_strict("AsKsQs").is_err());
+    /// ```
     ///
     /// # Errors
     ///
-    /// Returns a `Box<dyn Error>` if adding the card would result in more than 7 cards in the hand.
-    pub fn add_card(&mut self, new_card: Card) -> Result<(), Box<dyn Error>> {
-        if self.cards.len() + 1 > MAX_CARDS {
-            return Err("Too many cards in the hand.".into());
-        }
-        self.cards.push(new_card);
-        Ok(())
+    /// Returns the same errors as `Hand::new_from_str`.
+    pub fn parse_strict(s: &str) -> Result<Self, HandError> {
+        Hand::new(parse_tokens(s.split_whitespace())?)
     }
 
-    /// Adds multiple cards to the hand.
+    /// Parses as many cards of an incomplete input as possible, e.g. while a
+    /// user is still typing.
     ///
-    /// # Arguments
+    /// The input is split into card identifiers like by `Hand::new_from_str`.
+    /// Parsing stops at the first identifier that is not a card or repeats
+    /// an earlier card, which is returned as the `PartialToken`, and the
+    /// identifiers after it are ignored. Unlike `Hand::new_from_str`, any
+    /// number of cards is accepted.
     ///
-    /// * `new_cards` - A vector of cards to be added to the hand.
+    /// # Examples
     ///
-    /// # Errors
+    /// ```
+    /// use pkr::card::Card;
+    /// use pkr::hand::Hand;
     ///
-    /// Returns a `Box<dyn Error>` if adding the cards would result in more than 7 cards in the hand.
-    pub fn add_cards(&mut self, new_cards: Vec<Card>) -> Result<(), Box<dyn Error>> {
-        if self.cards.len() + new_cards.len() > MAX_CARDS {
-            return Err("Too many cards to add.".into());
-        }
-        for card in new_cards {
-            self.cards.push(card);
+    /// let (cards, pending) = Hand::parse_partial("As Kd Q");
+    /// assert_eq!(cards.len(), 2);
+    ///
+    /// let pending = pending.unwrap();
+    /// assert_eq!(pending.token, "Q");
+    /// assert_eq!(pending.index, 2);
+    /// assert_eq!(pending.completions(&cards).len(), 4);
+    /// ```
+    pub fn parse_partial(s: &str) -> (Vec<Card>, Option<PartialToken>) {
+        let tokens = s
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .flat_map(split_cards)
+            .filter(|token| !token.is_empty());
+        let mut cards = Vec::new();
+        for (index, token) in tokens.enumerate() {
+            match parse_tokens(core::iter::once(token)) {
+                Ok(card) if !cards.contains(&card[0]) => cards.push(card[0]),
+                _ => {
+                    let token = PartialToken {
+                        index,
+                        token: token.into(),
+                    };
+                    return (cards, Some(token));
+                }
+            }
         }
-        Ok(())
-    }
-
-    /// Returns a reference to the cards in the hand.
-    pub fn get_cards(&self) -> &Vec<Card> {
-        &self.cards
-    }
-
-    /// Returns the number of cards in the hand.
-    pub fn get_count(&self) -> usize {
-        self.cards.len()
+        (cards, None)
     }
 
-    /// Returns the score of a Hand instance by calling the `evaluate` function.
-    /// The score makes hands comparable by strength.
+    /// Parses one hand per line, like `Hand::new_from_str`, without stopping
+    /// at invalid lines.
     ///
-    /// The hand's score is used to rank the hand in comparison with other
-    /// poker hands. A higher score represents a stronger hand. This is useful
-    /// in games of poker where the strength of a player's hand needs to be
-    /// compared to the hands of others.
+    /// Blank lines are skipped, but still counted for the line numbers of
+    /// the errors.
     ///
-    /// # Returns
+    /// # Examples
     ///
-    /// * `u32` - An unsigned 32-bit integer representing the score of the hand.
+    /// ```
+    /// use pkr::hand::{Hand, HandError, ParseErrorKind};
+    ///
+    /// let hands = Hand::parse_many("As Ks Qs\n\nAs Kx\n7d 7c");
+    /// assert_eq!(hands.len(), 3);
+    /// assert!(hands[0].is_ok());
+    ///
+    /// let error = hands[1].as_ref().unwrap_err();
+    /// assert_eq!(error.line, 3);
+    /// assert!(matches!(error.kind, ParseErrorKind::Hand(HandError::InvalidCard { index: 1, .. })));
+    /// ```
+    pub fn parse_many(input: &str) -> Vec<Result<Hand, ParseError>> {
+        input
+            .lines()
+            .enumerate()
+            .flat_map(|(i, line)| parse_records(line, i + 1, None))
+            .collect()
+    }
+
+    /// Parses hands like `Hand::parse_many`, additionally splitting every
+    /// line into several records at the delimiter.
     ///
     /// # Examples
     ///
     /// ```
     /// use pkr::hand::Hand;
     ///
-    /// let hand1 = Hand::new_from_str("Ts Js Qs Ks As").unwrap();
-    /// assert_eq!(hand1.get_score(), 8000014);
+    /// let hands = Hand::parse_many_with("As Ks Qs;7d 7c\n2h 2h", ';');
+    /// assert_eq!(hands.len(), 3);
+    /// assert_eq!(hands[1].as_ref().unwrap().as_str(), "7d 7c");
+    /// assert_eq!(hands[2].as_ref().unwrap_err().line, 2);
+    /// ```
+    pub fn parse_many_with(input: &str, delimiter: char) -> Vec<Result<Hand, ParseError>> {
+        input
+            .lines()
+            .enumerate()
+            .flat_map(|(i, line)| parse_records(line, i + 1, Some(delimiter)))
+            .collect()
+    }
+
+    /// Parses one hand per line like `Hand::parse_many`, reading the lines
+    /// one at a time so large files are never loaded at once.
     ///
-    /// let hand2 = Hand::new_from_str("As Ah Ac Ad Ks").unwrap();
-    /// assert_eq!(hand2.get_score(), 7000237);
+    /// If reading a line fails, a `ParseErrorKind::Io` error is yielded and
+    /// the iterator ends.
+    ///
+    /// # Examples
     ///
-    /// assert!(hand1.get_score() > hand2.get_score());
     /// ```
-    pub fn get_score(&self) -> u32 {
-        evaluate(self)
+    /// use std::io::Cursor;
+    ///
+    /// use pkr::hand::Hand;
+    ///
+    /// let input = Cursor::new("As Ks Qs\nAs\n");
+    /// let results: Vec<_> = Hand::parse_reader(input).collect();
+    /// assert!(results[0].is_ok());
+    /// assert_eq!(results[1].as_ref().unwrap_err().line, 2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_reader<R: std::io::BufRead>(
+        reader: R,
+    ) -> impl Iterator<Item = Result<Hand, ParseError>> {
+        let mut failed = false;
+        reader
+            .lines()
+            .enumerate()
+            .map_while(move |(i, line)| {
+                if failed {
+                    return None;
+                }
+                match line {
+                    Ok(line) => Some(parse_records(&line, i + 1, None)),
+                    Err(e) => {
+                        failed = true;
+                        Some(vec![Err(ParseError {
+                            line: i + 1,
+                            kind: ParseErrorKind::Io(e.kind()),
+                        })])
+                    }
+                }
+            })
+            .flatten()
     }
 
-    /// Returns the ranks of all cards in the hand, ignoring the suits.
+    /// Decodes a hand encoded by `Hand::to_bytes`.
     ///
-    /// This can be useful when only the ranks of the cards matter for a certain
-    /// operation or comparison, and the suits are irrelevant.
+    /// # Examples
     ///
-    /// # Returns
+    /// ```
+    /// use pkr::hand::{Hand, HandError};
     ///
-    /// A Vec of Rank representing the ranks of all cards in the hand.
+    /// let hand = Hand::from_bytes(&[2, 51, 45]).unwrap();
+    /// assert_eq!(hand.as_str(), "As Kd");
+    ///
+    /// assert_eq!(
+    ///     Hand::from_bytes(&[2, 51]).err(),
+    ///     Some(HandError::InvalidLength { expected: 3, found: 2 })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::InvalidLength` if the length byte does not match
+    /// the number of bytes, a `HandError::InvalidCard` with the position of
+    /// the first invalid card byte, a `HandError::InvalidCardCount` if the
+    /// number of cards is not valid and a `HandError::DuplicateCard` if a card
+    /// appears more than once.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HandError> {
+        Hand::new(decode_cards(bytes)?)
+    }
+
+    /// Creates a hand from a bit mask with bit `Card::to_byte` set for every
+    /// card, see `Hand::as_bitmask`.
+    ///
+    /// A bit mask has no order, so the cards are in ascending order of
+    /// `Card::to_byte`, from "2c" to "As". A hand converted to a bit mask and
+    /// back equals the original hand, but only keeps its order if it was in
+    /// that order.
     ///
     /// # Examples
     ///
     /// ```
-    /// use pkr::card::{Card, Rank, Suit};
-    /// use pkr::hand::Hand;
+    /// use pkr::hand::{Hand, HandError};
     ///
-    /// let hand = Hand::new(vec![
-    ///     Card::new(Rank::Ace, Suit::Heart),
-    ///     Card::new(Rank::Two, Suit::Spade),
-    ///     Card::new(Rank::Four, Suit::Diamond),
-    ///     Card::new(Rank::Five, Suit::Heart),
-    ///     Card::new(Rank::Three, Suit::Heart),
-    /// ]).unwrap();
+    /// let hand = Hand::new_from_str("As Kd 2c").unwrap();
+    /// let round_trip = Hand::from_bitmask(hand.as_bitmask()).unwrap();
+    /// assert_eq!(round_trip.as_str(), "2c Kd As");
+    /// assert_eq!(round_trip, hand);
     ///
-    /// let ranks = hand.get_ranks();
-    /// assert_eq!(ranks, vec![Rank::Ace, Rank::Two, Rank::Four, Rank::Five, Rank::Three]);
+    /// assert_eq!(Hand::from_bitmask(1 << 52 | 3), Err(HandError::InvalidBitmask(1 << 52 | 3)));
     /// ```
-    pub fn get_ranks(&self) -> Vec<Rank> {
-        self.cards.iter().map(|card| card.rank).collect()
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::InvalidBitmask` if a bit above bit 51 is set and
+    /// a `HandError::InvalidCardCount` if the number of cards is not valid.
+    pub fn from_bitmask(mask: u64) -> Result<Self, HandError> {
+        if mask >> 52 != 0 {
+            return Err(HandError::InvalidBitmask(mask));
+        }
+        let cards = (0..52u8)
+            .filter(|&byte| mask & 1 << byte != 0)
+            .map(|byte| Card::from_byte(byte).expect("Bytes below 52 are cards"))
+            .collect();
+        Hand::new(cards)
     }
 
-    /// Returns a string representation of the `Hand`.
+    /// Creates a hand of `n` distinct cards drawn at random.
     ///
-    /// The string consists of card identifiers separated by spaces. Each card
-    /// identifier consists of two characters: the rank and the suit. For
-    /// example, the ace of clubs is represented as "Ac".
+    /// The hand only depends on the state of the generator, so a seeded
+    /// generator always creates the same hand.
     ///
     /// # Examples
     ///
     /// ```
     /// use pkr::hand::Hand;
-    /// use pkr::card::{Card, Rank, Suit};
-    ///
-    /// let hand = Hand::new(vec![
-    ///     Card { rank: Rank::Ace, suit: Suit::Club },
-    ///     Card { rank: Rank::King, suit: Suit::Spade },
-    ///     Card { rank: Rank::Queen, suit: Suit::Heart },
-    ///     Card { rank: Rank::Jack, suit: Suit::Diamond },
-    ///     Card { rank: Rank::Ten, suit: Suit::Club },
-    /// ]).unwrap();
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
     ///
-    /// assert_eq!(hand.as_str(), "Ac Ks Qh Jd Tc");
+    /// let hand = Hand::random(&mut StdRng::seed_from_u64(42), 7);
+    /// assert_eq!(hand.get_count(), 7);
+    /// assert!(hand.eq_ordered(&Hand::random(&mut StdRng::seed_from_u64(42), 7)));
     /// ```
-    pub fn as_str(&self) -> String {
-        self.cards
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not between `MIN_CARDS` and `MAX_CARDS`.
+    pub fn random<R: Rng + ?Sized>(rng: &mut R, n: usize) -> Hand {
+        Hand::random_with(rng, n, &[])
+    }
+
+    /// Creates a hand of `n` distinct cards drawn at random from the cards
+    /// that are not dead, e.g. the cards already dealt to other players.
+    ///
+    /// Like `Hand::random` a seeded generator always creates the same hand.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not between `MIN_CARDS` and `MAX_CARDS` or if fewer
+    /// than `n` cards are not dead.
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R, n: usize, dead: &[Card]) -> Hand {
+        assert!(
+            (Hand::MIN_CARDS..=Hand::MAX_CARDS).contains(&n),
+            "A poker hand must have between {} and {} cards, got {}.",
+            Hand::MIN_CARDS,
+            Hand::MAX_CARDS,
+            n
+        );
+        let deck = Deck::new();
+        let live: Vec<Card> = deck
+            .get_cards()
             .iter()
-            .map(|card| card.as_str())
-            .collect::<Vec<_>>()
-            .join(" ")
+            .filter(|card| !dead.contains(card))
+            .copied()
+            .collect();
+        assert!(
+            live.len() >= n,
+            "Cannot draw {} cards from {} live cards.",
+            n,
+            live.len()
+        );
+        Hand {
+            cards: live.choose_multiple(rng, n).copied().collect(),
+        }
     }
 
-    /// Sorts the cards in the hand by suit in ascending order.
+    /// Creates a new `Hand` from the hole cards of a player and the board.
     ///
-    /// The relative order of cards with the same suit is maintained.
+    /// # Arguments
+    ///
+    /// * `hole` - The hole cards of the player.
+    /// * `board` - The community cards.
     ///
     /// # Examples
     ///
     /// ```
-    /// use pkr::hand::Hand;
-    /// use pkr::card::{Card, Rank, Suit};
-    ///
-    /// let mut hand = Hand::new(vec![
-    ///     Card { rank: Rank::Ace, suit: Suit::Heart },
-    ///     Card { rank: Rank::King, suit: Suit::Club },
-    ///     Card { rank: Rank::Queen, suit: Suit::Spade },
-    ///     Card { rank: Rank::Jack, suit: Suit::Diamond },
-    ///     Card { rank: Rank::Ten, suit: Suit::Heart },
-    /// ]).unwrap();
+    /// use pkr::card::Card;
+    /// use pkr::hand::{Hand, HandRank};
     ///
-    /// hand.sort_by_suit();
+    /// let card = |s| Card::new_from_str(s).unwrap();
+    /// let hole = [card("Ah"), card("Ad")];
+    /// let board = [card("As"), card("7c"), card("2d")];
     ///
-    /// assert_eq!(hand.as_str(), "Kc Jd Ah Th Qs");
+    /// let hand = Hand::from_hole_and_board(&hole, &board).unwrap();
+    /// assert_eq!(hand.get_hand_rank(), HandRank::ThreeOfAKind);
     /// ```
-    pub fn sort_by_suit(&mut self) {
-        self.cards
-            .sort_by(|a, b| a.suit.partial_cmp(&b.suit).unwrap());
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::InvalidCardCount` if the hand would have more
+    /// than `MAX_CARDS` cards and a `HandError::DuplicateCard` if a card is
+    /// both in the hole cards and on the board.
+    pub fn from_hole_and_board(hole: &[Card; 2], board: &[Card]) -> Result<Hand, HandError> {
+        let mut cards = Vec::with_capacity(hole.len() + board.len());
+        cards.extend_from_slice(hole);
+        cards.extend_from_slice(board);
+        Hand::new(cards)
     }
 
-    /// Sorts the hand by rank, preserving the original order within each rank.
+    /// Returns a new hand holding the cards of this hand and the board,
+    /// leaving this hand unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `add_cards`.
+    pub fn with_board(&self, board: &[Card]) -> Result<Hand, HandError> {
+        let mut hand = self.clone();
+        hand.add_cards(board.to_vec())?;
+        Ok(hand)
+    }
+
+    /// Adds a single card to the hand.
+    ///
+    /// Adding a card never lowers the score of the hand. With the
+    /// `debug-invariants` feature this is checked by a `debug_assert`.
     ///
     /// # Arguments
     ///
-    /// * `ascending` - A boolean indicating if sorting should be in ascending
-    ///                 order (true) or descending order (false).
+    /// * `new_card` - A card to be added to the hand.
     ///
     /// # Errors
     ///
-    /// Returns a `Box<dyn Error>` if the ranks cannot be compared.
+    /// Returns a `HandError::TooManyCards` if adding the card would result in more than `MAX_CARDS` cards
+    /// in the hand and a `HandError::DuplicateCard` if the card is already in the hand.
+    pub fn add_card(&mut self, new_card: Card) -> Result<(), HandError> {
+        self.check_capacity(1)?;
+        if self.cards.contains(&new_card) {
+            return Err(HandError::DuplicateCard(new_card));
+        }
+        #[cfg(feature = "debug-invariants")]
+        let score = self.get_score();
+        self.cards.push(new_card);
+        #[cfg(feature = "debug-invariants")]
+        debug_assert!(
+            self.get_score() >= score,
+            "Adding {} lowered the score of the hand to {}",
+            new_card.as_str(),
+            self.get_score()
+        );
+        Ok(())
+    }
+
+    /// Adds multiple cards to the hand.
     ///
-    /// # Examples
+    /// # Arguments
     ///
-    /// ```
-    /// use pkr::card::{Card, Rank, Suit};
-    /// use pkr::hand::Hand;
+    /// * `new_cards` - A vector of cards to be added to the hand.
     ///
-    /// let mut hand = Hand::new_from_str("Ah 2s 4d 5h 3h").unwrap();
-    /// hand.sort_by_rank(true).unwrap();
-    /// assert_eq!(hand.as_str(), "2s 3h 4d 5h Ah");
+    /// # Errors
     ///
-    /// hand.sort_by_rank(false).unwrap();
-    /// assert_eq!(hand.as_str(), "Ah 5h 4d 3h 2s");
-    /// ```
-    pub fn sort_by_rank(&mut self, ascending: bool) -> Result<(), Box<dyn Error>> {
-        if ascending {
-            self.cards
-                .sort_by(|a, b| a.rank.partial_cmp(&b.rank).unwrap());
-        } else {
-            self.cards
-                .sort_by(|a, b| b.rank.partial_cmp(&a.rank).unwrap());
+    /// Returns a `HandError::TooManyCards` if adding the cards would result in more than `MAX_CARDS` cards
+    /// in the hand and a `HandError::DuplicateCard` if a card is already in the hand or given more than
+    /// once. The hand is left unchanged on error.
+    pub fn add_cards(&mut self, new_cards: Vec<Card>) -> Result<(), HandError> {
+        self.check_capacity(new_cards.len())?;
+        let num_cards = self.cards.len();
+        self.cards.extend(new_cards);
+        if let Err(e) = check_duplicates(&self.cards) {
+            self.cards.truncate(num_cards);
+            return Err(e);
         }
         Ok(())
     }
 
-    /// Returns all cards in the hand of a given suit.
+    /// Adds the cards of another hand to this hand, e.g. the exposed cards of
+    /// a stud player to the hole cards.
     ///
     /// # Arguments
     ///
-    /// * `suit` - A suit of which the cards are to be returned.
+    /// * `other` - The hand whose cards are added.
     ///
     /// # Examples
     ///
     /// ```
-    /// use pkr::hand::Hand;
-    /// use pkr::card::{Card, Rank, Suit};
+    /// use pkr::card::Card;
+    /// use pkr::hand::{Hand, HandError};
     ///
-    /// let hand = Hand::new(vec![
-    ///     Card { rank: Rank::Two, suit: Suit::Heart },
-    ///     Card { rank: Rank::Three, suit: Suit::Heart },
-    ///     Card { rank: Rank::Four, suit: Suit::Spade },
-    ///     Card { rank: Rank::Five, suit: Suit::Diamond },
-    ///     Card { rank: Rank::Six, suit: Suit::Heart },
-    /// ]).unwrap();
+    /// let mut hand = Hand::new_from_str("As Ks").unwrap();
+    /// hand.merge(&Hand::new_from_str("Qs Js Ts").unwrap()).unwrap();
+    /// assert_eq!(hand.as_str(), "As Ks Qs Js Ts");
     ///
-    /// let hearts = hand.cards_of_suit(Suit::Heart);
-    /// assert_eq!(hearts.len(), 3);
+    /// let overlap = Hand::new_from_str("9c Js").unwrap();
+    /// assert_eq!(
+    ///     hand.merge(&overlap),
+    ///     Err(HandError::DuplicateCard(Card::new_from_str("Js").unwrap()))
+    /// );
     /// ```
-    pub fn cards_of_suit(&self, suit: Suit) -> Vec<Card> {
-        self.cards
-            .iter()
-            .filter(|&card| card.suit == suit)
-            .cloned()
-            .collect()
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `add_cards`, a `HandError::DuplicateCard`
+    /// names the first card of `other` that is in both hands. The hand is
+    /// left unchanged on error.
+    pub fn merge(&mut self, other: &Hand) -> Result<(), HandError> {
+        self.add_cards(other.cards.clone())
     }
-}
-
-#[test]
-fn test_create_hand() {
-    let cards = vec![
-        Card::new_from_str("2h").unwrap(),
-        Card::new_from_str("3d").unwrap(),
-        Card::new_from_str("4s").unwrap(),
-        Card::new_from_str("5c").unwrap(),
-        Card::new_from_str("6h").unwrap(),
-        Card::new_from_str("7d").unwrap(),
-        Card::new_from_str("8s").unwrap(),
-    ];
-
-    let hand = Hand::new(cards);
 
-    assert!(hand.is_ok());
+    /// Returns a new hand holding the cards of both hands, leaving both
+    /// hands unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `merge`.
+    pub fn union(&self, other: &Hand) -> Result<Hand, HandError> {
+        let mut hand = self.clone();
+        hand.merge(other)?;
+        Ok(hand)
+    }
 
-    let hand = hand.unwrap();
-    assert_eq!(hand.get_cards().len(), 7)
-}
+    /// Returns whether the hands have no card in common.
+    pub fn is_disjoint(&self, other: &Hand) -> bool {
+        !self.cards.iter().any(|card| other.contains(card))
+    }
 
-#[test]
+    /// Returns the cards that are in both hands, in the order of this hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("As Kd 7c").unwrap();
+    /// let other = Hand::new_from_str("7c 2h As").unwrap();
+    /// let common: Vec<String> = hand.intersection(&other).iter().map(|c| c.as_str()).collect();
+    /// assert_eq!(common, ["As", "7c"]);
+    /// ```
+    pub fn intersection(&self, other: &Hand) -> Vec<Card> {
+        self.intersection_cards(&other.cards)
+    }
+
+    /// Returns the cards of this hand that are not in the other hand, in the
+    /// order of this hand.
+    pub fn difference(&self, other: &Hand) -> Vec<Card> {
+        self.difference_cards(&other.cards)
+    }
+
+    /// Returns the cards of this hand that are also among the given cards,
+    /// e.g. on a board.
+    pub fn intersection_cards(&self, cards: &[Card]) -> Vec<Card> {
+        self.filter_cards(|card| cards.contains(card))
+    }
+
+    /// Returns the cards of this hand that are not among the given cards.
+    pub fn difference_cards(&self, cards: &[Card]) -> Vec<Card> {
+        self.filter_cards(|card| !cards.contains(card))
+    }
+
+    /// Removes a card from the hand and returns it.
+    ///
+    /// The order of the remaining cards is preserved. A hand never holds
+    /// fewer than `MIN_CARDS` cards, the same holds for `remove_at` and
+    /// `clear_to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `card` - The card to be removed from the hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Card;
+    /// use pkr::hand::Hand;
+    ///
+    /// let mut hand = Hand::new_from_str("As Ks Qs").unwrap();
+    /// let king = Card::new_from_str("Ks").unwrap();
+    /// assert_eq!(hand.remove_card(&king).unwrap(), king);
+    /// assert_eq!(hand.as_str(), "As Qs");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::CardNotFound` if the card is not in the hand and
+    /// a `HandError::TooFewCards` if the hand would have fewer than
+    /// `MIN_CARDS` cards.
+    pub fn remove_card(&mut self, card: &Card) -> Result<Card, HandError> {
+        let index = self
+            .cards
+            .iter()
+            .position(|c| c == card)
+            .ok_or(HandError::CardNotFound(*card))?;
+        self.remove_at(index)
+    }
+
+    /// Replaces a card of the hand with another card, keeping its position.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The card to be replaced.
+    /// * `new` - The card taking its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Card;
+    /// use pkr::hand::Hand;
+    ///
+    /// let mut hand = Hand::new_from_str("As Ad 2c").unwrap();
+    /// let card = |s| Card::new_from_str(s).unwrap();
+    /// hand.replace_card(&card("2c"), card("Ac")).unwrap();
+    /// assert_eq!(hand.as_str(), "As Ad Ac");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::CardNotFound` if `old` is not in the hand and a
+    /// `HandError::DuplicateCard` if `new` is already in the hand. The hand
+    /// is left unchanged on error.
+    pub fn replace_card(&mut self, old: &Card, new: Card) -> Result<(), HandError> {
+        let index = self
+            .cards
+            .iter()
+            .position(|c| c == old)
+            .ok_or(HandError::CardNotFound(*old))?;
+        if new != *old && self.cards.contains(&new) {
+            return Err(HandError::DuplicateCard(new));
+        }
+        self.cards[index] = new;
+        Ok(())
+    }
+
+    /// Returns a new hand with `old` replaced by `new`, leaving this hand
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `replace_card`.
+    pub fn with_replaced(&self, old: &Card, new: Card) -> Result<Hand, HandError> {
+        let mut hand = self.clone();
+        hand.replace_card(old, new)?;
+        Ok(hand)
+    }
+
+    /// Returns a new hand without the given cards, e.g. the hole cards of a
+    /// hand of hole cards and board, leaving this hand unchanged.
+    ///
+    /// The order of the remaining cards is preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `cards` - The cards to be removed, all of which must be in the hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let board = Hand::new_from_str("Kh 9h 5h 2c 3d").unwrap();
+    /// let hand = Hand::new_from_str("Ah Qh Kh 9h 5h 2c 3d").unwrap();
+    /// assert_eq!(hand.without(board.as_slice()).unwrap().as_str(), "Ah Qh");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::CardNotFound` naming the first card that is not
+    /// in the hand, or is given twice, and a `HandError::TooFewCards` if the
+    /// hand would have fewer than `MIN_CARDS` cards.
+    pub fn without(&self, cards: &[Card]) -> Result<Hand, HandError> {
+        let mut remaining = self.cards.clone();
+        for card in cards {
+            let index = remaining
+                .iter()
+                .position(|c| c == card)
+                .ok_or(HandError::CardNotFound(*card))?;
+            remaining.remove(index);
+        }
+        if remaining.len() < Hand::MIN_CARDS {
+            return Err(HandError::TooFewCards {
+                count: self.cards.len(),
+                removing: cards.len(),
+                min: Hand::MIN_CARDS,
+            });
+        }
+        Ok(Hand { cards: remaining })
+    }
+
+    /// Removes the card at the given position and returns it.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero-based position of the card to be removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::IndexOutOfBounds` if there is no card at `index`
+    /// and a `HandError::TooFewCards` if the hand would have fewer than
+    /// `MIN_CARDS` cards.
+    pub fn remove_at(&mut self, index: usize) -> Result<Card, HandError> {
+        if index >= self.cards.len() {
+            return Err(HandError::IndexOutOfBounds {
+                index,
+                count: self.cards.len(),
+            });
+        }
+        self.check_removal(1)?;
+        Ok(self.cards.remove(index))
+    }
+
+    /// Keeps the first `n` cards of the hand and removes the others.
+    ///
+    /// Nothing happens if the hand has no more than `n` cards.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of cards to keep.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let mut hand = Hand::new_from_str("Ah Ad 7c 7s 2d").unwrap();
+    /// hand.clear_to(2).unwrap();
+    /// assert_eq!(hand.as_str(), "Ah Ad");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::TooFewCards` if `n` is smaller than `MIN_CARDS`.
+    pub fn clear_to(&mut self, n: usize) -> Result<(), HandError> {
+        if n >= self.cards.len() {
+            return Ok(());
+        }
+        self.check_removal(self.cards.len() - n)?;
+        self.cards.truncate(n);
+        Ok(())
+    }
+
+    /// Returns an error if removing `removing` cards would go below
+    /// `MIN_CARDS`.
+    fn check_removal(&self, removing: usize) -> Result<(), HandError> {
+        if self.cards.len() < Hand::MIN_CARDS + removing {
+            return Err(HandError::TooFewCards {
+                count: self.cards.len(),
+                removing,
+                min: Hand::MIN_CARDS,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns an error if adding `adding` cards would exceed `MAX_CARDS`.
+    fn check_capacity(&self, adding: usize) -> Result<(), HandError> {
+        if self.cards.len() + adding > Hand::MAX_CARDS {
+            return Err(HandError::TooManyCards {
+                count: self.cards.len(),
+                adding,
+                max: Hand::MAX_CARDS,
+            });
+        }
+        Ok(())
+    }
+
+    /// Adds the cards of an iterator to the hand.
+    ///
+    /// Unlike `Extend`, either all cards are added or none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let mut hand = Hand::new_from_str("As Ks").unwrap();
+    /// let board = Hand::new_from_str("Qs Js Ts").unwrap();
+    /// hand.try_extend(board).unwrap();
+    /// assert_eq!(hand.as_str(), "As Ks Qs Js Ts");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `add_cards`, leaving the hand unchanged.
+    pub fn try_extend<I: IntoIterator<Item = Card>>(&mut self, iter: I) -> Result<(), HandError> {
+        self.add_cards(iter.into_iter().collect())
+    }
+
+    /// Returns a reference to the cards in the hand.
+    #[deprecated(note = "Use `Hand::as_slice` instead")]
+    pub fn get_cards(&self) -> &Vec<Card> {
+        &self.cards
+    }
+
+    /// Returns the cards in the hand as a slice.
+    pub fn as_slice(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Consumes the hand and returns its cards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("As Ks Qs").unwrap();
+    /// let cards = hand.into_cards();
+    /// assert_eq!(cards.len(), 3);
+    /// assert!(Hand::try_from(cards).unwrap() == Hand::new_from_str("As Ks Qs").unwrap());
+    /// ```
+    pub fn into_cards(self) -> Vec<Card> {
+        self.cards
+    }
+
+    /// Returns whether both hands hold the same cards in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand1 = Hand::new_from_str("As Ks").unwrap();
+    /// let hand2 = Hand::new_from_str("Ks As").unwrap();
+    /// assert!(hand1 == hand2);
+    /// assert!(!hand1.eq_ordered(&hand2));
+    /// ```
+    pub fn eq_ordered(&self, other: &Hand) -> bool {
+        self.cards == other.cards
+    }
+
+    /// Returns the canonical form of the hand, which is the same for all
+    /// hands that only differ in the order of the cards or in a relabeling
+    /// of the suits. Such hands always have the same score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("As Ks Qd").unwrap();
+    /// let isomorphic = Hand::new_from_str("Qs Kh Ah").unwrap();
+    /// assert_eq!(hand.canonical(), isomorphic.canonical());
+    ///
+    /// let offsuit = Hand::new_from_str("As Kd Qh").unwrap();
+    /// assert_ne!(hand.canonical(), offsuit.canonical());
+    /// ```
+    pub fn canonical(&self) -> CanonicalHand {
+        CanonicalHand::new(&self.cards)
+    }
+
+    /// Returns a stable key of the canonical form of the hand, see
+    /// `CanonicalHand::key`.
+    pub fn canonical_key(&self) -> u64 {
+        self.canonical().key()
+    }
+
+    /// Returns the cards of the hand in ascending order of rank and suit.
+    fn sorted_cards(&self) -> Vec<Card> {
+        let mut cards = self.cards.clone();
+        cards.sort_unstable();
+        cards
+    }
+
+    /// Returns an iterator over all sub-hands of `k` cards.
+    ///
+    /// The sub-hands are yielded in lexicographic order of the positions of
+    /// their cards in the hand, and the cards of each sub-hand keep the order
+    /// of the hand. Nothing is yielded if `k` is larger than the hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("As Ks Qs Js Ts 9s 8s").unwrap();
+    /// assert_eq!(hand.combinations(5).count(), 21);
+    /// assert_eq!(hand.combinations(5).next().unwrap(), hand.as_slice()[..5]);
+    /// ```
+    pub fn combinations(&self, k: usize) -> Combinations<'_, Card> {
+        combinations(&self.cards, k)
+    }
+
+    /// Returns an iterator over all sub-hands of `K` cards as arrays, in the
+    /// same order as `Hand::combinations`.
+    pub fn combinations_array<const K: usize>(&self) -> impl Iterator<Item = [Card; K]> + '_ {
+        combinations_array(&self.cards)
+    }
+
+    /// Returns an iterator over the cards in the hand.
+    pub fn iter(&self) -> core::slice::Iter<'_, Card> {
+        self.cards.iter()
+    }
+
+    /// Returns the card at the given position or `None` if there is no card
+    /// at `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Card;
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("As Kd 7c").unwrap();
+    /// assert_eq!(hand.get(1), Some(&Card::new_from_str("Kd").unwrap()));
+    /// assert_eq!(hand.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&Card> {
+        self.cards.get(index)
+    }
+
+    /// Returns the first card of the hand, like `slice::first`.
+    pub fn first(&self) -> Option<&Card> {
+        self.cards.first()
+    }
+
+    /// Returns the last card of the hand, like `slice::last`.
+    pub fn last(&self) -> Option<&Card> {
+        self.cards.last()
+    }
+
+    /// Swaps the cards at the given positions.
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The zero-based position of the first card.
+    /// * `j` - The zero-based position of the second card.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let mut hand = Hand::new_from_str("As Kd 7c").unwrap();
+    /// hand.swap(0, 2).unwrap();
+    /// assert_eq!(hand.as_str(), "7c Kd As");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::IndexOutOfBounds` naming the first position
+    /// without a card. The hand is left unchanged in that case.
+    pub fn swap(&mut self, i: usize, j: usize) -> Result<(), HandError> {
+        let count = self.cards.len();
+        if let Some(&index) = [i, j].iter().find(|&&index| index >= count) {
+            return Err(HandError::IndexOutOfBounds { index, count });
+        }
+        self.cards.swap(i, j);
+        Ok(())
+    }
+
+    /// Returns the number of cards in the hand.
+    pub fn get_count(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns whether the card is in the hand.
+    pub fn contains(&self, card: &Card) -> bool {
+        self.cards.contains(card)
+    }
+
+    /// Returns whether the hand holds a card of the given rank.
+    pub fn contains_rank(&self, rank: Rank) -> bool {
+        self.cards.iter().any(|card| card.rank == rank)
+    }
+
+    /// Returns the number of cards of the given rank in the hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::{Rank, Suit};
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("Ks Kd 7s 2s").unwrap();
+    /// assert_eq!(hand.count_rank(Rank::King), 2);
+    /// assert_eq!(hand.count_suit(Suit::Spade), 3);
+    /// ```
+    pub fn count_rank(&self, rank: Rank) -> usize {
+        self.cards.iter().filter(|card| card.rank == rank).count()
+    }
+
+    /// Returns the number of cards of every rank, indexed by `Rank::to_index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Rank;
+    /// use pkr::hand::Hand;
+    ///
+    /// let counts = Hand::new_from_str("Ks Kd 7s").unwrap().rank_counts();
+    /// assert_eq!(counts[Rank::King.to_index()], 2);
+    /// assert_eq!(counts[Rank::Seven.to_index()], 1);
+    /// assert_eq!(counts[Rank::Ace.to_index()], 0);
+    /// ```
+    pub fn rank_counts(&self) -> [u8; 13] {
+        rank_counts(&self.cards)
+    }
+
+    /// Returns every rank in the hand with its number of cards, sorted by
+    /// the number of cards and then by rank, both descending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Rank;
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("7s Ks 7d Ad 7c").unwrap();
+    /// assert_eq!(
+    ///     hand.rank_multiplicities(),
+    ///     vec![(Rank::Seven, 3), (Rank::Ace, 1), (Rank::King, 1)]
+    /// );
+    /// ```
+    pub fn rank_multiplicities(&self) -> Vec<(Rank, u8)> {
+        rank_multiplicities(&self.rank_counts()).collect()
+    }
+
+    /// Returns the number of cards of the given suit in the hand.
+    pub fn count_suit(&self, suit: Suit) -> usize {
+        self.cards.iter().filter(|card| card.suit == suit).count()
+    }
+
+    /// Returns the score of a Hand instance by calling the `evaluate` function.
+    /// The score makes hands comparable by strength.
+    ///
+    /// The hand's score is used to rank the hand in comparison with other
+    /// poker hands. A higher score represents a stronger hand. This is useful
+    /// in games of poker where the strength of a player's hand needs to be
+    /// compared to the hands of others.
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - An unsigned 32-bit integer representing the score of the hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand1 = Hand::new_from_str("Ts Js Qs Ks As").unwrap();
+    /// assert_eq!(hand1.get_score(), 8000014);
+    ///
+    /// let hand2 = Hand::new_from_str("As Ah Ac Ad Ks").unwrap();
+    /// assert_eq!(hand2.get_score(), 7000237);
+    ///
+    /// assert!(hand1.get_score() > hand2.get_score());
+    /// ```
+    pub fn get_score(&self) -> u32 {
+        evaluate(self)
+    }
+
+    /// Returns the score of the ranks of the hand with flushes left out, see
+    /// `evaluate_rank_only` for the scale of the score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::{Hand, HandRank};
+    ///
+    /// let hand = Hand::new_from_str("Ks Qs 9s 7s 2s Kd").unwrap();
+    /// assert_eq!(HandRank::from_score(hand.rank_only_score()), HandRank::OnePair);
+    /// ```
+    pub fn rank_only_score(&self) -> u32 {
+        let ranks: Vec<Rank> = self.cards.iter().map(|card| card.rank).collect();
+        evaluate_rank_only(&ranks)
+    }
+
+    /// Returns the score of the hand calculated by the given evaluator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::{FastEvaluator, Hand};
+    ///
+    /// let hand = Hand::new_from_str("As Ah Ac Ad Ks").unwrap();
+    /// assert_eq!(hand.get_score_with(&FastEvaluator), hand.get_score());
+    /// ```
+    pub fn get_score_with(&self, evaluator: &impl Evaluator) -> u32 {
+        evaluator.score(self.as_slice())
+    }
+
+    /// Returns the `HandValue` of the hand, which gives access to the score,
+    /// the category and a description of the hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("Ks Kd Kh 2c 2s").unwrap();
+    /// assert_eq!(hand.get_value().describe(), "Full House, Kings full of Twos");
+    /// ```
+    pub fn get_value(&self) -> HandValue {
+        HandValue::new(self.get_score())
+    }
+
+    /// Returns the `HandRank` of the hand, i.e. its category such as a flush or
+    /// a full house.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::{Hand, HandRank};
+    ///
+    /// let hand = Hand::new_from_str("Ks Kd Kh 2c 2s").unwrap();
+    /// assert_eq!(hand.get_hand_rank(), HandRank::FullHouse);
+    /// ```
+    pub fn get_hand_rank(&self) -> HandRank {
+        HandRank::from_score(self.get_score())
+    }
+
+    /// Returns the cards making up the best hand, five cards or all cards
+    /// if the hand has fewer.
+    ///
+    /// The cards are ordered by significance like the ranks of
+    /// `HandValue::get_ranks`, e.g. the trips of a full house before the
+    /// pair, and a straight from its highest card down. Where several cards
+    /// of the same rank could be used, e.g. two of three kings for the pair
+    /// of a full house, the cards of the lower `Suit::to_index` are
+    /// preferred, from clubs to spades, and cards of the same rank are
+    /// ordered by it. So the result only depends on the cards of the hand,
+    /// not on their order, like `HandValue::describe`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("2h Ks Kh 9d Kc 2d 2s").unwrap();
+    /// let best: Vec<String> = hand.best_five().iter().map(|c| c.as_str()).collect();
+    /// assert_eq!(best, ["Kc", "Kh", "Ks", "2d", "2h"]);
+    /// ```
+    pub fn best_five(&self) -> Vec<Card> {
+        let value = self.get_value();
+        let ranks = value.get_ranks();
+        let flush_suit = [Suit::Club, Suit::Diamond, Suit::Heart, Suit::Spade]
+            .into_iter()
+            .find(|&suit| self.count_suit(suit) >= 5);
+
+        // The ranks in order of significance with the number of cards each.
+        let groups: Vec<(Rank, usize)> = match value.get_hand_rank() {
+            HandRank::Straight | HandRank::StraightFlush => (0..5)
+                .map(|i| {
+                    let num = ranks[0].as_num() as usize - i;
+                    (Rank::new_from_num(num).unwrap_or(Rank::Ace), 1)
+                })
+                .collect(),
+            category => {
+                let counts: &[usize] = match category {
+                    HandRank::OnePair => &[2],
+                    HandRank::TwoPair => &[2, 2],
+                    HandRank::ThreeOfAKind => &[3],
+                    HandRank::FullHouse => &[3, 2],
+                    HandRank::FourOfAKind => &[4],
+                    _ => &[],
+                };
+                ranks
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &rank)| (rank, counts.get(i).copied().unwrap_or(1)))
+                    .collect()
+            }
+        };
+        let suited = matches!(
+            value.get_hand_rank(),
+            HandRank::Flush | HandRank::StraightFlush
+        );
+
+        groups
+            .into_iter()
+            .flat_map(|(rank, count)| {
+                let mut cards: Vec<Card> = self
+                    .cards_of_rank_iter(rank)
+                    .filter(|card| !suited || Some(card.suit) == flush_suit)
+                    .copied()
+                    .collect();
+                cards.sort_by_key(|card| card.suit.to_index());
+                cards.truncate(count);
+                cards
+            })
+            .collect()
+    }
+
+    /// Returns whether the hand reaches the minimum of the qualifier.
+    ///
+    /// A `Qualifier::PairOfAtLeast` is decided from the ranks alone if the
+    /// hand holds a high enough pair, two pairs or three of a kind, the hand
+    /// is only evaluated otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::{Hand, HandRank, Qualifier};
+    ///
+    /// let tens = Hand::new_from_str("Ts Th 7c 4d 2s").unwrap();
+    /// let queens = Hand::new_from_str("Qs Qh 7c 4d 2s").unwrap();
+    /// assert!(!tens.qualifies(Qualifier::JACKS_OR_BETTER));
+    /// assert!(queens.qualifies(Qualifier::JACKS_OR_BETTER));
+    ///
+    /// assert!(queens.qualifies(Qualifier::AtLeast(HandRank::OnePair)));
+    /// assert!(!queens.qualifies(Qualifier::AtLeast(HandRank::TwoPair)));
+    /// ```
+    pub fn qualifies(&self, minimum: Qualifier) -> bool {
+        match minimum {
+            Qualifier::AtLeast(hand_rank) => self.get_hand_rank() >= hand_rank,
+            Qualifier::PairOfAtLeast(rank) => {
+                let counts = self.rank_counts();
+                let paired = counts.iter().filter(|&&count| count >= 2).count();
+                let high_pair = counts[rank.to_index()..].iter().any(|&count| count >= 2);
+                if high_pair || paired >= 2 || counts.iter().any(|&count| count >= 3) {
+                    return true;
+                }
+                // Only a straight or a flush can still qualify.
+                self.get_hand_rank() >= HandRank::TwoPair
+            }
+        }
+    }
+
+    /// Returns whether the hand combined with the board is the best possible
+    /// hand on that board.
+    ///
+    /// The hand is usually a player's hole cards. It is combined with the
+    /// board and its score is compared to the score of the nuts as computed by
+    /// `analysis::nut_score`.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The community cards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::board::Board;
+    /// use pkr::hand::Hand;
+    ///
+    /// let board = Board::new_from_str("As Ks Qs 2d 7c").unwrap();
+    ///
+    /// assert!(Hand::new_from_str("Js Ts").unwrap().is_nuts(&board));
+    /// assert!(!Hand::new_from_str("Ah Ad").unwrap().is_nuts(&board));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hand and the board together exceed `MAX_CARDS` cards.
+    #[cfg(feature = "std")]
+    pub fn is_nuts(&self, board: &Board) -> bool {
+        let score = self
+            .with_board(board.cards())
+            .expect("Hand and board must form a valid hand")
+            .get_score();
+        score >= nut_score(board)
+    }
+
+    /// Returns the fraction of all seven card hands this hand beats, see
+    /// `analysis::percentile`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let quads = Hand::new_from_str("As Ah Ad Ac Ks").unwrap();
+    /// assert!(quads.percentile() > 0.99);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn percentile(&self) -> f64 {
+        percentile(self.get_score())
+    }
+
+    /// Returns the ranks of all cards in the hand, ignoring the suits.
+    ///
+    /// This can be useful when only the ranks of the cards matter for a certain
+    /// operation or comparison, and the suits are irrelevant.
+    ///
+    /// # Returns
+    ///
+    /// A Vec of Rank representing the ranks of all cards in the hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::{Card, Rank, Suit};
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new(vec![
+    ///     Card::new(Rank::Ace, Suit::Heart),
+    ///     Card::new(Rank::Two, Suit::Spade),
+    ///     Card::new(Rank::Four, Suit::Diamond),
+    ///     Card::new(Rank::Five, Suit::Heart),
+    ///     Card::new(Rank::Three, Suit::Heart),
+    /// ]).unwrap();
+    ///
+    /// let ranks = hand.get_ranks();
+    /// assert_eq!(ranks, vec![Rank::Ace, Rank::Two, Rank::Four, Rank::Five, Rank::Three]);
+    /// ```
+    pub fn get_ranks(&self) -> Vec<Rank> {
+        self.cards.iter().map(|card| card.rank).collect()
+    }
+
+    /// Returns the ranks of all cards in the hand in descending order,
+    /// leaving the hand unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Rank;
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("5h As 5d 2c").unwrap();
+    /// assert_eq!(hand.ranks_desc(), vec![Rank::Ace, Rank::Five, Rank::Five, Rank::Two]);
+    /// assert_eq!(hand.as_str(), "5h As 5d 2c");
+    /// ```
+    pub fn ranks_desc(&self) -> Vec<Rank> {
+        let mut ranks = self.get_ranks();
+        ranks.sort_unstable_by_key(|&rank| core::cmp::Reverse(rank));
+        ranks
+    }
+
+    /// Returns the ranks of all cards in the hand in ascending order,
+    /// leaving the hand unchanged.
+    pub fn ranks_asc(&self) -> Vec<Rank> {
+        let mut ranks = self.get_ranks();
+        ranks.sort_unstable();
+        ranks
+    }
+
+    /// Returns the cards of the hand sorted by rank, leaving the hand
+    /// unchanged.
+    ///
+    /// Cards of the same rank keep their order in the hand, like with
+    /// `sort_by_rank`.
+    ///
+    /// # Arguments
+    ///
+    /// * `descending` - A boolean indicating if the cards should be sorted in
+    ///   descending order (true) or ascending order (false).
+    pub fn cards_sorted_by_rank(&self, descending: bool) -> Vec<Card> {
+        let mut cards = self.cards.clone();
+        if descending {
+            cards.sort_by_key(|card| core::cmp::Reverse(card.rank));
+        } else {
+            cards.sort_by_key(|card| card.rank);
+        }
+        cards
+    }
+
+    /// Returns a string representation of the `Hand`.
+    ///
+    /// The string consists of card identifiers separated by spaces. Each card
+    /// identifier consists of two characters: the rank and the suit. For
+    /// example, the ace of clubs is represented as "Ac".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    /// use pkr::card::{Card, Rank, Suit};
+    ///
+    /// let hand = Hand::new(vec![
+    ///     Card { rank: Rank::Ace, suit: Suit::Club },
+    ///     Card { rank: Rank::King, suit: Suit::Spade },
+    ///     Card { rank: Rank::Queen, suit: Suit::Heart },
+    ///     Card { rank: Rank::Jack, suit: Suit::Diamond },
+    ///     Card { rank: Rank::Ten, suit: Suit::Club },
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(hand.as_str(), "Ac Ks Qh Jd Tc");
+    /// ```
+    pub fn as_str(&self) -> String {
+        self.cards
+            .iter()
+            .map(|card| card.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns a one line summary of the hand with the cards in descending
+    /// order of rank and the description of its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("Ts Js 2d Qs Ks 9c As").unwrap();
+    /// assert_eq!(
+    ///     hand.summary(),
+    ///     "7 cards: A♠ K♠ Q♠ J♠ T♠ 9♣ 2♦ — Royal Flush"
+    /// );
+    /// ```
+    pub fn summary(&self) -> String {
+        let cards: Vec<String> = self
+            .cards_sorted_by_rank(true)
+            .iter()
+            .map(|card| format!("{}{}", card.rank.as_str(), card.suit.symbol()))
+            .collect();
+        format!(
+            "{} cards: {} — {}",
+            self.cards.len(),
+            cards.join(" "),
+            self.get_value().describe()
+        )
+    }
+
+    /// Explains how the hand is evaluated in plain English, e.g. for
+    /// teaching.
+    ///
+    /// Every sentence is generated from a step of `evaluate_traced`, so the
+    /// explanation follows the decisions of the real evaluator. The last
+    /// sentence names the best hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("As Ks Qs Js 9s 9d 2c").unwrap();
+    /// assert_eq!(
+    ///     hand.explain(),
+    ///     [
+    ///         "You have five spades → flush possible",
+    ///         "Your five highest spades are A K Q J 9",
+    ///         "No straight within the spades → not a straight flush",
+    ///         "A flush beats every straight → no need to look for one",
+    ///         "No four of a kind or full house → the flush plays",
+    ///         "Best hand: Flush, Ace high",
+    ///     ]
+    /// );
+    /// ```
+    pub fn explain(&self) -> Vec<String> {
+        explain(self)
+    }
+
+    /// Encodes the hand as one length byte followed by one byte per card in
+    /// the order of the hand, see `Card::to_byte`.
+    ///
+    /// The encoding is stable across versions of the crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("As Kd").unwrap();
+    /// assert_eq!(hand.to_bytes(), [2, 51, 45]);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encode_cards(&self.cards)
+    }
+
+    /// Returns the cards of the hand as a bit mask with bit `Card::to_byte`
+    /// set for every card, e.g. as a key of the hand regardless of the order
+    /// of its cards. See `Hand::from_bitmask` for the way back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("As 2c").unwrap();
+    /// assert_eq!(hand.as_bitmask(), 1 << 51 | 1);
+    /// ```
+    pub fn as_bitmask(&self) -> u64 {
+        bitmask(&self.cards)
+    }
+
+    /// Sorts the cards in the hand by suit in ascending order.
+    ///
+    /// The relative order of cards with the same suit is maintained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    /// use pkr::card::{Card, Rank, Suit};
+    ///
+    /// let mut hand = Hand::new(vec![
+    ///     Card { rank: Rank::Ace, suit: Suit::Heart },
+    ///     Card { rank: Rank::King, suit: Suit::Club },
+    ///     Card { rank: Rank::Queen, suit: Suit::Spade },
+    ///     Card { rank: Rank::Jack, suit: Suit::Diamond },
+    ///     Card { rank: Rank::Ten, suit: Suit::Heart },
+    /// ]).unwrap();
+    ///
+    /// hand.sort_by_suit();
+    ///
+    /// assert_eq!(hand.as_str(), "Kc Jd Ah Th Qs");
+    /// ```
+    pub fn sort_by_suit(&mut self) {
+        self.cards
+            .sort_by(|a, b| a.suit.partial_cmp(&b.suit).unwrap());
+    }
+
+    /// Sorts the hand by rank, preserving the original order within each rank.
+    ///
+    /// # Arguments
+    ///
+    /// * `ascending` - A boolean indicating if sorting should be in ascending
+    ///   order (true) or descending order (false).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let mut hand = Hand::new_from_str("Ah 2s 4d 5h 3h").unwrap();
+    /// hand.sort_by_rank(true);
+    /// assert_eq!(hand.as_str(), "2s 3h 4d 5h Ah");
+    ///
+    /// hand.sort_by_rank(false);
+    /// assert_eq!(hand.as_str(), "Ah 5h 4d 3h 2s");
+    /// ```
+    pub fn sort_by_rank(&mut self, ascending: bool) {
+        self.cards = self.cards_sorted_by_rank(!ascending);
+    }
+
+    /// Sorts the hand by rank and cards of the same rank by suit, so the
+    /// resulting order does not depend on the original order.
+    ///
+    /// Suits are ordered clubs, diamonds, hearts and spades, in the same
+    /// direction as the ranks.
+    ///
+    /// # Arguments
+    ///
+    /// * `ascending` - A boolean indicating if sorting should be in ascending
+    ///   order (true) or descending order (false).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let mut hand = Hand::new_from_str("Kh 2s Kc Ks").unwrap();
+    /// hand.sort_by_rank_and_suit(false);
+    /// assert_eq!(hand.as_str(), "Ks Kh Kc 2s");
+    /// ```
+    pub fn sort_by_rank_and_suit(&mut self, ascending: bool) {
+        // Cards are ordered by rank first and by suit for equal ranks.
+        if ascending {
+            self.cards.sort_unstable();
+        } else {
+            self.cards.sort_unstable_by(|a, b| b.cmp(a));
+        }
+    }
+
+    /// Returns all cards in the hand of a given suit.
+    ///
+    /// # Arguments
+    ///
+    /// * `suit` - A suit of which the cards are to be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    /// use pkr::card::{Card, Rank, Suit};
+    ///
+    /// let hand = Hand::new(vec![
+    ///     Card { rank: Rank::Two, suit: Suit::Heart },
+    ///     Card { rank: Rank::Three, suit: Suit::Heart },
+    ///     Card { rank: Rank::Four, suit: Suit::Spade },
+    ///     Card { rank: Rank::Five, suit: Suit::Diamond },
+    ///     Card { rank: Rank::Six, suit: Suit::Heart },
+    /// ]).unwrap();
+    ///
+    /// let hearts = hand.cards_of_suit(Suit::Heart);
+    /// assert_eq!(hearts.len(), 3);
+    /// ```
+    pub fn cards_of_suit(&self, suit: Suit) -> Vec<Card> {
+        self.cards_of_suit_iter(suit).copied().collect()
+    }
+
+    /// Returns an iterator over the cards in the hand of a given suit.
+    pub fn cards_of_suit_iter(&self, suit: Suit) -> impl Iterator<Item = &Card> {
+        self.filter_cards_iter(move |card| card.suit == suit)
+    }
+
+    /// Returns all cards in the hand of a given rank.
+    ///
+    /// # Arguments
+    ///
+    /// * `rank` - A rank of which the cards are to be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::{Rank, Suit};
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("Ks 7d Kh 2c Kc").unwrap();
+    /// let kings = hand.cards_of_rank(Rank::King);
+    /// let suits: Vec<Suit> = kings.iter().map(|card| card.suit).collect();
+    /// assert_eq!(suits, vec![Suit::Spade, Suit::Heart, Suit::Club]);
+    /// ```
+    pub fn cards_of_rank(&self, rank: Rank) -> Vec<Card> {
+        self.cards_of_rank_iter(rank).copied().collect()
+    }
+
+    /// Returns an iterator over the cards in the hand of a given rank.
+    pub fn cards_of_rank_iter(&self, rank: Rank) -> impl Iterator<Item = &Card> {
+        self.filter_cards_iter(move |card| card.rank == rank)
+    }
+
+    /// Returns all cards in the hand matching a predicate, in the order of
+    /// the hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - A function returning true for the cards to be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::{Rank, Suit};
+    /// use pkr::hand::Hand;
+    ///
+    /// let hand = Hand::new_from_str("As Ks 7d Qh 2s").unwrap();
+    /// let high_spades = hand.filter_cards(|card| card.suit == Suit::Spade && card.rank > Rank::Ten);
+    /// assert_eq!(high_spades.len(), 2);
+    /// ```
+    pub fn filter_cards<P: Fn(&Card) -> bool>(&self, predicate: P) -> Vec<Card> {
+        self.filter_cards_iter(predicate).copied().collect()
+    }
+
+    /// Returns an iterator over the cards in the hand matching a predicate.
+    pub fn filter_cards_iter<P: Fn(&Card) -> bool>(
+        &self,
+        predicate: P,
+    ) -> impl Iterator<Item = &Card> {
+        self.cards.iter().filter(move |card| predicate(card))
+    }
+
+    /// Splits the cards of the hand into those matching a predicate and the
+    /// others, both in the order of the hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Card;
+    /// use pkr::hand::Hand;
+    ///
+    /// let hole = [Card::new_from_str("Ah").unwrap(), Card::new_from_str("Qh").unwrap()];
+    /// let hand = Hand::new_from_str("Kh Ah 9h Qh 5h").unwrap();
+    /// let (hole_cards, board) = hand.partition(|card| hole.contains(card));
+    /// assert_eq!(hole_cards, hole);
+    /// assert_eq!(board.len(), 3);
+    /// ```
+    pub fn partition<P: Fn(&Card) -> bool>(&self, predicate: P) -> (Vec<Card>, Vec<Card>) {
+        self.cards.iter().partition(|card| predicate(card))
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cards.len() == other.cards.len() && self.sorted_cards() == other.sorted_cards()
+    }
+}
+
+impl Eq for Hand {}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get_score()
+            .cmp(&other.get_score())
+            .then_with(|| self.sorted_cards().cmp(&other.sorted_cards()))
+    }
+}
+
+/// Hashes the cards in sorted order, so equal hands have equal hashes.
+impl Hash for Hand {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.sorted_cards().hash(state);
+    }
+}
+
+/// Formats the hand as its cards separated by spaces, e.g. "As Ks 7d".
+///
+/// The alternate form `{:#}` shows one line per suit with the ranks in
+/// descending order:
+///
+/// ```
+/// use pkr::hand::Hand;
+///
+/// let hand = Hand::new_from_str("As 7d Ks 2d").unwrap();
+/// assert_eq!(hand.to_string(), "As 7d Ks 2d");
+/// assert_eq!(format!("{:#}", hand), "♠ A K\n♦ 7 2");
+/// ```
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            fmt_by_suit(&self.cards, f)
+        } else {
+            write!(f, "{}", self.as_str())
+        }
+    }
+}
+
+/// Formats the hand as `Hand(As Ks 7d)`, or with `{:#?}` like the alternate
+/// form of `Display` below a `Hand:` header.
+impl fmt::Debug for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            writeln!(f, "Hand:")?;
+            fmt_by_suit(&self.cards, f)
+        } else {
+            write!(f, "Hand({})", self.as_str())
+        }
+    }
+}
+
+/// Returns the card at the given position.
+///
+/// # Panics
+///
+/// Panics if there is no card at `index`, use `Hand::get` to handle missing
+/// cards.
+impl Index<usize> for Hand {
+    type Output = Card;
+
+    fn index(&self, index: usize) -> &Card {
+        &self.cards[index]
+    }
+}
+
+impl IntoIterator for Hand {
+    type Item = Card;
+    type IntoIter = alloc::vec::IntoIter<Card>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Hand {
+    type Item = &'a Card;
+    type IntoIter = core::slice::Iter<'a, Card>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.iter()
+    }
+}
+
+/// Collects cards into a hand.
+///
+/// # Panics
+///
+/// Panics if the cards do not form a valid hand, use `Hand::try_from` to
+/// handle invalid cards.
+impl FromIterator<Card> for Hand {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        Hand::new(iter.into_iter().collect()).expect("Cards must form a valid hand")
+    }
+}
+
+impl TryFrom<Vec<Card>> for Hand {
+    type Error = HandError;
+
+    fn try_from(cards: Vec<Card>) -> Result<Self, Self::Error> {
+        Hand::new(cards)
+    }
+}
+
+/// Adds cards to the hand, skipping every card that is already in the hand
+/// or would exceed `Hand::MAX_CARDS` cards. Use `Hand::try_extend` to be
+/// notified about skipped cards.
+impl Extend<Card> for Hand {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, iter: I) {
+        for card in iter {
+            if self.cards.len() < Hand::MAX_CARDS && !self.cards.contains(&card) {
+                self.cards.push(card);
+            }
+        }
+    }
+}
+
+/// Serializes the hand as its string form, e.g. "As Ks Qs Js Ts", in human
+/// readable formats and as one byte per card, see `Card::to_byte`, in binary
+/// formats.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Hand {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.as_str())
+        } else {
+            let bytes: Vec<u8> = self.cards.iter().map(Card::to_byte).collect();
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+/// Deserializes a hand from the forms written by `Serialize`, validating it
+/// like `Hand::new_from_str` and `Hand::new`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Hand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HandVisitor)
+        } else {
+            deserializer.deserialize_bytes(HandVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct HandVisitor;
+
+#[cfg(feature = "serde")]
+impl serde::de::Visitor<'_> for HandVisitor {
+    type Value = Hand;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "a hand like \"As Ks Qs Js Ts\" or one byte per card")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Hand, E> {
+        Hand::new_from_str(s).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Hand, E> {
+        let cards = bytes
+            .iter()
+            .map(|&byte| Card::from_byte(byte))
+            .collect::<Result<Vec<Card>, _>>()
+            .map_err(E::custom)?;
+        Hand::new(cards).map_err(E::custom)
+    }
+}
+
+/// Returns the bit mask of the cards with bit `Card::to_byte` set for every
+/// card, see `Hand::as_bitmask`.
+pub(crate) fn bitmask(cards: &[Card]) -> u64 {
+    cards
+        .iter()
+        .fold(0u64, |mask, card| mask | 1 << card.to_byte())
+}
+
+/// Writes the cards grouped by suit, one line per suit from spades to clubs
+/// with the ranks in descending order, e.g. "♠ A K\n♦ 7 2".
+pub(crate) fn fmt_by_suit(cards: &[Card], f: &mut fmt::Formatter) -> fmt::Result {
+    let mut lines = 0;
+    for suit in [Suit::Spade, Suit::Heart, Suit::Diamond, Suit::Club] {
+        let mut ranks: Vec<Rank> = cards
+            .iter()
+            .filter(|card| card.suit == suit)
+            .map(|card| card.rank)
+            .collect();
+        if ranks.is_empty() {
+            continue;
+        }
+        ranks.sort_unstable_by_key(|&rank| core::cmp::Reverse(rank));
+
+        if lines > 0 {
+            writeln!(f)?;
+        }
+        write!(f, "{}", suit.symbol())?;
+        for rank in ranks {
+            write!(f, " {}", rank.as_str())?;
+        }
+        lines += 1;
+    }
+    Ok(())
+}
+
+/// Returns the number of cards of every rank, indexed by `Rank::to_index`.
+fn rank_counts(cards: &[Card]) -> [u8; 13] {
+    let mut counts = [0; 13];
+    for card in cards {
+        counts[card.rank.to_index()] += 1;
+    }
+    counts
+}
+
+/// Returns every rank with at least one card and its number of cards, sorted
+/// by the number of cards and then by rank, both descending, given the
+/// number of cards of every rank indexed by `Rank::to_index`.
+///
+/// The multiplicities are produced on the fly, so that the evaluator can
+/// use them without allocating.
+pub(crate) fn rank_multiplicities(counts: &[u8; 13]) -> impl Iterator<Item = (Rank, u8)> + '_ {
+    let max_count = counts.iter().copied().max().unwrap_or(0);
+    (1..=max_count).rev().flat_map(move |count| {
+        (0..13)
+            .rev()
+            .filter(move |&index| counts[index] == count)
+            .map(move |index| {
+                let rank = Rank::new_from_num(index + 2).expect("Index must be a valid rank");
+                (rank, count)
+            })
+    })
+}
+
+/// Parses the cards of a string in the flexible notation accepted by
+/// `Hand::new_from_str` without checking the number of cards or duplicates.
+pub(crate) fn parse_cards(s: &str) -> Result<Vec<Card>, HandError> {
+    let s = s.trim();
+    let s = s
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(s);
+
+    let tokens = s
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .flat_map(split_cards);
+    parse_tokens(tokens)
+}
+
+/// Parses the non-blank records of a line, split at the delimiter if any.
+fn parse_records(
+    line: &str,
+    line_number: usize,
+    delimiter: Option<char>,
+) -> Vec<Result<Hand, ParseError>> {
+    let parse = |record: &str| {
+        Hand::new_from_str(record).map_err(|e| ParseError {
+            line: line_number,
+            kind: ParseErrorKind::Hand(e),
+        })
+    };
+    match delimiter {
+        Some(delimiter) => line
+            .split(delimiter)
+            .filter(|record| !record.trim().is_empty())
+            .map(parse)
+            .collect(),
+        None if line.trim().is_empty() => Vec::new(),
+        None => alloc::vec![parse(line)],
+    }
+}
+
+/// Parses card identifiers, naming the first invalid one.
+fn parse_tokens<'a>(tokens: impl Iterator<Item = &'a str>) -> Result<Vec<Card>, HandError> {
+    tokens
+        .enumerate()
+        .map(|(index, token)| {
+            let card = match token.strip_prefix("10") {
+                Some(suit) if !suit.is_empty() => Card::new_from_str(&format!("T{}", suit)),
+                _ => Card::new_from_str(token),
+            };
+            card.map_err(|source| HandError::InvalidCard {
+                index,
+                token: token.into(),
+                source,
+            })
+        })
+        .collect()
+}
+
+/// Splits a run of cards without separators like "AsKs10h" into the
+/// identifiers of the single cards. A trailing incomplete card is returned
+/// as is.
+fn split_cards(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = s;
+    core::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let len = if rest.starts_with("10") { 3 } else { 2 };
+        let end = rest
+            .char_indices()
+            .nth(len)
+            .map_or(rest.len(), |(index, _)| index);
+        let (card, tail) = rest.split_at(end);
+        rest = tail;
+        Some(card)
+    })
+}
+
+/// Returns an error naming the first card that appears more than once.
+pub(super) fn check_duplicates(cards: &[Card]) -> Result<(), HandError> {
+    for (i, card) in cards.iter().enumerate() {
+        if cards[..i].contains(card) {
+            return Err(HandError::DuplicateCard(*card));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_create_hand() {
+    let cards = vec![
+        Card::new_from_str("2h").unwrap(),
+        Card::new_from_str("3d").unwrap(),
+        Card::new_from_str("4s").unwrap(),
+        Card::new_from_str("5c").unwrap(),
+        Card::new_from_str("6h").unwrap(),
+        Card::new_from_str("7d").unwrap(),
+        Card::new_from_str("8s").unwrap(),
+    ];
+
+    let hand = Hand::new(cards);
+
+    assert!(hand.is_ok());
+
+    let hand = hand.unwrap();
+    assert_eq!(hand.as_slice().len(), 7)
+}
+
+#[test]
 fn test_create_hand_with_wrong_number_of_cards() {
     let cards = vec![Card::new_from_str("3d").unwrap()];
 
-    let result = Hand::new(cards);
-    assert!(result.is_err());
-}
+    let result = Hand::new(cards);
+    assert!(result.is_err());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::CardError;
+    use crate::hand::HoleCards;
+
+    #[test]
+    fn test_straight_flushes() {
+        let hand = Hand::new_from_str("2s As Js Ks Qs 9c Ts").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 8_000_000 + 14);
+
+        let hand = Hand::new_from_str("2s Kc Js Ks Qs 9s Ts").unwrap();
+        let score = hand.get_score();
+
+        assert_eq!(score, 8_000_000 + 13);
+
+        let hand = Hand::new_from_str("9h 8h Jc Tc Qh Jh Th").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 8_000_000 + 12);
+
+        let hand = Hand::new_from_str("2s 7s Js 9s 8s 9c Ts").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 8_000_000 + 11);
+
+        let hand = Hand::new_from_str("9d 8d Td 7d 6d 3c Th Kh Qd").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 8_000_000 + 10);
+
+        let hand = Hand::new_from_str("9d 8d 5d 6d 7d").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 8_000_000 + 9);
+
+        let hand = Hand::new_from_str("4c 5c 6c 7c 8c 3c 2c").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 8_000_000 + 8);
+
+        let hand = Hand::new_from_str("7d 7c 7s 6d 5d 3d 4d").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 8_000_000 + 7);
+
+        let hand = Hand::new_from_str("6d 5d 4d 3d 2d Ad").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 8_000_000 + 6);
+
+        let hand = Hand::new_from_str("2d Ad 3d 4d 5d 3c Th").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 8_000_000 + 5);
+    }
+
+    #[test]
+    fn test_four_of_a_kind() {
+        let hand = Hand::new_from_str("As Ac Ad Ah Ts 9c Qs").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 7_000_000 + (14 << 4) + 12);
+
+        let hand = Hand::new_from_str("As Ac Ad Ah").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 7_000_000 + 14);
+
+        let hand = Hand::new_from_str("9c Ks Kc Kd Kh Ts 2s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 7_000_000 + (13 << 4) + 10);
+
+        let hand = Hand::new_from_str("Qs Qc Qd Qh 8s 9c 9s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 7_000_000 + (12 << 4) + 9);
+
+        let hand = Hand::new_from_str("2s 2c 2d 2h As 9c Qs").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 7_000_000 + (2 << 4) + 14);
+    }
+
+    #[test]
+    fn test_full_house() {
+        let hand = Hand::new_from_str("As Ac Ad Kh Ts Kc Qs").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 6_000_000 + (14 << 4) + 13);
+
+        let hand = Hand::new_from_str("Ks Qc Kd Kh Qd").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 6_000_000 + (13 << 4) + 12);
+
+        let hand = Hand::new_from_str("Tc 9s 9c Td 9h Ts 2s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 6_000_000 + (10 << 4) + 9);
+
+        let hand = Hand::new_from_str("4s 4c 4d 5h 5s 9c 9s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 6_000_000 + (4 << 4) + 9);
+
+        let hand = Hand::new_from_str("2s 2c 2d 3h As 3c Qs").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 6_000_000 + (2 << 4) + 3);
+    }
+
+    #[test]
+    fn test_flush() {
+        let hand = Hand::new_from_str("As Ks Qs Js 9s 8s 7s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(
+            score,
+            5_000_000 + (14 << 16) + (13 << 12) + (12 << 8) + (11 << 4) + 9
+        );
+
+        // Check corner case vs. lowest full house
+        let hand = Hand::new_from_str("2s 2c 2d 3h As 3c Qs").unwrap();
+        let score_low_fh = hand.get_score();
+        assert!(score < score_low_fh);
+
+        let hand = Hand::new_from_str("Ks Kd Qs Js 9s 9d 7s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(
+            score,
+            5_000_000 + (13 << 16) + (12 << 12) + (11 << 8) + (9 << 4) + 7
+        );
+
+        let hand = Hand::new_from_str("Qs Js 9s 8s 7s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(
+            score,
+            5_000_000 + (12 << 16) + (11 << 12) + (9 << 8) + (8 << 4) + 7
+        );
+
+        let hand = Hand::new_from_str("7s Kd Qd 4s 5s 3s 2s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(
+            score,
+            5_000_000 + (7 << 16) + (5 << 12) + (4 << 8) + (3 << 4) + 2
+        );
+    }
+
+    #[test]
+    fn test_straight() {
+        let hand = Hand::new_from_str("2d Ac Js Ks Qs 9c Ts").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 4_000_000 + 14);
+
+        let hand = Hand::new_from_str("2s Kc Jh Kd Qs 9s Ts").unwrap();
+        let score = hand.get_score();
+
+        assert_eq!(score, 4_000_000 + 13);
+
+        let hand = Hand::new_from_str("9c 8h Jc Tc Qs Jh Th").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 4_000_000 + 12);
+
+        let hand = Hand::new_from_str("2c 7c Js 9s 8h 9c Ts").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 4_000_000 + 11);
+
+        let hand = Hand::new_from_str("9h 8d Ts 7d 6c 3c Th Kh Qd").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 4_000_000 + 10);
+
+        let hand = Hand::new_from_str("9c 8h 5d 6d 7d").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 4_000_000 + 9);
+
+        let hand = Hand::new_from_str("4c 5d 6c 7h 8c 3d 2c").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 4_000_000 + 8);
+
+        let hand = Hand::new_from_str("7d 7c 7s 6d 5c 3d 4d").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 4_000_000 + 7);
+
+        let hand = Hand::new_from_str("6d 5d 4d 3c 2d Ac").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 4_000_000 + 6);
+
+        let hand = Hand::new_from_str("2d Ac 3d 4d 5d 3c Th").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 4_000_000 + 5);
+    }
+
+    #[test]
+    fn test_three_of_a_kind() {
+        let hand = Hand::new_from_str("2s Ac Ad Ah Ts 9c Qs").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 3_000_000 + (14 << 8) + (12 << 4) + 10);
+
+        let hand = Hand::new_from_str("As Ac Ad 2h").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 3_000_000 + (14 << 4) + 2);
+
+        let hand = Hand::new_from_str("As Ac Ad").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 3_000_000 + 14);
+
+        let hand = Hand::new_from_str("9c Ks Kc Kd Ah Ts 2s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 3_000_000 + (13 << 8) + (14 << 4) + 10);
+
+        let hand = Hand::new_from_str("9c 3s 2c 2d Kh Ts 2s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 3_000_000 + (2 << 8) + (13 << 4) + 10);
+
+        let hand = Hand::new_from_str("2s 2c 2d").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 3_000_000 + 2);
+    }
+
+    #[test]
+    fn test_two_pair() {
+        let hand = Hand::new_from_str("Ks Ac Ad Kh Ts 2c Qs").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 2_000_000 + (14 << 8) + (13 << 4) + 12);
+
+        let hand = Hand::new_from_str("Ks Qc Kd Ah Qd").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 2_000_000 + (13 << 8) + (12 << 4) + 14);
+
+        let hand = Hand::new_from_str("Ks Qc Kd Qd").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 2_000_000 + (13 << 4) + 12);
+
+        let hand = Hand::new_from_str("Tc 8s 9c 8d 9h Ts 2s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 2_000_000 + (10 << 8) + (9 << 4) + 8);
+
+        let hand = Hand::new_from_str("4s 4c 2d 5h 5s 9c 9s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 2_000_000 + (9 << 8) + (5 << 4) + 4);
+
+        let hand = Hand::new_from_str("2s 2c 3h 3c").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 2_000_000 + (3 << 4) + 2);
+    }
+
+    #[test]
+    fn test_two_pair_kicker() {
+        let score = |s: &str| Hand::new_from_str(s).unwrap().get_score();
+
+        // The same two pairs are split by the kicker.
+        assert!(score("As Ad 5s 5d Ks") > score("Ac Ah 5c 5h Qs"));
+        assert_eq!(score("As Ad 5s 5d Ks 2c"), score("Ac Ah 5c 5h Kd 3c"));
+
+        // A counterfeited lower pair plays as the kicker.
+        let counterfeited = score("5s 4s Ad 5h 4c Ac");
+        assert_eq!(counterfeited, score("Ad Ac 5s 5h 4c"));
+        assert_eq!(counterfeited, score("5c 2d Ad 5h 4c Ac"));
+        assert!(counterfeited < score("Kd 5c Ad 5h 4c Ac"));
+    }
+
+    #[test]
+    fn test_pair() {
+        let hand = Hand::new_from_str("Ks Ac Ad 9h Js 2c Qs").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 1_000_000 + (14 << 12) + (13 << 8) + (12 << 4) + 11);
+
+        let hand = Hand::new_from_str("Ks 2c Kd Ah Qd").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 1_000_000 + (13 << 12) + (14 << 8) + (12 << 4) + 2);
+
+        let hand = Hand::new_from_str("Ks 2c Kd Qd").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 1_000_000 + (13 << 8) + (12 << 4) + 2);
+
+        let hand = Hand::new_from_str("Ks 2c Kd").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 1_000_000 + (13 << 4) + 2);
+
+        let hand = Hand::new_from_str("Ks Kd").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 1_000_000 + 13);
+
+        let hand = Hand::new_from_str("Tc 3s 5c 8d 9h Ts 2s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 1_000_000 + (10 << 12) + (9 << 8) + (8 << 4) + 5);
+
+        let hand = Hand::new_from_str("4s 4c 2d 3h 5s 9c Ts").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 1_000_000 + (4 << 12) + (10 << 8) + (9 << 4) + 5);
+
+        let hand = Hand::new_from_str("2s 2c Ah Kc").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, 1_000_000 + (2 << 8) + (14 << 4) + 13);
+    }
+
+    #[test]
+    fn test_high_card() {
+        let hand = Hand::new_from_str("Ks Ac 9d 8h Js 2c Qs").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, (14 << 16) + (13 << 12) + (12 << 8) + (11 << 4) + 9);
+
+        let hand = Hand::new_from_str("Ks 2c Jd Ah Qd").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, (14 << 16) + (13 << 12) + (12 << 8) + (11 << 4) + 2);
+
+        let hand = Hand::new_from_str("Ks 2c 3d Qd").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, (13 << 12) + (12 << 8) + (3 << 4) + 2);
+
+        let hand = Hand::new_from_str("Ks 2c 4d").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, (13 << 8) + (4 << 4) + 2);
+
+        let hand = Hand::new_from_str("As Kd").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, (14 << 4) + 13);
+
+        let hand = Hand::new_from_str("Ac 3s 5c 8d 9h Ts 2s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, (14 << 16) + (10 << 12) + (9 << 8) + (8 << 4) + 5);
+
+        let hand = Hand::new_from_str("7s 4c 2d 3h 5s 8c 9s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, (9 << 16) + (8 << 12) + (7 << 8) + (5 << 4) + 4);
+
+        let hand = Hand::new_from_str("2s 3c 4h 5c 7s").unwrap();
+        let score = hand.get_score();
+        assert_eq!(score, (7 << 16) + (5 << 12) + (4 << 8) + (3 << 4) + 2);
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_is_nuts() {
+        let board = Board::new_from_str("Kh Kd 7c 7s 2h").unwrap();
+
+        assert!(Hand::new_from_str("Ks Kc").unwrap().is_nuts(&board));
+        assert!(!Hand::new_from_str("7h 7d").unwrap().is_nuts(&board));
+        assert!(!Hand::new_from_str("As Ac").unwrap().is_nuts(&board));
+    }
+
+    #[test]
+    fn test_corner_cases() {
+        let hand1 = Hand::new_from_str("2d Ad 3d 4d 5d").unwrap();
+        let score1 = hand1.get_score();
+        assert_eq!(score1, 8_000_000 + 5);
+
+        let hand2 = Hand::new_from_str("As Ac Ad Ah Ks").unwrap();
+        let score2 = hand2.get_score();
+        assert_eq!(score2, 7_000_000 + (14 << 4) + 13);
+
+        assert!(score1 > score2);
+
+        let hand1 = Hand::new_from_str("2s 2c 2d 2h").unwrap();
+        let score1 = hand1.get_score();
+        assert_eq!(score1, 7_000_000 + 2);
+
+        let hand2 = Hand::new_from_str("As Ac Ad Kh Kc").unwrap();
+        let score2 = hand2.get_score();
+        assert_eq!(score2, 6_000_000 + (14 << 4) + 13);
+
+        assert!(score1 > score2);
+
+        let hand1 = Hand::new_from_str("2s 2c 2d 3h 3c").unwrap();
+        let score1 = hand1.get_score();
+        assert_eq!(score1, 6_000_000 + (2 << 4) + 3);
+
+        let hand2 = Hand::new_from_str("As Ks Qs Js 9s").unwrap();
+        let score2 = hand2.get_score();
+        assert_eq!(
+            score2,
+            5_000_000 + (14 << 16) + (13 << 12) + (12 << 8) + (11 << 4) + 9
+        );
+
+        assert!(score1 > score2);
+
+        let hand1 = Hand::new_from_str("7s 4s 5s 3s 2s").unwrap();
+        let score1 = hand1.get_score();
+        assert_eq!(
+            score1,
+            5_000_000 + (7 << 16) + (5 << 12) + (4 << 8) + (3 << 4) + 2
+        );
+
+        let hand2 = Hand::new_from_str("Ac Js Ks Qs Ts").unwrap();
+        let score2 = hand2.get_score();
+        assert_eq!(score2, 4_000_000 + 14);
+
+        assert!(score1 > score2);
+
+        let hand1 = Hand::new_from_str("2d Ac 3d 4d 5d").unwrap();
+        let score1 = hand1.get_score();
+        assert_eq!(score1, 4_000_000 + 5);
+
+        let hand2 = Hand::new_from_str("Ks Ac Ad Ah Qs").unwrap();
+        let score2 = hand2.get_score();
+        assert_eq!(score2, 3_000_000 + (14 << 8) + (13 << 4) + 12);
+
+        assert!(score1 > score2);
+
+        let hand1 = Hand::new_from_str("2s 2c 2d").unwrap();
+        let score1 = hand1.get_score();
+        assert_eq!(score1, 3_000_000 + 2);
+
+        let hand2 = Hand::new_from_str("Ks Ac Ad Kh Qs").unwrap();
+        let score2 = hand2.get_score();
+        assert_eq!(score2, 2_000_000 + (14 << 8) + (13 << 4) + 12);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        assert!(score1 > score2);
 
-    #[test]
-    fn test_straight_flushes() {
-        let hand = Hand::new_from_str("2s As Js Ks Qs 9c Ts").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 8_000_000 + 14);
+        let hand1 = Hand::new_from_str("2s 2c 3h 3c").unwrap();
+        let score1 = hand1.get_score();
+        assert_eq!(score1, 2_000_000 + (3 << 4) + 2);
 
-        let hand = Hand::new_from_str("2s Kc Js Ks Qs 9s Ts").unwrap();
-        let score = hand.get_score();
+        let hand2 = Hand::new_from_str("Ks Ac Ad Js Qs").unwrap();
+        let score2 = hand2.get_score();
+        assert_eq!(score2, 1_000_000 + (14 << 12) + (13 << 8) + (12 << 4) + 11);
 
-        assert_eq!(score, 8_000_000 + 13);
+        assert!(score1 > score2);
 
-        let hand = Hand::new_from_str("9h 8h Jc Tc Qh Jh Th").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 8_000_000 + 12);
+        let hand1 = Hand::new_from_str("2s 2c").unwrap();
+        let score1 = hand1.get_score();
+        assert_eq!(score1, 1_000_000 + 2);
 
-        let hand = Hand::new_from_str("2s 7s Js 9s 8s 9c Ts").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 8_000_000 + 11);
+        let hand2 = Hand::new_from_str("Ks Ac 9d Js Qs").unwrap();
+        let score2 = hand2.get_score();
+        assert_eq!(score2, (14 << 16) + (13 << 12) + (12 << 8) + (11 << 4) + 9);
 
-        let hand = Hand::new_from_str("9d 8d Td 7d 6d 3c Th Kh Qd").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 8_000_000 + 10);
+        assert!(score1 > score2);
+    }
 
-        let hand = Hand::new_from_str("9d 8d 5d 6d 7d").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 8_000_000 + 9);
+    /// Returns the error of parsing `s` as a hand.
+    fn parse_error(s: &str) -> HandError {
+        Hand::new_from_str(s).err().unwrap()
+    }
 
-        let hand = Hand::new_from_str("4c 5c 6c 7c 8c 3c 2c").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 8_000_000 + 8);
+    fn invalid_card(index: usize, token: &str, source: CardError) -> HandError {
+        HandError::InvalidCard {
+            index,
+            token: token.into(),
+            source,
+        }
+    }
 
-        let hand = Hand::new_from_str("7d 7c 7s 6d 5d 3d 4d").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 8_000_000 + 7);
+    fn invalid_count(found: usize) -> HandError {
+        HandError::InvalidCardCount {
+            min: Hand::MIN_CARDS,
+            max: Hand::MAX_CARDS,
+            found,
+        }
+    }
 
-        let hand = Hand::new_from_str("6d 5d 4d 3d 2d Ad").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 8_000_000 + 6);
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_error(""), invalid_count(0));
+        assert_eq!(parse_error("   "), invalid_count(0));
+        assert_eq!(parse_error("As"), invalid_count(1));
+        assert_eq!(
+            parse_error("As Ks Qs Js Ts 9s 8s 7s 6s 5s"),
+            invalid_count(10)
+        );
+        assert_eq!(
+            parse_error("As Kx Qd"),
+            invalid_card(1, "Kx", CardError::InvalidSuit("x".into()))
+        );
+        assert_eq!(
+            parse_error("As Ks Qs Js Ts x"),
+            invalid_card(5, "x", CardError::InvalidLength("x".into()))
+        );
+        assert_eq!(
+            Hand::parse_strict("As Ks Qs,").err(),
+            Some(invalid_card(
+                2,
+                "Qs,",
+                CardError::InvalidLength("Qs,".into())
+            ))
+        );
 
-        let hand = Hand::new_from_str("2d Ad 3d 4d 5d 3c Th").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 8_000_000 + 5);
+        // An invalid card is reported before a wrong number of cards.
+        assert_eq!(
+            parse_error("1s"),
+            invalid_card(0, "1s", CardError::InvalidRank("1".into()))
+        );
+        assert_eq!(
+            parse_error("As Ks Qs Js Ts 9s 8s 7s 6s Zs"),
+            invalid_card(9, "Zs", CardError::InvalidRank("Z".into()))
+        );
+    }
+
+    fn card(s: &str) -> Card {
+        Card::new_from_str(s).unwrap()
     }
 
     #[test]
-    fn test_four_of_a_kind() {
-        let hand = Hand::new_from_str("As Ac Ad Ah Ts 9c Qs").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 7_000_000 + (14 << 4) + 12);
+    fn test_duplicate_cards() {
+        let ace = card("As");
+        let duplicate = Some(HandError::DuplicateCard(ace));
 
-        let hand = Hand::new_from_str("As Ac Ad Ah").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 7_000_000 + 14);
+        assert_eq!(
+            Hand::new(vec![ace, card("Ks"), ace]).err(),
+            duplicate.clone()
+        );
+        assert_eq!(
+            Hand::new_from_str("As As Ks Qs Js").err(),
+            duplicate.clone()
+        );
 
-        let hand = Hand::new_from_str("9c Ks Kc Kd Kh Ts 2s").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 7_000_000 + (13 << 4) + 10);
+        let mut hand = Hand::new_from_str("As Ks").unwrap();
+        assert_eq!(hand.add_card(ace).err(), duplicate.clone());
+        assert_eq!(hand.get_count(), 2);
 
-        let hand = Hand::new_from_str("Qs Qc Qd Qh 8s 9c 9s").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 7_000_000 + (12 << 4) + 9);
+        assert_eq!(
+            hand.add_cards(vec![card("Qs"), ace]).err(),
+            duplicate.clone()
+        );
+        assert_eq!(
+            hand.add_cards(vec![card("Qs"), card("Qs")]).err(),
+            Some(HandError::DuplicateCard(card("Qs")))
+        );
+        assert_eq!(hand.as_str(), "As Ks");
 
-        let hand = Hand::new_from_str("2s 2c 2d 2h As 9c Qs").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 7_000_000 + (2 << 4) + 14);
+        hand.add_cards(vec![card("Qs"), card("Js")]).unwrap();
+        assert_eq!(hand.as_str(), "As Ks Qs Js");
     }
 
     #[test]
-    fn test_full_house() {
-        let hand = Hand::new_from_str("As Ac Ad Kh Ts Kc Qs").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 6_000_000 + (14 << 4) + 13);
+    fn test_new_unchecked() {
+        let hand = Hand::new_unchecked(vec![card("As"), card("As")]);
+        assert_eq!(hand.get_count(), 2);
+    }
 
-        let hand = Hand::new_from_str("Ks Qc Kd Kh Qd").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 6_000_000 + (13 << 4) + 12);
+    #[test]
+    fn test_evaluated_hands_have_distinct_cards() {
+        // A duplicated ace used to be evaluated as a fifth ace.
+        assert!(Hand::new_from_str("As As Ad Ah Ac").is_err());
+        let hand = Hand::new_from_str("As Ad Ah Ac Ks").unwrap();
+        assert_eq!(hand.get_hand_rank(), HandRank::FourOfAKind);
+    }
 
-        let hand = Hand::new_from_str("Tc 9s 9c Td 9h Ts 2s").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 6_000_000 + (10 << 4) + 9);
+    #[test]
+    fn test_capacity() {
+        let mut deck = crate::deck::Deck::new();
+        let mut hand = Hand::new(vec![deck.deal().unwrap(), deck.deal().unwrap()]).unwrap();
+        while hand.get_count() < Hand::MAX_CARDS {
+            hand.add_card(deck.deal().unwrap()).unwrap();
+        }
+        assert_eq!(hand.get_count(), 9);
 
-        let hand = Hand::new_from_str("4s 4c 4d 5h 5s 9c 9s").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 6_000_000 + (4 << 4) + 9);
+        let error = hand.add_card(deck.deal().unwrap()).err().unwrap();
+        assert_eq!(
+            error,
+            HandError::TooManyCards {
+                count: 9,
+                adding: 1,
+                max: 9
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Adding 1 card to a hand of 9 cards would exceed the maximum of 9 cards."
+        );
 
-        let hand = Hand::new_from_str("2s 2c 2d 3h As 3c Qs").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 6_000_000 + (2 << 4) + 3);
+        let mut hand = Hand::new_from_str("As Ks Qs").unwrap();
+        let cards = (0..7).map(|_| deck.deal().unwrap()).collect();
+        assert_eq!(
+            hand.add_cards(cards).err(),
+            Some(HandError::TooManyCards {
+                count: 3,
+                adding: 7,
+                max: 9
+            })
+        );
+        assert_eq!(hand.get_count(), 3);
     }
 
     #[test]
-    fn test_flush() {
-        let hand = Hand::new_from_str("As Ks Qs Js 9s 8s 7s").unwrap();
-        let score = hand.get_score();
+    fn test_remove_cards() {
+        let mut hand = Hand::new_from_str("As Ks Qs Js Ts 2d").unwrap();
+        assert_eq!(hand.get_hand_rank(), HandRank::StraightFlush);
+
+        assert_eq!(hand.remove_card(&card("Ts")), Ok(card("Ts")));
+        assert_eq!(hand.as_str(), "As Ks Qs Js 2d");
+        assert_eq!(hand.get_hand_rank(), HandRank::HighCard);
+
         assert_eq!(
-            score,
-            5_000_000 + (14 << 16) + (13 << 12) + (12 << 8) + (11 << 4) + 9
+            hand.remove_card(&card("Ts")),
+            Err(HandError::CardNotFound(card("Ts")))
+        );
+        assert_eq!(hand.remove_at(0), Ok(card("As")));
+        assert_eq!(
+            hand.remove_at(4),
+            Err(HandError::IndexOutOfBounds { index: 4, count: 4 })
         );
+        assert_eq!(hand.as_str(), "Ks Qs Js 2d");
 
-        // Check corner case vs. lowest full house
-        let hand = Hand::new_from_str("2s 2c 2d 3h As 3c Qs").unwrap();
-        let score_low_fh = hand.get_score();
-        assert!(score < score_low_fh);
+        assert_eq!(
+            hand.clear_to(1),
+            Err(HandError::TooFewCards {
+                count: 4,
+                removing: 3,
+                min: 2
+            })
+        );
+        hand.clear_to(5).unwrap();
+        hand.clear_to(2).unwrap();
+        assert_eq!(hand.as_str(), "Ks Qs");
 
-        let hand = Hand::new_from_str("Ks Kd Qs Js 9s 9d 7s").unwrap();
-        let score = hand.get_score();
+        let error = hand.remove_card(&card("Ks")).err().unwrap();
         assert_eq!(
-            score,
-            5_000_000 + (13 << 16) + (12 << 12) + (11 << 8) + (9 << 4) + 7
+            error,
+            HandError::TooFewCards {
+                count: 2,
+                removing: 1,
+                min: 2
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Removing 1 card from a hand of 2 cards would go below the minimum of 2 cards."
         );
+        assert_eq!(hand.get_count(), 2);
+        assert_eq!(hand.get_hand_rank(), HandRank::HighCard);
+    }
+
+    /// Returns every order of the cards.
+    fn permutations(cards: &[Card]) -> Vec<Vec<Card>> {
+        if cards.len() <= 1 {
+            return vec![cards.to_vec()];
+        }
+        (0..cards.len())
+            .flat_map(|i| {
+                let mut rest = cards.to_vec();
+                let first = rest.remove(i);
+                permutations(&rest).into_iter().map(move |mut order| {
+                    order.insert(0, first);
+                    order
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_best_five() {
+        for (s, best, description) in [
+            ("Kd Ks Kc 9h 4c", "Kc Kd Ks 9h 4c", "Three of a Kind, Kings"),
+            (
+                "2h Ks Kh 9d Kc 2d 2s",
+                "Kc Kh Ks 2d 2h",
+                "Full House, Kings full of Twos",
+            ),
+            ("Ah 9h 7h 5h 3h 2h Ac", "Ah 9h 7h 5h 3h", "Flush, Ace high"),
+            (
+                "Qs Qd 5h 5c 8s 8c 8d",
+                "8c 8d 8s Qd Qs",
+                "Full House, Eights full of Queens",
+            ),
+            (
+                "Qs Qd 5h 5c 9s 9c Ad",
+                "Qd Qs 9c 9s Ad",
+                "Two Pair, Queens and Nines",
+            ),
+            ("5d 4c 3h 2s As Ad", "5d 4c 3h 2s Ad", "Straight, Five high"),
+            (
+                "Jh Ts 9c 8d 7h 7c 7s",
+                "Jh Ts 9c 8d 7c",
+                "Straight, Jack high",
+            ),
+            ("9s 9h", "9h 9s", "One Pair, Nines"),
+        ] {
+            let cards = Hand::new_from_str(s).unwrap().into_cards();
+            for order in permutations(&cards) {
+                let hand = Hand::new(order).unwrap();
+                let five: Vec<String> = hand.best_five().iter().map(Card::as_str).collect();
+                assert_eq!(five.join(" "), best, "{}", hand);
+                assert_eq!(hand.get_value().describe(), description);
+            }
+        }
+    }
+
+    #[test]
+    fn test_without() {
+        let hand = Hand::new_from_str("Ah Qh Kh 9h 5h 2c 3d").unwrap();
+        let board = [card("Kh"), card("9h"), card("5h"), card("2c"), card("3d")];
+        let hole = hand.without(&board).unwrap();
+        assert_eq!(hole.as_str(), "Ah Qh");
+        assert_eq!(hand.get_count(), 7);
+
+        let (hole_cards, board_cards) = hand.partition(|card| !board.contains(card));
+        assert_eq!(hole_cards, hole.into_cards());
+        assert_eq!(board_cards, board);
 
-        let hand = Hand::new_from_str("Qs Js 9s 8s 7s").unwrap();
-        let score = hand.get_score();
         assert_eq!(
-            score,
-            5_000_000 + (12 << 16) + (11 << 12) + (9 << 8) + (8 << 4) + 7
+            hand.without(&[card("Kh"), card("Ts")]),
+            Err(HandError::CardNotFound(card("Ts")))
+        );
+        assert_eq!(
+            hand.without(&[card("Kh"), card("Kh")]),
+            Err(HandError::CardNotFound(card("Kh")))
+        );
+        assert_eq!(
+            hand.without(&hand.as_slice()[1..]),
+            Err(HandError::TooFewCards {
+                count: 7,
+                removing: 6,
+                min: 2
+            })
         );
+    }
 
-        let hand = Hand::new_from_str("7s Kd Qd 4s 5s 3s 2s").unwrap();
-        let score = hand.get_score();
+    #[test]
+    fn test_contains_and_counts() {
+        let hand = Hand::new_from_str("Ks Kd 7s 2s 9h").unwrap();
+
+        assert!(hand.contains(&card("Kd")));
+        assert!(!hand.contains(&card("Kc")));
+
+        assert!(hand.contains_rank(Rank::King));
+        assert!(!hand.contains_rank(Rank::Ace));
+
+        assert_eq!(hand.count_rank(Rank::King), 2);
+        assert_eq!(hand.count_rank(Rank::Seven), 1);
+        assert_eq!(hand.count_rank(Rank::Ace), 0);
+
+        assert_eq!(hand.count_suit(Suit::Spade), 3);
+        assert_eq!(hand.count_suit(Suit::Heart), 1);
+        assert_eq!(hand.count_suit(Suit::Club), 0);
+    }
+
+    #[test]
+    fn test_iterators() {
+        let hand = Hand::new_from_str("As Ks Qs").unwrap();
+        assert_eq!(hand.iter().count(), 3);
+        assert_eq!((&hand).into_iter().next(), Some(&card("As")));
+
+        let cards: Vec<Card> = hand.clone().into_iter().collect();
+        assert_eq!(cards, hand.as_slice());
+
+        let collected: Hand = cards.iter().copied().rev().collect();
+        assert_eq!(collected.as_str(), "Qs Ks As");
+
+        assert!(Hand::try_from(cards.clone()).is_ok());
         assert_eq!(
-            score,
-            5_000_000 + (7 << 16) + (5 << 12) + (4 << 8) + (3 << 4) + 2
+            Hand::try_from(vec![card("As")]).err(),
+            Some(HandError::InvalidCardCount {
+                min: 2,
+                max: 9,
+                found: 1
+            })
         );
     }
 
     #[test]
-    fn test_straight() {
-        let hand = Hand::new_from_str("2d Ac Js Ks Qs 9c Ts").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 4_000_000 + 14);
+    #[should_panic(expected = "Cards must form a valid hand")]
+    fn test_collect_invalid_hand() {
+        let _: Hand = vec![card("As"), card("As")].into_iter().collect();
+    }
 
-        let hand = Hand::new_from_str("2s Kc Jh Kd Qs 9s Ts").unwrap();
-        let score = hand.get_score();
+    #[test]
+    fn test_extend() {
+        let mut deck = crate::deck::Deck::new();
+        let mut hand = Hand::new_from_str("2h 3h").unwrap();
 
-        assert_eq!(score, 4_000_000 + 13);
+        hand.extend(vec![card("2h"), card("4h")]);
+        assert_eq!(hand.as_str(), "2h 3h 4h");
 
-        let hand = Hand::new_from_str("9c 8h Jc Tc Qs Jh Th").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 4_000_000 + 12);
+        let cards: Vec<Card> = (0..8).map(|_| deck.deal().unwrap()).collect();
+        assert_eq!(
+            hand.try_extend(cards.clone()),
+            Err(HandError::TooManyCards {
+                count: 3,
+                adding: 8,
+                max: 9
+            })
+        );
+        assert_eq!(hand.get_count(), 3);
 
-        let hand = Hand::new_from_str("2c 7c Js 9s 8h 9c Ts").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 4_000_000 + 11);
+        hand.extend(cards);
+        assert_eq!(hand.get_count(), Hand::MAX_CARDS);
 
-        let hand = Hand::new_from_str("9h 8d Ts 7d 6c 3c Th Kh Qd").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 4_000_000 + 10);
+        hand.try_extend(Vec::new()).unwrap();
+        assert_eq!(hand.get_count(), Hand::MAX_CARDS);
+    }
 
-        let hand = Hand::new_from_str("9c 8h 5d 6d 7d").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 4_000_000 + 9);
+    #[test]
+    fn test_equality_and_hashing() {
+        use std::collections::HashSet;
 
-        let hand = Hand::new_from_str("4c 5d 6c 7h 8c 3d 2c").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 4_000_000 + 8);
+        let hand = |s: &str| Hand::new_from_str(s).unwrap();
 
-        let hand = Hand::new_from_str("7d 7c 7s 6d 5c 3d 4d").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 4_000_000 + 7);
+        assert!(hand("As Ks") == hand("Ks As"));
+        assert!(hand("As Ks") != hand("As Kd"));
+        assert!(hand("As Ks") != hand("As Ks Qs"));
+        assert!(hand("As Ks").eq_ordered(&hand("As Ks")));
+        assert!(!hand("As Ks").eq_ordered(&hand("Ks As")));
 
-        let hand = Hand::new_from_str("6d 5d 4d 3c 2d Ac").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 4_000_000 + 6);
+        let set: HashSet<Hand> = ["As Ks Qs", "Qs As Ks", "Ks Qs As", "As Ks Qd"]
+            .iter()
+            .map(|s| hand(s))
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&hand("Qs Ks As")));
+    }
 
-        let hand = Hand::new_from_str("2d Ac 3d 4d 5d 3c Th").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 4_000_000 + 5);
+    #[test]
+    fn test_ordering() {
+        use std::collections::BinaryHeap;
+
+        let hand = |s: &str| Hand::new_from_str(s).unwrap();
+
+        let mut hands = vec![
+            hand("9h 8h 7h 6h 5h"),
+            hand("2c 2d 7s 9h Kd"),
+            hand("Ks Kd Kc 2h 2s"),
+            hand("As Kd 9c 7h 3s"),
+        ];
+        hands.sort();
+        let order: Vec<HandRank> = hands.iter().map(|hand| hand.get_hand_rank()).collect();
+        assert_eq!(
+            order,
+            vec![
+                HandRank::HighCard,
+                HandRank::OnePair,
+                HandRank::FullHouse,
+                HandRank::StraightFlush
+            ]
+        );
+        assert!(hands.iter().max().unwrap() == &hand("5h 6h 7h 8h 9h"));
+
+        let mut heap: BinaryHeap<Hand> = hands.into_iter().collect();
+        assert_eq!(heap.pop().unwrap().get_hand_rank(), HandRank::StraightFlush);
+
+        // Equally strong hands are ordered by their cards, so sorting is
+        // deterministic.
+        let spades = hand("As Ks Qs Js 9s");
+        let hearts = hand("Ah Kh Qh Jh 9h");
+        assert_eq!(spades.get_score(), hearts.get_score());
+        assert_eq!(spades.cmp(&hearts), Ordering::Greater);
+        assert_eq!(spades.cmp(&hand("9s Js Qs Ks As")), Ordering::Equal);
+
+        let mut tied = vec![spades.clone(), hearts.clone()];
+        tied.sort();
+        assert!(tied == vec![hearts, spades]);
+    }
+
+    #[test]
+    fn test_flexible_parsing() {
+        let expected = Hand::new_from_str("As Ks Qs Js Ts").unwrap();
+        for s in [
+            "AsKsQsJsTs",
+            "As,Ks,Qs,Js,Ts",
+            "As, Ks, Qs, Js, Ts",
+            "[As Ks Qs Js Ts]",
+            " [AsKs, Qs Js,10s] ",
+            "As Ks Qs Js 10s",
+            "AsKs QsJs10s",
+        ] {
+            let hand = Hand::new_from_str(s).unwrap();
+            assert!(hand.eq_ordered(&expected), "{}", s);
+        }
+
+        assert_eq!(
+            parse_error("AsKsQ"),
+            invalid_card(2, "Q", CardError::InvalidLength("Q".into()))
+        );
+        assert_eq!(
+            parse_error("As Ks,QsJx"),
+            invalid_card(3, "Jx", CardError::InvalidSuit("x".into()))
+        );
+        assert_eq!(
+            parse_error("As Ks 10x"),
+            invalid_card(2, "10x", CardError::InvalidSuit("x".into()))
+        );
+        assert_eq!(
+            parse_error("[As Ks"),
+            invalid_card(0, "[A", CardError::InvalidRank("[".into()))
+        );
+        assert_eq!(
+            parse_error("As é"),
+            invalid_card(1, "é", CardError::InvalidLength("é".into()))
+        );
+        assert!(Hand::parse_strict("AsKs").is_err());
+        assert!(Hand::parse_strict("As,Ks").is_err());
+    }
+
+    #[test]
+    fn test_rank_counts() {
+        let quads = Hand::new_from_str("9s 9d 9c 9h 2s 2d Ah").unwrap();
+        let counts = quads.rank_counts();
+        assert_eq!(counts[Rank::Nine.to_index()], 4);
+        assert_eq!(counts[Rank::Two.to_index()], 2);
+        assert_eq!(counts.iter().map(|&c| c as usize).sum::<usize>(), 7);
+        assert_eq!(
+            quads.rank_multiplicities(),
+            vec![(Rank::Nine, 4), (Rank::Two, 2), (Rank::Ace, 1)]
+        );
+
+        let two_pair = Hand::new_from_str("3s 3d Jc Jh Ks").unwrap();
+        assert_eq!(
+            two_pair.rank_multiplicities(),
+            vec![(Rank::Jack, 2), (Rank::Three, 2), (Rank::King, 1)]
+        );
+
+        let no_pair = Hand::new_from_str("2s 9d Jc 4h Ks").unwrap();
+        assert!(no_pair.rank_counts().iter().all(|&count| count <= 1));
+        assert_eq!(
+            no_pair.rank_multiplicities(),
+            vec![
+                (Rank::King, 1),
+                (Rank::Jack, 1),
+                (Rank::Nine, 1),
+                (Rank::Four, 1),
+                (Rank::Two, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cards_of_rank() {
+        let hand = Hand::new_from_str("Kd 7s Ks 2c Kh").unwrap();
+        assert_eq!(
+            hand.cards_of_rank(Rank::King),
+            vec![card("Kd"), card("Ks"), card("Kh")]
+        );
+        assert_eq!(hand.cards_of_rank_iter(Rank::Seven).count(), 1);
+        assert!(hand.cards_of_rank(Rank::Ace).is_empty());
+
+        assert_eq!(
+            hand.filter_cards(|card| card.rank == Rank::King && card.suit != Suit::Spade),
+            vec![card("Kd"), card("Kh")]
+        );
+        assert_eq!(
+            hand.filter_cards_iter(|card| card.rank < Rank::Eight)
+                .collect::<Vec<&Card>>(),
+            vec![&card("7s"), &card("2c")]
+        );
+        assert_eq!(hand.cards_of_suit_iter(Suit::Club).count(), 1);
     }
 
     #[test]
-    fn test_three_of_a_kind() {
-        let hand = Hand::new_from_str("2s Ac Ad Ah Ts 9c Qs").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 3_000_000 + (14 << 8) + (12 << 4) + 10);
+    fn test_from_hole_and_board() {
+        let hole = [card("Ah"), card("Kh")];
+        let board = [card("Qh"), card("Jh"), card("Th"), card("2c"), card("3d")];
 
-        let hand = Hand::new_from_str("As Ac Ad 2h").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 3_000_000 + (14 << 4) + 2);
+        let hand = Hand::from_hole_and_board(&hole, &board).unwrap();
+        let manual = Hand::new_from_str("Ah Kh Qh Jh Th 2c 3d").unwrap();
+        assert!(hand.eq_ordered(&manual));
+        assert_eq!(hand.get_score(), manual.get_score());
 
-        let hand = Hand::new_from_str("As Ac Ad").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 3_000_000 + 14);
+        assert_eq!(
+            Hand::from_hole_and_board(&[card("Ah"), card("Qh")], &board).err(),
+            Some(HandError::DuplicateCard(card("Qh")))
+        );
 
-        let hand = Hand::new_from_str("9c Ks Kc Kd Ah Ts 2s").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 3_000_000 + (13 << 8) + (14 << 4) + 10);
+        let hole = Hand::new_from_str("Ah Kh").unwrap();
+        let with_board = hole.with_board(&board).unwrap();
+        assert!(with_board == manual);
+        assert_eq!(hole.get_count(), 2);
+        assert_eq!(
+            hole.with_board(&[card("Kh")]).err(),
+            Some(HandError::DuplicateCard(card("Kh")))
+        );
+    }
 
-        let hand = Hand::new_from_str("9c 3s 2c 2d Kh Ts 2s").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 3_000_000 + (2 << 8) + (13 << 4) + 10);
+    #[test]
+    fn test_sorted_accessors() {
+        let hand = Hand::new_from_str("5h As 5d 2c Kd").unwrap();
 
-        let hand = Hand::new_from_str("2s 2c 2d").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 3_000_000 + 2);
+        assert_eq!(
+            hand.ranks_desc(),
+            vec![Rank::Ace, Rank::King, Rank::Five, Rank::Five, Rank::Two]
+        );
+        assert_eq!(
+            hand.ranks_asc(),
+            vec![Rank::Two, Rank::Five, Rank::Five, Rank::King, Rank::Ace]
+        );
+        assert_eq!(
+            hand.cards_sorted_by_rank(true),
+            vec![card("As"), card("Kd"), card("5h"), card("5d"), card("2c")]
+        );
+        assert_eq!(
+            hand.cards_sorted_by_rank(false),
+            vec![card("2c"), card("5h"), card("5d"), card("Kd"), card("As")]
+        );
+        assert_eq!(hand.as_str(), "5h As 5d 2c Kd");
     }
 
     #[test]
-    fn test_two_pair() {
-        let hand = Hand::new_from_str("Ks Ac Ad Kh Ts 2c Qs").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 2_000_000 + (14 << 8) + (13 << 4) + 12);
+    fn test_sort_by_rank() {
+        let mut hand = Hand::new_from_str("Kh 2s Kc 9d Ks").unwrap();
+        hand.sort_by_rank(false);
+        assert_eq!(hand.as_str(), "Kh Kc Ks 9d 2s");
+        hand.sort_by_rank(true);
+        assert_eq!(hand.as_str(), "2s 9d Kh Kc Ks");
+
+        hand.sort_by_rank_and_suit(false);
+        assert_eq!(hand.as_str(), "Ks Kh Kc 9d 2s");
+        hand.sort_by_rank_and_suit(true);
+        assert_eq!(hand.as_str(), "2s 9d Kc Kh Ks");
+
+        let mut reordered = Hand::new_from_str("Ks 9d Kc 2s Kh").unwrap();
+        reordered.sort_by_rank_and_suit(true);
+        assert!(reordered.eq_ordered(&hand));
+    }
 
-        let hand = Hand::new_from_str("Ks Qc Kd Ah Qd").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 2_000_000 + (13 << 8) + (12 << 4) + 14);
+    #[test]
+    fn test_into_cards() {
+        let hand = Hand::new_from_str("Kd 7s Ks").unwrap();
+        let slice = hand.as_slice().to_vec();
 
-        let hand = Hand::new_from_str("Ks Qc Kd Qd").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 2_000_000 + (13 << 4) + 12);
+        let cards = hand.clone().into_cards();
+        assert_eq!(cards, slice);
+        assert!(Hand::try_from(cards).unwrap().eq_ordered(&hand));
+    }
 
-        let hand = Hand::new_from_str("Tc 8s 9c 8d 9h Ts 2s").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 2_000_000 + (10 << 8) + (9 << 4) + 8);
+    #[test]
+    fn test_combinations() {
+        let hand = Hand::new_from_str("As Ks Qs Js Ts 9s 8s").unwrap();
+
+        let subsets: Vec<Vec<Card>> = hand.combinations(5).collect();
+        assert_eq!(subsets.len(), 21);
+        for subset in &subsets {
+            assert_eq!(subset.len(), 5);
+            assert!(Hand::new(subset.clone()).is_ok());
+        }
+        let distinct: std::collections::HashSet<Hand> = subsets
+            .into_iter()
+            .map(|subset| Hand::new(subset).unwrap())
+            .collect();
+        assert_eq!(distinct.len(), 21);
+
+        assert_eq!(hand.combinations_array::<5>().count(), 21);
+        assert_eq!(hand.combinations(8).count(), 0);
+        assert_eq!(hand.combinations(7).count(), 1);
+    }
 
-        let hand = Hand::new_from_str("4s 4c 2d 5h 5s 9c 9s").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 2_000_000 + (9 << 8) + (5 << 4) + 4);
+    #[test]
+    fn test_bytes_round_trip() {
+        let hand = Hand::new_from_str("As Ks Qs Js Ts 2c 3d").unwrap();
+        let bytes = hand.to_bytes();
+        assert_eq!(bytes, [7, 51, 47, 43, 39, 35, 0, 5]);
+        assert!(Hand::from_bytes(&bytes).unwrap().eq_ordered(&hand));
 
-        let hand = Hand::new_from_str("2s 2c 3h 3c").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 2_000_000 + (3 << 4) + 2);
+        let hole_cards = HoleCards::new_from_str("AsKd").unwrap();
+        assert_eq!(
+            HoleCards::from_bytes(&hole_cards.to_bytes()),
+            Ok(hole_cards)
+        );
+
+        assert_eq!(Hand::from_bytes(&[1, 51]).err(), Some(invalid_count(1)));
+        assert_eq!(
+            HoleCards::from_bytes(&[3, 51, 50, 49]),
+            Err(HandError::InvalidCardCount {
+                min: 2,
+                max: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            Hand::from_bytes(&[2, 51, 51]).err(),
+            Some(HandError::DuplicateCard(card("As")))
+        );
     }
 
     #[test]
-    fn test_pair() {
-        let hand = Hand::new_from_str("Ks Ac Ad 9h Js 2c Qs").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 1_000_000 + (14 << 12) + (13 << 8) + (12 << 4) + 11);
+    fn test_from_random_bytes() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(151);
+        for _ in 0..10_000 {
+            let len = rng.gen_range(0..12);
+            let mut bytes: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+            // Mostly announce the right length to get past the length check.
+            if len > 0 && rng.gen_bool(0.8) {
+                bytes[0] = len as u8 - 1;
+                for byte in &mut bytes[1..] {
+                    *byte %= 56;
+                }
+            }
+
+            if let Ok(hand) = Hand::from_bytes(&bytes) {
+                assert_eq!(hand.to_bytes(), bytes);
+            }
+            if let Ok(hole_cards) = HoleCards::from_bytes(&bytes) {
+                assert_eq!(hole_cards.to_bytes(), bytes);
+            }
+        }
+    }
 
-        let hand = Hand::new_from_str("Ks 2c Kd Ah Qd").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 1_000_000 + (13 << 12) + (14 << 8) + (12 << 4) + 2);
+    #[test]
+    fn test_bitmask_round_trip() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(209);
+        for _ in 0..1000 {
+            let n = rng.gen_range(Hand::MIN_CARDS..=Hand::MAX_CARDS);
+            let hand = Hand::random(&mut rng, n);
+            let mask = hand.as_bitmask();
+            assert_eq!(mask.count_ones() as usize, hand.get_count());
+            let round_trip = Hand::from_bitmask(mask).unwrap();
+            assert_eq!(round_trip, hand);
+            assert_eq!(round_trip.get_score(), hand.get_score());
+            assert!(round_trip
+                .to_bytes()
+                .windows(2)
+                .skip(1)
+                .all(|bytes| bytes[0] < bytes[1]));
+        }
 
-        let hand = Hand::new_from_str("Ks 2c Kd Qd").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 1_000_000 + (13 << 8) + (12 << 4) + 2);
+        assert_eq!(
+            Hand::from_bitmask(1 << 63 | 1 << 51),
+            Err(HandError::InvalidBitmask(1 << 63 | 1 << 51))
+        );
+        assert_eq!(
+            Hand::from_bitmask(1 << 51),
+            Err(HandError::InvalidCardCount {
+                min: 2,
+                max: 9,
+                found: 1
+            })
+        );
+    }
 
-        let hand = Hand::new_from_str("Ks 2c Kd").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 1_000_000 + (13 << 4) + 2);
+    #[test]
+    fn test_random() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(152);
+        let dead: Vec<Card> = "As Ks Qs Js Ts 9s 8s 7s 6s 5s 4s 3s 2s Ah Kh"
+            .split_whitespace()
+            .map(card)
+            .collect();
+        for n in Hand::MIN_CARDS..=Hand::MAX_CARDS {
+            for _ in 0..100 {
+                let hand = Hand::random(&mut rng, n);
+                assert!(Hand::new(hand.clone().into_cards()).is_ok());
+                assert_eq!(hand.get_count(), n);
+
+                let hand = Hand::random_with(&mut rng, n, &dead);
+                assert!(Hand::new(hand.clone().into_cards()).is_ok());
+                assert!(hand.iter().all(|card| !dead.contains(card)));
+            }
+        }
 
-        let hand = Hand::new_from_str("Ks Kd").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 1_000_000 + 13);
+        let first = Hand::random_with(&mut StdRng::seed_from_u64(7), 9, &dead);
+        let second = Hand::random_with(&mut StdRng::seed_from_u64(7), 9, &dead);
+        assert!(first.eq_ordered(&second));
 
-        let hand = Hand::new_from_str("Tc 3s 5c 8d 9h Ts 2s").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 1_000_000 + (10 << 12) + (9 << 8) + (8 << 4) + 5);
+        // Exactly as many live cards as requested.
+        let dead = Deck::new().get_cards()[2..].to_vec();
+        let hand = Hand::random_with(&mut rng, 2, &dead);
+        assert!(hand == Hand::new(Deck::new().get_cards()[..2].to_vec()).unwrap());
+    }
 
-        let hand = Hand::new_from_str("4s 4c 2d 3h 5s 9c Ts").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 1_000_000 + (4 << 12) + (10 << 8) + (9 << 4) + 5);
+    #[test]
+    #[should_panic(expected = "Cannot draw 3 cards from 2 live cards.")]
+    fn test_random_without_enough_live_cards() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
 
-        let hand = Hand::new_from_str("2s 2c Ah Kc").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, 1_000_000 + (2 << 8) + (14 << 4) + 13);
+        let dead = Deck::new().get_cards()[2..].to_vec();
+        Hand::random_with(&mut StdRng::seed_from_u64(0), 3, &dead);
     }
 
     #[test]
-    fn test_high_card() {
-        let hand = Hand::new_from_str("Ks Ac 9d 8h Js 2c Qs").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, (14 << 16) + (13 << 12) + (12 << 8) + (11 << 4) + 9);
+    fn test_positional_access() {
+        let mut hand = Hand::new_from_str("As Kd 7c 2h").unwrap();
+        assert_eq!(hand[0], card("As"));
+        assert_eq!(hand[3], card("2h"));
+        assert_eq!(hand.get(2), Some(&card("7c")));
+        assert_eq!(hand.get(4), None);
+        assert_eq!(hand.first(), Some(&card("As")));
+        assert_eq!(hand.last(), Some(&card("2h")));
 
-        let hand = Hand::new_from_str("Ks 2c Jd Ah Qd").unwrap();
         let score = hand.get_score();
-        assert_eq!(score, (14 << 16) + (13 << 12) + (12 << 8) + (11 << 4) + 2);
+        hand.swap(0, 3).unwrap();
+        assert_eq!(hand.as_str(), "2h Kd 7c As");
+        assert_eq!(hand.get_score(), score);
+        hand.swap(1, 1).unwrap();
+        assert_eq!(hand.as_str(), "2h Kd 7c As");
 
-        let hand = Hand::new_from_str("Ks 2c 3d Qd").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, (13 << 12) + (12 << 8) + (3 << 4) + 2);
+        assert_eq!(
+            hand.swap(1, 4),
+            Err(HandError::IndexOutOfBounds { index: 4, count: 4 })
+        );
+        assert_eq!(
+            hand.swap(5, 4),
+            Err(HandError::IndexOutOfBounds { index: 5, count: 4 })
+        );
+        assert_eq!(hand.as_str(), "2h Kd 7c As");
 
-        let hand = Hand::new_from_str("Ks 2c 4d").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, (13 << 8) + (4 << 4) + 2);
+        // The score follows the cards after changing the hand by position.
+        hand.remove_at(hand.get_count() - 1).unwrap();
+        hand.swap(0, 2).unwrap();
+        assert_eq!(
+            hand.get_score(),
+            Hand::new_from_str("7c Kd 2h").unwrap().get_score()
+        );
+        assert_ne!(hand.get_score(), score);
+    }
 
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
         let hand = Hand::new_from_str("As Kd").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, (14 << 4) + 13);
-
-        let hand = Hand::new_from_str("Ac 3s 5c 8d 9h Ts 2s").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, (14 << 16) + (10 << 12) + (9 << 8) + (8 << 4) + 5);
-
-        let hand = Hand::new_from_str("7s 4c 2d 3h 5s 8c 9s").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, (9 << 16) + (8 << 12) + (7 << 8) + (5 << 4) + 4);
-
-        let hand = Hand::new_from_str("2s 3c 4h 5c 7s").unwrap();
-        let score = hand.get_score();
-        assert_eq!(score, (7 << 16) + (5 << 12) + (4 << 8) + (3 << 4) + 2);
+        let _ = hand[2];
     }
-    #[test]
-    fn test_corner_cases() {
-        let hand1 = Hand::new_from_str("2d Ad 3d 4d 5d").unwrap();
-        let score1 = hand1.get_score();
-        assert_eq!(score1, 8_000_000 + 5);
 
-        let hand2 = Hand::new_from_str("As Ac Ad Ah Ks").unwrap();
-        let score2 = hand2.get_score();
-        assert_eq!(score2, 7_000_000 + (14 << 4) + 13);
-
-        assert!(score1 > score2);
+    #[test]
+    fn test_merge() {
+        let mut hand = Hand::new_from_str("As Ks").unwrap();
+        let other = Hand::new_from_str("Qs Js Ts").unwrap();
+        assert!(hand.is_disjoint(&other));
+
+        let union = hand.union(&other).unwrap();
+        assert_eq!(union.as_str(), "As Ks Qs Js Ts");
+        assert_eq!(hand.as_str(), "As Ks");
+        hand.merge(&other).unwrap();
+        assert!(hand.eq_ordered(&union));
+        assert!(!hand.is_disjoint(&other));
+        assert!(!other.is_disjoint(&hand));
+
+        let overlap = Hand::new_from_str("2c Ts").unwrap();
+        assert_eq!(
+            hand.merge(&overlap),
+            Err(HandError::DuplicateCard(card("Ts")))
+        );
+        assert_eq!(
+            hand.union(&overlap).err(),
+            Some(HandError::DuplicateCard(card("Ts")))
+        );
+        assert_eq!(hand.as_str(), "As Ks Qs Js Ts");
 
-        let hand1 = Hand::new_from_str("2s 2c 2d 2h").unwrap();
-        let score1 = hand1.get_score();
-        assert_eq!(score1, 7_000_000 + 2);
+        let overflow = Hand::new_from_str("2c 3c 4c 5c 6c").unwrap();
+        assert_eq!(
+            hand.merge(&overflow),
+            Err(HandError::TooManyCards {
+                count: 5,
+                adding: 5,
+                max: Hand::MAX_CARDS
+            })
+        );
+        assert_eq!(hand.as_str(), "As Ks Qs Js Ts");
+        hand.merge(&Hand::new_from_str("2c 3c 4c 5c").unwrap())
+            .unwrap();
+        assert_eq!(hand.get_count(), Hand::MAX_CARDS);
+    }
 
-        let hand2 = Hand::new_from_str("As Ac Ad Kh Kc").unwrap();
-        let score2 = hand2.get_score();
-        assert_eq!(score2, 6_000_000 + (14 << 4) + 13);
+    #[test]
+    fn test_replace_card() {
+        let mut hand = Hand::new_from_str("As Ad 2c 7h 9s").unwrap();
+        let score = hand.get_score();
 
-        assert!(score1 > score2);
+        let trips = hand.with_replaced(&card("2c"), card("Ac")).unwrap();
+        assert_eq!(trips.as_str(), "As Ad Ac 7h 9s");
+        assert_eq!(trips.get_hand_rank(), HandRank::ThreeOfAKind);
+        assert_eq!(hand.get_score(), score);
 
-        let hand1 = Hand::new_from_str("2s 2c 2d 3h 3c").unwrap();
-        let score1 = hand1.get_score();
-        assert_eq!(score1, 6_000_000 + (2 << 4) + 3);
+        hand.replace_card(&card("2c"), card("Ac")).unwrap();
+        assert!(hand.eq_ordered(&trips));
+        assert!(hand.get_score() > score);
+        hand.replace_card(&card("Ac"), card("Ac")).unwrap();
+        assert!(hand.eq_ordered(&trips));
 
-        let hand2 = Hand::new_from_str("As Ks Qs Js 9s").unwrap();
-        let score2 = hand2.get_score();
         assert_eq!(
-            score2,
-            5_000_000 + (14 << 16) + (13 << 12) + (12 << 8) + (11 << 4) + 9
+            hand.replace_card(&card("2c"), card("Kc")),
+            Err(HandError::CardNotFound(card("2c")))
         );
-
-        assert!(score1 > score2);
-
-        let hand1 = Hand::new_from_str("7s 4s 5s 3s 2s").unwrap();
-        let score1 = hand1.get_score();
         assert_eq!(
-            score1,
-            5_000_000 + (7 << 16) + (5 << 12) + (4 << 8) + (3 << 4) + 2
+            hand.replace_card(&card("7h"), card("As")),
+            Err(HandError::DuplicateCard(card("As")))
         );
+        assert_eq!(
+            hand.with_replaced(&card("7h"), card("9s")).err(),
+            Some(HandError::DuplicateCard(card("9s")))
+        );
+        assert!(hand.eq_ordered(&trips));
+    }
 
-        let hand2 = Hand::new_from_str("Ac Js Ks Qs Ts").unwrap();
-        let score2 = hand2.get_score();
-        assert_eq!(score2, 4_000_000 + 14);
-
-        assert!(score1 > score2);
+    #[test]
+    fn test_intersection_and_difference() {
+        let hand = Hand::new_from_str("As Kd 7c 2h").unwrap();
+        let other = Hand::new_from_str("2h 9s As").unwrap();
+        assert_eq!(hand.intersection(&other), [card("As"), card("2h")]);
+        assert_eq!(other.intersection(&hand), [card("2h"), card("As")]);
+        assert_eq!(hand.difference(&other), [card("Kd"), card("7c")]);
+        assert_eq!(other.difference(&hand), [card("9s")]);
+
+        let disjoint = Hand::new_from_str("Qh Jh").unwrap();
+        assert!(hand.intersection(&disjoint).is_empty());
+        assert_eq!(hand.difference(&disjoint), hand.as_slice());
+
+        let same = Hand::new_from_str("2h 7c Kd As").unwrap();
+        assert_eq!(hand.intersection(&same), hand.as_slice());
+        assert!(hand.difference(&same).is_empty());
+
+        let board = [card("Kd"), card("Qh"), card("2h")];
+        assert_eq!(hand.intersection_cards(&board), [card("Kd"), card("2h")]);
+        assert_eq!(hand.difference_cards(&board), [card("As"), card("7c")]);
+        assert_eq!(hand.difference_cards(&[]), hand.as_slice());
+    }
 
-        let hand1 = Hand::new_from_str("2d Ac 3d 4d 5d").unwrap();
-        let score1 = hand1.get_score();
-        assert_eq!(score1, 4_000_000 + 5);
+    #[test]
+    fn test_canonical() {
+        let hand = Hand::new_from_str("Ah Kh 7d 7s 2c").unwrap();
+        let isomorphic = Hand::new_from_str("2d 7c Ks As 7h").unwrap();
+        assert_eq!(hand.canonical(), isomorphic.canonical());
+        assert_eq!(hand.canonical_key(), isomorphic.canonical_key());
+        assert_eq!(hand.canonical().to_string(), "2c 7d 7h Ks As");
+
+        for other in ["Ah Kd 7d 7s 2c", "Ah Kh 7d 7s 3c", "Ah Kh 7d 7h 2c"] {
+            let other = Hand::new_from_str(other).unwrap();
+            assert_ne!(hand.canonical(), other.canonical());
+            assert_ne!(hand.canonical_key(), other.canonical_key());
+        }
+    }
 
-        let hand2 = Hand::new_from_str("Ks Ac Ad Ah Qs").unwrap();
-        let score2 = hand2.get_score();
-        assert_eq!(score2, 3_000_000 + (14 << 8) + (13 << 4) + 12);
+    #[test]
+    fn test_canonical_preserves_score() {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(158);
+        let mut suits = [Suit::Club, Suit::Diamond, Suit::Heart, Suit::Spade];
+        for _ in 0..1000 {
+            let n = rng.gen_range(Hand::MIN_CARDS..=Hand::MAX_CARDS);
+            let hand = Hand::random(&mut rng, n);
+
+            suits.shuffle(&mut rng);
+            let mut cards: Vec<Card> = hand
+                .iter()
+                .map(|card| Card::new(card.rank, suits[card.suit.to_index()]))
+                .collect();
+            cards.shuffle(&mut rng);
+            let relabeled = Hand::new(cards).unwrap();
+
+            let canonical = hand.canonical();
+            assert_eq!(relabeled.canonical(), canonical);
+            assert_eq!(relabeled.canonical_key(), hand.canonical_key());
+            assert_eq!(relabeled.get_score(), hand.get_score());
+            let canonical = Hand::new(canonical.as_slice().to_vec()).unwrap();
+            assert_eq!(canonical.get_score(), hand.get_score());
+        }
+    }
 
-        assert!(score1 > score2);
+    #[test]
+    fn test_formatting() {
+        let hand = Hand::new_from_str("Ts Js 2d Qs Ks 9c As").unwrap();
+        assert_eq!(hand.to_string(), "Ts Js 2d Qs Ks 9c As");
+        assert_eq!(format!("{:#}", hand), "♠ A K Q J T\n♦ 2\n♣ 9");
+        assert_eq!(format!("{:?}", hand), "Hand(Ts Js 2d Qs Ks 9c As)");
+        assert_eq!(format!("{:#?}", hand), "Hand:\n♠ A K Q J T\n♦ 2\n♣ 9");
+        assert_eq!(
+            hand.summary(),
+            "7 cards: A♠ K♠ Q♠ J♠ T♠ 9♣ 2♦ — Royal Flush"
+        );
 
-        let hand1 = Hand::new_from_str("2s 2c 2d").unwrap();
-        let score1 = hand1.get_score();
-        assert_eq!(score1, 3_000_000 + 2);
+        let hand = Hand::new_from_str("7h 7c 2d").unwrap();
+        assert_eq!(format!("{:#}", hand), "♥ 7\n♦ 2\n♣ 7");
+        assert_eq!(hand.summary(), "3 cards: 7♥ 7♣ 2♦ — One Pair, Sevens");
+    }
 
-        let hand2 = Hand::new_from_str("Ks Ac Ad Kh Qs").unwrap();
-        let score2 = hand2.get_score();
-        assert_eq!(score2, 2_000_000 + (14 << 8) + (13 << 4) + 12);
+    #[test]
+    fn test_qualifies() {
+        let qualifies = |s: &str, minimum| Hand::new_from_str(s).unwrap().qualifies(minimum);
+        let jacks = Qualifier::JACKS_OR_BETTER;
+        assert!(!qualifies("Ts Th 7c 4d 2s", jacks));
+        assert!(qualifies("Js Jh 7c 4d 2s", jacks));
+        assert!(qualifies("As Ah 7c 4d 2s", jacks));
+        assert!(qualifies("3s 3h 2c 2d Ks", jacks));
+        assert!(qualifies("3s 3h 3c 9d Ks", jacks));
+        assert!(qualifies("9s 8h 7c 6d 5s", jacks));
+        assert!(qualifies("Ks 8s 7s 6s 2s", jacks));
+        assert!(!qualifies("As Ks Qs Js 9h", jacks));
+        assert!(!qualifies("9s 8h 7c 6d 4s 4d 2c", jacks));
+        assert!(qualifies("9s 8h 7c 6d 4s 4d 5c", jacks));
+
+        let queens = Qualifier::PairOfAtLeast(Rank::Queen);
+        assert!(!qualifies("Js Jh 7c 4d 2s", queens));
+        assert!(qualifies("Qs Qh 7c 4d 2s", queens));
+
+        let two_pair = Qualifier::AtLeast(HandRank::TwoPair);
+        assert!(!qualifies("As Ah 7c 4d 2s", two_pair));
+        assert!(qualifies("3s 3h 2c 2d 4s", two_pair));
+        assert!(qualifies("As Ks Qs Js Ts", two_pair));
+    }
 
-        assert!(score1 > score2);
+    #[test]
+    fn test_parse_many() {
+        let input = "As Ks Qs Js Ts\nAs Kx\n\n  \n7d 7c\n2h 2h 3c\nAs\r\n9s 9d 9h";
+        let results = Hand::parse_many(input);
+        assert_eq!(results.len(), 6);
+        assert_eq!(
+            results[0],
+            Ok(Hand::new_from_str("As Ks Qs Js Ts").unwrap())
+        );
+        assert_eq!(results[2], Ok(Hand::new_from_str("7d 7c").unwrap()));
+        assert_eq!(results[5], Ok(Hand::new_from_str("9s 9d 9h").unwrap()));
+
+        let errors: Vec<(usize, HandError)> = results
+            .into_iter()
+            .filter_map(Result::err)
+            .map(|e| match e.kind {
+                ParseErrorKind::Hand(error) => (e.line, error),
+                #[cfg(feature = "std")]
+                ParseErrorKind::Io(_) => unreachable!(),
+            })
+            .collect();
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[0].0, 2);
+        assert!(matches!(
+            errors[0].1,
+            HandError::InvalidCard { index: 1, .. }
+        ));
+        assert_eq!(
+            errors[1],
+            (
+                6,
+                HandError::DuplicateCard(Card::new_from_str("2h").unwrap())
+            )
+        );
+        assert_eq!(
+            errors[2],
+            (
+                7,
+                HandError::InvalidCardCount {
+                    min: Hand::MIN_CARDS,
+                    max: Hand::MAX_CARDS,
+                    found: 1
+                }
+            )
+        );
+    }
 
-        let hand1 = Hand::new_from_str("2s 2c 3h 3c").unwrap();
-        let score1 = hand1.get_score();
-        assert_eq!(score1, 2_000_000 + (3 << 4) + 2);
+    #[test]
+    fn test_parse_many_with_delimiter() {
+        let results = Hand::parse_many_with("As Ks\t7d 7c\t\n2h\t3h 4h", '\t');
+        let lines: Vec<(usize, bool)> = results
+            .iter()
+            .map(|result| match result {
+                Ok(_) => (0, true),
+                Err(e) => (e.line, false),
+            })
+            .collect();
+        assert_eq!(lines, [(0, true), (0, true), (2, false), (0, true)]);
+    }
 
-        let hand2 = Hand::new_from_str("Ks Ac Ad Js Qs").unwrap();
-        let score2 = hand2.get_score();
-        assert_eq!(score2, 1_000_000 + (14 << 12) + (13 << 8) + (12 << 4) + 11);
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_reader() {
+        use std::io::{BufReader, Cursor, Read};
+
+        let input = "As Ks Qs Js Ts\nAs Kx\n\n7d 7c\n";
+        let streamed: Vec<_> = Hand::parse_reader(Cursor::new(input)).collect();
+        assert_eq!(streamed, Hand::parse_many(input));
+
+        /// Fails after the first line.
+        struct Failing(bool);
+
+        impl Read for Failing {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                if self.0 {
+                    return Err(std::io::Error::other("broken"));
+                }
+                self.0 = true;
+                let line = b"As Ks\n";
+                buf[..line.len()].copy_from_slice(line);
+                Ok(line.len())
+            }
+        }
 
-        assert!(score1 > score2);
+        let results: Vec<_> = Hand::parse_reader(BufReader::new(Failing(false))).collect();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert_eq!(
+            results[1],
+            Err(ParseError {
+                line: 2,
+                kind: ParseErrorKind::Io(std::io::ErrorKind::Other)
+            })
+        );
+    }
 
-        let hand1 = Hand::new_from_str("2s 2c").unwrap();
-        let score1 = hand1.get_score();
-        assert_eq!(score1, 1_000_000 + 2);
+    #[test]
+    fn test_score_monotone_under_card_addition() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(191);
+        let deck = Deck::new();
+        for _ in 0..20_000 {
+            let count = rng.gen_range(Hand::MIN_CARDS..Hand::MAX_CARDS);
+            let cards: Vec<Card> = deck
+                .get_cards()
+                .choose_multiple(&mut rng, count + 1)
+                .copied()
+                .collect();
+            let mut hand = Hand::new(cards[..count].to_vec()).unwrap();
+            let score = hand.get_score();
+            hand.add_card(cards[count]).unwrap();
+            assert!(hand.get_score() >= score, "{} scored below {}", hand, score);
+        }
+    }
 
-        let hand2 = Hand::new_from_str("Ks Ac 9d Js Qs").unwrap();
-        let score2 = hand2.get_score();
-        assert_eq!(score2, (14 << 16) + (13 << 12) + (12 << 8) + (11 << 4) + 9);
+    #[test]
+    fn test_parse_partial() {
+        let (cards, pending) = Hand::parse_partial("As Kd Q");
+        assert_eq!(cards, [card("As"), card("Kd")]);
+        assert_eq!(
+            pending,
+            Some(PartialToken {
+                index: 2,
+                token: "Q".to_string()
+            })
+        );
 
-        assert!(score1 > score2);
+        assert_eq!(Hand::parse_partial(""), (vec![], None));
+        assert_eq!(
+            Hand::parse_partial("AsKd10h "),
+            (vec![card("As"), card("Kd"), card("Th")], None)
+        );
+        let (cards, pending) = Hand::parse_partial("AsKd1");
+        assert_eq!(cards.len(), 2);
+        assert_eq!(pending.unwrap().completions(&cards).len(), 4);
+
+        // Parsing stops at an invalid or repeated card.
+        let (cards, pending) = Hand::parse_partial("As Xx Kd");
+        assert_eq!(cards, [card("As")]);
+        let pending = pending.unwrap();
+        assert_eq!(pending.token, "Xx");
+        assert!(pending.completions(&cards).is_empty());
+        let (cards, pending) = Hand::parse_partial("As Kd As Qh");
+        assert_eq!(cards.len(), 2);
+        assert_eq!(pending.unwrap().index, 2);
     }
 }
@@ -0,0 +1,131 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::card::Card;
+
+use super::{decode_cards, encode_cards, HandError};
+
+/// Represents the two private cards of a hold'em player.
+///
+/// The two cards are always distinct and keep the order they were given in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HoleCards {
+    cards: [Card; 2],
+}
+
+impl HoleCards {
+    /// Creates new `HoleCards` from two cards.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::DuplicateCard` if both cards are the same.
+    pub fn new(first: Card, second: Card) -> Result<Self, HandError> {
+        if first == second {
+            return Err(HandError::DuplicateCard(first));
+        }
+        Ok(Self {
+            cards: [first, second],
+        })
+    }
+
+    /// Creates new `HoleCards` from a string like "AhKh" or "Ah Kh".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::HoleCards;
+    ///
+    /// let hole_cards = HoleCards::new_from_str("AhKh").unwrap();
+    /// assert_eq!(hole_cards.to_string(), "AhKh");
+    /// assert_eq!(hole_cards, HoleCards::new_from_str("Ah Kh").unwrap());
+    ///
+    /// assert!(HoleCards::new_from_str("AhAh").is_err());
+    /// assert!(HoleCards::new_from_str("AhKhQh").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HandError::InvalidCard` with the position of the first
+    /// invalid card, a `HandError::InvalidCardCount` if the string does not
+    /// hold exactly two cards and a `HandError::DuplicateCard` if both cards
+    /// are the same.
+    pub fn new_from_str(s: &str) -> Result<Self, HandError> {
+        let chars: Vec<char> = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let cards = chars
+            .chunks(2)
+            .enumerate()
+            .map(|(index, chunk)| {
+                let token: String = chunk.iter().collect();
+                Card::new_from_str(&token).map_err(|source| HandError::InvalidCard {
+                    index,
+                    token,
+                    source,
+                })
+            })
+            .collect::<Result<Vec<Card>, HandError>>()?;
+
+        match cards[..] {
+            [first, second] => HoleCards::new(first, second),
+            _ => Err(HandError::InvalidCardCount {
+                min: 2,
+                max: 2,
+                found: cards.len(),
+            }),
+        }
+    }
+
+    /// Decodes hole cards encoded by `HoleCards::to_bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `Hand::from_bytes`, with a
+    /// `HandError::InvalidCardCount` if there are not exactly two cards.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HandError> {
+        let cards = decode_cards(bytes)?;
+        match cards[..] {
+            [first, second] => HoleCards::new(first, second),
+            _ => Err(HandError::InvalidCardCount {
+                min: 2,
+                max: 2,
+                found: cards.len(),
+            }),
+        }
+    }
+
+    /// Encodes the hole cards like `Hand::to_bytes`, e.g. "AsKd" is encoded
+    /// as `[2, 51, 45]`. The encoding is stable across versions of the crate.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        encode_cards(&self.cards)
+    }
+
+    /// Returns the two cards.
+    pub fn get_cards(&self) -> [Card; 2] {
+        self.cards
+    }
+
+    /// Returns whether the given card is one of the hole cards.
+    pub fn contains(&self, card: &Card) -> bool {
+        self.cards.contains(card)
+    }
+}
+
+impl From<HoleCards> for [Card; 2] {
+    fn from(hole_cards: HoleCards) -> Self {
+        hole_cards.cards
+    }
+}
+
+impl fmt::Display for HoleCards {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.cards[0].as_str(), self.cards[1].as_str())
+    }
+}
+
+/// Serializes the hole cards as their string form, e.g. "AhKh".
+#[cfg(feature = "serde")]
+impl serde::Serialize for HoleCards {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
@@ -1,4 +1,38 @@
+mod builder;
+mod canonical;
+pub mod detectors;
+mod encoding;
+mod error;
 mod evaluator;
+mod explain;
+mod fixed;
+#[allow(clippy::module_inception)]
 mod hand;
+mod hole_cards;
+mod partial;
+mod qualifier;
+mod value;
 
+pub use builder::HandBuilder;
+pub use canonical::CanonicalHand;
+pub(crate) use encoding::{decode_cards, encode_cards};
+pub use error::{HandError, ParseError, ParseErrorKind};
+#[cfg(feature = "std")]
+pub(crate) use evaluator::CardCounts;
+#[cfg(feature = "std")]
+pub use evaluator::EvalCache;
+pub use evaluator::HandRank;
+pub use evaluator::{classify_counts, compare, evaluate, evaluate_rank_only};
+#[cfg(feature = "std")]
+pub use evaluator::{evaluate_holdem, evaluate_holdem_value};
+pub use evaluator::{evaluate_traced, EvalTrace, TraceStep};
+pub use evaluator::{evaluate_with_wild_set, WildSpec, FIVE_OF_A_KIND};
+pub use evaluator::{Disagreement, Evaluator, FastEvaluator, ReferenceEvaluator};
+pub use fixed::{FiveCardHand, FixedHand, SevenCardHand};
 pub use hand::Hand;
+#[cfg(feature = "std")]
+pub(crate) use hand::{bitmask, fmt_by_suit};
+pub use hole_cards::HoleCards;
+pub use partial::PartialToken;
+pub use qualifier::Qualifier;
+pub use value::HandValue;
@@ -0,0 +1,38 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::card::Card;
+
+/// The identifier `Hand::parse_partial` stopped at, either a card still
+/// being typed or an invalid one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialToken {
+    /// The zero-based position of the identifier among the identifiers.
+    pub index: usize,
+    /// The identifier as written.
+    pub token: String,
+}
+
+impl PartialToken {
+    /// Returns the cards the identifier can still be completed to, leaving
+    /// out the dead cards, see `Card::completions_excluding`.
+    ///
+    /// The list is empty if the identifier cannot become a card, e.g. "Xx",
+    /// or only a dead one, e.g. a repeated card.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let (cards, pending) = Hand::parse_partial("As Ah");
+    /// assert_eq!(cards.len(), 2);
+    /// assert!(pending.is_none());
+    ///
+    /// let (cards, pending) = Hand::parse_partial("As Ah As");
+    /// assert!(pending.unwrap().completions(&cards).is_empty());
+    /// ```
+    pub fn completions(&self, dead: &[Card]) -> Vec<String> {
+        Card::completions_excluding(&self.token, dead)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::card::Rank;
+
+use super::HandRank;
+
+/// A minimum a hand has to reach to qualify, e.g. in video poker or for the
+/// dealer in casino games. See `Hand::qualifies`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Qualifier {
+    /// The hand is at least of the given category.
+    AtLeast(HandRank),
+    /// The hand has a pair of at least the given rank or is two pair or
+    /// better, e.g. "Jacks or better".
+    PairOfAtLeast(Rank),
+}
+
+impl Qualifier {
+    /// The qualifier of "Jacks or better" video poker.
+    pub const JACKS_OR_BETTER: Qualifier = Qualifier::PairOfAtLeast(Rank::Jack);
+}
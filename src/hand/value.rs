@@ -0,0 +1,180 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::card::Rank;
+
+use super::HandRank;
+
+/// Represents the evaluated value of a poker hand.
+///
+/// A `HandValue` wraps the score returned by the evaluator and gives access to
+/// the hand's category and a human readable description. Values compare
+/// exactly like their scores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandValue {
+    score: u32,
+}
+
+impl HandValue {
+    /// Creates a new `HandValue` from a score returned by the evaluator.
+    pub fn new(score: u32) -> Self {
+        Self { score }
+    }
+
+    /// Returns the score of the hand.
+    pub fn get_score(&self) -> u32 {
+        self.score
+    }
+
+    /// Returns the category of the hand.
+    pub fn get_hand_rank(&self) -> HandRank {
+        HandRank::from_score(self.score)
+    }
+
+    /// Returns the ranks encoded in the score in order of significance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Rank;
+    /// use pkr::hand::Hand;
+    ///
+    /// let value = Hand::new_from_str("Ks Kd 2h 2c Ah").unwrap().get_value();
+    /// assert_eq!(value.get_ranks(), vec![Rank::King, Rank::Two, Rank::Ace]);
+    /// ```
+    pub fn get_ranks(&self) -> Vec<Rank> {
+        let mut rank_score = self.score % 1_000_000;
+        let mut ranks = Vec::new();
+        while rank_score > 0 {
+            let rank = Rank::new_from_num((rank_score & 0xF) as usize)
+                .expect("Score must only encode valid ranks");
+            ranks.push(rank);
+            rank_score >>= 4;
+        }
+        ranks.reverse();
+        ranks
+    }
+
+    /// Returns a human readable description of the hand, e.g.
+    /// "Full House, Kings full of Twos".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::hand::Hand;
+    ///
+    /// let value = Hand::new_from_str("Ts Js Qs Ks As").unwrap().get_value();
+    /// assert_eq!(value.describe(), "Royal Flush");
+    ///
+    /// let value = Hand::new_from_str("9c 9d 4h 4s 2c").unwrap().get_value();
+    /// assert_eq!(value.describe(), "Two Pair, Nines and Fours");
+    /// ```
+    pub fn describe(&self) -> String {
+        let ranks = self.get_ranks();
+        let first = ranks.first().copied().unwrap_or(Rank::Two);
+        let second = ranks.get(1).copied().unwrap_or(Rank::Two);
+
+        match self.get_hand_rank() {
+            HandRank::HighCard => format!("High Card, {}", rank_name(first)),
+            HandRank::OnePair => format!("One Pair, {}", rank_plural(first)),
+            HandRank::TwoPair => format!(
+                "Two Pair, {} and {}",
+                rank_plural(first),
+                rank_plural(second)
+            ),
+            HandRank::ThreeOfAKind => format!("Three of a Kind, {}", rank_plural(first)),
+            HandRank::Straight => format!("Straight, {} high", rank_name(first)),
+            HandRank::Flush => format!("Flush, {} high", rank_name(first)),
+            HandRank::FullHouse => format!(
+                "Full House, {} full of {}",
+                rank_plural(first),
+                rank_plural(second)
+            ),
+            HandRank::FourOfAKind => format!("Four of a Kind, {}", rank_plural(first)),
+            HandRank::StraightFlush if first == Rank::Ace => "Royal Flush".to_string(),
+            HandRank::StraightFlush => format!("Straight Flush, {} high", rank_name(first)),
+        }
+    }
+}
+
+impl fmt::Display for HandValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.describe())
+    }
+}
+
+/// Serializes the value as `{"score": 6000210, "category": "FullHouse",
+/// "description": "Full House, Kings full of Twos"}`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for HandValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("HandValue", 3)?;
+        state.serialize_field("score", &self.score)?;
+        state.serialize_field("category", &self.get_hand_rank())?;
+        state.serialize_field("description", &self.describe())?;
+        state.end()
+    }
+}
+
+/// Returns the English name of a rank.
+pub(super) fn rank_name(rank: Rank) -> &'static str {
+    match rank {
+        Rank::AceLow | Rank::Ace => "Ace",
+        Rank::Two => "Two",
+        Rank::Three => "Three",
+        Rank::Four => "Four",
+        Rank::Five => "Five",
+        Rank::Six => "Six",
+        Rank::Seven => "Seven",
+        Rank::Eight => "Eight",
+        Rank::Nine => "Nine",
+        Rank::Ten => "Ten",
+        Rank::Jack => "Jack",
+        Rank::Queen => "Queen",
+        Rank::King => "King",
+    }
+}
+
+/// Returns the plural English name of a rank.
+pub(super) fn rank_plural(rank: Rank) -> String {
+    match rank {
+        Rank::Six => "Sixes".to_string(),
+        _ => format!("{}s", rank_name(rank)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hand::Hand;
+
+    fn describe(s: &str) -> String {
+        Hand::new_from_str(s).unwrap().get_value().describe()
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(describe("As Kd 9c 7h 2s"), "High Card, Ace");
+        assert_eq!(describe("6s 6d 9c 7h 2s"), "One Pair, Sixes");
+        assert_eq!(describe("Ks Kd Qc Qh 2s"), "Two Pair, Kings and Queens");
+        assert_eq!(describe("7s 7d 7c Qh 2s"), "Three of a Kind, Sevens");
+        assert_eq!(describe("As 2d 3c 4h 5s"), "Straight, Five high");
+        assert_eq!(describe("Ks 9s 7s 4s 2s"), "Flush, King high");
+        assert_eq!(describe("Ks Kd Kc 2h 2s"), "Full House, Kings full of Twos");
+        assert_eq!(describe("Js Jd Jc Jh 2s"), "Four of a Kind, Jacks");
+        assert_eq!(describe("9h 8h 7h 6h 5h"), "Straight Flush, Nine high");
+        assert_eq!(describe("Ah Kh Qh Jh Th"), "Royal Flush");
+    }
+
+    #[test]
+    fn test_ordering_matches_score() {
+        let low = Hand::new_from_str("Ks Kd Qc Qh 2s").unwrap().get_value();
+        let high = Hand::new_from_str("Ks Kd Qc Qh 3s").unwrap().get_value();
+        assert!(low < high);
+        assert_eq!(low.get_score(), 2_000_000 + (13 << 8) + (12 << 4) + 2);
+        assert_eq!(low.to_string(), low.describe());
+    }
+}
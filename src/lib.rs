@@ -1,3 +1,46 @@
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
+
+extern crate alloc;
+
+mod error;
+
+#[cfg(feature = "std")]
+pub mod analysis;
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+#[cfg(feature = "std")]
+pub mod board;
 pub mod card;
+pub mod combinations;
+pub mod combinatorics;
+#[cfg(feature = "std")]
+pub mod csv;
 pub mod deck;
+pub mod encoding;
+#[cfg(feature = "std")]
+pub mod equity;
+#[cfg(feature = "std")]
+pub mod game;
 pub mod hand;
+pub mod prelude;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "std")]
+pub mod range;
+#[cfg(feature = "service")]
+pub mod service;
+#[cfg(feature = "std")]
+pub mod showdown;
+#[cfg(feature = "std")]
+pub mod spot;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+#[cfg(feature = "std")]
+pub mod verify;
+
+pub use card::{Card, Rank, Suit};
+pub use deck::Deck;
+pub use error::Error;
+pub use hand::{compare, evaluate, Hand, HandRank};
+#[cfg(feature = "std")]
+pub use showdown::winners;
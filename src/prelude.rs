@@ -0,0 +1,23 @@
+//! The types and functions most programs need, meant to be glob imported.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::cmp::Ordering;
+//!
+//! use pkr::prelude::*;
+//!
+//! let flush = Hand::new_from_str("As 9s 7s 4s 2s").unwrap();
+//! let straight = Hand::new_from_str("Tc 9d 8h 7s 6c").unwrap();
+//!
+//! assert_eq!(HandRank::from_score(evaluate(&flush)), HandRank::Flush);
+//! assert_eq!(compare(&flush, &straight), Ordering::Greater);
+//! ```
+
+#[cfg(feature = "std")]
+pub use crate::board::Board;
+pub use crate::card::{Card, Rank, Suit};
+pub use crate::deck::Deck;
+pub use crate::hand::{compare, evaluate, Hand, HandRank, HoleCards};
+#[cfg(feature = "std")]
+pub use crate::showdown::winners;
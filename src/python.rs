@@ -0,0 +1,169 @@
+//! Python bindings, enabled with the `python` feature.
+//!
+//! The module is built with maturin, see `pyproject.toml`:
+//!
+//! ```python
+//! import pkr
+//!
+//! pkr.evaluate("As Ks Qs Js Ts")  # 8000014
+//! pkr.describe("As Ks Qs Js Ts")  # 'Royal Flush'
+//! pkr.compare("As Ad", "Ks Kd")   # 1
+//! pkr.equity("AhKh", "9c9d", board="Qs Js 2d", trials=10000, seed=42)
+//! ```
+
+// The code generated by `#[pyfunction]` converts the returned errors into
+// `PyErr` even if they already are one.
+#![allow(clippy::useless_conversion)]
+
+use std::cmp::Ordering;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use rand::Rng;
+
+use crate::board::Board;
+use crate::equity::equity_monte_carlo;
+use crate::hand::{Hand, HoleCards};
+
+/// Converts an error into a `ValueError`.
+fn value_error(e: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+/// Parses a hand, naming the position of the first invalid card in the
+/// raised `ValueError`.
+fn parse_hand(s: &str) -> PyResult<Hand> {
+    Hand::new_from_str(s).map_err(value_error)
+}
+
+/// Returns the score of a hand.
+#[pyfunction]
+fn evaluate(hand: &str) -> PyResult<u32> {
+    Ok(parse_hand(hand)?.get_score())
+}
+
+/// Returns a human readable description of a hand.
+#[pyfunction]
+fn describe(hand: &str) -> PyResult<String> {
+    Ok(parse_hand(hand)?.get_value().describe())
+}
+
+/// Compares two hands, returning 1 if the first wins, -1 if the second wins
+/// and 0 on a tie.
+#[pyfunction]
+fn compare(first: &str, second: &str) -> PyResult<i32> {
+    let ordering = parse_hand(first)?
+        .get_score()
+        .cmp(&parse_hand(second)?.get_score());
+    Ok(match ordering {
+        Ordering::Greater => 1,
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+    })
+}
+
+/// Estimates the equity of the hero against a villain hand.
+///
+/// Returns a dict with the keys `equity`, `win`, `tie`, `lose` and `trials`
+/// from the hero's point of view. Without a seed a random one is used.
+#[pyfunction]
+#[pyo3(signature = (hero, villain, board=None, trials=10000, seed=None))]
+fn equity<'py>(
+    py: Python<'py>,
+    hero: &str,
+    villain: &str,
+    board: Option<&str>,
+    trials: usize,
+    seed: Option<u64>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let hands = [
+        HoleCards::new_from_str(hero).map_err(value_error)?,
+        HoleCards::new_from_str(villain).map_err(value_error)?,
+    ];
+    let board = match board {
+        Some(board) => Board::new_from_str(board).map_err(value_error)?,
+        None => Board::Preflop,
+    };
+    let seed = seed.unwrap_or_else(|| rand::thread_rng().gen());
+
+    let result = equity_monte_carlo(&hands, &board, trials, seed).map_err(value_error)?[0];
+
+    let dict = PyDict::new_bound(py);
+    dict.set_item("equity", result.equity())?;
+    dict.set_item("win", result.win())?;
+    dict.set_item("tie", result.tie())?;
+    dict.set_item("lose", result.lose())?;
+    dict.set_item("trials", result.get_trials())?;
+    Ok(dict)
+}
+
+/// The `pkr` Python module.
+#[pymodule]
+fn pkr(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(evaluate, m)?)?;
+    m.add_function(wrap_pyfunction!(describe, m)?)?;
+    m.add_function(wrap_pyfunction!(compare, m)?)?;
+    m.add_function(wrap_pyfunction!(equity, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_and_describe() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|_| {
+            assert_eq!(evaluate("As Ks Qs Js Ts").unwrap(), 8_000_014);
+            assert_eq!(describe("As Ks Qs Js Ts").unwrap(), "Royal Flush");
+            assert_eq!(compare("As Ad", "Ks Kd").unwrap(), 1);
+            assert_eq!(compare("Ks Kd", "As Ad").unwrap(), -1);
+            assert_eq!(compare("As Ad", "Ac Ah").unwrap(), 0);
+        });
+    }
+
+    #[test]
+    fn test_errors_are_value_errors() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let err = evaluate("As Kx Qd").unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py));
+            assert!(err.to_string().contains("at token 1 (\"Kx\")"));
+
+            assert!(equity(py, "AhKh", "Ah9d", None, 10, Some(1)).is_err());
+        });
+    }
+
+    #[test]
+    fn test_equity() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let result = equity(py, "AhKh", "9c9d", Some("Qs Js 2d"), 1000, Some(42)).unwrap();
+            let trials: u64 = result
+                .get_item("trials")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            let value: f64 = result
+                .get_item("equity")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(trials, 1000);
+            assert!(value > 0.0 && value < 1.0);
+
+            let again = equity(py, "AhKh", "9c9d", Some("Qs Js 2d"), 1000, Some(42)).unwrap();
+            let again: f64 = again
+                .get_item("equity")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(value, again);
+        });
+    }
+}
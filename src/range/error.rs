@@ -0,0 +1,31 @@
+use std::fmt;
+
+use crate::card::Card;
+
+/// The error returned when creating a range or a starting hand fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeError {
+    /// The combination holds the same card twice.
+    InvalidCombo([Card; 2]),
+    /// An entry of a range string cannot be parsed.
+    InvalidEntry(String),
+    /// The string is not a starting hand like "AA", "AKs" or "T9o".
+    InvalidStartingHand(String),
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RangeError::InvalidCombo(combo) => write!(
+                f,
+                "Invalid combination: {} {}",
+                combo[0].as_str(),
+                combo[1].as_str()
+            ),
+            RangeError::InvalidEntry(entry) => write!(f, "Invalid range entry: {}", entry),
+            RangeError::InvalidStartingHand(s) => write!(f, "Invalid starting hand: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
@@ -0,0 +1,8 @@
+mod error;
+#[allow(clippy::module_inception)]
+mod range;
+mod starting_hand;
+
+pub use error::RangeError;
+pub use range::Range;
+pub use starting_hand::StartingHand;
@@ -0,0 +1,503 @@
+use crate::board::Board;
+use crate::card::{Card, Rank};
+
+use super::{RangeError, StartingHand};
+
+/// Represents a range of hole card combinations an opponent may hold.
+///
+/// Every combination carries a weight, where 1.0 means the combination is
+/// fully part of the range. The cards of each combination are stored ordered
+/// by descending rank and suit, and every combination appears at most once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    combos: Vec<([Card; 2], f64)>,
+}
+
+impl Range {
+    /// Creates a new `Range` from hole card combinations with weight 1.0.
+    ///
+    /// Combinations appearing more than once are only added once.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RangeError::InvalidCombo` if a combination holds the same
+    /// card twice.
+    pub fn from_combos(combos: &[[Card; 2]]) -> Result<Self, RangeError> {
+        let mut range = Range { combos: Vec::new() };
+        for combo in combos {
+            if combo[0] == combo[1] {
+                return Err(RangeError::InvalidCombo(*combo));
+            }
+            range.insert(*combo, 1.0);
+        }
+        Ok(range)
+    }
+
+    /// Creates a new `Range` holding all 1326 hole card combinations.
+    pub fn full() -> Self {
+        let mut range = Range { combos: Vec::new() };
+        let mut ranks = vec![
+            Rank::Two,
+            Rank::Three,
+            Rank::Four,
+            Rank::Five,
+            Rank::Six,
+            Rank::Seven,
+            Rank::Eight,
+            Rank::Nine,
+            Rank::Ten,
+            Rank::Jack,
+            Rank::Queen,
+            Rank::King,
+            Rank::Ace,
+        ];
+        ranks.reverse();
+
+        for (i, &high) in ranks.iter().enumerate() {
+            range.insert_starting_hand(StartingHand::Pair(high));
+            for &low in &ranks[i + 1..] {
+                range.insert_starting_hand(StartingHand::Suited(high, low));
+                range.insert_starting_hand(StartingHand::Offsuit(high, low));
+            }
+        }
+        range
+    }
+
+    /// Creates a new `Range` from a string in the common range notation.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - A comma separated list of range entries. Supported entries are
+    ///   pairs ("QQ"), suited and offsuit hands ("AKs", "AKo"), both at once
+    ///   ("AK"), a "+" suffix adding all stronger hands of the same kind
+    ///   ("TT+" for tens or better, "A9s+" for A9s to AKs) and explicit
+    ///   combinations ("AhKh").
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::range::Range;
+    ///
+    /// let range = Range::new_from_str("QQ+, AKs, AhQd").unwrap();
+    /// assert_eq!(range.get_count(), 3 * 6 + 4 + 1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `RangeError::InvalidEntry` if an entry cannot be parsed.
+    pub fn new_from_str(s: &str) -> Result<Self, RangeError> {
+        let mut range = Range { combos: Vec::new() };
+
+        for token in s.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            let hands = parse_range_entry(token)
+                .ok_or_else(|| RangeError::InvalidEntry(token.to_string()))?;
+            for hand in hands {
+                match hand {
+                    RangeEntry::Class(class) => range.insert_starting_hand(class),
+                    RangeEntry::Combo(combo) => range.insert(combo, 1.0),
+                }
+            }
+        }
+
+        Ok(range)
+    }
+
+    /// Returns the combinations of the range together with their weights.
+    pub fn get_combos(&self) -> &[([Card; 2], f64)] {
+        &self.combos
+    }
+
+    /// Returns the number of combinations in the range.
+    pub fn get_count(&self) -> usize {
+        self.combos.len()
+    }
+
+    /// Returns the weight of a combination, or 0.0 if it is not in the range.
+    ///
+    /// The order of the two cards does not matter.
+    pub fn weight(&self, combo: &[Card; 2]) -> f64 {
+        let combo = ordered(*combo);
+        self.combos
+            .iter()
+            .find(|(c, _)| *c == combo)
+            .map(|(_, weight)| *weight)
+            .unwrap_or(0.0)
+    }
+
+    /// Returns whether a combination is part of the range.
+    ///
+    /// The order of the two cards does not matter.
+    pub fn contains(&self, combo: &[Card; 2]) -> bool {
+        self.weight(combo) > 0.0
+    }
+
+    /// Returns the range with the weight of every combination multiplied by
+    /// the factor `f` returns for its starting hand, e.g. to discount hands
+    /// an opponent would have folded earlier in the hand.
+    ///
+    /// Combinations whose weight becomes zero are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::range::{Range, StartingHand};
+    ///
+    /// let range = Range::new_from_str("AA, AKs").unwrap();
+    /// let aces = StartingHand::new_from_str("AA").unwrap();
+    /// let weighted = range.weighted_by(|hand| if hand == aces { 0.5 } else { 0.0 });
+    /// assert_eq!(weighted.get_count(), 6);
+    /// assert_eq!(weighted.get_combos()[0].1, 0.5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` returns a negative or NaN factor.
+    pub fn weighted_by(&self, f: impl Fn(StartingHand) -> f64) -> Range {
+        let combos = self
+            .combos
+            .iter()
+            .filter_map(|&(combo, weight)| {
+                let factor = f(StartingHand::from_hole_cards(&combo));
+                assert!(
+                    factor >= 0.0,
+                    "Weight factors must not be negative, got {}",
+                    factor
+                );
+                let weight = weight * factor;
+                (weight > 0.0).then_some((combo, weight))
+            })
+            .collect();
+        Range { combos }
+    }
+
+    /// Returns the range left after the board is dealt, without the
+    /// combinations holding a board card.
+    ///
+    /// The weights are renormalized so that they sum up to one, making the
+    /// weight of every combination the probability of holding it given the
+    /// board. A range whose combinations are all blocked stays empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::board::Board;
+    /// use pkr::range::Range;
+    ///
+    /// let board = Board::new_from_str("Ah 7d 2c").unwrap();
+    /// let range = Range::new_from_str("AA, KK").unwrap().on_board(&board);
+    ///
+    /// assert_eq!(range.get_count(), 3 + 6);
+    /// assert_eq!(range.get_combos()[0].1, 1.0 / 9.0);
+    /// ```
+    pub fn on_board(&self, board: &Board) -> Range {
+        let blocked = board.cards();
+        let live = self
+            .combos
+            .iter()
+            .filter(|(combo, _)| !combo.iter().any(|card| blocked.contains(card)));
+        let total: f64 = live.clone().map(|(_, weight)| weight).sum();
+        let combos = live
+            .map(|&(combo, weight)| (combo, weight / total))
+            .collect();
+        Range { combos }
+    }
+
+    /// Creates a range of the strongest starting hands making up `percent`
+    /// percent of all 1326 combinations, e.g. a "top 15%" opening range.
+    ///
+    /// The starting hands are ordered by their preflop win rate against one
+    /// random hand and added whole until the range holds at least the share
+    /// of combinations asked for. A smaller range is therefore always part
+    /// of a larger one, and 100 percent is the full range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::range::Range;
+    ///
+    /// let range = Range::top_percent(5.0);
+    /// let expected = Range::new_from_str("77+, AQs+, AKo").unwrap();
+    /// assert_eq!(range.get_count(), expected.get_count());
+    /// assert!(range.get_combos().iter().all(|(combo, _)| expected.contains(combo)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `percent` is not between 0 and 100.
+    pub fn top_percent(percent: f64) -> Range {
+        Range::percent_of_order(percent, PREFLOP_ORDER.iter())
+    }
+
+    /// Creates a range of the weakest starting hands making up `percent`
+    /// percent of all 1326 combinations, like `Range::top_percent` from the
+    /// other end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `percent` is not between 0 and 100.
+    pub fn bottom_percent(percent: f64) -> Range {
+        Range::percent_of_order(percent, PREFLOP_ORDER.iter().rev())
+    }
+
+    /// Takes the starting hands of the ids in order until they make up
+    /// `percent` percent of all combinations, keeping the order of
+    /// `Range::full`.
+    fn percent_of_order<'a>(percent: f64, ids: impl Iterator<Item = &'a u8>) -> Range {
+        assert!(
+            (0.0..=100.0).contains(&percent),
+            "The percentage must be between 0 and 100, got {}",
+            percent
+        );
+        let wanted = percent / 100.0 * 1326.0;
+        let mut count = 0;
+        let mut hands = Vec::new();
+        for &id in ids {
+            if count as f64 >= wanted {
+                break;
+            }
+            let hand = StartingHand::from_id(id).expect("The order holds valid ids");
+            count += hand.combos().len();
+            hands.push(hand);
+        }
+
+        let mut range = Range::full();
+        range
+            .combos
+            .retain(|(combo, _)| hands.contains(&StartingHand::from_hole_cards(combo)));
+        range
+    }
+
+    fn insert_starting_hand(&mut self, hand: StartingHand) {
+        for combo in hand.combos() {
+            self.insert(combo, 1.0);
+        }
+    }
+
+    fn insert(&mut self, combo: [Card; 2], weight: f64) {
+        let combo = ordered(combo);
+        if !self.combos.iter().any(|(c, _)| *c == combo) {
+            self.combos.push((combo, weight));
+        }
+    }
+}
+
+/// The ids of all starting hands, see `StartingHand::id`, from the highest
+/// to the lowest win rate against one random hand, as estimated by
+/// `analysis::preflop_win_rates` with 400,000 showdowns per hand.
+const PREFLOP_ORDER: [u8; 169] = [
+    0, 14, 28, 42, 56, 70, 84, 1, 2, 98, 13, 3, 4, 26, 39, 15, 112, 5, 52, 16, 6, 17, 27, 7, 65,
+    40, 126, 29, 8, 9, 18, 53, 78, 30, 10, 91, 19, 11, 41, 66, 31, 117, 104, 20, 43, 12, 54, 140,
+    21, 130, 79, 32, 22, 143, 44, 67, 92, 55, 156, 23, 33, 105, 24, 45, 57, 154, 34, 118, 80, 68,
+    25, 35, 58, 131, 46, 93, 36, 144, 81, 69, 37, 106, 71, 47, 59, 157, 168, 38, 119, 48, 82, 94,
+    72, 132, 60, 49, 145, 50, 83, 85, 95, 107, 73, 51, 158, 120, 61, 62, 96, 108, 86, 133, 63, 74,
+    146, 99, 97, 64, 109, 87, 159, 121, 75, 100, 134, 76, 110, 113, 147, 88, 122, 77, 111, 101,
+    160, 127, 114, 123, 89, 135, 124, 90, 148, 125, 102, 128, 115, 136, 161, 137, 141, 103, 139,
+    138, 129, 116, 149, 162, 142, 150, 152, 151, 155, 153, 163, 165, 164, 166, 167,
+];
+
+enum RangeEntry {
+    Class(StartingHand),
+    Combo([Card; 2]),
+}
+
+fn ordered(combo: [Card; 2]) -> [Card; 2] {
+    [combo[0].max(combo[1]), combo[0].min(combo[1])]
+}
+
+fn next_rank(rank: Rank) -> Option<Rank> {
+    Rank::new_from_num(rank.as_num() as usize + 1).ok()
+}
+
+/// Parses a single entry of the range notation, or returns `None` if it is
+/// invalid.
+fn parse_range_entry(token: &str) -> Option<Vec<RangeEntry>> {
+    if !token.is_ascii() {
+        return None;
+    }
+
+    if token.len() == 4 {
+        if let (Ok(first), Ok(second)) = (
+            Card::new_from_str(&token[0..2]),
+            Card::new_from_str(&token[2..4]),
+        ) {
+            if first == second {
+                return None;
+            }
+            return Some(vec![RangeEntry::Combo([first, second])]);
+        }
+    }
+
+    let (body, plus) = match token.strip_suffix('+') {
+        Some(body) => (body, true),
+        None => (token, false),
+    };
+
+    let classes: Vec<StartingHand> = if body.len() == 2 && body[0..1] != body[1..2] {
+        vec![
+            StartingHand::new_from_str(&format!("{}s", body)).ok()?,
+            StartingHand::new_from_str(&format!("{}o", body)).ok()?,
+        ]
+    } else {
+        vec![StartingHand::new_from_str(body).ok()?]
+    };
+
+    let mut entries = Vec::new();
+    for class in classes {
+        entries.push(RangeEntry::Class(class));
+        if !plus {
+            continue;
+        }
+
+        let mut current = class;
+        loop {
+            current = match current {
+                StartingHand::Pair(rank) => match next_rank(rank) {
+                    Some(rank) => StartingHand::Pair(rank),
+                    None => break,
+                },
+                StartingHand::Suited(high, low) => match next_rank(low).filter(|&r| r < high) {
+                    Some(low) => StartingHand::Suited(high, low),
+                    None => break,
+                },
+                StartingHand::Offsuit(high, low) => match next_rank(low).filter(|&r| r < high) {
+                    Some(low) => StartingHand::Offsuit(high, low),
+                    None => break,
+                },
+            };
+            entries.push(RangeEntry::Class(current));
+        }
+    }
+
+    Some(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_range() {
+        let range = Range::full();
+        assert_eq!(range.get_count(), 1326);
+        assert!(range
+            .get_combos()
+            .iter()
+            .all(|(combo, weight)| combo[0] > combo[1] && *weight == 1.0));
+    }
+
+    #[test]
+    fn test_on_board() {
+        let board = Board::new_from_str("Ah Kd 7c 7s").unwrap();
+        let range = Range::new_from_str("AA, AK, 77, 72s")
+            .unwrap()
+            .on_board(&board);
+        // Three aces and kings are left, and only the red sevens.
+        let count = |s: &str| {
+            range
+                .get_combos()
+                .iter()
+                .filter(|(combo, _)| StartingHand::from_hole_cards(combo).as_str() == s)
+                .count()
+        };
+        assert_eq!(count("AA"), 3);
+        assert_eq!(count("AKs"), 2);
+        assert_eq!(count("AKo"), 7);
+        assert_eq!(count("77"), 1);
+        assert_eq!(count("72s"), 2);
+        assert_eq!(range.get_count(), 15);
+        let total: f64 = range.get_combos().iter().map(|(_, weight)| weight).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+
+        let blocked = Range::new_from_str("AhKd").unwrap().on_board(&board);
+        assert_eq!(blocked.get_count(), 0);
+    }
+
+    #[test]
+    fn test_top_percent() {
+        assert_eq!(Range::top_percent(100.0), Range::full());
+        assert_eq!(Range::bottom_percent(100.0), Range::full());
+        assert_eq!(Range::top_percent(0.0).get_count(), 0);
+        assert_eq!(Range::top_percent(0.1), Range::new_from_str("AA").unwrap());
+        assert!(Range::bottom_percent(0.1).contains(&[
+            Card::new_from_str("3s").unwrap(),
+            Card::new_from_str("2h").unwrap(),
+        ]));
+
+        let mut smaller = Range::top_percent(0.0);
+        for percent in 1..=100 {
+            let larger = Range::top_percent(percent as f64);
+            assert!(larger.get_count() as f64 >= percent as f64 / 100.0 * 1326.0);
+            assert!(smaller
+                .get_combos()
+                .iter()
+                .all(|(combo, _)| larger.contains(combo)));
+            smaller = larger;
+        }
+    }
+
+    #[test]
+    fn test_new_from_str() {
+        let range = Range::new_from_str("TT+").unwrap();
+        assert_eq!(range.get_count(), 5 * 6);
+
+        let range = Range::new_from_str("A9s+").unwrap();
+        assert_eq!(range.get_count(), 5 * 4);
+
+        let range = Range::new_from_str("KQ, 22").unwrap();
+        assert_eq!(range.get_count(), 16 + 6);
+
+        let range = Range::new_from_str("AhKh, KhAh, AKs").unwrap();
+        assert_eq!(range.get_count(), 4);
+
+        let ace_king = [
+            Card::new_from_str("Kd").unwrap(),
+            Card::new_from_str("Ac").unwrap(),
+        ];
+        assert!(Range::new_from_str("AKo").unwrap().contains(&ace_king));
+        assert!(!Range::new_from_str("AKs").unwrap().contains(&ace_king));
+    }
+
+    #[test]
+    fn test_new_from_invalid_str() {
+        assert!(Range::new_from_str("AX").is_err());
+        assert!(Range::new_from_str("AA, KKs").is_err());
+        assert!(Range::new_from_str("AhAh").is_err());
+        assert!(Range::new_from_str("AKs++").is_err());
+    }
+
+    #[test]
+    fn test_from_combos() {
+        let ah = Card::new_from_str("Ah").unwrap();
+        let kh = Card::new_from_str("Kh").unwrap();
+
+        let range = Range::from_combos(&[[kh, ah], [ah, kh]]).unwrap();
+        assert_eq!(range.get_combos(), &[([ah, kh], 1.0)]);
+
+        assert!(Range::from_combos(&[[ah, ah]]).is_err());
+    }
+
+    #[test]
+    fn test_weighted_by() {
+        let range = Range::new_from_str("AA, KK, AKs").unwrap();
+        let weighted = range.weighted_by(|hand| match hand {
+            StartingHand::Pair(Rank::Ace) => 2.0,
+            StartingHand::Pair(_) => 0.5,
+            _ => 0.0,
+        });
+        assert_eq!(weighted.get_count(), 12);
+        let aces = [
+            Card::new_from_str("As").unwrap(),
+            Card::new_from_str("Ah").unwrap(),
+        ];
+        let kings = [
+            Card::new_from_str("Ks").unwrap(),
+            Card::new_from_str("Kh").unwrap(),
+        ];
+        assert_eq!(weighted.weight(&aces), 2.0);
+        assert_eq!(weighted.weight(&kings), 0.5);
+
+        let twice = weighted.weighted_by(|_| 2.0);
+        assert_eq!(twice.weight(&aces), 4.0);
+        assert_eq!(range.weighted_by(|_| 1.0), range);
+    }
+}
@@ -0,0 +1,315 @@
+use strum::IntoEnumIterator;
+
+use crate::card::{Card, Rank, Suit};
+use crate::hand::HandError;
+
+use super::RangeError;
+
+/// Represents one of the 169 classes of two card starting hands.
+///
+/// Pairs hold a single rank, suited and offsuit hands hold the higher rank
+/// first.
+///
+/// Human readable serde formats use the string form, e.g. "AKs", binary
+/// formats the id of `StartingHand::id` as a single byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StartingHand {
+    Pair(Rank),
+    Suited(Rank, Rank),
+    Offsuit(Rank, Rank),
+}
+
+impl StartingHand {
+    /// Creates a new `StartingHand` from a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - A string slice like "AA", "AKs" or "T9o". Non-pairs require
+    ///   the suffix "s" for suited or "o" for offsuit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Rank;
+    /// use pkr::range::StartingHand;
+    ///
+    /// let hand = StartingHand::new_from_str("KAs").unwrap();
+    /// assert_eq!(hand, StartingHand::Suited(Rank::Ace, Rank::King));
+    /// assert_eq!(hand.as_str(), "AKs");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `RangeError::InvalidStartingHand` if the string does not
+    /// represent a starting hand.
+    pub fn new_from_str(s: &str) -> Result<Self, RangeError> {
+        let invalid = || RangeError::InvalidStartingHand(s.to_string());
+        if !s.is_ascii() || s.len() < 2 || s.len() > 3 {
+            return Err(invalid());
+        }
+
+        let first = Rank::new_from_str(&s[0..1]).map_err(|_| invalid())?;
+        let second = Rank::new_from_str(&s[1..2]).map_err(|_| invalid())?;
+        let (high, low) = (first.max(second), first.min(second));
+
+        match (&s[2..], high == low) {
+            ("", true) => Ok(StartingHand::Pair(high)),
+            ("s", false) => Ok(StartingHand::Suited(high, low)),
+            ("o", false) => Ok(StartingHand::Offsuit(high, low)),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Returns all 169 starting hands in the order of the usual 13 by 13
+    /// chart: row by row from aces down to deuces, with the pairs on the
+    /// diagonal, suited hands above and offsuit hands below it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::range::StartingHand;
+    ///
+    /// let hands: Vec<String> = StartingHand::all().map(|hand| hand.as_str()).collect();
+    /// assert_eq!(hands.len(), 169);
+    /// assert_eq!(hands[..3], ["AA", "AKs", "AQs"]);
+    /// assert_eq!(hands[13..15], ["AKo", "KK"]);
+    /// ```
+    pub fn all() -> impl Iterator<Item = StartingHand> {
+        (0..169).map(|id| StartingHand::from_id(id).expect("Ids below 169 are starting hands"))
+    }
+
+    /// Returns the id of the starting hand, its position in the order of
+    /// `StartingHand::all` from 0 for "AA" to 168 for "22".
+    ///
+    /// The ids are guaranteed to never change, so they can be used as keys
+    /// of persisted data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::range::StartingHand;
+    ///
+    /// let hand = StartingHand::new_from_str("AKo").unwrap();
+    /// assert_eq!(hand.id(), 13);
+    /// assert_eq!(StartingHand::from_id(13), Some(hand));
+    /// assert!(StartingHand::try_from(169).is_err());
+    /// ```
+    pub fn id(&self) -> u8 {
+        let position = |rank: Rank| 14 - rank.as_num() as u8;
+        let (row, column) = match *self {
+            StartingHand::Pair(rank) => (position(rank), position(rank)),
+            StartingHand::Suited(high, low) => (position(high), position(low)),
+            StartingHand::Offsuit(high, low) => (position(low), position(high)),
+        };
+        row * 13 + column
+    }
+
+    /// Returns the starting hand of an id returned by `StartingHand::id`, or
+    /// `None` if the id is not smaller than 169.
+    pub fn from_id(id: u8) -> Option<StartingHand> {
+        if id >= 169 {
+            return None;
+        }
+        let rank = |i: u8| Rank::new_from_num(14 - i as usize).expect("Chart index is a rank");
+        let (row, column) = (id / 13, id % 13);
+        Some(match row.cmp(&column) {
+            core::cmp::Ordering::Equal => StartingHand::Pair(rank(row)),
+            core::cmp::Ordering::Less => StartingHand::Suited(rank(row), rank(column)),
+            core::cmp::Ordering::Greater => StartingHand::Offsuit(rank(column), rank(row)),
+        })
+    }
+
+    /// Returns the `StartingHand` class of the given hole cards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pkr::card::Card;
+    /// use pkr::range::StartingHand;
+    ///
+    /// let hole = [
+    ///     Card::new_from_str("9h").unwrap(),
+    ///     Card::new_from_str("Th").unwrap(),
+    /// ];
+    /// assert_eq!(StartingHand::from_hole_cards(&hole).as_str(), "T9s");
+    /// ```
+    pub fn from_hole_cards(hole: &[Card; 2]) -> Self {
+        let high = hole[0].rank.max(hole[1].rank);
+        let low = hole[0].rank.min(hole[1].rank);
+
+        if high == low {
+            StartingHand::Pair(high)
+        } else if hole[0].suit == hole[1].suit {
+            StartingHand::Suited(high, low)
+        } else {
+            StartingHand::Offsuit(high, low)
+        }
+    }
+
+    /// Returns a string representation of the `StartingHand`, e.g. "AKs".
+    pub fn as_str(&self) -> String {
+        match self {
+            StartingHand::Pair(rank) => format!("{}{}", rank.as_str(), rank.as_str()),
+            StartingHand::Suited(high, low) => format!("{}{}s", high.as_str(), low.as_str()),
+            StartingHand::Offsuit(high, low) => format!("{}{}o", high.as_str(), low.as_str()),
+        }
+    }
+
+    /// Returns all hole card combinations of this class.
+    ///
+    /// Pairs have 6, suited hands 4 and offsuit hands 12 combinations. The
+    /// cards of each combination are ordered by descending rank and suit.
+    pub fn combos(&self) -> Vec<[Card; 2]> {
+        let mut combos = Vec::new();
+
+        match *self {
+            StartingHand::Pair(rank) => {
+                let suits: Vec<Suit> = Suit::iter().collect();
+                for i in 0..suits.len() {
+                    for j in (i + 1)..suits.len() {
+                        combos.push([Card::new(rank, suits[j]), Card::new(rank, suits[i])]);
+                    }
+                }
+            }
+            StartingHand::Suited(high, low) => {
+                for suit in Suit::iter() {
+                    combos.push([Card::new(high, suit), Card::new(low, suit)]);
+                }
+            }
+            StartingHand::Offsuit(high, low) => {
+                for high_suit in Suit::iter() {
+                    for low_suit in Suit::iter().filter(|&suit| suit != high_suit) {
+                        combos.push([Card::new(high, high_suit), Card::new(low, low_suit)]);
+                    }
+                }
+            }
+        }
+
+        combos
+    }
+}
+
+impl TryFrom<u8> for StartingHand {
+    type Error = HandError;
+
+    /// Converts an id returned by `StartingHand::id` into its starting hand.
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        StartingHand::from_id(id).ok_or(HandError::InvalidStartingHandId(id))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for StartingHand {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.as_str())
+        } else {
+            serializer.serialize_u8(self.id())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StartingHand {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StartingHandVisitor)
+        } else {
+            deserializer.deserialize_u8(StartingHandVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct StartingHandVisitor;
+
+#[cfg(feature = "serde")]
+impl serde::de::Visitor<'_> for StartingHandVisitor {
+    type Value = StartingHand;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "a starting hand like \"AKs\" or its id")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<StartingHand, E> {
+        StartingHand::new_from_str(s).map_err(E::custom)
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, id: u64) -> Result<StartingHand, E> {
+        u8::try_from(id)
+            .ok()
+            .and_then(StartingHand::from_id)
+            .ok_or_else(|| E::custom(format!("Invalid starting hand id: {}", id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_from_str() {
+        assert_eq!(
+            StartingHand::new_from_str("AA").unwrap(),
+            StartingHand::Pair(Rank::Ace)
+        );
+        assert_eq!(
+            StartingHand::new_from_str("AKs").unwrap(),
+            StartingHand::Suited(Rank::Ace, Rank::King)
+        );
+        assert_eq!(
+            StartingHand::new_from_str("72o").unwrap(),
+            StartingHand::Offsuit(Rank::Seven, Rank::Two)
+        );
+
+        assert!(StartingHand::new_from_str("AK").is_err());
+        assert!(StartingHand::new_from_str("AAs").is_err());
+        assert!(StartingHand::new_from_str("AKx").is_err());
+        assert!(StartingHand::new_from_str("A").is_err());
+        assert!(StartingHand::new_from_str("").is_err());
+    }
+
+    #[test]
+    fn test_combos() {
+        for s in ["QQ", "QJs", "QJo"] {
+            let hand = StartingHand::new_from_str(s).unwrap();
+            let combos = hand.combos();
+            for combo in &combos {
+                assert!(combo[0] > combo[1]);
+                assert_eq!(StartingHand::from_hole_cards(combo), hand);
+            }
+        }
+
+        assert_eq!(StartingHand::new_from_str("QQ").unwrap().combos().len(), 6);
+        assert_eq!(StartingHand::new_from_str("QJs").unwrap().combos().len(), 4);
+        assert_eq!(
+            StartingHand::new_from_str("QJo").unwrap().combos().len(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_all() {
+        let hands: Vec<StartingHand> = StartingHand::all().collect();
+        assert_eq!(hands.len(), 169);
+        for (i, hand) in hands.iter().enumerate() {
+            assert!(!hands[..i].contains(hand));
+        }
+        let combos: usize = hands.iter().map(|hand| hand.combos().len()).sum();
+        assert_eq!(combos, 1326);
+        assert_eq!(hands[168], StartingHand::Pair(Rank::Two));
+    }
+
+    #[test]
+    fn test_ids() {
+        for (id, hand) in StartingHand::all().enumerate() {
+            assert_eq!(hand.id() as usize, id);
+            assert_eq!(StartingHand::try_from(hand.id()), Ok(hand));
+        }
+        assert_eq!(StartingHand::from_id(168).unwrap().as_str(), "22");
+        assert_eq!(
+            StartingHand::try_from(200),
+            Err(HandError::InvalidStartingHandId(200))
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! Request and response types for serving the library over a network, with
+//! a pure function answering them.
+//!
+//! The module does not depend on a web framework. Deserialize a `Request`
+//! from the body of an HTTP request, pass it to `handle` and serialize the
+//! `Response`. Invalid input is answered with a `ServiceError`, never with
+//! a panic, and every response carries the version of the crate.
+//!
+//! Requests are tagged by their `type`, e.g.
+//! `{"type": "eval", "hand": "As Ks Qs"}` or
+//! `{"type": "equity", "hero": "AhKh", "villain_or_range": "QQ+", "board": "Qs Js 2d", "trials": 10000, "seed": 1}`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::board::Board;
+use crate::equity::{
+    equity_exact, equity_monte_carlo, equity_vs_weighted_range,
+    equity_vs_weighted_range_monte_carlo, EquityError,
+};
+use crate::hand::{Hand, HandValue, HoleCards};
+use crate::range::Range;
+
+/// The version of the crate sent with every response.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The largest number of trials a simulation may run.
+pub const MAX_TRIALS: usize = 10_000_000;
+
+/// A request to the service.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Request {
+    Eval(EvalRequest),
+    Equity(EquityRequest),
+}
+
+/// Evaluates a hand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvalRequest {
+    /// The cards of the hand, see `Hand::new_from_str`.
+    pub hand: String,
+}
+
+/// Calculates the equity of the hero's hole cards against the hole cards
+/// or the range of a villain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EquityRequest {
+    /// The hole cards of the hero, e.g. "AhKh".
+    pub hero: String,
+    /// The hole cards of the villain, e.g. "9c9d", or a range, e.g. "QQ+, AK".
+    pub villain_or_range: String,
+    /// The community cards dealt so far, empty preflop.
+    #[serde(default)]
+    pub board: String,
+    /// The number of trials to simulate, every runout is enumerated if not
+    /// given. A range needs trials preflop.
+    #[serde(default)]
+    pub trials: Option<usize>,
+    /// The seed of the simulation, 0 if not given.
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+/// The answer to a `Request`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Response {
+    /// The version of the crate that answered, see `VERSION`.
+    pub version: &'static str,
+    /// The result or the error.
+    pub body: ResponseBody,
+}
+
+/// The result of a `Request` or the reason it failed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseBody {
+    Eval(EvalResponse),
+    Equity(EquityResponse),
+    Error(ServiceError),
+}
+
+/// The value of an evaluated hand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EvalResponse {
+    /// The cards of the hand as parsed.
+    pub hand: String,
+    /// The score, category and description of the hand.
+    #[serde(flatten)]
+    pub value: HandValue,
+}
+
+/// The equity of the hero.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct EquityResponse {
+    /// The hero's expected share of the pot.
+    pub equity: f64,
+    /// Whether every runout was enumerated rather than simulated.
+    pub exact: bool,
+}
+
+/// Why a request failed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ServiceError {
+    /// The kind of the error, for clients to act on.
+    pub code: ErrorCode,
+    /// A message for humans.
+    pub message: String,
+}
+
+/// The kind of a `ServiceError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// The hand or the hole cards are invalid.
+    InvalidHand,
+    /// The board is invalid.
+    InvalidBoard,
+    /// The villain is neither hole cards nor a range.
+    InvalidRange,
+    /// The number of trials is zero or above `MAX_TRIALS`.
+    InvalidTrials,
+    /// Enumerating every runout would take too long, trials are needed.
+    TrialsRequired,
+    /// A card is dealt more than once.
+    DuplicateCard,
+    /// Every combination of the range is blocked.
+    EmptyRange,
+}
+
+impl ServiceError {
+    fn new(code: ErrorCode, message: impl ToString) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<EquityError> for ServiceError {
+    fn from(e: EquityError) -> Self {
+        let code = match e {
+            EquityError::EmptyRange | EquityError::RangesCollide => ErrorCode::EmptyRange,
+            EquityError::NoHands | EquityError::DuplicateCard(_) => ErrorCode::DuplicateCard,
+        };
+        ServiceError::new(code, e)
+    }
+}
+
+/// Answers a request.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::service::{handle, EvalRequest, Request, ResponseBody};
+///
+/// let request = Request::Eval(EvalRequest { hand: "As Ks Qs Js Ts".to_string() });
+/// match handle(request).body {
+///     ResponseBody::Eval(eval) => assert_eq!(eval.value.describe(), "Royal Flush"),
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn handle(request: Request) -> Response {
+    let body = match request {
+        Request::Eval(request) => eval(&request).map(ResponseBody::Eval),
+        Request::Equity(request) => equity(&request).map(ResponseBody::Equity),
+    };
+    Response {
+        version: VERSION,
+        body: body.unwrap_or_else(ResponseBody::Error),
+    }
+}
+
+fn eval(request: &EvalRequest) -> Result<EvalResponse, ServiceError> {
+    let hand = Hand::new_from_str(&request.hand)
+        .map_err(|e| ServiceError::new(ErrorCode::InvalidHand, e))?;
+    Ok(EvalResponse {
+        hand: hand.as_str(),
+        value: hand.get_value(),
+    })
+}
+
+fn equity(request: &EquityRequest) -> Result<EquityResponse, ServiceError> {
+    let hero = HoleCards::new_from_str(&request.hero)
+        .map_err(|e| ServiceError::new(ErrorCode::InvalidHand, e))?;
+    let board = Board::new_from_str(&request.board)
+        .map_err(|e| ServiceError::new(ErrorCode::InvalidBoard, e))?;
+    if let Some(trials) = request.trials {
+        if trials == 0 || trials > MAX_TRIALS {
+            return Err(ServiceError::new(
+                ErrorCode::InvalidTrials,
+                format!(
+                    "The trials must be between 1 and {}, got {}",
+                    MAX_TRIALS, trials
+                ),
+            ));
+        }
+    }
+    let seed = request.seed.unwrap_or(0);
+
+    let equity =
+        match HoleCards::new_from_str(&request.villain_or_range) {
+            Ok(villain) => {
+                let hands = [hero, villain];
+                let equities = match request.trials {
+                    Some(trials) => equity_monte_carlo(&hands, &board, trials, seed)?,
+                    None => equity_exact(&hands, &board)?,
+                };
+                equities[0].equity()
+            }
+            Err(_) => {
+                let range = Range::new_from_str(&request.villain_or_range)
+                    .map_err(|e| ServiceError::new(ErrorCode::InvalidRange, e))?;
+                match request.trials {
+                    Some(trials) => {
+                        equity_vs_weighted_range_monte_carlo(&hero, &range, &board, trials, seed)?
+                    }
+                    None if board == Board::Preflop => return Err(ServiceError::new(
+                        ErrorCode::TrialsRequired,
+                        "Enumerating a range preflop takes too long, give the trials to simulate",
+                    )),
+                    None => equity_vs_weighted_range(&hero, &range, &board)?,
+                }
+            }
+        };
+    Ok(EquityResponse {
+        equity,
+        exact: request.trials.is_none(),
+    })
+}
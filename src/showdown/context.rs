@@ -0,0 +1,168 @@
+use crate::board::Board;
+use crate::card::Card;
+use crate::hand::{CardCounts, HoleCards};
+
+/// The evaluation state of a board shared by every player at a showdown.
+///
+/// The ranks and suits of the board are counted once, and the suit a flush
+/// can be made in is found once, since only a suit with at least three
+/// board cards can make a flush with two hole cards. Scoring a player then
+/// only adds the hole cards, which makes evaluating many players against the
+/// same board cheaper than building a `Hand` for each of them.
+///
+/// The scores are always equal to those of `Hand::get_score`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::board::Board;
+/// use pkr::hand::{Hand, HoleCards};
+/// use pkr::showdown::BoardContext;
+///
+/// let board = Board::new_from_str("Kh 9h 5h 2c 3d").unwrap();
+/// let context = BoardContext::new(&board);
+///
+/// let hole = HoleCards::new_from_str("AhQh").unwrap();
+/// let hand = Hand::from_hole_and_board(&hole.get_cards(), board.cards()).unwrap();
+/// assert_eq!(context.score(&hole), hand.get_score());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoardContext {
+    board: Vec<Card>,
+    counts: CardCounts,
+    flush_suit: Option<usize>,
+}
+
+impl BoardContext {
+    /// Precomputes the evaluation state of the board.
+    pub fn new(board: &Board) -> Self {
+        Self::from_cards(board.cards())
+    }
+
+    /// Precomputes the evaluation state of up to five community cards,
+    /// which may also be an incomplete board of one or two cards.
+    pub(crate) fn from_cards(board: &[Card]) -> Self {
+        debug_assert!(board.len() <= 5, "A board has at most five cards");
+        let counts = CardCounts::new(board);
+        Self {
+            board: board.to_vec(),
+            counts,
+            flush_suit: counts.flush_suit(3),
+        }
+    }
+
+    /// Returns the score of the hole cards combined with the board, like
+    /// `Hand::get_score` of the same cards.
+    ///
+    /// The hole cards must not be on the board, which is checked in debug
+    /// builds.
+    pub fn score(&self, hole: &HoleCards) -> u32 {
+        self.score_cards(&hole.get_cards())
+    }
+
+    pub(super) fn score_cards(&self, hole: &[Card; 2]) -> u32 {
+        debug_assert!(
+            !hole.iter().any(|card| self.board.contains(card)),
+            "The hole cards must not be on the board"
+        );
+        let mut counts = self.counts;
+        counts.add(hole[0]);
+        counts.add(hole[1]);
+        counts.score(self.flush_suit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::deck::Deck;
+    use crate::hand::Hand;
+
+    fn naive_score(hole: &[Card; 2], board: &[Card]) -> u32 {
+        Hand::from_hole_and_board(hole, board).unwrap().get_score()
+    }
+
+    #[test]
+    fn test_matches_naive_scores() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let deck = Deck::new();
+        for board_len in [0, 1, 2, 3, 4, 5] {
+            for _ in 0..2000 {
+                let cards: Vec<Card> = deck
+                    .get_cards()
+                    .choose_multiple(&mut rng, board_len + 18)
+                    .copied()
+                    .collect();
+                let (board, holes) = cards.split_at(board_len);
+                let context = BoardContext::from_cards(board);
+                for hole in holes.chunks_exact(2) {
+                    let hole = [hole[0], hole[1]];
+                    assert_eq!(context.score_cards(&hole), naive_score(&hole, board));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_board_flushes() {
+        let board = Board::new_from_str("Ah Kh Qh Jh Th").unwrap();
+        let context = BoardContext::new(&board);
+        for hole in ["2c3d", "9h2c", "AsAd"] {
+            let hole = HoleCards::new_from_str(hole).unwrap();
+            assert_eq!(
+                context.score(&hole),
+                naive_score(&hole.get_cards(), board.cards())
+            );
+        }
+    }
+
+    #[test]
+    #[ignore = "Timing depends on the machine"]
+    fn test_cheaper_than_naive() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let deck = Deck::new();
+        let deals: Vec<Vec<Card>> = (0..20_000)
+            .map(|_| {
+                deck.get_cards()
+                    .choose_multiple(&mut rng, 23)
+                    .copied()
+                    .collect()
+            })
+            .collect();
+
+        let start = Instant::now();
+        let mut naive = 0u64;
+        for deal in &deals {
+            let (board, holes) = deal.split_at(5);
+            for hole in holes.chunks_exact(2) {
+                naive += u64::from(naive_score(&[hole[0], hole[1]], board));
+            }
+        }
+        let naive_time = start.elapsed();
+
+        let start = Instant::now();
+        let mut shared = 0u64;
+        for deal in &deals {
+            let (board, holes) = deal.split_at(5);
+            let context = BoardContext::from_cards(board);
+            for hole in holes.chunks_exact(2) {
+                shared += u64::from(context.score_cards(&[hole[0], hole[1]]));
+            }
+        }
+        let shared_time = start.elapsed();
+
+        assert_eq!(naive, shared);
+        assert!(
+            shared_time * 2 < naive_time,
+            "{:?} with a shared board, {:?} naive",
+            shared_time,
+            naive_time
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use crate::board::Board;
+use crate::card::Card;
+
+use super::{showdown, split_pot, OddChipRule, PlayerId, ShowdownError, ShowdownResult};
+
+/// The outcome of a showdown on two boards, where half of the pot goes to
+/// the best hand of every board.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DoubleBoardResult {
+    /// The showdown on the first board.
+    pub board_a: ShowdownResult,
+    /// The showdown on the second board.
+    pub board_b: ShowdownResult,
+    /// The share of the pot every player wins, in the order the players
+    /// were given. The shares sum up to one.
+    pub shares: Vec<(PlayerId, f64)>,
+    /// The players taking the whole pot by winning both boards, in the order
+    /// they were given. Several players only scoop if they tie on both
+    /// boards, and nobody scoops if the boards have different winners.
+    pub scoopers: Vec<PlayerId>,
+}
+
+impl DoubleBoardResult {
+    /// Splits a pot of chips, half for the winners of every board.
+    ///
+    /// An odd chip left over when halving the pot goes to the first board.
+    /// The half of every board is split among its winners by `split_pot`.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(PlayerId, u64)>` - The chips every player wins, in the order
+    ///   the players were given. The chips sum up to `amount`.
+    pub fn split(&self, amount: u64, odd_chip_rule: OddChipRule) -> Vec<(PlayerId, u64)> {
+        let mut payouts: Vec<(PlayerId, u64)> = self
+            .board_a
+            .players
+            .iter()
+            .map(|player| (player.id, 0))
+            .collect();
+        let halves = [
+            (amount - amount / 2, &self.board_a.winners),
+            (amount / 2, &self.board_b.winners),
+        ];
+        for (half, winners) in halves {
+            for (id, chips) in split_pot(half, winners, odd_chip_rule) {
+                let payout = payouts
+                    .iter_mut()
+                    .find(|(player, _)| *player == id)
+                    .expect("Every winner is in showdown");
+                payout.1 += chips;
+            }
+        }
+        payouts
+    }
+}
+
+/// Evaluates a double board pot, e.g. a bomb pot dealt with two boards.
+///
+/// Every player's hole cards are played on both boards, and each board
+/// awards half the pot to its best hand, split evenly on ties.
+///
+/// # Arguments
+///
+/// * `board_a` - The first board.
+/// * `board_b` - The second board.
+/// * `players` - The players in showdown together with their hole cards.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::board::Board;
+/// use pkr::card::Card;
+/// use pkr::showdown::{double_board, OddChipRule, PlayerId};
+///
+/// let board_a = Board::new_from_str("Kh 9h 5h 2c 3d").unwrap();
+/// let board_b = Board::new_from_str("Ks Qd Tc 7s 4c").unwrap();
+/// let hole = |a: &str, b: &str| [Card::new_from_str(a).unwrap(), Card::new_from_str(b).unwrap()];
+/// let players = [(PlayerId(0), hole("Ah", "Qh")), (PlayerId(1), hole("Kd", "7d"))];
+///
+/// let result = double_board(&board_a, &board_b, &players).unwrap();
+/// assert_eq!(result.board_a.winners, vec![PlayerId(0)]);
+/// assert_eq!(result.board_b.winners, vec![PlayerId(1)]);
+/// assert!(result.scoopers.is_empty());
+/// assert_eq!(
+///     result.split(101, OddChipRule::OneEach),
+///     vec![(PlayerId(0), 51), (PlayerId(1), 50)]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns `ShowdownError::DuplicateCard` if a card appears more than once
+/// among the two boards and the hole cards.
+pub fn double_board(
+    board_a: &Board,
+    board_b: &Board,
+    players: &[(PlayerId, [Card; 2])],
+) -> Result<DoubleBoardResult, ShowdownError> {
+    if let Some(card) = board_a
+        .cards()
+        .iter()
+        .find(|card| board_b.cards().contains(card))
+    {
+        return Err(ShowdownError::DuplicateCard(*card));
+    }
+    let board_a = showdown(board_a.cards(), players)?;
+    let board_b = showdown(board_b.cards(), players)?;
+
+    let share = |winners: &[PlayerId], id: &PlayerId| {
+        if winners.contains(id) {
+            0.5 / winners.len() as f64
+        } else {
+            0.0
+        }
+    };
+    let shares = players
+        .iter()
+        .map(|(id, _)| {
+            (
+                *id,
+                share(&board_a.winners, id) + share(&board_b.winners, id),
+            )
+        })
+        .collect();
+    let scoopers = if board_a.winners == board_b.winners {
+        board_a.winners.clone()
+    } else {
+        Vec::new()
+    };
+
+    Ok(DoubleBoardResult {
+        board_a,
+        board_b,
+        shares,
+        scoopers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(s: &str) -> Board {
+        Board::new_from_str(s).unwrap()
+    }
+
+    fn player(id: usize, s: &str) -> (PlayerId, [Card; 2]) {
+        let cards: Vec<Card> = s
+            .split_whitespace()
+            .map(|c| Card::new_from_str(c).unwrap())
+            .collect();
+        (PlayerId(id), [cards[0], cards[1]])
+    }
+
+    #[test]
+    fn test_scoop() {
+        let players = [player(0, "As Ad"), player(1, "Kc Qc"), player(2, "7h 6h")];
+        let result =
+            double_board(&board("Ah 9d 4s 2c Jh"), &board("Ac 8s 3d Td 5s"), &players).unwrap();
+
+        assert_eq!(result.scoopers, vec![PlayerId(0)]);
+        assert_eq!(
+            result.shares,
+            vec![(PlayerId(0), 1.0), (PlayerId(1), 0.0), (PlayerId(2), 0.0)]
+        );
+        assert_eq!(
+            result.split(300, OddChipRule::OneEach),
+            vec![(PlayerId(0), 300), (PlayerId(1), 0), (PlayerId(2), 0)]
+        );
+    }
+
+    #[test]
+    fn test_split_on_one_board() {
+        // Both players play the straight on the first board, the flush of
+        // the second board goes to the player with the heart.
+        let players = [player(0, "2c 3d"), player(1, "4h 2s")];
+        let result =
+            double_board(&board("Ad Kd Qc Js Th"), &board("9h 8h 6h 5h Kc"), &players).unwrap();
+
+        assert_eq!(result.board_a.winners, vec![PlayerId(0), PlayerId(1)]);
+        assert_eq!(result.board_b.winners, vec![PlayerId(1)]);
+        assert!(result.scoopers.is_empty());
+        assert_eq!(
+            result.shares,
+            vec![(PlayerId(0), 0.25), (PlayerId(1), 0.75)]
+        );
+        let total: f64 = result.shares.iter().map(|(_, share)| share).sum();
+        assert_eq!(total, 1.0);
+
+        assert_eq!(
+            result.split(103, OddChipRule::OneEach),
+            vec![(PlayerId(0), 26), (PlayerId(1), 77)]
+        );
+        assert_eq!(
+            result.split(103, OddChipRule::FirstWinner),
+            vec![(PlayerId(0), 26), (PlayerId(1), 77)]
+        );
+    }
+
+    #[test]
+    fn test_chop_both_boards() {
+        // Both players holding king queen chop both boards.
+        let players = [player(0, "Ks Qd"), player(1, "Kh Qs"), player(2, "Qh 5c")];
+        let result =
+            double_board(&board("8h 9d 4s 2c Jd"), &board("7c 3h 2s Th 8d"), &players).unwrap();
+
+        assert_eq!(result.scoopers, vec![PlayerId(0), PlayerId(1)]);
+        assert_eq!(
+            result.shares,
+            vec![(PlayerId(0), 0.5), (PlayerId(1), 0.5), (PlayerId(2), 0.0)]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_cards() {
+        let card = |s| Card::new_from_str(s).unwrap();
+        let players = [player(0, "As Ad"), player(1, "Kc Qc")];
+        let board_a = board("Ah 9d 4s 2c Jh");
+
+        let overlapping = double_board(&board_a, &board("Jh 8s 3d Td 5s"), &players);
+        assert_eq!(overlapping, Err(ShowdownError::DuplicateCard(card("Jh"))));
+        assert_eq!(
+            overlapping.unwrap_err().to_string(),
+            "Duplicate card in showdown: Jh"
+        );
+        assert_eq!(
+            double_board(&board_a, &board("Kc 8s 3d Td 5s"), &players),
+            Err(ShowdownError::DuplicateCard(card("Kc")))
+        );
+        assert_eq!(
+            double_board(&board("As 8s 3d Td 5s"), &board_a, &players),
+            Err(ShowdownError::DuplicateCard(card("As")))
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt;
+
+use crate::card::Card;
+
+/// The error returned when a showdown cannot be evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShowdownError {
+    /// The board has more than five cards.
+    InvalidBoard(usize),
+    /// The card appears more than once among the board and the hole cards.
+    DuplicateCard(Card),
+    /// Chips are in play but no player in showdown is eligible for any pot.
+    NoEligiblePlayer,
+}
+
+impl fmt::Display for ShowdownError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShowdownError::InvalidBoard(found) => {
+                write!(f, "A board must have at most 5 cards, found {}.", found)
+            }
+            ShowdownError::DuplicateCard(card) => {
+                write!(f, "Duplicate card in showdown: {}", card.as_str())
+            }
+            ShowdownError::NoEligiblePlayer => {
+                write!(f, "No player in showdown is eligible for any pot.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShowdownError {}
@@ -0,0 +1,14 @@
+mod context;
+mod double_board;
+mod error;
+mod pot;
+#[allow(clippy::module_inception)]
+mod showdown;
+mod split;
+
+pub use context::BoardContext;
+pub use double_board::{double_board, DoubleBoardResult};
+pub use error::ShowdownError;
+pub use pot::{build_pots, settle, Pot};
+pub use showdown::{showdown, winners, PlayerId, PlayerResult, ShowdownResult};
+pub use split::{split_pot, OddChipRule};
@@ -0,0 +1,254 @@
+use crate::card::Card;
+
+use super::{showdown, split_pot, OddChipRule, PlayerId, ShowdownError};
+
+/// Represents a main pot or a side pot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Pot {
+    /// The number of chips in the pot.
+    pub amount: u64,
+    /// The players who contributed to the pot and may win it, in the order
+    /// the contributions were given.
+    pub eligible: Vec<PlayerId>,
+}
+
+/// Builds the main pot and the side pots from the total contribution of every
+/// player in a hand.
+///
+/// Each distinct contribution level opens a new pot, which every player who
+/// contributed at least that level is eligible for. The first pot is the main
+/// pot. Contributions of players who folded are included as well; they stay
+/// eligible here and are excluded when the pots are settled.
+///
+/// # Arguments
+///
+/// * `contributions` - The total number of chips each player put in.
+///
+/// # Returns
+///
+/// * `Vec<Pot>` - The main pot followed by the side pots.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::showdown::{build_pots, PlayerId};
+///
+/// let pots = build_pots(&[(PlayerId(0), 50), (PlayerId(1), 100), (PlayerId(2), 100)]);
+/// assert_eq!(pots.len(), 2);
+/// assert_eq!(pots[0].amount, 150);
+/// assert_eq!(pots[1].amount, 100);
+/// assert_eq!(pots[1].eligible, vec![PlayerId(1), PlayerId(2)]);
+/// ```
+pub fn build_pots(contributions: &[(PlayerId, u64)]) -> Vec<Pot> {
+    let mut levels: Vec<u64> = contributions
+        .iter()
+        .map(|(_, amount)| *amount)
+        .filter(|&amount| amount > 0)
+        .collect();
+    levels.sort_unstable();
+    levels.dedup();
+
+    let mut pots = Vec::new();
+    let mut previous_level = 0;
+    for level in levels {
+        let eligible: Vec<PlayerId> = contributions
+            .iter()
+            .filter(|(_, amount)| *amount >= level)
+            .map(|(id, _)| *id)
+            .collect();
+        let amount = (level - previous_level) * eligible.len() as u64;
+        pots.push(Pot { amount, eligible });
+        previous_level = level;
+    }
+
+    pots
+}
+
+/// Awards every pot to the best eligible hand, splitting it on ties.
+///
+/// Only players holding hole cards take part in the showdown, so folded
+/// players are simply left out of `hole_cards`. A pot in which no remaining
+/// player is eligible only holds dead money of folded players and is added to
+/// the main pot. Odd chips are handed out one at a time in the order of
+/// `hole_cards`.
+///
+/// # Arguments
+///
+/// * `pots` - The pots as built by `build_pots`.
+/// * `board` - A slice of up to five community cards.
+/// * `hole_cards` - The players in showdown together with their hole cards.
+///
+/// # Returns
+///
+/// * `Vec<(PlayerId, u64)>` - The total winnings of every player in showdown,
+///   in the order of `hole_cards`.
+///
+/// # Errors
+///
+/// Returns the `ShowdownError` of `showdown` if the showdown is invalid and
+/// `ShowdownError::NoEligiblePlayer` if chips are in play but no player is
+/// left to win them.
+pub fn settle(
+    pots: &[Pot],
+    board: &[Card],
+    hole_cards: &[(PlayerId, [Card; 2])],
+) -> Result<Vec<(PlayerId, u64)>, ShowdownError> {
+    let result = showdown(board, hole_cards)?;
+    let mut payouts: Vec<(PlayerId, u64)> = hole_cards.iter().map(|(id, _)| (*id, 0)).collect();
+
+    let mut live_pots: Vec<(u64, Vec<PlayerId>)> = Vec::new();
+    let mut dead_money = 0;
+    for pot in pots {
+        let live: Vec<PlayerId> = hole_cards
+            .iter()
+            .map(|(id, _)| *id)
+            .filter(|id| pot.eligible.contains(id))
+            .collect();
+        if live.is_empty() {
+            dead_money += pot.amount;
+        } else {
+            live_pots.push((pot.amount, live));
+        }
+    }
+
+    match live_pots.first_mut() {
+        Some(main_pot) => main_pot.0 += dead_money,
+        None if dead_money > 0 => return Err(ShowdownError::NoEligiblePlayer),
+        None => {}
+    }
+
+    for (amount, live) in live_pots {
+        let best = result
+            .players
+            .iter()
+            .filter(|player| live.contains(&player.id))
+            .map(|player| player.value)
+            .max()
+            .expect("A live pot has at least one eligible player");
+        let winners: Vec<PlayerId> = result
+            .players
+            .iter()
+            .filter(|player| live.contains(&player.id) && player.value == best)
+            .map(|player| player.id)
+            .collect();
+
+        for (id, share) in split_pot(amount, &winners, OddChipRule::OneEach) {
+            let payout = payouts
+                .iter_mut()
+                .find(|(payout_id, _)| *payout_id == id)
+                .expect("Winners are players in showdown");
+            payout.1 += share;
+        }
+    }
+
+    Ok(payouts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(s: &str) -> Vec<Card> {
+        s.split_whitespace()
+            .map(|c| Card::new_from_str(c).unwrap())
+            .collect()
+    }
+
+    fn hole(s: &str) -> [Card; 2] {
+        let cards = cards(s);
+        [cards[0], cards[1]]
+    }
+
+    #[test]
+    fn test_build_pots() {
+        let pots = build_pots(&[(PlayerId(0), 50), (PlayerId(1), 150), (PlayerId(2), 300)]);
+        assert_eq!(
+            pots,
+            vec![
+                Pot {
+                    amount: 150,
+                    eligible: vec![PlayerId(0), PlayerId(1), PlayerId(2)]
+                },
+                Pot {
+                    amount: 200,
+                    eligible: vec![PlayerId(1), PlayerId(2)]
+                },
+                Pot {
+                    amount: 150,
+                    eligible: vec![PlayerId(2)]
+                },
+            ]
+        );
+
+        assert!(build_pots(&[]).is_empty());
+        assert!(build_pots(&[(PlayerId(0), 0)]).is_empty());
+    }
+
+    #[test]
+    fn test_short_stack_wins_main_pot() {
+        // Player 0 is all-in for 50 and holds the best hand, player 1 is
+        // all-in for 150 and holds the second best hand, player 2 covers
+        // both and gets the uncalled rest back.
+        let contributions = [(PlayerId(0), 50), (PlayerId(1), 150), (PlayerId(2), 300)];
+        let board = cards("Kh 9h 5c 2c 3d");
+        let hole_cards = [
+            (PlayerId(0), hole("Kd Ks")),
+            (PlayerId(1), hole("9d 9s")),
+            (PlayerId(2), hole("Ac Qd")),
+        ];
+
+        let payouts = settle(&build_pots(&contributions), &board, &hole_cards).unwrap();
+        assert_eq!(
+            payouts,
+            vec![(PlayerId(0), 150), (PlayerId(1), 200), (PlayerId(2), 150)]
+        );
+    }
+
+    #[test]
+    fn test_split_side_pot_with_dead_money() {
+        // Player 3 folded after putting in 30, which stays in the main pot.
+        // Players 1 and 2 split the side pot with the same straight.
+        let contributions = [
+            (PlayerId(0), 50),
+            (PlayerId(1), 100),
+            (PlayerId(2), 100),
+            (PlayerId(3), 30),
+        ];
+        let board = cards("9h 8h 7c 2c 3d");
+        let hole_cards = [
+            (PlayerId(0), hole("Ac Ad")),
+            (PlayerId(1), hole("Td 6s")),
+            (PlayerId(2), hole("Ts 6d")),
+        ];
+
+        let pots = build_pots(&contributions);
+        assert_eq!(pots.iter().map(|pot| pot.amount).sum::<u64>(), 280);
+
+        let payouts = settle(&pots, &board, &hole_cards).unwrap();
+        assert_eq!(
+            payouts,
+            vec![(PlayerId(0), 0), (PlayerId(1), 140), (PlayerId(2), 140)]
+        );
+    }
+
+    #[test]
+    fn test_folded_excess_goes_to_main_pot() {
+        let contributions = [(PlayerId(0), 40), (PlayerId(1), 40), (PlayerId(2), 60)];
+        let board = cards("9h 8h 7c 2c 3d");
+        let hole_cards = [(PlayerId(0), hole("Ac Ad")), (PlayerId(1), hole("Kc Kd"))];
+
+        let payouts = settle(&build_pots(&contributions), &board, &hole_cards).unwrap();
+        assert_eq!(payouts, vec![(PlayerId(0), 140), (PlayerId(1), 0)]);
+
+        assert_eq!(
+            settle(&build_pots(&contributions), &board, &[]),
+            Err(ShowdownError::NoEligiblePlayer)
+        );
+        let duplicate = [(PlayerId(0), hole("Ac Ad")), (PlayerId(1), hole("9h Kd"))];
+        assert_eq!(
+            settle(&build_pots(&contributions), &board, &duplicate),
+            Err(ShowdownError::DuplicateCard(cards("9h")[0]))
+        );
+    }
+}
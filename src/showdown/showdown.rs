@@ -0,0 +1,234 @@
+use crate::card::Card;
+use crate::hand::{Hand, HandValue};
+
+use super::{BoardContext, ShowdownError};
+
+/// Identifies a player at the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PlayerId(pub usize);
+
+/// The evaluated hand of a single player at showdown.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PlayerResult {
+    pub id: PlayerId,
+    pub hole_cards: [Card; 2],
+    pub value: HandValue,
+}
+
+/// The outcome of a showdown.
+///
+/// With the `serde` feature it serializes as
+/// `{"players": [{"id": 0, "hole_cards": ["Ah", "Qh"], "value": {...}}], "winners": [0]}`,
+/// where `value` uses the schema of `HandValue`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ShowdownResult {
+    /// The evaluated hands in the order the players were given.
+    pub players: Vec<PlayerResult>,
+    /// The players holding the best hand, in the order they were given.
+    pub winners: Vec<PlayerId>,
+}
+
+/// Returns the indices of the hands with the highest score.
+///
+/// Several indices are returned if the best hands tie. An empty slice yields
+/// no winners.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::hand::Hand;
+/// use pkr::showdown::winners;
+///
+/// let hands = vec![
+///     Hand::new_from_str("As Ad 7c 8d 2h").unwrap(),
+///     Hand::new_from_str("Ks Kd 7c 8d 2h").unwrap(),
+///     Hand::new_from_str("Ah Ac 7c 8d 2h").unwrap(),
+/// ];
+/// assert_eq!(winners(&hands), vec![0, 2]);
+/// ```
+pub fn winners(hands: &[Hand]) -> Vec<usize> {
+    let scores: Vec<u32> = hands.iter().map(|hand| hand.get_score()).collect();
+    let best = match scores.iter().max() {
+        Some(best) => *best,
+        None => return Vec::new(),
+    };
+
+    scores
+        .iter()
+        .enumerate()
+        .filter(|(_, &score)| score == best)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Evaluates the hands of all players at showdown and determines the winners.
+///
+/// Each player's hole cards are combined with the board and scored, counting
+/// the board only once, see `BoardContext`. Ties are allowed, in which case
+/// several players win.
+///
+/// # Arguments
+///
+/// * `board` - A slice of up to five community cards.
+/// * `players` - The players in showdown together with their hole cards.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::card::Card;
+/// use pkr::showdown::{showdown, PlayerId};
+///
+/// let cards: Vec<Card> = ["Ah", "Kh", "9c", "9d", "2s", "7h", "Jd", "Qc", "3s"]
+///     .iter()
+///     .map(|s| Card::new_from_str(s).unwrap())
+///     .collect();
+/// let players = vec![
+///     (PlayerId(0), [cards[0], cards[1]]),
+///     (PlayerId(1), [cards[2], cards[3]]),
+/// ];
+///
+/// let result = showdown(&cards[4..], &players).unwrap();
+/// assert_eq!(result.winners, vec![PlayerId(1)]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `ShowdownError::InvalidBoard` if the board has more than five
+/// cards and `ShowdownError::DuplicateCard` if a card appears more than once
+/// among the board and the hole cards.
+pub fn showdown(
+    board: &[Card],
+    players: &[(PlayerId, [Card; 2])],
+) -> Result<ShowdownResult, ShowdownError> {
+    if board.len() > 5 {
+        return Err(ShowdownError::InvalidBoard(board.len()));
+    }
+
+    let mut seen: Vec<Card> = board.to_vec();
+    for (_, hole_cards) in players {
+        for card in hole_cards {
+            if seen.contains(card) {
+                return Err(ShowdownError::DuplicateCard(*card));
+            }
+            seen.push(*card);
+        }
+    }
+
+    let context = BoardContext::from_cards(board);
+    let scores: Vec<u32> = players
+        .iter()
+        .map(|(_, hole_cards)| context.score_cards(hole_cards))
+        .collect();
+
+    let results = players
+        .iter()
+        .zip(&scores)
+        .map(|((id, hole_cards), &score)| PlayerResult {
+            id: *id,
+            hole_cards: *hole_cards,
+            value: HandValue::new(score),
+        })
+        .collect();
+
+    let best = scores.iter().max().copied();
+    let winners = players
+        .iter()
+        .zip(&scores)
+        .filter(|(_, &score)| Some(score) == best)
+        .map(|((id, _), _)| *id)
+        .collect();
+
+    Ok(ShowdownResult {
+        players: results,
+        winners,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(s: &str) -> Vec<Card> {
+        s.split_whitespace()
+            .map(|c| Card::new_from_str(c).unwrap())
+            .collect()
+    }
+
+    fn hole(s: &str) -> [Card; 2] {
+        let cards = cards(s);
+        [cards[0], cards[1]]
+    }
+
+    #[test]
+    fn test_showdown_single_winner() {
+        let board = cards("Kh 9h 5h 2c 3d");
+        let players = vec![
+            (PlayerId(3), hole("Ah Qh")),
+            (PlayerId(5), hole("Ks Kd")),
+            (PlayerId(7), hole("4c 4d")),
+        ];
+
+        let result = showdown(&board, &players).unwrap();
+        assert_eq!(result.winners, vec![PlayerId(3)]);
+        assert_eq!(result.players.len(), 3);
+        assert_eq!(result.players[0].value.describe(), "Flush, Ace high");
+        assert_eq!(result.players[1].value.describe(), "Three of a Kind, Kings");
+        assert_eq!(result.players[2].value.describe(), "One Pair, Fours");
+    }
+
+    #[test]
+    fn test_showdown_matches_winners() {
+        let board = cards("Ah Kd Qc Jh Ts");
+        let players = vec![
+            (PlayerId(0), hole("2c 3d")),
+            (PlayerId(1), hole("4s 5s")),
+            (PlayerId(2), hole("Ac Ad")),
+        ];
+
+        let result = showdown(&board, &players).unwrap();
+
+        let hands: Vec<Hand> = players
+            .iter()
+            .map(|(_, hole_cards)| {
+                let mut cards = hole_cards.to_vec();
+                cards.extend_from_slice(&board);
+                Hand::new(cards).unwrap()
+            })
+            .collect();
+        let expected: Vec<PlayerId> = winners(&hands).into_iter().map(|i| players[i].0).collect();
+
+        assert_eq!(result.winners, expected);
+        assert_eq!(result.winners.len(), 3);
+    }
+
+    #[test]
+    fn test_showdown_invalid_input() {
+        let board = cards("Ah Kd Qc Jh Ts");
+        let players = vec![(PlayerId(0), hole("Ah 3d")), (PlayerId(1), hole("4s 5s"))];
+        assert_eq!(
+            showdown(&board, &players),
+            Err(ShowdownError::DuplicateCard(cards("Ah")[0]))
+        );
+
+        let players = vec![(PlayerId(0), hole("2c 3d")), (PlayerId(1), hole("3d 5s"))];
+        assert_eq!(
+            showdown(&board, &players),
+            Err(ShowdownError::DuplicateCard(cards("3d")[0]))
+        );
+
+        let board = cards("Ah Kd Qc Jh Ts 9s");
+        let players = vec![(PlayerId(0), hole("2c 3d"))];
+        assert_eq!(
+            showdown(&board, &players),
+            Err(ShowdownError::InvalidBoard(6))
+        );
+    }
+
+    #[test]
+    fn test_winners_empty() {
+        assert!(winners(&[]).is_empty());
+    }
+}
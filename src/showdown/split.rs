@@ -0,0 +1,106 @@
+use super::PlayerId;
+
+/// Determines who receives the chips left over when a pot cannot be divided
+/// evenly among its winners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OddChipRule {
+    /// The odd chips are handed out one at a time to the winners in the order
+    /// they are given, e.g. starting with the first winner left of the button.
+    OneEach,
+    /// All odd chips go to the first winner in the given order.
+    FirstWinner,
+}
+
+/// Splits a pot among its winners.
+///
+/// Every winner receives the same share. Chips that cannot be divided evenly
+/// are distributed according to `odd_chip_rule`, so the result only depends
+/// on the order of `winners` and the sum of all shares always equals `amount`.
+///
+/// # Arguments
+///
+/// * `amount` - The number of chips in the pot.
+/// * `winners` - The players sharing the pot in odd chip order.
+/// * `odd_chip_rule` - The rule to distribute the remaining chips.
+///
+/// # Returns
+///
+/// * `Vec<(PlayerId, u64)>` - The share of every winner in the order given.
+///   No shares are returned if there are no winners.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::showdown::{split_pot, OddChipRule, PlayerId};
+///
+/// let winners = [PlayerId(0), PlayerId(1), PlayerId(2)];
+/// let shares = split_pot(100, &winners, OddChipRule::OneEach);
+/// assert_eq!(
+///     shares,
+///     vec![(PlayerId(0), 34), (PlayerId(1), 33), (PlayerId(2), 33)]
+/// );
+/// ```
+pub fn split_pot(
+    amount: u64,
+    winners: &[PlayerId],
+    odd_chip_rule: OddChipRule,
+) -> Vec<(PlayerId, u64)> {
+    if winners.is_empty() {
+        return Vec::new();
+    }
+
+    let share = amount / winners.len() as u64;
+    let remainder = amount % winners.len() as u64;
+
+    winners
+        .iter()
+        .enumerate()
+        .map(|(i, &id)| {
+            let odd_chips = match odd_chip_rule {
+                OddChipRule::OneEach => u64::from((i as u64) < remainder),
+                OddChipRule::FirstWinner if i == 0 => remainder,
+                OddChipRule::FirstWinner => 0,
+            };
+            (id, share + odd_chips)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_three_way_chop() {
+        let winners = [PlayerId(4), PlayerId(1), PlayerId(2)];
+
+        let shares = split_pot(100, &winners, OddChipRule::OneEach);
+        assert_eq!(
+            shares,
+            vec![(PlayerId(4), 34), (PlayerId(1), 33), (PlayerId(2), 33)]
+        );
+
+        let shares = split_pot(101, &winners, OddChipRule::OneEach);
+        assert_eq!(
+            shares,
+            vec![(PlayerId(4), 34), (PlayerId(1), 34), (PlayerId(2), 33)]
+        );
+
+        let shares = split_pot(101, &winners, OddChipRule::FirstWinner);
+        assert_eq!(
+            shares,
+            vec![(PlayerId(4), 35), (PlayerId(1), 33), (PlayerId(2), 33)]
+        );
+    }
+
+    #[test]
+    fn test_split_pot_edge_cases() {
+        assert!(split_pot(100, &[], OddChipRule::OneEach).is_empty());
+
+        let shares = split_pot(0, &[PlayerId(0), PlayerId(1)], OddChipRule::OneEach);
+        assert_eq!(shares, vec![(PlayerId(0), 0), (PlayerId(1), 0)]);
+
+        let shares = split_pot(7, &[PlayerId(0)], OddChipRule::FirstWinner);
+        assert_eq!(shares, vec![(PlayerId(0), 7)]);
+    }
+}
@@ -0,0 +1,248 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::board::{Board, BoardError, Street};
+use crate::card::Card;
+use crate::hand::{HandError, HoleCards};
+
+/// Represents a single-string poker situation: the hero's hole cards, any
+/// number of known villain hands and the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spot {
+    pub hero: HoleCards,
+    pub villains: Vec<HoleCards>,
+    pub board: Board,
+}
+
+/// The error returned when parsing a `Spot` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpotError {
+    /// The hole cards of the hero or of a villain are invalid.
+    Hand(HandError),
+    /// The board is invalid.
+    Board(BoardError),
+    /// The same card appears more than once in the spot.
+    DuplicateCard(Card),
+}
+
+impl fmt::Display for SpotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SpotError::Hand(e) => write!(f, "{}", e),
+            SpotError::Board(e) => write!(f, "{}", e),
+            SpotError::DuplicateCard(card) => {
+                write!(f, "Duplicate card in spot: {}", card.as_str())
+            }
+        }
+    }
+}
+
+impl std::error::Error for SpotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SpotError::Hand(e) => Some(e),
+            SpotError::Board(e) => Some(e),
+            SpotError::DuplicateCard(_) => None,
+        }
+    }
+}
+
+impl From<HandError> for SpotError {
+    fn from(e: HandError) -> Self {
+        SpotError::Hand(e)
+    }
+}
+
+impl From<BoardError> for SpotError {
+    fn from(e: BoardError) -> Self {
+        SpotError::Board(e)
+    }
+}
+
+/// Parses a spot from its compact notation.
+///
+/// The hero's hole cards come first, followed by any number of villain hands
+/// each introduced by `vs`. An optional board follows after `|`, with the
+/// flop, turn and river separated by `/`. Whitespace between cards is
+/// optional, so "AhKh|QsJsTs" and "AhKh vs 9c9d | Qs Js Ts / 2d / 2c" are both
+/// valid.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::board::Street;
+/// use pkr::spot::parse_spot;
+///
+/// let spot = parse_spot("AhKh vs 9c9d | Qs Js Ts / 2d").unwrap();
+/// assert_eq!(spot.hero.to_string(), "AhKh");
+/// assert_eq!(spot.villains.len(), 1);
+/// assert_eq!(spot.board.get_street(), Street::Turn);
+/// assert_eq!(spot.to_string(), "AhKh vs 9c9d | Qs Js Ts / 2d");
+/// ```
+///
+/// # Errors
+///
+/// Returns a `SpotError` if a hand or street has the wrong number of cards, a
+/// card cannot be parsed or a card appears more than once anywhere in the
+/// spot.
+pub fn parse_spot(s: &str) -> Result<Spot, SpotError> {
+    let mut parts = s.splitn(2, '|');
+    let hands = parts.next().unwrap_or("");
+    let board = parts.next();
+
+    let mut hands = hands.split("vs");
+    let hero = HoleCards::new_from_str(hands.next().unwrap_or(""))?;
+    let villains = hands
+        .map(HoleCards::new_from_str)
+        .collect::<Result<Vec<HoleCards>, _>>()?;
+
+    let board = match board {
+        Some(board) => parse_board(board)?,
+        None => Board::Preflop,
+    };
+
+    let mut seen: Vec<Card> = board.cards().to_vec();
+    for card in std::iter::once(&hero)
+        .chain(villains.iter())
+        .flat_map(|hole_cards| hole_cards.get_cards())
+    {
+        if seen.contains(&card) {
+            return Err(SpotError::DuplicateCard(card));
+        }
+        seen.push(card);
+    }
+
+    Ok(Spot {
+        hero,
+        villains,
+        board,
+    })
+}
+
+fn parse_board(s: &str) -> Result<Board, BoardError> {
+    let streets: Vec<&str> = s.split('/').collect();
+    if streets.len() > 3 {
+        return Err(BoardError::TooManyStreets(streets.len()));
+    }
+
+    let mut cards = Vec::with_capacity(5);
+    for (street, (name, expected)) in
+        streets
+            .iter()
+            .zip([(Street::Flop, 3), (Street::Turn, 1), (Street::River, 1)])
+    {
+        let street_cards = parse_cards(street)?;
+        if street_cards.len() != expected {
+            return Err(BoardError::InvalidStreet {
+                street: name,
+                expected,
+                found: street_cards.len(),
+            });
+        }
+        cards.extend(street_cards);
+    }
+    Board::try_from(cards.as_slice())
+}
+
+/// Parses a run of cards with optional whitespace between them.
+fn parse_cards(s: &str) -> Result<Vec<Card>, BoardError> {
+    let chars: Vec<char> = s.chars().filter(|c| !c.is_whitespace()).collect();
+    let cards = chars
+        .chunks(2)
+        .map(|chunk| Card::new_from_str(&chunk.iter().collect::<String>()))
+        .collect::<Result<Vec<Card>, _>>()?;
+    Ok(cards)
+}
+
+impl FromStr for Spot {
+    type Err = SpotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_spot(s)
+    }
+}
+
+impl fmt::Display for Spot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.hero)?;
+        for villain in &self.villains {
+            write!(f, " vs {}", villain)?;
+        }
+        if self.board != Board::Preflop {
+            write!(f, " | {}", self.board.to_string().replace('|', "/"))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_compact_spot() {
+        let spot = parse_spot("AhKh|QsJsTs").unwrap();
+        assert_eq!(spot.hero, HoleCards::new_from_str("AhKh").unwrap());
+        assert!(spot.villains.is_empty());
+        assert_eq!(spot.board, Board::new_from_str("Qs Js Ts").unwrap());
+        assert_eq!(spot.to_string(), "AhKh | Qs Js Ts");
+    }
+
+    #[test]
+    fn test_parse_multiple_villains() {
+        let s = "AhKh vs 9c9d vs QcJc | Qs Js Ts / 2d / 2c";
+        let spot = parse_spot(s).unwrap();
+        assert_eq!(spot.villains.len(), 2);
+        assert_eq!(spot.villains[1].to_string(), "QcJc");
+        assert_eq!(spot.board.get_street(), Street::River);
+        assert_eq!(spot.to_string(), s);
+        assert_eq!(s.parse::<Spot>().unwrap(), spot);
+    }
+
+    #[test]
+    fn test_parse_missing_streets() {
+        let spot = parse_spot("AhKh vs 9c9d").unwrap();
+        assert_eq!(spot.board, Board::Preflop);
+        assert_eq!(spot.to_string(), "AhKh vs 9c9d");
+
+        let spot = parse_spot("AhKh | Qs Js Ts / 2d").unwrap();
+        assert_eq!(spot.board.get_street(), Street::Turn);
+
+        assert_eq!(
+            parse_spot("AhKh | Qs Js"),
+            Err(SpotError::Board(BoardError::InvalidStreet {
+                street: Street::Flop,
+                expected: 3,
+                found: 2,
+            }))
+        );
+        assert!(parse_spot("AhKh | Qs Js Ts / 2d 3d").is_err());
+        assert_eq!(
+            parse_spot("AhKh | Qs Js Ts / 2d / 3d / 4d"),
+            Err(SpotError::Board(BoardError::TooManyStreets(4)))
+        );
+    }
+
+    #[test]
+    fn test_whitespace_tolerance() {
+        let spot = parse_spot("  Ah Kh   vs9c 9d|QsJs Ts/2d/ 2c ").unwrap();
+        assert_eq!(
+            spot,
+            parse_spot("AhKh vs 9c9d | Qs Js Ts / 2d / 2c").unwrap()
+        );
+        assert_eq!(parse_spot(&spot.to_string()).unwrap(), spot);
+    }
+
+    #[test]
+    fn test_duplicate_cards() {
+        assert!(parse_spot("AhAh").is_err());
+        assert_eq!(
+            parse_spot("AhKh vs Ah9d"),
+            Err(SpotError::DuplicateCard(Card::new_from_str("Ah").unwrap()))
+        );
+        assert!(parse_spot("AhKh vs 9c9d | Qs Js Kh").is_err());
+        assert!(parse_spot("AhKh vs 9c9d | Qs Js Ts / 2d / 9c").is_err());
+        assert!(parse_spot("AhKh | Qs Js Ts / Qs").is_err());
+        assert!(parse_spot("").is_err());
+    }
+}
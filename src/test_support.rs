@@ -0,0 +1,141 @@
+//! Strategies and invariant checks for property tests of code built on the
+//! crate, enabled with the `test-support` feature.
+//!
+//! The invariants are guarantees of the crate itself, which its own test
+//! suite checks with these helpers. They panic with a description of the
+//! violation, which `proptest` reports as a failing case.
+//!
+//! ```
+//! use pkr::test_support::{arb_card, arb_hand, assert_score_monotone_under_card_addition};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn adding_a_card_never_hurts(hand in arb_hand(2..=6), card in arb_card()) {
+//!         prop_assume!(!hand.contains(&card));
+//!         assert_score_monotone_under_card_addition(&hand, card);
+//!     }
+//! }
+//! # adding_a_card_never_hurts();
+//! ```
+
+use core::ops::RangeInclusive;
+
+use proptest::prelude::*;
+
+pub use crate::arbitrary::{arb_board, arb_card, arb_hole_cards};
+use crate::card::{Card, Suit};
+use crate::hand::Hand;
+
+/// Returns a strategy generating valid hands with a number of distinct
+/// cards in `sizes`, in a random order.
+///
+/// # Panics
+///
+/// Panics if `sizes` is empty or not within `Hand::MIN_CARDS` and
+/// `Hand::MAX_CARDS`.
+pub fn arb_hand(sizes: RangeInclusive<usize>) -> impl Strategy<Value = Hand> {
+    assert!(
+        !sizes.is_empty() && *sizes.start() >= Hand::MIN_CARDS && *sizes.end() <= Hand::MAX_CARDS,
+        "The sizes must be between {} and {} cards, got {:?}.",
+        Hand::MIN_CARDS,
+        Hand::MAX_CARDS,
+        sizes
+    );
+    sizes.prop_flat_map(crate::arbitrary::arb_hand)
+}
+
+/// Asserts that adding a card to a hand does not lower its score, since the
+/// best hand of the old cards can still be made.
+///
+/// # Panics
+///
+/// Panics if the score drops, or if the card is already in the hand or the
+/// hand already holds `Hand::MAX_CARDS` cards.
+pub fn assert_score_monotone_under_card_addition(hand: &Hand, card: Card) {
+    let mut larger = hand.clone();
+    larger
+        .add_card(card)
+        .unwrap_or_else(|e| panic!("Cannot add {} to {}: {}", card.as_str(), hand, e));
+    assert!(
+        larger.get_score() >= hand.get_score(),
+        "Adding {} to {} lowered the score from {} to {}",
+        card.as_str(),
+        hand,
+        hand.get_score(),
+        larger.get_score()
+    );
+}
+
+/// Asserts that the score of a hand does not change under any of the 24
+/// permutations of the suits, since no suit ranks above another.
+///
+/// # Panics
+///
+/// Panics if a permutation of the suits changes the score.
+pub fn assert_suit_invariance(hand: &Hand) {
+    let score = hand.get_score();
+    for permutation in suit_permutations() {
+        let permuted: Vec<Card> = hand
+            .iter()
+            .map(|card| Card::new(card.rank, permutation[card.suit.to_index()]))
+            .collect();
+        let permuted = Hand::new(permuted).expect("Permuting the suits keeps the cards distinct");
+        assert_eq!(
+            permuted.get_score(),
+            score,
+            "Permuting the suits of {} to {} changed the score",
+            hand,
+            permuted
+        );
+    }
+}
+
+/// Returns all 24 permutations of the suits, indexed by `Suit::to_index`.
+fn suit_permutations() -> Vec<[Suit; 4]> {
+    let suit = |i| Suit::new_from_num(i).expect("Every index below 4 is a suit");
+    let mut permutations = Vec::with_capacity(24);
+    for a in 0..4 {
+        for b in (0..4).filter(|&b| b != a) {
+            for c in (0..4).filter(|&c| c != a && c != b) {
+                let d = 6 - a - b - c;
+                permutations.push([suit(a), suit(b), suit(c), suit(d)]);
+            }
+        }
+    }
+    permutations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suit_permutations() {
+        let permutations = suit_permutations();
+        assert_eq!(permutations.len(), 24);
+        for (i, permutation) in permutations.iter().enumerate() {
+            assert!(!permutations[..i].contains(permutation));
+        }
+    }
+
+    #[test]
+    fn test_invariants_hold() {
+        let hand = Hand::new_from_str("As Ks Qs Js 9h").unwrap();
+        assert_suit_invariance(&hand);
+        assert_score_monotone_under_card_addition(&hand, Card::new_from_str("Ts").unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot add As")]
+    fn test_monotone_check_rejects_duplicate_card() {
+        let hand = Hand::new_from_str("As Ad").unwrap();
+        assert_score_monotone_under_card_addition(&hand, Card::new_from_str("As").unwrap());
+    }
+
+    proptest! {
+        #[test]
+        fn test_arb_hand_sizes(hand in arb_hand(3..=5)) {
+            prop_assert!((3..=5).contains(&hand.get_count()));
+        }
+    }
+}
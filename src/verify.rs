@@ -0,0 +1,798 @@
+//! Randomized checks of guarantees the evaluator makes.
+//!
+//! The checks are cheap smoke tests for downstream crates that build their
+//! own rules on top of `Hand`, e.g. a custom scoring function, and for the
+//! parsing and construction APIs of the crate itself.
+
+use std::error::Error;
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::board::{Board, BoardError};
+use crate::card::{Card, Rank, Suit};
+use crate::deck::Deck;
+use crate::hand::detectors::{
+    find_flush_unsorted, find_four_of_a_kind_unsorted, find_full_house_unsorted,
+    find_pair_unsorted, find_straight_unsorted, find_three_of_a_kind_unsorted,
+    find_two_pair_unsorted,
+};
+use crate::hand::{
+    Evaluator, FastEvaluator, FixedHand, Hand, HandBuilder, HandError, HoleCards, ParseErrorKind,
+};
+use crate::range::{Range, RangeError, StartingHand};
+
+/// A hand whose score changed when its suits were relabeled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    /// The sampled hand.
+    pub hand: Hand,
+    /// The hand with its suits relabeled.
+    pub permuted: Hand,
+    /// The score of the sampled hand.
+    pub score: u32,
+    /// The score of the relabeled hand.
+    pub permuted_score: u32,
+}
+
+impl fmt::Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Relabeling the suits of {} to {} changed the score from {} to {}.",
+            self.hand, self.permuted, self.score, self.permuted_score
+        )
+    }
+}
+
+impl Error for Counterexample {}
+
+/// Checks that scores are suit-blind: a hand keeps its score when its suits
+/// are relabeled, so e.g. flushes of the same ranks in different suits tie.
+///
+/// Samples `sample_size` random hands of `Hand::MIN_CARDS` to
+/// `Hand::MAX_CARDS` cards, relabels the suits of each with a random
+/// permutation of the four suits and compares the scores. The same seed always checks the same hands.
+///
+/// # Arguments
+///
+/// * `sample_size` - The number of hands to sample.
+/// * `seed` - The seed of the random number generator.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::verify::suit_invariance;
+///
+/// assert!(suit_invariance(1000, 42).is_ok());
+/// ```
+///
+/// # Errors
+///
+/// Returns the first hand whose score changed as a `Counterexample`.
+pub fn suit_invariance(sample_size: usize, seed: u64) -> Result<(), Counterexample> {
+    suit_invariance_with(sample_size, seed, Hand::get_score)
+}
+
+/// Checks that a custom scoring function is suit-blind, see
+/// `suit_invariance`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::hand::Hand;
+/// use pkr::verify::suit_invariance_with;
+///
+/// // Counting the spades is not suit-blind.
+/// let spades = |hand: &Hand| hand.cards_of_suit(pkr::card::Suit::Spade).len() as u32;
+/// assert!(suit_invariance_with(1000, 42, spades).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns the first hand whose score changed as a `Counterexample`.
+pub fn suit_invariance_with(
+    sample_size: usize,
+    seed: u64,
+    score: impl Fn(&Hand) -> u32,
+) -> Result<(), Counterexample> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    for _ in 0..sample_size {
+        let n = rng.gen_range(Hand::MIN_CARDS..=Hand::MAX_CARDS);
+        let hand = Hand::random(&mut rng, n);
+        let mut permutation = [Suit::Club, Suit::Diamond, Suit::Heart, Suit::Spade];
+        permutation.shuffle(&mut rng);
+        let permuted = relabel_suits(&hand, &permutation);
+
+        let (original_score, permuted_score) = (score(&hand), score(&permuted));
+        if original_score != permuted_score {
+            return Err(Counterexample {
+                hand,
+                permuted,
+                score: original_score,
+                permuted_score,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// An input a public API handled wrongly, found by `fuzz_inputs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzFailure {
+    /// The name of the API, e.g. "Hand::new_from_str".
+    pub api: &'static str,
+    /// The input in debug notation.
+    pub input: String,
+    /// The panic message or the violated guarantee.
+    pub message: String,
+}
+
+impl fmt::Display for FuzzFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} failed on {}: {}", self.api, self.input, self.message)
+    }
+}
+
+impl Error for FuzzFailure {}
+
+/// Throws random and malformed inputs at every parsing and construction API
+/// and checks that none of them panics.
+///
+/// Every iteration generates random bytes, random text, strings of valid
+/// and invalid card tokens with duplicates and wrong numbers of cards, and
+/// random cards and ranks, and passes them to the parsers of cards, hands,
+/// hole cards, boards and ranges, to the constructors of hands, to the
+/// standalone detectors and to the evaluator. An API passes if it returns
+/// an error with a message whose variant matches the input, e.g. a
+/// `HandError::DuplicateCard` naming a card that really is repeated, or a
+/// value that upholds its guarantees, e.g. a hand of distinct cards. The
+/// same seed always generates the same inputs.
+///
+/// Panics are caught, but the panic hook still prints them.
+///
+/// # Arguments
+///
+/// * `iterations` - The number of rounds of inputs to generate.
+/// * `seed` - The seed of the random number generator.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::verify::fuzz_inputs;
+///
+/// assert_eq!(fuzz_inputs(100, 42), Ok(()));
+/// ```
+///
+/// # Errors
+///
+/// Returns the first input an API panicked on or handled wrongly as a
+/// `FuzzFailure`.
+pub fn fuzz_inputs(iterations: usize, seed: u64) -> Result<(), FuzzFailure> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    for _ in 0..iterations {
+        let bytes = random_bytes(&mut rng);
+        let text = random_text(&mut rng);
+        let cards_text = random_card_string(&mut rng);
+        let cards = random_cards(&mut rng);
+        let ranks: Vec<Rank> = cards.iter().map(|card| card.rank).collect();
+
+        for s in [text.as_str(), cards_text.as_str()] {
+            fuzz_str(s)?;
+        }
+        fuzz_bytes(&bytes)?;
+        fuzz_cards(&cards)?;
+        fuzz_ranks(&ranks)?;
+        fuzz_numbers(rng.gen_range(0..20))?;
+    }
+    Ok(())
+}
+
+fn fuzz_str(s: &str) -> Result<(), FuzzFailure> {
+    check("Card::new_from_str", s, || {
+        Card::new_from_str(s).err().map(|_| None)
+    })?;
+    check("Rank::new_from_str", s, || {
+        Rank::new_from_str(s).map(|_| ()).err().map(|_| None)
+    })?;
+    check("Suit::new_from_str", s, || {
+        Suit::new_from_str(s).map(|_| ()).err().map(|_| None)
+    })?;
+    check("Hand::new_from_str", s, || {
+        Some(valid_hand(Hand::new_from_str(s), parse_error))
+    })?;
+    check("Hand::parse_strict", s, || {
+        Some(valid_hand(Hand::parse_strict(s), parse_error))
+    })?;
+    check("Hand::parse_many", s, || {
+        Hand::parse_many(s)
+            .into_iter()
+            .find_map(|result| {
+                valid_hand(
+                    result,
+                    |e| matches!(&e.kind, ParseErrorKind::Hand(error) if parse_error(error)),
+                )
+            })
+            .map(Some)
+    })?;
+    check("HandBuilder::parse", s, || {
+        let built = HandBuilder::new().parse(s).and_then(HandBuilder::build);
+        Some(valid_hand(built, parse_error))
+    })?;
+    check("HoleCards::new_from_str", s, || {
+        Some(valid_hole_cards(HoleCards::new_from_str(s), parse_error))
+    })?;
+    check("Board::new_from_str", s, || {
+        Some(valid_board(Board::new_from_str(s), |e| {
+            !matches!(
+                e,
+                BoardError::IllegalDeal { .. }
+                    | BoardError::InvalidLength { .. }
+                    | BoardError::InvalidByte { .. }
+            )
+        }))
+    })?;
+    check("Range::new_from_str", s, || {
+        Some(valid_range(Range::new_from_str(s), |e| {
+            matches!(e, RangeError::InvalidEntry(_))
+        }))
+    })?;
+    check("StartingHand::new_from_str", s, || {
+        StartingHand::new_from_str(s)
+            .err()
+            .map(|e| valid_error(&e, matches!(e, RangeError::InvalidStartingHand(_))))
+    })
+}
+
+fn fuzz_bytes(bytes: &[u8]) -> Result<(), FuzzFailure> {
+    check("Card::from_byte", bytes, || {
+        bytes
+            .first()
+            .and_then(|&byte| Card::from_byte(byte).err().map(|_| None))
+    })?;
+    check("Hand::from_bytes", bytes, || {
+        Some(valid_hand(Hand::from_bytes(bytes), |e| {
+            decode_error(bytes, e)
+        }))
+    })?;
+    check("HoleCards::from_bytes", bytes, || {
+        Some(valid_hole_cards(HoleCards::from_bytes(bytes), |e| {
+            decode_error(bytes, e)
+        }))
+    })?;
+    check("Board::from_bytes", bytes, || {
+        Some(valid_board(Board::from_bytes(bytes), |e| match *e {
+            BoardError::InvalidLength { expected, found } => {
+                found == bytes.len() && expected != found
+            }
+            BoardError::InvalidCardCount(count) => count + 1 == bytes.len(),
+            BoardError::InvalidByte { index, byte } => {
+                bytes.get(index + 1) == Some(&byte) && Card::from_byte(byte).is_err()
+            }
+            BoardError::DuplicateCard(card) => repeated(&bytes[1..], &card.to_byte()),
+            _ => false,
+        }))
+    })
+}
+
+fn fuzz_cards(cards: &[Card]) -> Result<(), FuzzFailure> {
+    check("Hand::new", cards, || {
+        Some(valid_hand(Hand::new(cards.to_vec()), |e| {
+            count_error(cards, e)
+        }))
+    })?;
+    check("Hand::add_cards", cards, || {
+        let (first, rest) = cards.split_at(cards.len().min(2));
+        let mut hand = Hand::new(first.to_vec()).ok()?;
+        let added = hand.add_cards(rest.to_vec()).map(|_| hand);
+        Some(valid_hand(added, |e| match *e {
+            HandError::TooManyCards { count, adding, max } => count + adding > max,
+            HandError::DuplicateCard(card) => repeated(cards, &card),
+            _ => false,
+        }))
+    })?;
+    check("HandBuilder::cards", cards, || {
+        let built = HandBuilder::new().cards(cards).build();
+        Some(valid_hand(built, |e| count_error(cards, e)))
+    })?;
+    check("FixedHand::new", cards, || {
+        let cards: [Card; 5] = cards.try_into().ok()?;
+        let fixed = FixedHand::new(cards).map(Hand::from);
+        Some(valid_hand(fixed, |e| count_error(&cards, e)))
+    })?;
+    check("Board::try_from", cards, || {
+        Some(valid_board(Board::try_from(cards), |e| match *e {
+            BoardError::InvalidCardCount(count) => count == cards.len(),
+            BoardError::DuplicateCard(card) => repeated(cards, &card),
+            _ => false,
+        }))
+    })?;
+    check("Deck::claim_hand", cards, || {
+        let hand = Hand::new(cards.to_vec()).ok()?;
+        let mut deck = Deck::new();
+        deck.claim_hand(&hand)
+            .err()
+            .map(|e| Some(format!("A new deck did not hand out {}: {}", hand, e)))
+    })?;
+    check("Evaluator::score", cards, || {
+        let hand = Hand::new(cards.to_vec()).ok()?;
+        let score = FastEvaluator.score(hand.as_slice());
+        (score != hand.get_score()).then(|| {
+            Some(format!(
+                "FastEvaluator scored {} instead of {}",
+                score,
+                hand.get_score()
+            ))
+        })
+    })?;
+    check("find_flush_unsorted", cards, || {
+        find_flush_unsorted(cards);
+        None
+    })
+}
+
+fn fuzz_ranks(ranks: &[Rank]) -> Result<(), FuzzFailure> {
+    check("detectors", ranks, || {
+        find_pair_unsorted(ranks);
+        find_two_pair_unsorted(ranks);
+        find_three_of_a_kind_unsorted(ranks);
+        find_straight_unsorted(ranks);
+        find_full_house_unsorted(ranks);
+        find_four_of_a_kind_unsorted(ranks);
+        None
+    })
+}
+
+fn fuzz_numbers(num: usize) -> Result<(), FuzzFailure> {
+    check("Rank::new_from_num", num, || {
+        Rank::new_from_num(num).err().map(|_| None)
+    })?;
+    check("Suit::new_from_num", num, || {
+        Suit::new_from_num(num).err().map(|_| None)
+    })
+}
+
+/// Runs the call, turning a panic or a violated guarantee into a
+/// `FuzzFailure`.
+///
+/// The call returns `Some(Some(message))` for a violated guarantee and
+/// `Some(None)` or `None` if the input was handled correctly.
+fn check(
+    api: &'static str,
+    input: impl fmt::Debug,
+    call: impl FnOnce() -> Option<Option<String>>,
+) -> Result<(), FuzzFailure> {
+    let message = match catch_unwind(AssertUnwindSafe(call)) {
+        Ok(violation) => violation.flatten(),
+        Err(payload) => Some(
+            payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Panicked".to_string()),
+        ),
+    };
+    match message {
+        Some(message) => Err(FuzzFailure {
+            api,
+            input: format!("{:?}", input),
+            message,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Returns a violation if an error has no message or does not match the
+/// input, as decided by the API specific `explained`.
+fn valid_error<E: fmt::Debug + fmt::Display>(error: &E, explained: bool) -> Option<String> {
+    if error.to_string().trim().is_empty() {
+        Some("The error has no message".to_string())
+    } else if !explained {
+        Some(format!("The error {:?} does not match the input", error))
+    } else {
+        None
+    }
+}
+
+/// Returns whether the error is one a parser of card strings returns.
+fn parse_error(error: &HandError) -> bool {
+    matches!(
+        error,
+        HandError::InvalidCard { .. }
+            | HandError::DuplicateCard(_)
+            | HandError::InvalidCardCount { .. }
+    )
+}
+
+/// Returns whether the error names what is wrong with the encoded cards.
+fn decode_error(bytes: &[u8], error: &HandError) -> bool {
+    match *error {
+        HandError::InvalidLength { expected, found } => found == bytes.len() && expected != found,
+        HandError::InvalidCard { index, .. } => bytes
+            .get(index + 1)
+            .is_some_and(|&byte| Card::from_byte(byte).is_err()),
+        HandError::InvalidCardCount { found, .. } => found + 1 == bytes.len(),
+        HandError::DuplicateCard(card) => repeated(&bytes[1..], &card.to_byte()),
+        _ => false,
+    }
+}
+
+/// Returns whether the error names what is wrong with the number of cards
+/// or a repeated card.
+fn count_error(cards: &[Card], error: &HandError) -> bool {
+    match *error {
+        HandError::InvalidCardCount { min, max, found } => {
+            found == cards.len() && !(min..=max).contains(&found)
+        }
+        HandError::DuplicateCard(card) => repeated(cards, &card),
+        _ => false,
+    }
+}
+
+/// Returns whether the item appears more than once.
+fn repeated<T: PartialEq>(items: &[T], item: &T) -> bool {
+    items.iter().filter(|other| *other == item).count() > 1
+}
+
+/// Returns a violation if the cards are not distinct.
+fn distinct(cards: &[Card]) -> Option<String> {
+    cards.iter().enumerate().find_map(|(i, card)| {
+        cards[..i]
+            .contains(card)
+            .then(|| format!("Accepted the duplicate card {}", card.as_str()))
+    })
+}
+
+fn valid_hand<E: fmt::Debug + fmt::Display>(
+    hand: Result<Hand, E>,
+    explained: impl FnOnce(&E) -> bool,
+) -> Option<String> {
+    match hand {
+        Ok(hand) if !(Hand::MIN_CARDS..=Hand::MAX_CARDS).contains(&hand.get_count()) => {
+            Some(format!("Accepted a hand of {} cards", hand.get_count()))
+        }
+        Ok(hand) => distinct(hand.as_slice()),
+        Err(e) => valid_error(&e, explained(&e)),
+    }
+}
+
+fn valid_hole_cards(
+    hole_cards: Result<HoleCards, HandError>,
+    explained: impl FnOnce(&HandError) -> bool,
+) -> Option<String> {
+    match hole_cards {
+        Ok(hole_cards) => distinct(&hole_cards.get_cards()),
+        Err(e) => valid_error(&e, explained(&e)),
+    }
+}
+
+fn valid_board(
+    board: Result<Board, BoardError>,
+    explained: impl FnOnce(&BoardError) -> bool,
+) -> Option<String> {
+    match board {
+        Ok(board) if ![0, 3, 4, 5].contains(&board.cards().len()) => {
+            Some(format!("Accepted a board of {} cards", board.cards().len()))
+        }
+        Ok(board) => distinct(board.cards()),
+        Err(e) => valid_error(&e, explained(&e)),
+    }
+}
+
+fn valid_range(
+    range: Result<Range, RangeError>,
+    explained: impl FnOnce(&RangeError) -> bool,
+) -> Option<String> {
+    match range {
+        Ok(range) => range.get_combos().iter().find_map(|(combo, weight)| {
+            distinct(combo).or_else(|| {
+                (!(0.0..=1.0).contains(weight)).then(|| format!("Accepted the weight {}", weight))
+            })
+        }),
+        Err(e) => valid_error(&e, explained(&e)),
+    }
+}
+
+/// Returns up to 12 bytes, mostly around the valid card bytes and lengths.
+fn random_bytes(rng: &mut StdRng) -> Vec<u8> {
+    let len = rng.gen_range(0..12);
+    (0..len)
+        .map(|_| {
+            if rng.gen_bool(0.8) {
+                rng.gen_range(0..60)
+            } else {
+                rng.gen()
+            }
+        })
+        .collect()
+}
+
+/// Returns up to 30 characters of card notation, separators and others.
+fn random_text(rng: &mut StdRng) -> String {
+    const CHARS: &[char] = &[
+        'A', 'K', 'Q', 'J', 'T', '9', '5', '2', '1', '0', 'a', 'k', 's', 'h', 'd', 'c', 'x', 'o',
+        '+', '-', ':', '.', ',', '|', '[', ']', ' ', '\t', '\n', '\u{2660}', '\u{e9}', '\0',
+    ];
+    let len = rng.gen_range(0..30);
+    (0..len)
+        .map(|_| *CHARS.choose(rng).expect("The characters are not empty"))
+        .collect()
+}
+
+/// Returns up to 12 card tokens, mostly valid ones with some duplicates,
+/// joined by random separators.
+fn random_card_string(rng: &mut StdRng) -> String {
+    const INVALID: &[&str] = &["", "A", "1s", "10", "Ax", "AsK", "ss", "\u{2660}A", "Zz"];
+    const SEPARATORS: &[&str] = &[" ", "", ",", ", ", "  ", "|", "\t"];
+    let deck = Deck::new();
+    let mut tokens: Vec<String> = Vec::new();
+    for _ in 0..rng.gen_range(0..12) {
+        let token = match rng.gen_range(0..10) {
+            0 => INVALID
+                .choose(rng)
+                .expect("The tokens are not empty")
+                .to_string(),
+            1 if !tokens.is_empty() => tokens.choose(rng).expect("Tokens exist").clone(),
+            _ => deck
+                .get_cards()
+                .choose(rng)
+                .expect("The deck is full")
+                .as_str(),
+        };
+        tokens.push(token);
+    }
+    let separator = SEPARATORS
+        .choose(rng)
+        .expect("The separators are not empty");
+    tokens.join(separator)
+}
+
+/// Returns up to 12 cards, some of them more than once.
+fn random_cards(rng: &mut StdRng) -> Vec<Card> {
+    let deck = Deck::new();
+    let len = rng.gen_range(0..12);
+    let mut cards: Vec<Card> = deck
+        .get_cards()
+        .choose_multiple(rng, len)
+        .copied()
+        .collect();
+    if len > 1 && rng.gen_bool(0.3) {
+        cards[len - 1] = cards[0];
+    }
+    cards
+}
+
+/// The corpus of `check_golden`, a record per hand of its encoding by
+/// `Hand::to_bytes` followed by its score as a big endian `u32`.
+///
+/// It holds 2,400 random hands of every size and 64 random hands of five to
+/// seven cards of every category, generated once and never regenerated.
+const GOLDEN_SCORES: &[u8] = include_bytes!("golden_scores.bin");
+
+/// A hand of the golden corpus whose score changed, found by
+/// `check_golden`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The hand of the corpus.
+    pub hand: Hand,
+    /// The score stored in the corpus.
+    pub expected: u32,
+    /// The score calculated now.
+    pub actual: u32,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "The score of {} changed from {} to {}.",
+            self.hand, self.expected, self.actual
+        )
+    }
+}
+
+impl Error for Mismatch {}
+
+/// Checks that the scores of the evaluator did not change, by evaluating an
+/// embedded corpus of hands with their scores of earlier versions.
+///
+/// The score encoding is a stability guarantee of the crate, see
+/// `hand::evaluate`, so scores persisted e.g. in a database stay comparable
+/// with scores of later versions. The corpus holds a few thousand hands of
+/// every size and category.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::verify::check_golden;
+///
+/// assert_eq!(check_golden(), Ok(()));
+/// ```
+///
+/// # Errors
+///
+/// Returns every hand whose score changed as a `Mismatch`.
+pub fn check_golden() -> Result<(), Vec<Mismatch>> {
+    check_golden_with(Hand::get_score)
+}
+
+/// Checks the scores of a custom scoring function against the golden
+/// corpus, see `check_golden`.
+///
+/// # Examples
+///
+/// ```
+/// use pkr::hand::{FastEvaluator, Hand};
+/// use pkr::verify::check_golden_with;
+///
+/// assert_eq!(check_golden_with(|hand: &Hand| hand.get_score_with(&FastEvaluator)), Ok(()));
+/// ```
+///
+/// # Errors
+///
+/// Returns every hand whose score differs from the corpus as a `Mismatch`.
+pub fn check_golden_with(score: impl Fn(&Hand) -> u32) -> Result<(), Vec<Mismatch>> {
+    let mismatches: Vec<Mismatch> = golden_corpus()
+        .filter_map(|(hand, expected)| {
+            let actual = score(&hand);
+            (actual != expected).then_some(Mismatch {
+                hand,
+                expected,
+                actual,
+            })
+        })
+        .collect();
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(mismatches)
+    }
+}
+
+/// Decodes the hands of the golden corpus with their scores.
+fn golden_corpus() -> impl Iterator<Item = (Hand, u32)> {
+    let mut bytes = GOLDEN_SCORES;
+    core::iter::from_fn(move || {
+        let (&len, _) = bytes.split_first()?;
+        let (hand, rest) = bytes.split_at(len as usize + 1);
+        let (score, rest) = rest.split_at(4);
+        bytes = rest;
+        let hand = Hand::from_bytes(hand).expect("The golden corpus holds valid hands");
+        let score = u32::from_be_bytes(score.try_into().expect("A score has four bytes"));
+        Some((hand, score))
+    })
+}
+
+/// Replaces the suit of every card by the suit at its index in `permutation`.
+fn relabel_suits(hand: &Hand, permutation: &[Suit; 4]) -> Hand {
+    let cards = hand
+        .as_slice()
+        .iter()
+        .map(|card| Card::new(card.rank, permutation[card.suit.to_index()]))
+        .collect();
+    Hand::new(cards).expect("Relabeling keeps the cards distinct")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand::HandRank;
+
+    #[test]
+    fn test_suit_invariance() {
+        assert_eq!(suit_invariance(20_000, 165), Ok(()));
+    }
+
+    #[test]
+    fn test_flushes_tie_across_suits() {
+        let score = |s| Hand::new_from_str(s).unwrap().get_score();
+        let spades = score("As Js 9s 6s 3s");
+        assert_eq!(HandRank::from_score(spades), HandRank::Flush);
+        for other in ["Ah Jh 9h 6h 3h", "Ad Jd 9d 6d 3d", "Ac Jc 9c 6c 3c"] {
+            assert_eq!(score(other), spades);
+        }
+        assert_eq!(score("As Ks Qs Js Ts 2c"), score("Ah Kh Qh Jh Th 2d"));
+        assert_eq!(score("Ks 9s 7s 4s 2s Kh Kd"), score("Kh 9h 7h 4h 2h Ks Kc"));
+    }
+
+    #[test]
+    fn test_golden_corpus() {
+        assert_eq!(check_golden(), Ok(()));
+
+        let hands: Vec<(Hand, u32)> = golden_corpus().collect();
+        assert_eq!(hands.len(), 2976);
+        for hand_rank in [HandRank::HighCard, HandRank::StraightFlush] {
+            assert!(hands
+                .iter()
+                .any(|(hand, _)| hand.get_hand_rank() == hand_rank));
+        }
+        for n in Hand::MIN_CARDS..=Hand::MAX_CARDS {
+            assert!(hands.iter().any(|(hand, _)| hand.as_slice().len() == n));
+        }
+    }
+
+    #[test]
+    fn test_golden_mismatch() {
+        // Scoring every flush as a straight breaks exactly the flushes.
+        let broken = |hand: &Hand| match hand.get_hand_rank() {
+            HandRank::Flush => HandRank::Straight as u32 + 14,
+            _ => hand.get_score(),
+        };
+        let mismatches = check_golden_with(broken).unwrap_err();
+        assert!(mismatches.len() >= 64);
+        for mismatch in &mismatches {
+            assert_eq!(HandRank::from_score(mismatch.expected), HandRank::Flush);
+            assert_eq!(mismatch.actual, 4_000_014);
+            assert_eq!(mismatch.expected, mismatch.hand.get_score());
+        }
+        assert!(mismatches[0].to_string().contains("changed from"));
+    }
+
+    #[test]
+    fn test_counterexample() {
+        let spades = |hand: &Hand| hand.cards_of_suit(Suit::Spade).len() as u32;
+        let counterexample = suit_invariance_with(1000, 1, spades).unwrap_err();
+        assert_ne!(counterexample.score, counterexample.permuted_score);
+        assert_eq!(
+            counterexample.hand.get_count(),
+            counterexample.permuted.get_count()
+        );
+        assert!(counterexample
+            .to_string()
+            .starts_with("Relabeling the suits of"));
+    }
+
+    #[test]
+    fn test_fuzz_inputs() {
+        assert_eq!(fuzz_inputs(300, 194), Ok(()));
+    }
+
+    #[test]
+    fn test_fuzz_failure() {
+        let failure = check("Rank::new_from_num", 99, || panic!("Broken")).unwrap_err();
+        assert_eq!(failure.input, "99");
+        assert_eq!(
+            failure.to_string(),
+            "Rank::new_from_num failed on 99: Broken"
+        );
+    }
+
+    #[test]
+    fn test_unexplained_error() {
+        let cards = [Card::new_from_str("As").unwrap()];
+        // A single card is too few, not a duplicate.
+        let duplicate = Err(HandError::DuplicateCard(cards[0]));
+        assert_eq!(
+            valid_hand(duplicate, |e| count_error(&cards, e)),
+            Some(
+                "The error DuplicateCard(Card { rank: Ace, suit: Spade }) does not match the input"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            valid_hand(Hand::new(cards.to_vec()), |e| count_error(&cards, e)),
+            None
+        );
+        assert!(!decode_error(
+            &[1, 60],
+            &HandError::InvalidLength {
+                expected: 2,
+                found: 2
+            }
+        ));
+        assert!(decode_error(
+            &[1, 60],
+            &Hand::from_bytes(&[1, 60]).unwrap_err()
+        ));
+    }
+
+    #[test]
+    fn test_relabel_suits() {
+        let hand = Hand::new_from_str("As Kh 2c").unwrap();
+        let permutation = [Suit::Diamond, Suit::Club, Suit::Spade, Suit::Heart];
+        assert_eq!(relabel_suits(&hand, &permutation).as_str(), "Ah Ks 2d");
+    }
+}
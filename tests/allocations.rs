@@ -0,0 +1,94 @@
+//! Checks that evaluating cards does not allocate, by counting the
+//! allocations of the current thread with a global allocator.
+#![cfg(feature = "std")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use pkr::board::Board;
+use pkr::card::Card;
+use pkr::hand::{evaluate_holdem, Evaluator, HoleCards, ReferenceEvaluator};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations `f` made on this thread.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn cards(s: &str) -> Vec<Card> {
+    s.split_whitespace()
+        .map(|card| Card::new_from_str(card).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_evaluate_cards_does_not_allocate() {
+    // One hand of every category, from two to nine cards.
+    let hands: Vec<Vec<Card>> = [
+        "Ah Kd",
+        "As Ks Qs Js Ts 9s 8s 2d 3c",
+        "7s 7h 7c 7d Ks 2s",
+        "Ks Kh Kd 7c 7d 2s 2h",
+        "Ks Qs Ts 8s 6s 6d 2c",
+        "9s 8h 7c 6d 5s",
+        "Ah 2d 3c 4s 5h Kd Kc",
+        "Qs Qh Qc 7d 2s",
+        "Qs Qh 7c 7d 2s 2c 9h Ad",
+        "Qs Qh 7c 4d",
+        "As Qh 7c 4d 2s 9d",
+    ]
+    .iter()
+    .map(|s| cards(s))
+    .collect();
+
+    let mut scores = Vec::with_capacity(hands.len());
+    let count = allocations(|| {
+        for hand in &hands {
+            scores.push(ReferenceEvaluator.score(hand));
+        }
+    });
+    assert_eq!(count, 0);
+    assert_eq!(scores.len(), hands.len());
+}
+
+#[test]
+fn test_evaluate_holdem_does_not_allocate() {
+    let hole = HoleCards::new_from_str("AhKh").unwrap();
+    let boards: Vec<Board> = ["", "Qh Jh 2c", "Qh Jh 2c 2d", "Qh Jh 2c 2d Th"]
+        .iter()
+        .map(|s| Board::new_from_str(s).unwrap())
+        .collect();
+
+    let count = allocations(|| {
+        for board in &boards {
+            evaluate_holdem(&hole, board);
+        }
+    });
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "std")]
+
+use std::process::{Command, Output};
+
+use serde_json::Value;
+
+fn pkr(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_pkr"))
+        .args(args)
+        .output()
+        .expect("Failed to run the pkr binary")
+}
+
+fn json(args: &[&str]) -> Value {
+    let output = pkr(args);
+    assert!(output.status.success());
+    serde_json::from_slice(&output.stdout).expect("Output must be valid JSON")
+}
+
+#[test]
+fn test_eval() {
+    let output = pkr(&["eval", "As Ks Qs Js Ts"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Score: 8000014"));
+    assert!(stdout.contains("Category: StraightFlush"));
+    assert!(stdout.contains("Description: Royal Flush"));
+
+    let value = json(&["eval", "As Ks Qs Js Ts", "--json"]);
+    assert_eq!(value["hand"], "As Ks Qs Js Ts");
+    assert_eq!(value["score"], 8_000_014);
+    assert_eq!(value["category"], "StraightFlush");
+    assert_eq!(value["description"], "Royal Flush");
+}
+
+#[test]
+fn test_compare() {
+    let value = json(&["compare", "Ah Ad 7c", "Kh Kd 7d", "--json"]);
+    assert_eq!(value["winner"], "first");
+    assert_eq!(value["first"]["description"], "One Pair, Aces");
+    assert_eq!(value["second"]["category"], "OnePair");
+
+    let output = pkr(&["compare", "2h 2d", "2c 2s"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .starts_with("Winner: tie"));
+}
+
+#[test]
+fn test_equity() {
+    let args = [
+        "equity", "AhKh", "9c9d", "--board", "Qs Js 2d", "--trials", "2000", "--seed", "42",
+        "--json",
+    ];
+    let value = json(&args);
+    assert_eq!(value["method"], "monte_carlo");
+    assert_eq!(value["seed"], 42);
+    assert_eq!(value["trials"], 2000);
+    assert_eq!(value["board"], "Qs Js 2d");
+
+    let players = value["players"].as_array().unwrap();
+    assert_eq!(players.len(), 2);
+    assert_eq!(players[0]["hand"], "AhKh");
+    let total: f64 = players.iter().map(|p| p["equity"].as_f64().unwrap()).sum();
+    assert!((total - 1.0).abs() < 1e-9);
+    assert_eq!(json(&args), value);
+
+    let value = json(&["equity", "AhKh", "9c9d", "--board", "Qs Js 2d 7c", "--json"]);
+    assert_eq!(value["method"], "exact");
+    assert_eq!(value["trials"], 44);
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(pkr(&[]).status.code(), Some(2));
+    assert_eq!(pkr(&["eval"]).status.code(), Some(2));
+    assert_eq!(pkr(&["eval", "As Ks", "--bogus"]).status.code(), Some(2));
+    assert_eq!(pkr(&["equity", "AhKh", "--trials"]).status.code(), Some(2));
+
+    let output = pkr(&["eval", "As Xx"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("error:"));
+    assert_eq!(
+        pkr(&["equity", "AhKh", "Ah9d", "--trials", "10"])
+            .status
+            .code(),
+        Some(1)
+    );
+}
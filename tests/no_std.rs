@@ -0,0 +1,61 @@
+//! Uses the core evaluator from a `#![no_std]` crate, run it with
+//! `cargo test --no-default-features` to check the library without std.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use pkr::card::{Card, CardError, Rank, Suit};
+use pkr::deck::Deck;
+use pkr::hand::{Hand, HandError, HandRank};
+use rand::rngs::mock::StepRng;
+
+#[test]
+fn test_evaluate_and_compare() {
+    let royal_flush = Hand::new_from_str("As Ks Qs Js Ts").unwrap();
+    assert_eq!(royal_flush.get_hand_rank(), HandRank::StraightFlush);
+
+    let full_house = Hand::new(vec![
+        Card::new(Rank::King, Suit::Spade),
+        Card::new(Rank::King, Suit::Diamond),
+        Card::new(Rank::King, Suit::Club),
+        Card::new(Rank::Two, Suit::Heart),
+        Card::new(Rank::Two, Suit::Spade),
+    ])
+    .unwrap();
+    assert_eq!(full_house.get_hand_rank(), HandRank::FullHouse);
+    assert!(royal_flush.get_score() > full_house.get_score());
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        Card::new_from_str("Xs"),
+        Err(CardError::InvalidRank("X".into()))
+    );
+    assert_eq!(
+        Hand::new_from_str("As").err(),
+        Some(HandError::InvalidCardCount {
+            min: 2,
+            max: 9,
+            found: 1
+        })
+    );
+    assert_eq!(
+        Hand::new_from_str("As Kx").err(),
+        Some(HandError::InvalidCard {
+            index: 1,
+            token: "Kx".into(),
+            source: CardError::InvalidSuit("x".into())
+        })
+    );
+}
+
+#[test]
+fn test_shuffle_with_external_rng() {
+    let mut deck = Deck::new();
+    deck.shuffle_with(&mut StepRng::new(0, 1));
+    assert_eq!(deck.get_cards().len(), 52);
+    assert!(deck.deal().is_some());
+}
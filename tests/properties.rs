@@ -0,0 +1,29 @@
+#![cfg(feature = "test-support")]
+
+use pkr::hand::Hand;
+use pkr::test_support::{
+    arb_board, arb_card, arb_hand, arb_hole_cards, assert_score_monotone_under_card_addition,
+    assert_suit_invariance,
+};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn score_is_monotone_under_card_addition(hand in arb_hand(2..=8), card in arb_card()) {
+        prop_assume!(!hand.contains(&card));
+        assert_score_monotone_under_card_addition(&hand, card);
+    }
+
+    #[test]
+    fn score_is_suit_invariant(hand in arb_hand(5..=7)) {
+        assert_suit_invariance(&hand);
+    }
+
+    #[test]
+    fn hole_cards_and_board_make_a_hand(hole_cards in arb_hole_cards(), board in arb_board()) {
+        prop_assume!(!board.cards().iter().any(|card| hole_cards.contains(card)));
+        let hand = Hand::from_hole_and_board(&hole_cards.get_cards(), board.cards()).unwrap();
+        prop_assert_eq!(hand.get_count(), 2 + board.cards().len());
+        assert_suit_invariance(&hand);
+    }
+}
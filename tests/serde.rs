@@ -0,0 +1,170 @@
+#![cfg(all(feature = "serde", feature = "std"))]
+
+use std::fs;
+
+use pkr::analysis::{blockers, EquityCurve};
+use pkr::board::Board;
+use pkr::equity::{equity_exact, equity_monte_carlo_with_report};
+use pkr::game::{report, HandRecord};
+use pkr::hand::{Hand, HandRank, HoleCards};
+use pkr::range::{Range, StartingHand};
+use pkr::showdown::{showdown, PlayerId};
+
+/// Compares the pretty printed JSON of a value with the golden file of the
+/// same name in `tests/golden`.
+fn assert_golden<T: serde::Serialize>(name: &str, value: &T) {
+    let path = format!("{}/tests/golden/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+    let expected = fs::read_to_string(&path).expect("Golden file must exist");
+    let actual = serde_json::to_string_pretty(value).unwrap();
+    assert_eq!(actual.trim_end(), expected.trim_end(), "{} changed", path);
+}
+
+fn hole(s: &str) -> [pkr::card::Card; 2] {
+    HoleCards::new_from_str(s).unwrap().get_cards()
+}
+
+#[test]
+fn test_hand_value_schema() {
+    let value = Hand::new_from_str("Ks Kd Kc 2h 2s").unwrap().get_value();
+    assert_golden("hand_value", &value);
+}
+
+#[test]
+fn test_equity_schema() {
+    let hands = [
+        HoleCards::new_from_str("AhKh").unwrap(),
+        HoleCards::new_from_str("9c9d").unwrap(),
+    ];
+    let board = Board::new_from_str("Qs Js 2d 7c").unwrap();
+    let equities = equity_exact(&hands, &board).unwrap();
+    assert_golden("equity", &equities[0]);
+}
+
+#[test]
+fn test_showdown_result_schema() {
+    let board = Board::new_from_str("Kh 9h 5h 2c 3d").unwrap();
+    let players = [(PlayerId(0), hole("AhQh")), (PlayerId(1), hole("KsKd"))];
+    let result = showdown(board.cards(), &players).unwrap();
+    assert_golden("showdown_result", &result);
+}
+
+#[test]
+fn test_blocker_report_schema() {
+    let board = Board::new_from_str("Kh 9h 5h 2c 3d").unwrap();
+    let range = Range::new_from_str("AA, 99, AhQh").unwrap();
+    let report = blockers(hole("As9c"), &range, &board);
+    assert_golden("blocker_report", &report);
+}
+
+#[test]
+fn test_hand_report_schema() {
+    let record = HandRecord {
+        hole_cards: vec![hole("AhAd"), hole("KsKc"), hole("6h5h")],
+        board: Board::new_from_str("9h 2c 7d Kh 3h").unwrap(),
+        winners: Vec::new(),
+        values: Vec::new(),
+    };
+    assert_golden("hand_report", &report(&record, 301).unwrap());
+}
+
+#[test]
+fn test_hand_round_trip() {
+    let hand = Hand::new_from_str("As Ks Qs Js Ts").unwrap();
+
+    let json = serde_json::to_string(&hand).unwrap();
+    assert_eq!(json, "\"As Ks Qs Js Ts\"");
+    let parsed: Hand = serde_json::from_str(&json).unwrap();
+    assert!(parsed.eq_ordered(&hand));
+
+    let parsed: Hand = serde_json::from_str("\"[AsKs,Qs Js Ts]\"").unwrap();
+    assert!(parsed == hand);
+
+    let bytes = bincode::serialize(&hand).unwrap();
+    // An eight byte length prefix followed by one byte per card.
+    assert_eq!(bytes.len(), 8 + 5);
+    assert_eq!(bytes[8], 51);
+    let parsed: Hand = bincode::deserialize(&bytes).unwrap();
+    assert!(parsed.eq_ordered(&hand));
+}
+
+#[test]
+fn test_invalid_hands_fail_to_deserialize() {
+    let error = serde_json::from_str::<Hand>("\"As As Ks\"").err().unwrap();
+    assert!(error.to_string().contains("Duplicate card: As"));
+    assert!(serde_json::from_str::<Hand>("\"As\"").is_err());
+    assert!(serde_json::from_str::<Hand>("\"As Kx\"").is_err());
+    assert!(serde_json::from_str::<Hand>("[51, 50]").is_err());
+
+    let duplicate = bincode::serialize(&vec![51u8, 51]).unwrap();
+    assert!(bincode::deserialize::<Hand>(&duplicate).is_err());
+    let invalid = bincode::serialize(&vec![51u8, 52]).unwrap();
+    let error = bincode::deserialize::<Hand>(&invalid).err().unwrap();
+    assert!(error.to_string().contains("Invalid card byte: 52"));
+}
+
+#[test]
+fn test_simulation_report_serializes() {
+    let hands = [
+        HoleCards::new_from_str("AhKh").unwrap(),
+        HoleCards::new_from_str("9c9d").unwrap(),
+    ];
+    let (_, report) = equity_monte_carlo_with_report(&hands, &Board::Preflop, 2000, 3).unwrap();
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["trials"], 2000);
+    assert_eq!(json["seed"], 3);
+    assert_eq!(json["batch_means"].as_array().unwrap().len(), 10);
+    assert_eq!(json["r_hat"].as_array().unwrap().len(), 2);
+    assert!(json["wall_time"]["nanos"].is_u64());
+    assert_eq!(json["suspicious"], false);
+}
+
+#[test]
+fn test_equity_curve_serializes() {
+    let curve = EquityCurve {
+        preflop: 0.8,
+        flop: 0.9,
+        turn: 0.95,
+        river: 0.0,
+    };
+    let json = serde_json::to_value(curve).unwrap();
+    assert_eq!(json["flop"], 0.9);
+    assert_eq!(json["river"], 0.0);
+}
+
+#[test]
+fn test_starting_hand_ids() {
+    let ids: Vec<(u8, StartingHand)> = (0..=u8::MAX)
+        .filter_map(|id| Some((id, StartingHand::from_id(id)?)))
+        .collect();
+    assert_golden("starting_hand_ids", &ids);
+
+    let hand = StartingHand::new_from_str("T9s").unwrap();
+    assert_eq!(serde_json::to_string(&hand).unwrap(), "\"T9s\"");
+    let bytes = bincode::serialize(&hand).unwrap();
+    assert_eq!(bytes, [hand.id()]);
+    assert_eq!(bincode::deserialize::<StartingHand>(&bytes).unwrap(), hand);
+    assert!(bincode::deserialize::<StartingHand>(&[169]).is_err());
+    assert!(serde_json::from_str::<StartingHand>("\"T9\"").is_err());
+}
+
+#[test]
+fn test_hand_rank_ids() {
+    let ids: Vec<(u8, HandRank)> = (0..=u8::MAX)
+        .filter_map(|id| Some((id, HandRank::from_id(id)?)))
+        .collect();
+    assert_golden("hand_rank_ids", &ids);
+
+    let json = serde_json::to_string(&HandRank::FullHouse).unwrap();
+    assert_eq!(json, "\"FullHouse\"");
+    assert_eq!(
+        serde_json::from_str::<HandRank>(&json).unwrap(),
+        HandRank::FullHouse
+    );
+    let bytes = bincode::serialize(&HandRank::FullHouse).unwrap();
+    assert_eq!(bytes, [6]);
+    assert_eq!(
+        bincode::deserialize::<HandRank>(&bytes).unwrap(),
+        HandRank::FullHouse
+    );
+    assert!(bincode::deserialize::<HandRank>(&[9]).is_err());
+}
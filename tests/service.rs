@@ -0,0 +1,60 @@
+#![cfg(feature = "service")]
+
+use std::fs;
+
+use pkr::service::{handle, Request};
+
+/// Deserializes the request, answers it and compares the pretty printed
+/// JSON of the response with the golden file of the same name in
+/// `tests/golden`.
+fn assert_golden_response(name: &str, request: &str) {
+    let request: Request = serde_json::from_str(request).unwrap();
+    let response = handle(request);
+    let path = format!("{}/tests/golden/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+    let expected = fs::read_to_string(&path).expect("Golden file must exist");
+    let actual = serde_json::to_string_pretty(&response).unwrap();
+    assert_eq!(actual.trim_end(), expected.trim_end(), "{} changed", path);
+}
+
+#[test]
+fn test_eval() {
+    assert_golden_response(
+        "service_eval",
+        r#"{"type": "eval", "hand": "Ks Kd Kc 2h 2s"}"#,
+    );
+}
+
+#[test]
+fn test_equity_against_hole_cards() {
+    assert_golden_response(
+        "service_equity",
+        r#"{"type": "equity", "hero": "AhKh", "villain_or_range": "9c9d", "board": "Qs Js 2d 7c"}"#,
+    );
+}
+
+#[test]
+fn test_equity_against_range() {
+    assert_golden_response(
+        "service_range_equity",
+        r#"{"type": "equity", "hero": "AhKh", "villain_or_range": "QQ+, AK", "trials": 2000, "seed": 7}"#,
+    );
+}
+
+#[test]
+fn test_validation_failures() {
+    assert_golden_response(
+        "service_invalid_hand",
+        r#"{"type": "eval", "hand": "As Kx"}"#,
+    );
+    assert_golden_response(
+        "service_trials_required",
+        r#"{"type": "equity", "hero": "AhKh", "villain_or_range": "QQ+"}"#,
+    );
+}
+
+#[test]
+fn test_request_round_trip() {
+    let json = r#"{"type":"equity","hero":"AhKh","villain_or_range":"9c9d","board":"","trials":100,"seed":null}"#;
+    let request: Request = serde_json::from_str(json).unwrap();
+    assert_eq!(serde_json::to_string(&request).unwrap(), json);
+}